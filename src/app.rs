@@ -1,27 +1,104 @@
 use crate::k8s::{
-    self, ConfigMapInfo, CronJobInfo, DeploymentInfo, IngressInfo, JobInfo, K8sClient, PodInfo,
-    SecretInfo, ServiceInfo,
+    self, ConfigMapInfo, CronJobInfo, CustomResourceKind, DaemonSetInfo, DynamicObjectInfo, DeploymentInfo, EventInfo, IngressInfo,
+    JobInfo, K8sClient, NodeInfo, PodInfo, ReplicaSetInfo, SecretInfo, ServiceInfo,
+    StatefulSetInfo,
 };
 use crate::views::{
-    ConfigView, CronJobsView, DeploymentsView, JobsView, PodsView, ServicesView,
+    confirm_dialog, truncate_string, ConfigView, CronJobsView, CustomResourcesView, DaemonSetsView, DeploymentsView, EventsView,
+    JobsView, NodesView, PodsView, PortForwardsView, ReplicaSetsView, ServicesView,
+    StatefulSetsView, TopologyView, AuditView,
     cronjobs::CronJobAction, deployments::DeploymentAction, jobs::JobAction, pods::PodAction,
-    config::ConfigAction,
+    nodes::NodeAction, statefulsets::StatefulSetAction, daemonsets::DaemonSetAction,
+    replicasets::ReplicaSetAction, custom::CustomResourceAction,
+    config::ConfigAction, portforwards::PortForwardAction, services::ServiceAction,
+    events::ObjectEvents, yaml::{YamlAction, YamlEditor},
+    palette::{CommandPalette, PaletteCommand, PaletteEntry},
+    selector::{label_chips, SelectorBar},
+    columns::{TableColumns, TableLayout},
+    bulk::BulkSummary,
+    safety::{self, ContextProfile, GuardedAction, UnconfirmedChange},
 };
+use crate::audit::{self, AuditEntry, PendingAudit};
+use crate::settings::{Settings, WindowGeometry};
 use eframe::egui;
 use egui::{Color32, RichText};
+use serde::{Deserialize, Serialize};
+use serde_json::json;
+use std::collections::{HashMap, HashSet};
+use std::path::PathBuf;
 use std::sync::mpsc::{channel, Receiver, Sender};
 use std::sync::Arc;
 use tokio::runtime::Runtime;
+use tokio::sync::mpsc::{unbounded_channel, UnboundedSender};
+use tokio::task::JoinHandle;
 
-#[derive(Clone, Copy, PartialEq, Default)]
+/// How often usage is re-read from metrics-server, which itself scrapes every ~15s.
+const METRICS_POLL_INTERVAL: std::time::Duration = std::time::Duration::from_secs(15);
+
+/// Minimum time between settings writes, so resizing the window does not write every frame.
+const SETTINGS_SAVE_INTERVAL: std::time::Duration = std::time::Duration::from_secs(2);
+
+/// Most recent audit log entries shown in the audit view.
+const AUDIT_HISTORY_LIMIT: usize = 1000;
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq, PartialOrd, Ord, Default, Serialize, Deserialize)]
 pub enum View {
     #[default]
     Deployments,
+    StatefulSets,
+    DaemonSets,
+    ReplicaSets,
     Pods,
     Services,
     Config,
     Jobs,
     CronJobs,
+    PortForwards,
+    Nodes,
+    Events,
+    Topology,
+    CustomResources,
+    Audit,
+}
+
+impl View {
+    pub const ALL: [View; 15] = [
+        View::Deployments,
+        View::StatefulSets,
+        View::DaemonSets,
+        View::ReplicaSets,
+        View::Pods,
+        View::Services,
+        View::Config,
+        View::Jobs,
+        View::CronJobs,
+        View::PortForwards,
+        View::Nodes,
+        View::Events,
+        View::Topology,
+        View::CustomResources,
+        View::Audit,
+    ];
+
+    pub fn label(self) -> &'static str {
+        match self {
+            View::Deployments => "Deployments",
+            View::StatefulSets => "StatefulSets",
+            View::DaemonSets => "DaemonSets",
+            View::ReplicaSets => "ReplicaSets",
+            View::Pods => "Pods",
+            View::Services => "Services & Ingresses",
+            View::Config => "ConfigMaps & Secrets",
+            View::Jobs => "Jobs",
+            View::CronJobs => "CronJobs",
+            View::PortForwards => "Port Forwards",
+            View::Nodes => "Nodes",
+            View::Events => "Events",
+            View::Topology => "Topology",
+            View::CustomResources => "Custom Resources",
+            View::Audit => "Audit Log",
+        }
+    }
 }
 
 pub struct KubeDashboard {
@@ -39,6 +116,9 @@ pub struct KubeDashboard {
 
     // Data
     deployments: Vec<DeploymentInfo>,
+    statefulsets: Vec<StatefulSetInfo>,
+    daemonsets: Vec<DaemonSetInfo>,
+    replicasets: Vec<ReplicaSetInfo>,
     pods: Vec<PodInfo>,
     services: Vec<ServiceInfo>,
     ingresses: Vec<IngressInfo>,
@@ -46,30 +126,92 @@ pub struct KubeDashboard {
     secrets: Vec<SecretInfo>,
     jobs: Vec<JobInfo>,
     cronjobs: Vec<CronJobInfo>,
+    events: Vec<EventInfo>,
+    nodes: Vec<NodeInfo>,
+    pod_usage: HashMap<(String, String), k8s::UsageHistory>,
+    node_usage: HashMap<String, k8s::UsageHistory>,
+    // Usage columns stay hidden until metrics-server answers
+    metrics_available: bool,
+    custom_kinds: Vec<CustomResourceKind>,
+    custom_objects: Vec<DynamicObjectInfo>,
+    topology: k8s::Topology,
 
     // Loading states
     loading_deployments: bool,
+    loading_statefulsets: bool,
+    loading_daemonsets: bool,
+    loading_replicasets: bool,
     loading_pods: bool,
     loading_services: bool,
     loading_config: bool,
     loading_jobs: bool,
     loading_cronjobs: bool,
+    loading_events: bool,
+    loading_nodes: bool,
+    loading_custom_kinds: bool,
+    loading_custom_objects: bool,
+    loading_topology: bool,
 
     // Errors
     error_deployments: Option<String>,
+    error_statefulsets: Option<String>,
+    error_daemonsets: Option<String>,
+    error_replicasets: Option<String>,
     error_pods: Option<String>,
     error_services: Option<String>,
     error_config: Option<String>,
     error_jobs: Option<String>,
     error_cronjobs: Option<String>,
+    error_events: Option<String>,
+    error_nodes: Option<String>,
+    error_custom_kinds: Option<String>,
+    error_custom_objects: Option<String>,
+    error_topology: Option<String>,
 
     // Views
     deployments_view: DeploymentsView,
+    statefulsets_view: StatefulSetsView,
+    daemonsets_view: DaemonSetsView,
+    replicasets_view: ReplicaSetsView,
     pods_view: PodsView,
     services_view: ServicesView,
     config_view: ConfigView,
     jobs_view: JobsView,
     cronjobs_view: CronJobsView,
+    portforwards_view: PortForwardsView,
+    events_view: EventsView,
+    nodes_view: NodesView,
+    custom_view: CustomResourcesView,
+    topology_view: TopologyView,
+    audit_view: AuditView,
+    palette: CommandPalette,
+
+    // Background list/watch tasks for the current view
+    live_updates: bool,
+    watch_tasks: Vec<JoinHandle<()>>,
+
+    // Followed log stream; lines from older streams are dropped by id
+    log_follow_task: Option<JoinHandle<()>>,
+    log_stream_id: u64,
+
+    // Interactive exec session; output from older sessions is dropped by id
+    exec_input: Option<UnboundedSender<k8s::ExecInput>>,
+    exec_session_id: u64,
+
+    // Port forwards
+    port_forwards: Vec<k8s::PortForwardInfo>,
+    port_forward_tasks: HashMap<u64, JoinHandle<()>>,
+    next_port_forward_id: u64,
+
+    // Node drain; progress from cancelled drains is dropped by id
+    drain_task: Option<JoinHandle<()>>,
+    drain_id: u64,
+
+    // Followed deployment rollout; updates from older watches are dropped by id
+    rollout_task: Option<JoinHandle<()>>,
+    rollout_id: u64,
+    // Deployment whose revision history is reloaded once the followed rollout settles
+    history_after_rollout: Option<k8s::ResourceRef>,
 
     // Message channels
     message_tx: Sender<AppMessage>,
@@ -77,9 +219,29 @@ pub struct KubeDashboard {
 
     // Notifications
     notifications: Vec<Notification>,
+    // Per-object results of the last bulk action, until the window is closed
+    bulk_summary: Option<BulkSummary>,
+    // A view's change waiting for its target's name to be typed, in a protected context
+    held_change: Option<HeldChange>,
+
+    // Changes made from the dashboard; `None` path when there is nowhere to record them
+    audit_log_path: Option<PathBuf>,
+    audit_entries: Vec<AuditEntry>,
 
     // Theme
     dark_mode: bool,
+
+    // Persisted preferences: the current session and what was last written to disk.
+    // Nothing is written back until the saved session has been restored.
+    settings: Settings,
+    saved_settings: Settings,
+    settings_restored: bool,
+    settings_saved_at: std::time::Instant,
+}
+
+struct HeldChange {
+    change: UnconfirmedChange,
+    run: Box<dyn FnOnce(&mut KubeDashboard)>,
 }
 
 struct Notification {
@@ -94,6 +256,9 @@ enum AppMessage {
     NamespacesLoaded(Vec<String>),
     ContextSwitched(Result<(), String>),
     DeploymentsLoaded(Result<Vec<DeploymentInfo>, String>),
+    StatefulSetsLoaded(Result<Vec<StatefulSetInfo>, String>),
+    DaemonSetsLoaded(Result<Vec<DaemonSetInfo>, String>),
+    ReplicaSetsLoaded(Result<Vec<ReplicaSetInfo>, String>),
     PodsLoaded(Result<Vec<PodInfo>, String>),
     ServicesLoaded(Result<Vec<ServiceInfo>, String>),
     IngressesLoaded(Result<Vec<IngressInfo>, String>),
@@ -101,13 +266,44 @@ enum AppMessage {
     SecretsLoaded(Result<Vec<SecretInfo>, String>),
     JobsLoaded(Result<Vec<JobInfo>, String>),
     CronJobsLoaded(Result<Vec<CronJobInfo>, String>),
+    EventsLoaded(Result<Vec<EventInfo>, String>),
+    ObjectEventsLoaded(k8s::ResourceRef, Result<Vec<EventInfo>, String>),
+    DeploymentHistoryLoaded(k8s::ResourceRef, Result<Vec<k8s::RevisionInfo>, String>),
+    RolloutLoaded(k8s::ResourceRef, Result<Vec<k8s::RolloutPodStatus>, String>),
+    NodesLoaded(Result<Vec<NodeInfo>, String>),
+    PodMetricsLoaded(Option<Vec<k8s::PodUsage>>),
+    NodeMetricsLoaded(Option<Vec<k8s::NodeUsage>>),
+    ResourceKindsDiscovered(Result<Vec<CustomResourceKind>, String>),
+    CustomObjectsLoaded(CustomResourceKind, Result<Vec<DynamicObjectInfo>, String>),
+    CustomObjectYamlLoaded(String, Result<String, String>),
+    TopologyLoaded(Result<k8s::Topology, String>),
+    DrainProgress(u64, k8s::DrainPodStatus),
+    DrainFinished(u64, Result<(), String>),
+    DeploymentRolloutUpdate(u64, Result<k8s::DeploymentRolloutStatus, String>),
+    /// The followed rollout completed or got stuck, or the change starting it failed.
+    DeploymentRolloutEnded(u64),
     PodLogsLoaded(Result<String, String>),
+    PodLogLine(u64, String),
+    PodLogFollowEnded(u64, Result<(), String>),
+    ExecOutput(u64, Vec<u8>),
+    ExecEnded(u64, Result<(), String>),
+    PortForwardListening(u64, String, u16),
+    PortForwardFailed(u64, String),
+    YamlLoaded(k8s::ResourceRef, Result<String, String>),
+    YamlDiffed(k8s::ResourceRef, Result<(String, String), k8s::ApplyError>),
+    YamlApplied(k8s::ResourceRef, Result<(), k8s::ApplyError>),
+    SecretValueRevealed(k8s::ResourceRef, String, Result<String, String>),
+    SecretDataLoaded(k8s::ResourceRef, Result<k8s::SecretData, String>),
     CronJobHistoryLoaded(Result<Vec<JobInfo>, String>),
     ActionCompleted(Result<String, String>),
+    /// A bulk action finished: its title and the outcome per object.
+    BulkCompleted(String, Vec<k8s::BulkItemResult>),
+    /// A change to the cluster was answered and should be recorded.
+    Audited(AuditEntry),
 }
 
 impl KubeDashboard {
-    pub fn new(cc: &eframe::CreationContext<'_>) -> Self {
+    pub fn new(cc: &eframe::CreationContext<'_>, settings: Settings) -> Self {
         // Configure custom font (Berkeley Mono)
         let mut fonts = egui::FontDefinitions::default();
 
@@ -128,7 +324,7 @@ impl KubeDashboard {
                 if let Ok(font_data) = std::fs::read(font_path) {
                     fonts.font_data.insert(
                         "berkeley_mono".to_owned(),
-                        egui::FontData::from_owned(font_data).into(),
+                        egui::FontData::from_owned(font_data),
                     );
 
                     // Set Berkeley Mono as the primary font for all text styles
@@ -155,7 +351,7 @@ impl KubeDashboard {
             if let Ok(bold_data) = std::fs::read(&bold_path) {
                 fonts.font_data.insert(
                     "berkeley_mono_bold".to_owned(),
-                    egui::FontData::from_owned(bold_data).into(),
+                    egui::FontData::from_owned(bold_data),
                 );
                 tracing::info!("Loaded Berkeley Mono Bold font");
             }
@@ -167,8 +363,11 @@ impl KubeDashboard {
 
         cc.egui_ctx.set_fonts(fonts);
 
-        // Set dark mode by default
-        cc.egui_ctx.set_visuals(egui::Visuals::dark());
+        cc.egui_ctx.set_visuals(if settings.dark_mode {
+            egui::Visuals::dark()
+        } else {
+            egui::Visuals::light()
+        });
 
         let runtime = Arc::new(Runtime::new().expect("Failed to create Tokio runtime"));
         let (message_tx, message_rx) = channel();
@@ -184,6 +383,9 @@ impl KubeDashboard {
             initialized: false,
             init_error: None,
             deployments: vec![],
+            statefulsets: vec![],
+            daemonsets: vec![],
+            replicasets: vec![],
             pods: vec![],
             services: vec![],
             ingresses: vec![],
@@ -191,34 +393,361 @@ impl KubeDashboard {
             secrets: vec![],
             jobs: vec![],
             cronjobs: vec![],
+            events: vec![],
+            nodes: vec![],
+            pod_usage: HashMap::new(),
+            node_usage: HashMap::new(),
+            metrics_available: false,
+            custom_kinds: vec![],
+            custom_objects: vec![],
+            topology: k8s::Topology::default(),
             loading_deployments: false,
+            loading_statefulsets: false,
+            loading_daemonsets: false,
+            loading_replicasets: false,
             loading_pods: false,
             loading_services: false,
             loading_config: false,
             loading_jobs: false,
             loading_cronjobs: false,
+            loading_events: false,
+            loading_nodes: false,
+            loading_custom_kinds: false,
+            loading_custom_objects: false,
+            loading_topology: false,
             error_deployments: None,
+            error_statefulsets: None,
+            error_daemonsets: None,
+            error_replicasets: None,
             error_pods: None,
             error_services: None,
             error_config: None,
             error_jobs: None,
             error_cronjobs: None,
+            error_events: None,
+            error_nodes: None,
+            error_custom_kinds: None,
+            error_custom_objects: None,
+            error_topology: None,
             deployments_view: DeploymentsView::default(),
+            statefulsets_view: StatefulSetsView::default(),
+            daemonsets_view: DaemonSetsView::default(),
+            replicasets_view: ReplicaSetsView::default(),
             pods_view: PodsView::default(),
             services_view: ServicesView::default(),
             config_view: ConfigView::default(),
             jobs_view: JobsView::default(),
             cronjobs_view: CronJobsView::default(),
+            portforwards_view: PortForwardsView,
+            events_view: EventsView::default(),
+            nodes_view: NodesView::default(),
+            custom_view: CustomResourcesView::default(),
+            topology_view: TopologyView::default(),
+            audit_view: AuditView::default(),
+            palette: CommandPalette::default(),
+            live_updates: true,
+            watch_tasks: vec![],
+            log_follow_task: None,
+            log_stream_id: 0,
+            exec_input: None,
+            exec_session_id: 0,
+            port_forwards: vec![],
+            port_forward_tasks: HashMap::new(),
+            next_port_forward_id: 0,
+            drain_task: None,
+            drain_id: 0,
+            rollout_task: None,
+            rollout_id: 0,
+            history_after_rollout: None,
             message_tx,
             message_rx,
             notifications: vec![],
-            dark_mode: true,
+            bulk_summary: None,
+            held_change: None,
+            audit_log_path: audit::log_path(),
+            audit_entries: vec![],
+            dark_mode: settings.dark_mode,
+            saved_settings: settings.clone(),
+            settings,
+            settings_restored: false,
+            settings_saved_at: std::time::Instant::now(),
         };
 
+        // Same bounds as the tail size input in the logs window
+        app.pods_view.tail_lines = app.settings.log_tail_lines.clamp(10, 10_000);
+        for (view, filter) in app.settings.search_filters.clone() {
+            if let Some(search) = app.search_filter_mut(view) {
+                *search = filter;
+            }
+        }
+        let layouts = app.settings.tables.clone();
+        for (id, columns) in app.table_columns_mut() {
+            if let Some(layout) = layouts.get(id) {
+                columns.layout = layout.clone();
+            }
+        }
+
         app.initialize();
         app
     }
 
+    /// Brings back the saved context, its namespace and the last view once the
+    /// kubeconfig has been read. A saved context that no longer exists is ignored.
+    fn restore_session(&mut self) {
+        self.current_view = self.settings.view;
+
+        let saved_context = self
+            .settings
+            .last_context
+            .clone()
+            .filter(|name| self.current_context.as_ref() != Some(name))
+            .filter(|name| self.contexts.iter().any(|c| &c.name == name));
+        self.settings_restored = true;
+
+        if let Some(name) = saved_context {
+            self.current_context = Some(name.clone());
+            // Refreshes the view once the new client is in place
+            self.switch_context(&name);
+            return;
+        }
+
+        if let Some(namespace) = self.current_context.as_deref().and_then(|c| self.settings.namespace_for(c)) {
+            self.selected_namespace = namespace;
+        }
+        self.refresh_current_view();
+    }
+
+    /// Everything the command palette can jump to: views, contexts, namespaces and
+    /// the resources already loaded, in that order.
+    fn palette_entries(&self) -> Vec<PaletteEntry> {
+        let mut entries: Vec<PaletteEntry> = View::ALL
+            .iter()
+            .map(|&view| PaletteEntry::new(view.label(), "View", PaletteCommand::ShowView(view)))
+            .collect();
+        for context in &self.contexts {
+            entries.push(PaletteEntry::new(&context.name, "Context", PaletteCommand::SwitchContext(context.name.clone())));
+        }
+        entries.push(PaletteEntry::new("All namespaces", "Namespace", PaletteCommand::SelectNamespace(None)));
+        for ns in &self.namespaces {
+            entries.push(PaletteEntry::new(ns, "Namespace", PaletteCommand::SelectNamespace(Some(ns.clone()))));
+        }
+
+        let mut resources = |view: View, kind: &'static str, namespace: &str, name: &str| {
+            let label = if namespace.is_empty() { name.to_string() } else { format!("{}/{}", namespace, name) };
+            entries.push(PaletteEntry::new(
+                label,
+                kind,
+                PaletteCommand::OpenResource(view, namespace.to_string(), name.to_string()),
+            ));
+        };
+        for d in &self.deployments {
+            resources(View::Deployments, "Deployment", &d.namespace, &d.name);
+        }
+        for s in &self.statefulsets {
+            resources(View::StatefulSets, "StatefulSet", &s.namespace, &s.name);
+        }
+        for d in &self.daemonsets {
+            resources(View::DaemonSets, "DaemonSet", &d.namespace, &d.name);
+        }
+        for r in &self.replicasets {
+            resources(View::ReplicaSets, "ReplicaSet", &r.namespace, &r.name);
+        }
+        for p in &self.pods {
+            resources(View::Pods, "Pod", &p.namespace, &p.name);
+        }
+        for s in &self.services {
+            resources(View::Services, "Service", &s.namespace, &s.name);
+        }
+        for j in &self.jobs {
+            resources(View::Jobs, "Job", &j.namespace, &j.name);
+        }
+        for c in &self.cronjobs {
+            resources(View::CronJobs, "CronJob", &c.namespace, &c.name);
+        }
+        for n in &self.nodes {
+            resources(View::Nodes, "Node", "", &n.name);
+        }
+        entries
+    }
+
+    fn handle_palette_command(&mut self, command: PaletteCommand) {
+        match command {
+            PaletteCommand::ShowView(view) => {
+                self.current_view = view;
+                self.refresh_current_view();
+            }
+            PaletteCommand::SwitchContext(name) => {
+                self.current_context = Some(name.clone());
+                self.switch_context(&name);
+            }
+            PaletteCommand::SelectNamespace(namespace) => {
+                self.selected_namespace = namespace;
+                self.refresh_current_view();
+            }
+            PaletteCommand::OpenResource(view, namespace, name) => {
+                let is = |ns: &str, n: &str| ns == namespace && n == name;
+                match view {
+                    View::Deployments => {
+                        self.deployments_view.selected_deployment =
+                            self.deployments.iter().find(|d| is(&d.namespace, &d.name)).cloned();
+                    }
+                    View::StatefulSets => {
+                        self.statefulsets_view.selected_statefulset =
+                            self.statefulsets.iter().find(|s| is(&s.namespace, &s.name)).cloned();
+                    }
+                    View::DaemonSets => {
+                        self.daemonsets_view.selected_daemonset =
+                            self.daemonsets.iter().find(|d| is(&d.namespace, &d.name)).cloned();
+                    }
+                    View::ReplicaSets => {
+                        self.replicasets_view.selected_replicaset =
+                            self.replicasets.iter().find(|r| is(&r.namespace, &r.name)).cloned();
+                    }
+                    View::Pods => {
+                        self.pods_view.selected_pod = self.pods.iter().find(|p| is(&p.namespace, &p.name)).cloned();
+                    }
+                    View::Services => {
+                        self.services_view.selected_service =
+                            self.services.iter().find(|s| is(&s.namespace, &s.name)).cloned();
+                    }
+                    View::Jobs => {
+                        self.jobs_view.selected_job = self.jobs.iter().find(|j| is(&j.namespace, &j.name)).cloned();
+                    }
+                    View::CronJobs => {
+                        self.cronjobs_view.selected_cronjob =
+                            self.cronjobs.iter().find(|c| is(&c.namespace, &c.name)).cloned();
+                    }
+                    View::Nodes => {
+                        self.nodes_view.selected_node = self.nodes.iter().find(|n| n.name == name).cloned();
+                    }
+                    _ => {}
+                }
+                if self.current_view != view {
+                    self.current_view = view;
+                    self.refresh_current_view();
+                }
+            }
+        }
+    }
+
+    fn search_filter_mut(&mut self, view: View) -> Option<&mut String> {
+        match view {
+            View::Deployments => Some(&mut self.deployments_view.search_filter),
+            View::StatefulSets => Some(&mut self.statefulsets_view.search_filter),
+            View::DaemonSets => Some(&mut self.daemonsets_view.search_filter),
+            View::ReplicaSets => Some(&mut self.replicasets_view.search_filter),
+            View::Pods => Some(&mut self.pods_view.search_filter),
+            View::Services => Some(&mut self.services_view.search_filter),
+            View::Config => Some(&mut self.config_view.search_filter),
+            View::Jobs => Some(&mut self.jobs_view.search_filter),
+            View::CronJobs => Some(&mut self.cronjobs_view.search_filter),
+            View::Nodes => Some(&mut self.nodes_view.search_filter),
+            View::Events => Some(&mut self.events_view.search_filter),
+            View::CustomResources => Some(&mut self.custom_view.search_filter),
+            View::Audit => Some(&mut self.audit_view.search_filter),
+            View::PortForwards | View::Topology => None,
+        }
+    }
+
+    fn selector_bar_mut(&mut self, view: View) -> Option<&mut SelectorBar> {
+        match view {
+            View::Deployments => Some(&mut self.deployments_view.selector),
+            View::StatefulSets => Some(&mut self.statefulsets_view.selector),
+            View::DaemonSets => Some(&mut self.daemonsets_view.selector),
+            View::ReplicaSets => Some(&mut self.replicasets_view.selector),
+            View::Pods => Some(&mut self.pods_view.selector),
+            View::Services => Some(&mut self.services_view.selector),
+            View::Config => Some(&mut self.config_view.selector),
+            View::Jobs => Some(&mut self.jobs_view.selector),
+            View::CronJobs => Some(&mut self.cronjobs_view.selector),
+            View::Nodes => Some(&mut self.nodes_view.selector),
+            View::Events => Some(&mut self.events_view.selector),
+            View::CustomResources => Some(&mut self.custom_view.selector),
+            View::PortForwards | View::Topology | View::Audit => None,
+        }
+    }
+
+    /// Every table with configurable columns, keyed by the id its layout is saved under.
+    fn table_columns_mut(&mut self) -> Vec<(&'static str, &mut TableColumns)> {
+        vec![
+            ("deployments", &mut self.deployments_view.columns),
+            ("statefulsets", &mut self.statefulsets_view.columns),
+            ("daemonsets", &mut self.daemonsets_view.columns),
+            ("replicasets", &mut self.replicasets_view.columns),
+            ("pods", &mut self.pods_view.columns),
+            ("services", &mut self.services_view.service_columns),
+            ("ingresses", &mut self.services_view.ingress_columns),
+            ("configmaps", &mut self.config_view.configmap_columns),
+            ("secrets", &mut self.config_view.secret_columns),
+            ("jobs", &mut self.jobs_view.columns),
+            ("cronjobs", &mut self.cronjobs_view.columns),
+            ("nodes", &mut self.nodes_view.columns),
+        ]
+    }
+
+    /// Selector chips from what is loaded: each view gets the labels of its own
+    /// objects, and pods also get the selectors of the services pointing at them.
+    fn refresh_selector_chips(&mut self) {
+        let deployment_labels = label_chips(self.deployments.iter().map(|d| &d.labels));
+        let service_selectors = label_chips(self.services.iter().map(|s| &s.selector));
+
+        let mut pod_chips = service_selectors.clone();
+        pod_chips.extend(deployment_labels.iter().filter(|c| !service_selectors.contains(*c)).cloned());
+
+        self.deployments_view.selector.chips = deployment_labels;
+        self.services_view.selector.chips = label_chips(self.services.iter().map(|s| &s.labels));
+        self.pods_view.selector.chips = pod_chips;
+    }
+
+    /// Copies the current session into `settings`.
+    fn capture_settings(&mut self, ctx: &egui::Context) {
+        let mut settings = self.settings.clone();
+        settings.last_context = self.current_context.clone();
+        if let Some(context) = &self.current_context {
+            settings.namespaces.insert(context.clone(), self.selected_namespace.clone());
+        }
+        settings.view = self.current_view;
+        settings.dark_mode = self.dark_mode;
+        settings.log_tail_lines = self.pods_view.tail_lines;
+        settings.search_filters = View::ALL
+            .into_iter()
+            .filter_map(|view| Some((view, self.search_filter_mut(view)?.clone())))
+            .filter(|(_, filter)| !filter.is_empty())
+            .collect();
+        settings.tables = self
+            .table_columns_mut()
+            .into_iter()
+            .filter(|(_, columns)| columns.layout != TableLayout::default())
+            .map(|(id, columns)| (id.to_string(), columns.layout.clone()))
+            .collect();
+        let (inner, outer) = ctx.input(|i| (i.viewport().inner_rect, i.viewport().outer_rect));
+        if let Some(inner) = inner {
+            settings.window = WindowGeometry {
+                position: outer.map(|r| [r.min.x, r.min.y]),
+                size: [inner.width(), inner.height()],
+            };
+        }
+
+        self.settings = settings;
+    }
+
+    /// Writes `settings` out if it changed since the last write, at most once per
+    /// `SETTINGS_SAVE_INTERVAL` unless `force` is set.
+    fn save_settings(&mut self, force: bool) {
+        if !self.settings_restored || self.settings == self.saved_settings {
+            return;
+        }
+        if !force && self.settings_saved_at.elapsed() < SETTINGS_SAVE_INTERVAL {
+            return;
+        }
+
+        self.settings_saved_at = std::time::Instant::now();
+        match self.settings.save() {
+            Ok(()) => self.saved_settings = self.settings.clone(),
+            Err(e) => tracing::warn!("Failed to save settings: {:#}", e),
+        }
+    }
+
     fn initialize(&mut self) {
         let client = self.k8s_client.clone();
         let tx = self.message_tx.clone();
@@ -249,6 +778,12 @@ impl KubeDashboard {
     }
 
     fn switch_context(&mut self, context_name: &str) {
+        self.stop_all_port_forwards();
+
+        if let Some(namespace) = self.settings.namespace_for(context_name) {
+            self.selected_namespace = namespace;
+        }
+
         let client = self.k8s_client.clone();
         let tx = self.message_tx.clone();
         let name = context_name.to_string();
@@ -274,10 +809,24 @@ impl KubeDashboard {
         });
     }
 
+    fn stop_watches(&mut self) {
+        for handle in self.watch_tasks.drain(..) {
+            handle.abort();
+        }
+    }
+
     fn refresh_current_view(&mut self) {
+        self.stop_watches();
+
         match self.current_view {
             View::Deployments => self.load_deployments(),
-            View::Pods => self.load_pods(),
+            View::StatefulSets => self.load_statefulsets(),
+            View::DaemonSets => self.load_daemonsets(),
+            View::ReplicaSets => self.load_replicasets(),
+            View::Pods => {
+                self.load_pods();
+                self.load_pod_metrics();
+            }
             View::Services => {
                 self.load_services();
                 self.load_ingresses();
@@ -288,7 +837,50 @@ impl KubeDashboard {
             }
             View::Jobs => self.load_jobs(),
             View::CronJobs => self.load_cronjobs(),
+            View::PortForwards => {}
+            View::Nodes => {
+                self.load_nodes();
+                // Pod counts per node span every namespace and every label
+                self.load_pods_in(None, k8s::Selectors::default());
+                self.load_node_metrics();
+            }
+            View::Events => self.load_events(),
+            View::Topology => self.load_topology(),
+            View::CustomResources => {
+                if self.custom_kinds.is_empty() {
+                    self.discover_resource_kinds();
+                }
+                if let Some(kind) = self.custom_view.selected_kind.clone() {
+                    self.load_custom_objects(kind);
+                }
+            }
+            View::Audit => self.load_audit_log(),
+        }
+    }
+
+    /// Rereads the audit log, picking up changes recorded by other dashboards.
+    fn load_audit_log(&mut self) {
+        let Some(path) = &self.audit_log_path else {
+            return;
+        };
+        match audit::load_recent(path, AUDIT_HISTORY_LIMIT) {
+            Ok(entries) => self.audit_entries = entries,
+            Err(e) => self.add_notification(&format!("Failed to read audit log: {:#}", e), true),
+        }
+    }
+
+    /// Appends a finished change to the audit log and the audit view.
+    fn record_audit(&mut self, entry: AuditEntry) {
+        if let Some(path) = &self.audit_log_path {
+            if let Err(e) = audit::append(path, &entry) {
+                tracing::warn!("Failed to write audit log: {:#}", e);
+                self.add_notification(&format!("Failed to write audit log: {:#}", e), true);
+            }
+        }
+        if self.audit_entries.len() >= AUDIT_HISTORY_LIMIT {
+            self.audit_entries.remove(0);
         }
+        self.audit_entries.push(entry);
     }
 
     fn load_deployments(&mut self) {
@@ -297,11 +889,21 @@ impl KubeDashboard {
 
         let client = self.k8s_client.clone();
         let tx = self.message_tx.clone();
+        let selectors = self.deployments_view.selector.applied().clone();
         let ns = self.selected_namespace.clone();
+        let live = self.live_updates;
 
-        self.runtime.spawn(async move {
+        let handle = self.runtime.spawn(async move {
             if let Some(c) = client.get_client().await {
-                match k8s::list_deployments(&c, ns.as_deref()).await {
+                if live {
+                    k8s::watch_deployments(&c, ns.as_deref(), &selectors, |result| {
+                        let _ = tx.send(AppMessage::DeploymentsLoaded(result.map_err(|e| e.to_string())));
+                    })
+                    .await;
+                    return;
+                }
+
+                match k8s::list_deployments(&c, ns.as_deref(), &selectors).await {
                     Ok(deps) => {
                         let _ = tx.send(AppMessage::DeploymentsLoaded(Ok(deps)));
                     }
@@ -311,19 +913,131 @@ impl KubeDashboard {
                 }
             }
         });
+        self.watch_tasks.push(handle);
+    }
+
+    fn load_statefulsets(&mut self) {
+        self.loading_statefulsets = true;
+        self.error_statefulsets = None;
+
+        let client = self.k8s_client.clone();
+        let tx = self.message_tx.clone();
+        let selectors = self.statefulsets_view.selector.applied().clone();
+        let ns = self.selected_namespace.clone();
+        let live = self.live_updates;
+
+        let handle = self.runtime.spawn(async move {
+            if let Some(c) = client.get_client().await {
+                if live {
+                    k8s::watch_statefulsets(&c, ns.as_deref(), &selectors, |result| {
+                        let _ = tx.send(AppMessage::StatefulSetsLoaded(result.map_err(|e| e.to_string())));
+                    })
+                    .await;
+                    return;
+                }
+
+                match k8s::list_statefulsets(&c, ns.as_deref(), &selectors).await {
+                    Ok(sets) => {
+                        let _ = tx.send(AppMessage::StatefulSetsLoaded(Ok(sets)));
+                    }
+                    Err(e) => {
+                        let _ = tx.send(AppMessage::StatefulSetsLoaded(Err(e.to_string())));
+                    }
+                }
+            }
+        });
+        self.watch_tasks.push(handle);
+    }
+
+    fn load_daemonsets(&mut self) {
+        self.loading_daemonsets = true;
+        self.error_daemonsets = None;
+
+        let client = self.k8s_client.clone();
+        let tx = self.message_tx.clone();
+        let selectors = self.daemonsets_view.selector.applied().clone();
+        let ns = self.selected_namespace.clone();
+        let live = self.live_updates;
+
+        let handle = self.runtime.spawn(async move {
+            if let Some(c) = client.get_client().await {
+                if live {
+                    k8s::watch_daemonsets(&c, ns.as_deref(), &selectors, |result| {
+                        let _ = tx.send(AppMessage::DaemonSetsLoaded(result.map_err(|e| e.to_string())));
+                    })
+                    .await;
+                    return;
+                }
+
+                match k8s::list_daemonsets(&c, ns.as_deref(), &selectors).await {
+                    Ok(sets) => {
+                        let _ = tx.send(AppMessage::DaemonSetsLoaded(Ok(sets)));
+                    }
+                    Err(e) => {
+                        let _ = tx.send(AppMessage::DaemonSetsLoaded(Err(e.to_string())));
+                    }
+                }
+            }
+        });
+        self.watch_tasks.push(handle);
+    }
+
+    fn load_replicasets(&mut self) {
+        self.loading_replicasets = true;
+        self.error_replicasets = None;
+
+        let client = self.k8s_client.clone();
+        let tx = self.message_tx.clone();
+        let selectors = self.replicasets_view.selector.applied().clone();
+        let ns = self.selected_namespace.clone();
+        let live = self.live_updates;
+
+        let handle = self.runtime.spawn(async move {
+            if let Some(c) = client.get_client().await {
+                if live {
+                    k8s::watch_replicasets(&c, ns.as_deref(), &selectors, |result| {
+                        let _ = tx.send(AppMessage::ReplicaSetsLoaded(result.map_err(|e| e.to_string())));
+                    })
+                    .await;
+                    return;
+                }
+
+                match k8s::list_replicasets(&c, ns.as_deref(), &selectors).await {
+                    Ok(sets) => {
+                        let _ = tx.send(AppMessage::ReplicaSetsLoaded(Ok(sets)));
+                    }
+                    Err(e) => {
+                        let _ = tx.send(AppMessage::ReplicaSetsLoaded(Err(e.to_string())));
+                    }
+                }
+            }
+        });
+        self.watch_tasks.push(handle);
     }
 
     fn load_pods(&mut self) {
+        self.load_pods_in(self.selected_namespace.clone(), self.pods_view.selector.applied().clone());
+    }
+
+    fn load_pods_in(&mut self, ns: Option<String>, selectors: k8s::Selectors) {
         self.loading_pods = true;
         self.error_pods = None;
 
         let client = self.k8s_client.clone();
         let tx = self.message_tx.clone();
-        let ns = self.selected_namespace.clone();
+        let live = self.live_updates;
 
-        self.runtime.spawn(async move {
+        let handle = self.runtime.spawn(async move {
             if let Some(c) = client.get_client().await {
-                match k8s::list_pods(&c, ns.as_deref()).await {
+                if live {
+                    k8s::watch_pods(&c, ns.as_deref(), &selectors, |result| {
+                        let _ = tx.send(AppMessage::PodsLoaded(result.map_err(|e| e.to_string())));
+                    })
+                    .await;
+                    return;
+                }
+
+                match k8s::list_pods(&c, ns.as_deref(), &selectors).await {
                     Ok(pods) => {
                         let _ = tx.send(AppMessage::PodsLoaded(Ok(pods)));
                     }
@@ -333,6 +1047,56 @@ impl KubeDashboard {
                 }
             }
         });
+        self.watch_tasks.push(handle);
+    }
+
+    fn load_pod_metrics(&mut self) {
+        let client = self.k8s_client.clone();
+        let tx = self.message_tx.clone();
+        let ns = self.selected_namespace.clone();
+        let live = self.live_updates;
+
+        let handle = self.runtime.spawn(async move {
+            if let Some(c) = client.get_client().await {
+                loop {
+                    match k8s::list_pod_metrics(&c, ns.as_deref()).await {
+                        Ok(usage) => {
+                            let _ = tx.send(AppMessage::PodMetricsLoaded(usage));
+                        }
+                        Err(e) => tracing::warn!("{:#}", e),
+                    }
+                    if !live {
+                        return;
+                    }
+                    tokio::time::sleep(METRICS_POLL_INTERVAL).await;
+                }
+            }
+        });
+        self.watch_tasks.push(handle);
+    }
+
+    fn load_node_metrics(&mut self) {
+        let client = self.k8s_client.clone();
+        let tx = self.message_tx.clone();
+        let live = self.live_updates;
+
+        let handle = self.runtime.spawn(async move {
+            if let Some(c) = client.get_client().await {
+                loop {
+                    match k8s::list_node_metrics(&c).await {
+                        Ok(usage) => {
+                            let _ = tx.send(AppMessage::NodeMetricsLoaded(usage));
+                        }
+                        Err(e) => tracing::warn!("{:#}", e),
+                    }
+                    if !live {
+                        return;
+                    }
+                    tokio::time::sleep(METRICS_POLL_INTERVAL).await;
+                }
+            }
+        });
+        self.watch_tasks.push(handle);
     }
 
     fn load_services(&mut self) {
@@ -341,11 +1105,21 @@ impl KubeDashboard {
 
         let client = self.k8s_client.clone();
         let tx = self.message_tx.clone();
+        let selectors = self.services_view.selector.applied().clone();
         let ns = self.selected_namespace.clone();
+        let live = self.live_updates;
 
-        self.runtime.spawn(async move {
+        let handle = self.runtime.spawn(async move {
             if let Some(c) = client.get_client().await {
-                match k8s::list_services(&c, ns.as_deref()).await {
+                if live {
+                    k8s::watch_services(&c, ns.as_deref(), &selectors, |result| {
+                        let _ = tx.send(AppMessage::ServicesLoaded(result.map_err(|e| e.to_string())));
+                    })
+                    .await;
+                    return;
+                }
+
+                match k8s::list_services(&c, ns.as_deref(), &selectors).await {
                     Ok(svcs) => {
                         let _ = tx.send(AppMessage::ServicesLoaded(Ok(svcs)));
                     }
@@ -355,16 +1129,27 @@ impl KubeDashboard {
                 }
             }
         });
+        self.watch_tasks.push(handle);
     }
 
     fn load_ingresses(&mut self) {
         let client = self.k8s_client.clone();
         let tx = self.message_tx.clone();
+        let selectors = self.services_view.selector.applied().clone();
         let ns = self.selected_namespace.clone();
+        let live = self.live_updates;
 
-        self.runtime.spawn(async move {
+        let handle = self.runtime.spawn(async move {
             if let Some(c) = client.get_client().await {
-                match k8s::list_ingresses(&c, ns.as_deref()).await {
+                if live {
+                    k8s::watch_ingresses(&c, ns.as_deref(), &selectors, |result| {
+                        let _ = tx.send(AppMessage::IngressesLoaded(result.map_err(|e| e.to_string())));
+                    })
+                    .await;
+                    return;
+                }
+
+                match k8s::list_ingresses(&c, ns.as_deref(), &selectors).await {
                     Ok(ings) => {
                         let _ = tx.send(AppMessage::IngressesLoaded(Ok(ings)));
                     }
@@ -374,6 +1159,7 @@ impl KubeDashboard {
                 }
             }
         });
+        self.watch_tasks.push(handle);
     }
 
     fn load_configmaps(&mut self) {
@@ -382,11 +1168,21 @@ impl KubeDashboard {
 
         let client = self.k8s_client.clone();
         let tx = self.message_tx.clone();
+        let selectors = self.config_view.selector.applied().clone();
         let ns = self.selected_namespace.clone();
+        let live = self.live_updates;
 
-        self.runtime.spawn(async move {
+        let handle = self.runtime.spawn(async move {
             if let Some(c) = client.get_client().await {
-                match k8s::list_configmaps(&c, ns.as_deref()).await {
+                if live {
+                    k8s::watch_configmaps(&c, ns.as_deref(), &selectors, |result| {
+                        let _ = tx.send(AppMessage::ConfigMapsLoaded(result.map_err(|e| e.to_string())));
+                    })
+                    .await;
+                    return;
+                }
+
+                match k8s::list_configmaps(&c, ns.as_deref(), &selectors).await {
                     Ok(cms) => {
                         let _ = tx.send(AppMessage::ConfigMapsLoaded(Ok(cms)));
                     }
@@ -396,16 +1192,27 @@ impl KubeDashboard {
                 }
             }
         });
+        self.watch_tasks.push(handle);
     }
 
     fn load_secrets(&mut self) {
         let client = self.k8s_client.clone();
         let tx = self.message_tx.clone();
+        let selectors = self.config_view.selector.applied().clone();
         let ns = self.selected_namespace.clone();
+        let live = self.live_updates;
 
-        self.runtime.spawn(async move {
+        let handle = self.runtime.spawn(async move {
             if let Some(c) = client.get_client().await {
-                match k8s::list_secrets(&c, ns.as_deref()).await {
+                if live {
+                    k8s::watch_secrets(&c, ns.as_deref(), &selectors, |result| {
+                        let _ = tx.send(AppMessage::SecretsLoaded(result.map_err(|e| e.to_string())));
+                    })
+                    .await;
+                    return;
+                }
+
+                match k8s::list_secrets(&c, ns.as_deref(), &selectors).await {
                     Ok(secrets) => {
                         let _ = tx.send(AppMessage::SecretsLoaded(Ok(secrets)));
                     }
@@ -415,6 +1222,7 @@ impl KubeDashboard {
                 }
             }
         });
+        self.watch_tasks.push(handle);
     }
 
     fn load_jobs(&mut self) {
@@ -423,11 +1231,21 @@ impl KubeDashboard {
 
         let client = self.k8s_client.clone();
         let tx = self.message_tx.clone();
+        let selectors = self.jobs_view.selector.applied().clone();
         let ns = self.selected_namespace.clone();
+        let live = self.live_updates;
 
-        self.runtime.spawn(async move {
+        let handle = self.runtime.spawn(async move {
             if let Some(c) = client.get_client().await {
-                match k8s::list_jobs(&c, ns.as_deref()).await {
+                if live {
+                    k8s::watch_jobs(&c, ns.as_deref(), &selectors, |result| {
+                        let _ = tx.send(AppMessage::JobsLoaded(result.map_err(|e| e.to_string())));
+                    })
+                    .await;
+                    return;
+                }
+
+                match k8s::list_jobs(&c, ns.as_deref(), &selectors).await {
                     Ok(jobs) => {
                         let _ = tx.send(AppMessage::JobsLoaded(Ok(jobs)));
                     }
@@ -437,6 +1255,7 @@ impl KubeDashboard {
                 }
             }
         });
+        self.watch_tasks.push(handle);
     }
 
     fn load_cronjobs(&mut self) {
@@ -445,11 +1264,21 @@ impl KubeDashboard {
 
         let client = self.k8s_client.clone();
         let tx = self.message_tx.clone();
+        let selectors = self.cronjobs_view.selector.applied().clone();
         let ns = self.selected_namespace.clone();
+        let live = self.live_updates;
 
-        self.runtime.spawn(async move {
+        let handle = self.runtime.spawn(async move {
             if let Some(c) = client.get_client().await {
-                match k8s::list_cronjobs(&c, ns.as_deref()).await {
+                if live {
+                    k8s::watch_cronjobs(&c, ns.as_deref(), &selectors, |result| {
+                        let _ = tx.send(AppMessage::CronJobsLoaded(result.map_err(|e| e.to_string())));
+                    })
+                    .await;
+                    return;
+                }
+
+                match k8s::list_cronjobs(&c, ns.as_deref(), &selectors).await {
                     Ok(cjs) => {
                         let _ = tx.send(AppMessage::CronJobsLoaded(Ok(cjs)));
                     }
@@ -459,152 +1288,892 @@ impl KubeDashboard {
                 }
             }
         });
+        self.watch_tasks.push(handle);
     }
 
-    fn handle_deployment_action(&mut self, action: DeploymentAction) {
+    fn load_events(&mut self) {
+        self.loading_events = true;
+        self.error_events = None;
+
+        let client = self.k8s_client.clone();
+        let tx = self.message_tx.clone();
+        let selectors = self.events_view.selector.applied().clone();
+        let ns = self.selected_namespace.clone();
+        let live = self.live_updates;
+
+        let handle = self.runtime.spawn(async move {
+            if let Some(c) = client.get_client().await {
+                if live {
+                    k8s::watch_events(&c, ns.as_deref(), &selectors, |result| {
+                        let _ = tx.send(AppMessage::EventsLoaded(result.map_err(|e| e.to_string())));
+                    })
+                    .await;
+                    return;
+                }
+
+                match k8s::list_events(&c, ns.as_deref(), &selectors).await {
+                    Ok(events) => {
+                        let _ = tx.send(AppMessage::EventsLoaded(Ok(events)));
+                    }
+                    Err(e) => {
+                        let _ = tx.send(AppMessage::EventsLoaded(Err(e.to_string())));
+                    }
+                }
+            }
+        });
+        self.watch_tasks.push(handle);
+    }
+
+    fn load_nodes(&mut self) {
+        self.loading_nodes = true;
+        self.error_nodes = None;
+
+        let client = self.k8s_client.clone();
+        let tx = self.message_tx.clone();
+        let selectors = self.nodes_view.selector.applied().clone();
+        let live = self.live_updates;
+
+        let handle = self.runtime.spawn(async move {
+            if let Some(c) = client.get_client().await {
+                if live {
+                    k8s::watch_nodes(&c, &selectors, |result| {
+                        let _ = tx.send(AppMessage::NodesLoaded(result.map_err(|e| e.to_string())));
+                    })
+                    .await;
+                    return;
+                }
+
+                match k8s::list_nodes(&c, &selectors).await {
+                    Ok(nodes) => {
+                        let _ = tx.send(AppMessage::NodesLoaded(Ok(nodes)));
+                    }
+                    Err(e) => {
+                        let _ = tx.send(AppMessage::NodesLoaded(Err(e.to_string())));
+                    }
+                }
+            }
+        });
+        self.watch_tasks.push(handle);
+    }
+
+    fn load_topology(&mut self) {
+        self.loading_topology = true;
+        self.error_topology = None;
+
+        let client = self.k8s_client.clone();
+        let tx = self.message_tx.clone();
+        let ns = self.selected_namespace.clone();
+
+        let handle = self.runtime.spawn(async move {
+            if let Some(c) = client.get_client().await {
+                let result = k8s::namespace_topology(&c, ns.as_deref())
+                    .await
+                    .map_err(|e| format!("{:#}", e));
+                let _ = tx.send(AppMessage::TopologyLoaded(result));
+            }
+        });
+        self.watch_tasks.push(handle);
+    }
+
+    fn discover_resource_kinds(&mut self) {
+        self.loading_custom_kinds = true;
+        self.error_custom_kinds = None;
+
+        let client = self.k8s_client.clone();
+        let tx = self.message_tx.clone();
+
+        self.runtime.spawn(async move {
+            if let Some(c) = client.get_client().await {
+                let result = k8s::discover_resource_kinds(&c).await.map_err(|e| e.to_string());
+                let _ = tx.send(AppMessage::ResourceKindsDiscovered(result));
+            }
+        });
+    }
+
+    fn load_custom_objects(&mut self, kind: CustomResourceKind) {
+        self.loading_custom_objects = true;
+        self.error_custom_objects = None;
+
         let client = self.k8s_client.clone();
         let tx = self.message_tx.clone();
+        let selectors = self.custom_view.selector.applied().clone();
+        let ns = self.selected_namespace.clone();
+
+        let handle = self.runtime.spawn(async move {
+            if let Some(c) = client.get_client().await {
+                let result = k8s::list_dynamic_objects(&c, &kind, ns.as_deref(), &selectors)
+                    .await
+                    .map_err(|e| e.to_string());
+                let _ = tx.send(AppMessage::CustomObjectsLoaded(kind, result));
+            }
+        });
+        self.watch_tasks.push(handle);
+    }
 
+    fn handle_custom_resource_action(&mut self, action: CustomResourceAction) {
         match action {
-            DeploymentAction::Scale(ns, name, replicas) => {
+            CustomResourceAction::Discover => self.discover_resource_kinds(),
+            CustomResourceAction::List(kind) => {
+                self.stop_watches();
+                self.custom_objects.clear();
+                self.load_custom_objects(kind);
+            }
+            CustomResourceAction::LoadYaml(kind, namespace, name) => {
+                let client = self.k8s_client.clone();
+                let tx = self.message_tx.clone();
+
                 self.runtime.spawn(async move {
                     if let Some(c) = client.get_client().await {
-                        match k8s::scale_deployment(&c, &ns, &name, replicas).await {
-                            Ok(()) => {
-                                let _ = tx.send(AppMessage::ActionCompleted(Ok(format!(
-                                    "Scaled {} to {} replicas",
-                                    name, replicas
-                                ))));
-                            }
-                            Err(e) => {
-                                let _ = tx.send(AppMessage::ActionCompleted(Err(e.to_string())));
-                            }
+                        let result = k8s::get_dynamic_object_yaml(&c, &kind.resource, namespace.as_deref(), &name)
+                            .await
+                            .map_err(|e| e.to_string());
+                        let _ = tx.send(AppMessage::CustomObjectYamlLoaded(name, result));
+                    }
+                });
+            }
+        }
+    }
+
+    fn load_object_events(&mut self, target: k8s::ResourceRef) {
+        let client = self.k8s_client.clone();
+        let tx = self.message_tx.clone();
+
+        self.runtime.spawn(async move {
+            if let Some(c) = client.get_client().await {
+                let result = k8s::list_object_events(&c, &target).await.map_err(|e| e.to_string());
+                let _ = tx.send(AppMessage::ObjectEventsLoaded(target, result));
+            }
+        });
+    }
+
+    fn object_events(&mut self, kind: k8s::ResourceKind) -> Option<&mut ObjectEvents> {
+        match kind {
+            k8s::ResourceKind::Deployment => Some(&mut self.deployments_view.events),
+            k8s::ResourceKind::StatefulSet => Some(&mut self.statefulsets_view.events),
+            k8s::ResourceKind::DaemonSet => Some(&mut self.daemonsets_view.events),
+            k8s::ResourceKind::ReplicaSet => Some(&mut self.replicasets_view.events),
+            k8s::ResourceKind::Pod => Some(&mut self.pods_view.events),
+            k8s::ResourceKind::Job => Some(&mut self.jobs_view.events),
+            k8s::ResourceKind::CronJob => Some(&mut self.cronjobs_view.events),
+            _ => None,
+        }
+    }
+
+    fn handle_node_action(&mut self, action: NodeAction) {
+        let client = self.k8s_client.clone();
+        let tx = self.message_tx.clone();
+
+        let cordon = matches!(action, NodeAction::Cordon(_));
+        match action {
+            NodeAction::Cordon(name) | NodeAction::Uncordon(name) => {
+                let action = if cordon { "cordon" } else { "uncordon" };
+                let audit = self.audit("Node", "", &name, action).with_patch(json!({"spec": {"unschedulable": cordon}}));
+                self.run_audited(audit, move |c| async move {
+                    k8s::cordon_node(&c, &name, cordon).await?;
+                    Ok(if cordon {
+                        format!("Cordoned node {}", name)
+                    } else {
+                        format!("Uncordoned node {}", name)
+                    })
+                });
+            }
+            NodeAction::Drain(name) => {
+                self.stop_drain();
+                let id = self.drain_id;
+                let audit = self.audit("Node", "", &name, "drain");
+                if let Err(reason) = self.permit(&audit) {
+                    self.nodes_view.drain_finished(Err(reason));
+                    return;
+                }
+                self.drain_task = Some(self.runtime.spawn(async move {
+                    if let Some(c) = client.get_client().await {
+                        let result = k8s::drain_node(&c, &name, |status| {
+                            let _ = tx.send(AppMessage::DrainProgress(id, status));
+                        })
+                        .await
+                        .map_err(|e| e.to_string());
+                        let _ = tx.send(AppMessage::Audited(audit.finish(&result)));
+                        let _ = tx.send(AppMessage::DrainFinished(id, result));
+                    }
+                }));
+            }
+            NodeAction::CancelDrain => {
+                self.stop_drain();
+                self.nodes_view.drain_finished(Err("Drain cancelled; the node stays cordoned".to_string()));
+            }
+        }
+    }
+
+    fn stop_drain(&mut self) {
+        if let Some(handle) = self.drain_task.take() {
+            handle.abort();
+        }
+        self.drain_id += 1;
+    }
+
+    fn stop_following_rollout(&mut self) {
+        if let Some(handle) = self.rollout_task.take() {
+            handle.abort();
+        }
+        self.rollout_id += 1;
+        // Not settled yet, so there may be no new revision to show
+        self.history_after_rollout = None;
+    }
+
+    /// Runs `change` against the deployment (if any) and records it in the audit log,
+    /// then follows its rollout in the deployments view's progress panel until it
+    /// completes or gets stuck.
+    fn follow_rollout<F, Fut>(&mut self, ns: String, name: String, change: Option<(PendingAudit, F)>)
+    where
+        F: FnOnce(kube::Client) -> Fut + Send + 'static,
+        Fut: std::future::Future<Output = anyhow::Result<String>> + Send,
+    {
+        self.stop_following_rollout();
+        self.deployments_view.open_rollout(&ns, &name);
+        let id = self.rollout_id;
+
+        let client = self.k8s_client.clone();
+        let tx = self.message_tx.clone();
+
+        if let Some((audit, _)) = &change {
+            if let Err(reason) = self.permit(audit) {
+                let _ = tx.send(AppMessage::DeploymentRolloutUpdate(id, Err(reason)));
+                return;
+            }
+        }
+
+        let handle = self.runtime.spawn(async move {
+            if let Some(c) = client.get_client().await {
+                if let Some((audit, change)) = change {
+                    let result = change(c.clone()).await.map_err(|e| format!("{:#}", e));
+                    let _ = tx.send(AppMessage::Audited(audit.finish(&result)));
+                    match result {
+                        Ok(msg) => {
+                            let _ = tx.send(AppMessage::ActionCompleted(Ok(msg)));
+                        }
+                        Err(msg) => {
+                            let _ = tx.send(AppMessage::DeploymentRolloutUpdate(id, Err(msg.clone())));
+                            let _ = tx.send(AppMessage::DeploymentRolloutEnded(id));
+                            let _ = tx.send(AppMessage::ActionCompleted(Err(msg)));
+                            return;
                         }
                     }
+                }
+
+                k8s::watch_deployment_rollout(&c, &ns, &name, |result| {
+                    let _ = tx.send(AppMessage::DeploymentRolloutUpdate(id, result.map_err(|e| e.to_string())));
+                })
+                .await;
+                let _ = tx.send(AppMessage::DeploymentRolloutEnded(id));
+            }
+        });
+        self.rollout_task = Some(handle);
+    }
+
+    /// The safety profile of the current context.
+    fn context_profile(&self) -> ContextProfile {
+        self.current_context
+            .as_ref()
+            .and_then(|context| self.settings.context_profiles.get(context))
+            .copied()
+            .unwrap_or_default()
+    }
+
+    /// Why changes to the current context are refused, if they are.
+    fn read_only_reason(&self) -> Option<String> {
+        let context = self.current_context.as_deref().unwrap_or_default();
+        (!self.context_profile().allows_changes()).then(|| format!("Context {} is read-only", context))
+    }
+
+    /// Refuses the change `audit` describes when the current context is read-only,
+    /// recording and reporting the attempt instead.
+    fn permit(&self, audit: &PendingAudit) -> Result<(), String> {
+        let Some(reason) = self.read_only_reason() else {
+            return Ok(());
+        };
+        let result = Err(reason.clone());
+        let _ = self.message_tx.send(AppMessage::Audited(audit.clone().finish(&result)));
+        let _ = self.message_tx.send(AppMessage::ActionCompleted(result));
+        Err(reason)
+    }
+
+    /// Hands a view's action to `handle`, holding it back for the typed-name
+    /// confirmation first when it is an unconfirmed change in a protected context.
+    fn dispatch<A: GuardedAction + 'static>(&mut self, action: A, handle: fn(&mut Self, A)) {
+        match action.unconfirmed_change() {
+            Some(change) if self.context_profile() == ContextProfile::Protected => {
+                self.held_change = Some(HeldChange {
+                    change,
+                    run: Box::new(move |app: &mut Self| handle(app, action)),
+                });
+            }
+            _ => handle(self, action),
+        }
+    }
+
+    /// Starts the audit log entry for a change to an object in the current context.
+    fn audit(&self, kind: &str, namespace: &str, name: &str, action: &str) -> PendingAudit {
+        PendingAudit::new(self.current_context.clone(), kind, namespace, name, action)
+    }
+
+    /// Runs the change `op` in the background, records it in the audit log and
+    /// reports the message it returns, or its error, as a notification.
+    fn run_audited<F, Fut>(&self, audit: PendingAudit, op: F)
+    where
+        F: FnOnce(kube::Client) -> Fut + Send + 'static,
+        Fut: std::future::Future<Output = anyhow::Result<String>> + Send,
+    {
+        if self.permit(&audit).is_err() {
+            return;
+        }
+        let client = self.k8s_client.clone();
+        let tx = self.message_tx.clone();
+        self.runtime.spawn(async move {
+            if let Some(c) = client.get_client().await {
+                let result = op(c).await.map_err(|e| format!("{:#}", e));
+                let _ = tx.send(AppMessage::Audited(audit.finish(&result)));
+                let _ = tx.send(AppMessage::ActionCompleted(result));
+            }
+        });
+    }
+
+    /// Runs `op` on every `(namespace, name)` target concurrently and reports the
+    /// outcomes together under `title`, rather than one notification each. Each
+    /// object gets its own audit log entry.
+    fn run_bulk_action<F, Fut>(&self, title: String, audit: PendingAudit, targets: Vec<(String, String)>, op: F)
+    where
+        F: Fn(kube::Client, String, String) -> Fut + Send + Sync + 'static,
+        Fut: std::future::Future<Output = anyhow::Result<()>> + Send,
+    {
+        let client = self.k8s_client.clone();
+        let tx = self.message_tx.clone();
+        if let Some(reason) = self.read_only_reason() {
+            let results: Vec<_> = targets
+                .into_iter()
+                .map(|(namespace, name)| k8s::BulkItemResult { namespace, name, result: Err(reason.clone()) })
+                .collect();
+            for item in &results {
+                let _ = tx.send(AppMessage::Audited(audit.for_object(&item.namespace, &item.name).finish(&item.result)));
+            }
+            let _ = tx.send(AppMessage::BulkCompleted(title, results));
+            return;
+        }
+        self.runtime.spawn(async move {
+            if let Some(c) = client.get_client().await {
+                let results = k8s::run_bulk(&c, targets, op).await;
+                for item in &results {
+                    let entry = audit.for_object(&item.namespace, &item.name).finish(&item.result);
+                    let _ = tx.send(AppMessage::Audited(entry));
+                }
+                let _ = tx.send(AppMessage::BulkCompleted(title, results));
+            }
+        });
+    }
+
+    fn handle_deployment_action(&mut self, action: DeploymentAction) {
+        let client = self.k8s_client.clone();
+        let tx = self.message_tx.clone();
+
+        match action {
+            DeploymentAction::Scale(ns, name, replicas) => {
+                let audit = self.audit("Deployment", &ns, &name, "scale").with_patch(json!({"spec": {"replicas": replicas}}));
+                self.run_audited(audit, move |c| async move {
+                    k8s::scale_deployment(&c, &ns, &name, replicas).await?;
+                    Ok(format!("Scaled {} to {} replicas", name, replicas))
                 });
             }
             DeploymentAction::Restart(ns, name) => {
+                let audit = self.audit("Deployment", &ns, &name, "restart");
+                let (n, d) = (ns.clone(), name.clone());
+                self.follow_rollout(ns, name, Some((audit, move |c: kube::Client| async move {
+                    k8s::restart_deployment(&c, &n, &d).await?;
+                    Ok(format!("Restarted deployment {}", d))
+                })));
+            }
+            DeploymentAction::RestartMany(targets) => {
+                let audit = self.audit("Deployment", "", "", "restart");
+                self.run_bulk_action("Restarted deployments".to_string(), audit, targets, |c, ns, name| async move {
+                    k8s::restart_deployment(&c, &ns, &name).await
+                });
+            }
+            DeploymentAction::ScaleMany(targets, replicas) => {
+                let title = format!("Scaled deployments to {} replicas", replicas);
+                let audit = self.audit("Deployment", "", "", "scale").with_patch(json!({"spec": {"replicas": replicas}}));
+                self.run_bulk_action(title, audit, targets, move |c, ns, name| async move {
+                    k8s::scale_deployment(&c, &ns, &name, replicas).await
+                });
+            }
+            DeploymentAction::Pause(ns, name) => {
+                let audit = self.audit("Deployment", &ns, &name, "pause").with_patch(json!({"spec": {"paused": true}}));
+                self.run_audited(audit, move |c| async move {
+                    k8s::pause_deployment(&c, &ns, &name, true).await?;
+                    Ok(format!("Paused rollout of deployment {}", name))
+                });
+            }
+            DeploymentAction::Resume(ns, name) => {
+                let audit = self.audit("Deployment", &ns, &name, "resume").with_patch(json!({"spec": {"paused": false}}));
+                let (n, d) = (ns.clone(), name.clone());
+                self.follow_rollout(ns, name, Some((audit, move |c: kube::Client| async move {
+                    k8s::pause_deployment(&c, &n, &d, false).await?;
+                    Ok(format!("Resumed rollout of deployment {}", d))
+                })));
+            }
+            DeploymentAction::WatchRollout(ns, name) => {
+                self.follow_rollout(ns, name, None::<(PendingAudit, fn(kube::Client) -> std::future::Ready<anyhow::Result<String>>)>);
+            }
+            DeploymentAction::StopWatchingRollout => self.stop_following_rollout(),
+            DeploymentAction::SetImage { namespace, name, container, image, change_cause } => {
+                let patch = k8s::set_image_patch(&container, &image, &change_cause);
+                let audit = self.audit("Deployment", &namespace, &name, "set image").with_patch(patch);
+                let (n, d) = (namespace.clone(), name.clone());
+                self.follow_rollout(namespace.clone(), name.clone(), Some((audit, move |c: kube::Client| async move {
+                    k8s::set_deployment_image(&c, &n, &d, &container, &image, &change_cause).await?;
+                    Ok(format!("Set image of {}/{} to {}", d, container, image))
+                })));
+                self.history_after_rollout = Some(k8s::ResourceRef::new(k8s::ResourceKind::Deployment, &namespace, &name));
+            }
+            DeploymentAction::Rollback(ns, name, revision) => {
+                let audit = self.audit("Deployment", &ns, &name, "rollback").with_patch(json!({"revision": revision}));
+                let (n, d) = (ns.clone(), name.clone());
+                self.follow_rollout(ns.clone(), name.clone(), Some((audit, move |c: kube::Client| async move {
+                    k8s::rollback_deployment(&c, &n, &d, revision).await?;
+                    Ok(format!("Rolled back deployment {} to revision {}", d, revision))
+                })));
+                self.history_after_rollout = Some(k8s::ResourceRef::new(k8s::ResourceKind::Deployment, &ns, &name));
+            }
+            DeploymentAction::Yaml(action) => self.handle_yaml_action(action),
+            DeploymentAction::LoadEvents(target) => self.load_object_events(target),
+            DeploymentAction::LoadHistory(target) => {
                 self.runtime.spawn(async move {
                     if let Some(c) = client.get_client().await {
-                        match k8s::restart_deployment(&c, &ns, &name).await {
-                            Ok(()) => {
-                                let _ = tx.send(AppMessage::ActionCompleted(Ok(format!(
-                                    "Restarted deployment {}",
-                                    name
-                                ))));
-                            }
-                            Err(e) => {
-                                let _ = tx.send(AppMessage::ActionCompleted(Err(e.to_string())));
-                            }
-                        }
+                        let result = k8s::list_deployment_revisions(&c, &target.namespace, &target.name)
+                            .await
+                            .map_err(|e| format!("{:#}", e));
+                        let _ = tx.send(AppMessage::DeploymentHistoryLoaded(target, result));
                     }
                 });
             }
             DeploymentAction::Delete(ns, name) => {
+                let audit = self.audit("Deployment", &ns, &name, "delete");
+                self.run_audited(audit, move |c| async move {
+                    k8s::delete_deployment(&c, &ns, &name).await?;
+                    Ok(format!("Deleted deployment {}", name))
+                });
+            }
+        }
+    }
+
+    fn handle_statefulset_action(&mut self, action: StatefulSetAction) {
+        match action {
+            StatefulSetAction::Scale(ns, name, replicas) => {
+                let audit = self.audit("StatefulSet", &ns, &name, "scale").with_patch(json!({"spec": {"replicas": replicas}}));
+                self.run_audited(audit, move |c| async move {
+                    k8s::scale_statefulset(&c, &ns, &name, replicas).await?;
+                    Ok(format!("Scaled {} to {} replicas", name, replicas))
+                });
+            }
+            StatefulSetAction::Restart(ns, name) => {
+                let audit = self.audit("StatefulSet", &ns, &name, "restart");
+                self.run_audited(audit, move |c| async move {
+                    k8s::restart_statefulset(&c, &ns, &name).await?;
+                    Ok(format!("Restarted statefulset {}", name))
+                });
+            }
+            StatefulSetAction::Delete(ns, name) => {
+                let audit = self.audit("StatefulSet", &ns, &name, "delete");
+                self.run_audited(audit, move |c| async move {
+                    k8s::delete_statefulset(&c, &ns, &name).await?;
+                    Ok(format!("Deleted statefulset {}", name))
+                });
+            }
+            StatefulSetAction::Yaml(action) => self.handle_yaml_action(action),
+            StatefulSetAction::LoadEvents(target) => self.load_object_events(target),
+            StatefulSetAction::LoadRollout(target) => self.load_rollout(target),
+        }
+    }
+
+    fn handle_daemonset_action(&mut self, action: DaemonSetAction) {
+        match action {
+            DaemonSetAction::Restart(ns, name) => {
+                let audit = self.audit("DaemonSet", &ns, &name, "restart");
+                self.run_audited(audit, move |c| async move {
+                    k8s::restart_daemonset(&c, &ns, &name).await?;
+                    Ok(format!("Restarted daemonset {}", name))
+                });
+            }
+            DaemonSetAction::Delete(ns, name) => {
+                let audit = self.audit("DaemonSet", &ns, &name, "delete");
+                self.run_audited(audit, move |c| async move {
+                    k8s::delete_daemonset(&c, &ns, &name).await?;
+                    Ok(format!("Deleted daemonset {}", name))
+                });
+            }
+            DaemonSetAction::Yaml(action) => self.handle_yaml_action(action),
+            DaemonSetAction::LoadEvents(target) => self.load_object_events(target),
+            DaemonSetAction::LoadRollout(target) => self.load_rollout(target),
+        }
+    }
+
+    fn handle_replicaset_action(&mut self, action: ReplicaSetAction) {
+        match action {
+            ReplicaSetAction::Scale(ns, name, replicas) => {
+                let audit = self.audit("ReplicaSet", &ns, &name, "scale").with_patch(json!({"spec": {"replicas": replicas}}));
+                self.run_audited(audit, move |c| async move {
+                    k8s::scale_replicaset(&c, &ns, &name, replicas).await?;
+                    Ok(format!("Scaled {} to {} replicas", name, replicas))
+                });
+            }
+            ReplicaSetAction::Delete(ns, name) => {
+                let audit = self.audit("ReplicaSet", &ns, &name, "delete");
+                self.run_audited(audit, move |c| async move {
+                    k8s::delete_replicaset(&c, &ns, &name).await?;
+                    Ok(format!("Deleted replicaset {}", name))
+                });
+            }
+            ReplicaSetAction::Yaml(action) => self.handle_yaml_action(action),
+            ReplicaSetAction::LoadEvents(target) => self.load_object_events(target),
+        }
+    }
+
+    fn load_rollout(&mut self, target: k8s::ResourceRef) {
+        let client = self.k8s_client.clone();
+        let tx = self.message_tx.clone();
+
+        self.runtime.spawn(async move {
+            if let Some(c) = client.get_client().await {
+                let result = match target.kind {
+                    k8s::ResourceKind::StatefulSet => k8s::get_statefulset_rollout(&c, &target.namespace, &target.name).await,
+                    _ => k8s::get_daemonset_rollout(&c, &target.namespace, &target.name).await,
+                };
+                let _ = tx.send(AppMessage::RolloutLoaded(target, result.map_err(|e| e.to_string())));
+            }
+        });
+    }
+
+    fn handle_pod_action(&mut self, action: PodAction) {
+        let client = self.k8s_client.clone();
+        let tx = self.message_tx.clone();
+
+        match action {
+            PodAction::Delete(ns, name) => {
+                let audit = self.audit("Pod", &ns, &name, "delete");
+                self.run_audited(audit, move |c| async move {
+                    k8s::delete_pod(&c, &ns, &name).await?;
+                    Ok(format!("Deleted pod {}", name))
+                });
+            }
+            PodAction::DeleteMany(targets) => {
+                let audit = self.audit("Pod", "", "", "delete");
+                self.run_bulk_action("Deleted pods".to_string(), audit, targets, |c, ns, name| async move {
+                    k8s::delete_pod(&c, &ns, &name).await
+                });
+            }
+            PodAction::GetLogs(ns, name, container, tail_lines) => {
+                self.stop_following_logs();
+                self.pods_view.set_logs_loading();
                 self.runtime.spawn(async move {
                     if let Some(c) = client.get_client().await {
-                        match k8s::delete_deployment(&c, &ns, &name).await {
-                            Ok(()) => {
-                                let _ = tx.send(AppMessage::ActionCompleted(Ok(format!(
-                                    "Deleted deployment {}",
-                                    name
-                                ))));
+                        match k8s::get_pod_logs(&c, &ns, &name, container.as_deref(), Some(tail_lines)).await {
+                            Ok(logs) => {
+                                let _ = tx.send(AppMessage::PodLogsLoaded(Ok(logs)));
                             }
                             Err(e) => {
-                                let _ = tx.send(AppMessage::ActionCompleted(Err(e.to_string())));
+                                let _ = tx.send(AppMessage::PodLogsLoaded(Err(e.to_string())));
                             }
                         }
                     }
                 });
             }
+            PodAction::FollowLogs(ns, name, container, tail_lines) => {
+                self.stop_following_logs();
+                self.pods_view.clear_logs();
+                self.log_stream_id += 1;
+                let id = self.log_stream_id;
+                self.log_follow_task = Some(self.runtime.spawn(async move {
+                    if let Some(c) = client.get_client().await {
+                        let result = k8s::follow_pod_logs(&c, &ns, &name, container.as_deref(), Some(tail_lines), |line| {
+                            let _ = tx.send(AppMessage::PodLogLine(id, line));
+                        })
+                        .await;
+                        let _ = tx.send(AppMessage::PodLogFollowEnded(id, result.map_err(|e| e.to_string())));
+                    }
+                }));
+            }
+            PodAction::StopFollowingLogs => {
+                self.stop_following_logs();
+            }
+            PodAction::Exec(ns, name, container, command) => {
+                self.exec_session_id += 1;
+                let id = self.exec_session_id;
+                // A shell can change anything the container can reach
+                if let Some(reason) = self.read_only_reason() {
+                    let _ = tx.send(AppMessage::ExecEnded(id, Err(reason)));
+                    return;
+                }
+                let (input_tx, input_rx) = unbounded_channel();
+                self.exec_input = Some(input_tx);
+                self.runtime.spawn(async move {
+                    if let Some(c) = client.get_client().await {
+                        let result = k8s::exec_in_pod(&c, &ns, &name, container.as_deref(), command, input_rx, |bytes| {
+                            let _ = tx.send(AppMessage::ExecOutput(id, bytes));
+                        })
+                        .await;
+                        let _ = tx.send(AppMessage::ExecEnded(id, result.map_err(|e| e.to_string())));
+                    }
+                });
+            }
+            PodAction::ExecInput(bytes) => {
+                if let Some(input) = &self.exec_input {
+                    let _ = input.send(k8s::ExecInput::Data(bytes));
+                }
+            }
+            PodAction::ExecResize(width, height) => {
+                if let Some(input) = &self.exec_input {
+                    let _ = input.send(k8s::ExecInput::Resize { width, height });
+                }
+            }
+            PodAction::Yaml(action) => self.handle_yaml_action(action),
+            PodAction::LoadEvents(target) => self.load_object_events(target),
+            PodAction::PortForward(ns, name, remote_port, local_port) => {
+                self.start_port_forward(ns, format!("pod/{}", name), remote_port, local_port);
+            }
+            PodAction::CloseExec => {
+                // Dropping the input sender ends the session
+                self.exec_input = None;
+                self.exec_session_id += 1;
+            }
         }
     }
 
-    fn handle_pod_action(&mut self, action: PodAction) {
+    fn handle_service_action(&mut self, action: ServiceAction) {
+        match action {
+            ServiceAction::PortForward(ns, name, port, local_port) => {
+                self.start_port_forward(ns, format!("svc/{}", name), port, local_port);
+            }
+            ServiceAction::Yaml(action) => self.handle_yaml_action(action),
+        }
+    }
+
+    fn handle_port_forward_action(&mut self, action: PortForwardAction) {
+        match action {
+            PortForwardAction::Stop(id) => self.stop_port_forward(id),
+            PortForwardAction::StopAll => self.stop_all_port_forwards(),
+        }
+    }
+
+    /// Starts forwarding `local_port` to `remote_port` on `target`, which is either
+    /// `pod/<name>` or `svc/<name>`; services are resolved to one ready pod up front.
+    fn start_port_forward(&mut self, namespace: String, target: String, remote_port: u16, local_port: u16) {
+        let client = self.k8s_client.clone();
+        let tx = self.message_tx.clone();
+
+        self.next_port_forward_id += 1;
+        let id = self.next_port_forward_id;
+        let stats = Arc::new(k8s::ForwardStats::default());
+
+        self.port_forwards.push(k8s::PortForwardInfo {
+            id,
+            namespace: namespace.clone(),
+            target: target.clone(),
+            pod: None,
+            local_port: None,
+            remote_port,
+            status: k8s::ForwardStatus::Starting,
+            stats: stats.clone(),
+        });
+
+        let handle = self.runtime.spawn(async move {
+            let Some(c) = client.get_client().await else {
+                return;
+            };
+
+            let resolved = match target.split_once('/') {
+                Some(("svc", service)) => k8s::resolve_service_target(&c, &namespace, service, remote_port).await,
+                Some((_, pod)) => Ok((pod.to_string(), remote_port)),
+                None => Err(anyhow::anyhow!("Invalid port-forward target {}", target)),
+            };
+            let (pod, pod_port) = match resolved {
+                Ok(r) => r,
+                Err(e) => {
+                    let _ = tx.send(AppMessage::PortForwardFailed(id, format!("{:#}", e)));
+                    return;
+                }
+            };
+
+            let listening_pod = pod.clone();
+            let listening_tx = tx.clone();
+            let result = k8s::run_port_forward(&c, &namespace, &pod, pod_port, local_port, stats, |port| {
+                let _ = listening_tx.send(AppMessage::PortForwardListening(id, listening_pod, port));
+            })
+            .await;
+            if let Err(e) = result {
+                let _ = tx.send(AppMessage::PortForwardFailed(id, format!("{:#}", e)));
+            }
+        });
+        self.port_forward_tasks.insert(id, handle);
+    }
+
+    fn stop_port_forward(&mut self, id: u64) {
+        if let Some(handle) = self.port_forward_tasks.remove(&id) {
+            handle.abort();
+        }
+        self.port_forwards.retain(|f| f.id != id);
+    }
+
+    fn stop_all_port_forwards(&mut self) {
+        for (_, handle) in self.port_forward_tasks.drain() {
+            handle.abort();
+        }
+        self.port_forwards.clear();
+    }
+
+    fn handle_yaml_action(&mut self, action: YamlAction) {
         let client = self.k8s_client.clone();
         let tx = self.message_tx.clone();
 
         match action {
-            PodAction::Delete(ns, name) => {
+            YamlAction::Load(target) => {
                 self.runtime.spawn(async move {
                     if let Some(c) = client.get_client().await {
-                        match k8s::delete_pod(&c, &ns, &name).await {
-                            Ok(()) => {
-                                let _ = tx.send(AppMessage::ActionCompleted(Ok(format!(
-                                    "Deleted pod {}",
-                                    name
-                                ))));
-                            }
-                            Err(e) => {
-                                let _ = tx.send(AppMessage::ActionCompleted(Err(e.to_string())));
-                            }
-                        }
+                        let result = k8s::get_object_yaml(&c, &target).await.map_err(|e| format!("{:#}", e));
+                        let _ = tx.send(AppMessage::YamlLoaded(target, result));
                     }
                 });
             }
-            PodAction::GetLogs(ns, name, container, tail_lines) => {
-                self.pods_view.set_logs_loading();
+            YamlAction::Diff(target, yaml) => {
+                self.runtime.spawn(async move {
+                    if let Some(c) = client.get_client().await {
+                        let result = k8s::preview_apply(&c, &target, &yaml).await;
+                        let _ = tx.send(AppMessage::YamlDiffed(target, result));
+                    }
+                });
+            }
+            YamlAction::Apply(target, yaml, force) => {
+                let action = if force { "apply (force)" } else { "apply" };
+                let mut audit = self.audit(target.kind.as_str(), &target.namespace, &target.name, action);
+                if let Ok(body) = serde_yaml::from_str::<serde_json::Value>(&yaml) {
+                    audit = audit.with_patch(match target.kind {
+                        k8s::ResourceKind::Secret => audit::redact_secret(body),
+                        _ => body,
+                    });
+                }
+                if let Err(reason) = self.permit(&audit) {
+                    self.yaml_editor(target.kind).set_apply_error(k8s::ApplyError::Failed(reason));
+                    return;
+                }
                 self.runtime.spawn(async move {
                     if let Some(c) = client.get_client().await {
-                        match k8s::get_pod_logs(&c, &ns, &name, container.as_deref(), Some(tail_lines)).await {
-                            Ok(logs) => {
-                                let _ = tx.send(AppMessage::PodLogsLoaded(Ok(logs)));
-                            }
-                            Err(e) => {
-                                let _ = tx.send(AppMessage::PodLogsLoaded(Err(e.to_string())));
-                            }
-                        }
+                        let result = k8s::apply_object_yaml(&c, &target, &yaml, force).await;
+                        let outcome = result.as_ref().map(|_| ()).map_err(|e| e.to_string());
+                        let _ = tx.send(AppMessage::Audited(audit.finish(&outcome)));
+                        let _ = tx.send(AppMessage::YamlApplied(target, result));
                     }
                 });
             }
         }
     }
 
+    fn yaml_editor(&mut self, kind: k8s::ResourceKind) -> &mut YamlEditor {
+        match kind {
+            k8s::ResourceKind::Deployment => &mut self.deployments_view.yaml,
+            k8s::ResourceKind::StatefulSet => &mut self.statefulsets_view.yaml,
+            k8s::ResourceKind::DaemonSet => &mut self.daemonsets_view.yaml,
+            k8s::ResourceKind::ReplicaSet => &mut self.replicasets_view.yaml,
+            k8s::ResourceKind::Pod => &mut self.pods_view.yaml,
+            k8s::ResourceKind::Service => &mut self.services_view.service_yaml,
+            k8s::ResourceKind::Ingress => &mut self.services_view.ingress_yaml,
+            k8s::ResourceKind::ConfigMap => &mut self.config_view.configmap_yaml,
+            k8s::ResourceKind::Secret => &mut self.config_view.secret_yaml,
+            k8s::ResourceKind::Job => &mut self.jobs_view.yaml,
+            k8s::ResourceKind::CronJob => &mut self.cronjobs_view.yaml,
+        }
+    }
+
+    fn stop_following_logs(&mut self) {
+        if let Some(handle) = self.log_follow_task.take() {
+            handle.abort();
+        }
+        self.log_stream_id += 1;
+    }
+
     fn handle_config_action(&mut self, action: ConfigAction) {
         let client = self.k8s_client.clone();
         let tx = self.message_tx.clone();
 
         match action {
+            ConfigAction::Yaml(action) => self.handle_yaml_action(action),
             ConfigAction::UpdateConfigMap(ns, name, data) => {
+                let audit = self.audit("ConfigMap", &ns, &name, "update").with_patch(json!({"data": &data}));
+                self.run_audited(audit, move |c| async move {
+                    k8s::update_configmap(&c, &ns, &name, data).await?;
+                    Ok(format!("Updated configmap {}", name))
+                });
+            }
+            ConfigAction::CreateConfigMap(ns, name, data) => {
+                let patch = json!({"data": audit::printable_data(&data)});
+                let audit = self.audit("ConfigMap", &ns, &name, "create").with_patch(patch);
+                self.run_audited(audit, move |c| async move {
+                    k8s::create_configmap(&c, &ns, &name, data).await?;
+                    Ok(format!("Created configmap {}", name))
+                });
+            }
+            ConfigAction::CreateSecret(ns, name, kind, data) => {
+                // Only the keys of a secret are recorded
+                let patch = audit::redact_secret(json!({"data": audit::printable_data(&data)}));
+                let audit = self.audit("Secret", &ns, &name, "create").with_patch(patch);
+                self.run_audited(audit, move |c| async move {
+                    k8s::create_secret(&c, &ns, &name, kind, data).await?;
+                    Ok(format!("Created secret {}", name))
+                });
+            }
+            ConfigAction::RevealSecretKey(ns, name, key) => {
                 self.runtime.spawn(async move {
                     if let Some(c) = client.get_client().await {
-                        match k8s::update_configmap(&c, &ns, &name, data).await {
-                            Ok(()) => {
-                                let _ = tx.send(AppMessage::ActionCompleted(Ok(format!(
-                                    "Updated configmap {}",
-                                    name
-                                ))));
-                            }
-                            Err(e) => {
-                                let _ = tx.send(AppMessage::ActionCompleted(Err(e.to_string())));
-                            }
-                        }
+                        let result = k8s::get_secret_value(&c, &ns, &name, &key)
+                            .await
+                            .map_err(|e| format!("{:#}", e));
+                        let target = k8s::ResourceRef::new(k8s::ResourceKind::Secret, &ns, &name);
+                        let _ = tx.send(AppMessage::SecretValueRevealed(target, key, result));
+                    }
+                });
+            }
+            ConfigAction::EditSecret(ns, name) => {
+                self.runtime.spawn(async move {
+                    if let Some(c) = client.get_client().await {
+                        let result = k8s::get_secret_data(&c, &ns, &name)
+                            .await
+                            .map_err(|e| format!("{:#}", e));
+                        let target = k8s::ResourceRef::new(k8s::ResourceKind::Secret, &ns, &name);
+                        let _ = tx.send(AppMessage::SecretDataLoaded(target, result));
                     }
                 });
             }
+            ConfigAction::UpdateSecret(ns, name, data) => {
+                let patch = audit::redact_secret(json!({"data": &data.data}));
+                let audit = self.audit("Secret", &ns, &name, "update").with_patch(patch);
+                self.run_audited(audit, move |c| async move {
+                    k8s::update_secret(&c, &ns, &name, data).await?;
+                    Ok(format!("Updated secret {}", name))
+                });
+            }
         }
     }
 
     fn handle_job_action(&mut self, action: JobAction) {
-        let client = self.k8s_client.clone();
-        let tx = self.message_tx.clone();
-
         match action {
+            JobAction::Yaml(action) => self.handle_yaml_action(action),
+            JobAction::LoadEvents(target) => self.load_object_events(target),
             JobAction::Delete(ns, name) => {
-                self.runtime.spawn(async move {
-                    if let Some(c) = client.get_client().await {
-                        match k8s::delete_job(&c, &ns, &name).await {
-                            Ok(()) => {
-                                let _ = tx.send(AppMessage::ActionCompleted(Ok(format!(
-                                    "Deleted job {}",
-                                    name
-                                ))));
-                            }
-                            Err(e) => {
-                                let _ = tx.send(AppMessage::ActionCompleted(Err(e.to_string())));
-                            }
-                        }
-                    }
+                let audit = self.audit("Job", &ns, &name, "delete");
+                self.run_audited(audit, move |c| async move {
+                    k8s::delete_job(&c, &ns, &name).await?;
+                    Ok(format!("Deleted job {}", name))
+                });
+            }
+            JobAction::DeleteMany(targets) => {
+                let audit = self.audit("Job", "", "", "delete");
+                self.run_bulk_action("Deleted jobs".to_string(), audit, targets, |c, ns, name| async move {
+                    k8s::delete_job(&c, &ns, &name).await
                 });
             }
         }
@@ -616,41 +2185,34 @@ impl KubeDashboard {
 
         match action {
             CronJobAction::Trigger(ns, name) => {
-                self.runtime.spawn(async move {
-                    if let Some(c) = client.get_client().await {
-                        match k8s::trigger_cronjob(&c, &ns, &name).await {
-                            Ok(job_name) => {
-                                let _ = tx.send(AppMessage::ActionCompleted(Ok(format!(
-                                    "Created job {} from cronjob {}",
-                                    job_name, name
-                                ))));
-                            }
-                            Err(e) => {
-                                let _ = tx.send(AppMessage::ActionCompleted(Err(e.to_string())));
-                            }
-                        }
-                    }
+                let audit = self.audit("CronJob", &ns, &name, "trigger");
+                self.run_audited(audit, move |c| async move {
+                    let job_name = k8s::trigger_cronjob(&c, &ns, &name).await?;
+                    Ok(format!("Created job {} from cronjob {}", job_name, name))
                 });
             }
             CronJobAction::Suspend(ns, name, suspend) => {
-                self.runtime.spawn(async move {
-                    if let Some(c) = client.get_client().await {
-                        match k8s::suspend_cronjob(&c, &ns, &name, suspend).await {
-                            Ok(()) => {
-                                let msg = if suspend {
-                                    format!("Suspended cronjob {}", name)
-                                } else {
-                                    format!("Resumed cronjob {}", name)
-                                };
-                                let _ = tx.send(AppMessage::ActionCompleted(Ok(msg)));
-                            }
-                            Err(e) => {
-                                let _ = tx.send(AppMessage::ActionCompleted(Err(e.to_string())));
-                            }
-                        }
-                    }
+                let action = if suspend { "suspend" } else { "resume" };
+                let audit = self.audit("CronJob", &ns, &name, action).with_patch(json!({"spec": {"suspend": suspend}}));
+                self.run_audited(audit, move |c| async move {
+                    k8s::suspend_cronjob(&c, &ns, &name, suspend).await?;
+                    Ok(if suspend {
+                        format!("Suspended cronjob {}", name)
+                    } else {
+                        format!("Resumed cronjob {}", name)
+                    })
+                });
+            }
+            CronJobAction::SuspendMany(targets, suspend) => {
+                let title = if suspend { "Suspended cronjobs" } else { "Resumed cronjobs" };
+                let action = if suspend { "suspend" } else { "resume" };
+                let audit = self.audit("CronJob", "", "", action).with_patch(json!({"spec": {"suspend": suspend}}));
+                self.run_bulk_action(title.to_string(), audit, targets, move |c, ns, name| async move {
+                    k8s::suspend_cronjob(&c, &ns, &name, suspend).await
                 });
             }
+            CronJobAction::Yaml(action) => self.handle_yaml_action(action),
+            CronJobAction::LoadEvents(target) => self.load_object_events(target),
             CronJobAction::GetHistory(ns, name) => {
                 self.runtime.spawn(async move {
                     if let Some(c) = client.get_client().await {
@@ -675,7 +2237,11 @@ impl KubeDashboard {
                     match result {
                         Ok(()) => {
                             self.initialized = true;
-                            self.refresh_current_view();
+                            if self.settings_restored {
+                                self.refresh_current_view();
+                            } else {
+                                self.restore_session();
+                            }
                         }
                         Err(e) => {
                             self.init_error = Some(e);
@@ -693,6 +2259,14 @@ impl KubeDashboard {
                     match result {
                         Ok(()) => {
                             self.add_notification("Context switched successfully", false);
+                            // Another cluster serves a different set of kinds
+                            self.custom_kinds.clear();
+                            self.pod_usage.clear();
+                            self.node_usage.clear();
+                            self.metrics_available = false;
+                            self.custom_objects.clear();
+                            self.custom_view.selected_kind = None;
+                            self.custom_view.selected_object = None;
                             self.refresh_current_view();
                         }
                         Err(e) => {
@@ -703,21 +2277,81 @@ impl KubeDashboard {
                 AppMessage::DeploymentsLoaded(result) => {
                     self.loading_deployments = false;
                     match result {
-                        Ok(deps) => self.deployments = deps,
+                        Ok(deps) => {
+                            self.deployments = deps;
+                            self.error_deployments = None;
+                            self.refresh_selector_chips();
+                        }
                         Err(e) => self.error_deployments = Some(e),
                     }
                 }
+                AppMessage::StatefulSetsLoaded(result) => {
+                    self.loading_statefulsets = false;
+                    match result {
+                        Ok(sets) => {
+                            self.statefulsets = sets;
+                            self.error_statefulsets = None;
+                        }
+                        Err(e) => self.error_statefulsets = Some(e),
+                    }
+                }
+                AppMessage::DaemonSetsLoaded(result) => {
+                    self.loading_daemonsets = false;
+                    match result {
+                        Ok(sets) => {
+                            self.daemonsets = sets;
+                            self.error_daemonsets = None;
+                        }
+                        Err(e) => self.error_daemonsets = Some(e),
+                    }
+                }
+                AppMessage::ReplicaSetsLoaded(result) => {
+                    self.loading_replicasets = false;
+                    match result {
+                        Ok(sets) => {
+                            self.replicasets = sets;
+                            self.error_replicasets = None;
+                        }
+                        Err(e) => self.error_replicasets = Some(e),
+                    }
+                }
                 AppMessage::PodsLoaded(result) => {
                     self.loading_pods = false;
                     match result {
-                        Ok(pods) => self.pods = pods,
+                        Ok(pods) => {
+                            self.pods = pods;
+                            self.error_pods = None;
+                        }
                         Err(e) => self.error_pods = Some(e),
                     }
                 }
+                AppMessage::PodMetricsLoaded(usage) => {
+                    self.metrics_available = usage.is_some();
+                    let usage = usage.unwrap_or_default();
+                    // Forget pods that are gone, or out of the namespace now shown
+                    let current: HashSet<(&str, &str)> =
+                        usage.iter().map(|u| (u.namespace.as_str(), u.name.as_str())).collect();
+                    self.pod_usage.retain(|(ns, name), _| current.contains(&(ns.as_str(), name.as_str())));
+                    for u in usage {
+                        self.pod_usage.entry((u.namespace, u.name)).or_default().push(u.usage);
+                    }
+                }
+                AppMessage::NodeMetricsLoaded(usage) => {
+                    self.metrics_available = usage.is_some();
+                    let usage = usage.unwrap_or_default();
+                    self.node_usage.retain(|name, _| usage.iter().any(|u| &u.name == name));
+                    for u in usage {
+                        self.node_usage.entry(u.name).or_default().push(u.usage);
+                    }
+                }
                 AppMessage::ServicesLoaded(result) => {
                     self.loading_services = false;
                     match result {
-                        Ok(svcs) => self.services = svcs,
+                        Ok(svcs) => {
+                            self.services = svcs;
+                            self.error_services = None;
+                            self.refresh_selector_chips();
+                        }
                         Err(e) => self.error_services = Some(e),
                     }
                 }
@@ -730,7 +2364,10 @@ impl KubeDashboard {
                 AppMessage::ConfigMapsLoaded(result) => {
                     self.loading_config = false;
                     match result {
-                        Ok(cms) => self.configmaps = cms,
+                        Ok(cms) => {
+                            self.configmaps = cms;
+                            self.error_config = None;
+                        }
                         Err(e) => self.error_config = Some(e),
                     }
                 }
@@ -743,23 +2380,195 @@ impl KubeDashboard {
                 AppMessage::JobsLoaded(result) => {
                     self.loading_jobs = false;
                     match result {
-                        Ok(jobs) => self.jobs = jobs,
+                        Ok(jobs) => {
+                            self.jobs = jobs;
+                            self.error_jobs = None;
+                        }
                         Err(e) => self.error_jobs = Some(e),
                     }
                 }
                 AppMessage::CronJobsLoaded(result) => {
                     self.loading_cronjobs = false;
                     match result {
-                        Ok(cjs) => self.cronjobs = cjs,
+                        Ok(cjs) => {
+                            self.cronjobs = cjs;
+                            self.error_cronjobs = None;
+                        }
                         Err(e) => self.error_cronjobs = Some(e),
                     }
                 }
+                AppMessage::EventsLoaded(result) => {
+                    self.loading_events = false;
+                    match result {
+                        Ok(events) => {
+                            self.events = events;
+                            self.error_events = None;
+                        }
+                        Err(e) => self.error_events = Some(e),
+                    }
+                }
+                AppMessage::ObjectEventsLoaded(target, result) => {
+                    if let Some(events) = self.object_events(target.kind) {
+                        events.set_loaded(result);
+                    }
+                }
+                AppMessage::DeploymentHistoryLoaded(target, result) => {
+                    let selected = self.deployments_view.selected_deployment.as_ref();
+                    if selected.is_some_and(|d| d.namespace == target.namespace && d.name == target.name) {
+                        self.deployments_view.history.set_loaded(result);
+                    }
+                }
+                AppMessage::RolloutLoaded(target, result) => {
+                    match target.kind {
+                        k8s::ResourceKind::StatefulSet => self.statefulsets_view.rollout.set_loaded(result),
+                        k8s::ResourceKind::DaemonSet => self.daemonsets_view.rollout.set_loaded(result),
+                        _ => {}
+                    }
+                }
+                AppMessage::NodesLoaded(result) => {
+                    self.loading_nodes = false;
+                    match result {
+                        Ok(nodes) => {
+                            self.nodes = nodes;
+                            self.error_nodes = None;
+                        }
+                        Err(e) => self.error_nodes = Some(e),
+                    }
+                }
+                AppMessage::TopologyLoaded(result) => {
+                    self.loading_topology = false;
+                    self.topology_view.set_loaded();
+                    match result {
+                        Ok(topology) => {
+                            self.topology = topology;
+                            self.error_topology = None;
+                        }
+                        Err(e) => self.error_topology = Some(e),
+                    }
+                }
+                AppMessage::ResourceKindsDiscovered(result) => {
+                    self.loading_custom_kinds = false;
+                    match result {
+                        Ok(kinds) => {
+                            self.custom_kinds = kinds;
+                            self.error_custom_kinds = None;
+                        }
+                        Err(e) => self.error_custom_kinds = Some(e),
+                    }
+                }
+                AppMessage::CustomObjectsLoaded(kind, result) => {
+                    // Drop lists for a kind that is no longer selected
+                    if self.custom_view.selected_kind.as_ref() == Some(&kind) {
+                        self.loading_custom_objects = false;
+                        match result {
+                            Ok(objects) => {
+                                self.custom_objects = objects;
+                                self.error_custom_objects = None;
+                            }
+                            Err(e) => self.error_custom_objects = Some(e),
+                        }
+                    }
+                }
+                AppMessage::CustomObjectYamlLoaded(name, result) => {
+                    self.custom_view.set_yaml(&name, result);
+                }
+                AppMessage::DrainProgress(id, status) => {
+                    if id == self.drain_id {
+                        self.nodes_view.drain_progress(status);
+                    }
+                }
+                AppMessage::DeploymentRolloutUpdate(id, result) => {
+                    if id == self.rollout_id {
+                        self.deployments_view.rollout_update(result);
+                    }
+                }
+                AppMessage::DeploymentRolloutEnded(id) => {
+                    if id == self.rollout_id {
+                        self.rollout_task = None;
+                        // The change's new revision is recorded by the time the rollout settles
+                        if let Some(target) = self.history_after_rollout.take() {
+                            self.handle_deployment_action(DeploymentAction::LoadHistory(target));
+                        }
+                    }
+                }
+                AppMessage::DrainFinished(id, result) => {
+                    if id == self.drain_id {
+                        self.drain_task = None;
+                        match &result {
+                            Ok(()) => self.add_notification("Node drained", false),
+                            Err(e) => self.add_notification(&format!("Drain failed: {}", e), true),
+                        }
+                        self.nodes_view.drain_finished(result);
+                    }
+                }
                 AppMessage::PodLogsLoaded(result) => {
                     match result {
                         Ok(logs) => self.pods_view.set_logs(logs),
                         Err(e) => self.pods_view.set_logs(format!("Error: {}", e)),
                     }
                 }
+                AppMessage::PodLogLine(id, line) => {
+                    if id == self.log_stream_id {
+                        self.pods_view.append_log_line(line);
+                    }
+                }
+                AppMessage::PodLogFollowEnded(id, result) => {
+                    if id == self.log_stream_id {
+                        self.log_follow_task = None;
+                        self.pods_view.follow_ended(result);
+                    }
+                }
+                AppMessage::ExecOutput(id, bytes) => {
+                    if id == self.exec_session_id {
+                        self.pods_view.exec_output(&bytes);
+                    }
+                }
+                AppMessage::ExecEnded(id, result) => {
+                    if id == self.exec_session_id {
+                        self.exec_input = None;
+                        self.pods_view.exec_ended(result);
+                    }
+                }
+                AppMessage::PortForwardListening(id, pod, port) => {
+                    if let Some(fwd) = self.port_forwards.iter_mut().find(|f| f.id == id) {
+                        fwd.pod = Some(pod);
+                        fwd.local_port = Some(port);
+                        fwd.status = k8s::ForwardStatus::Active;
+                        let message = format!("Forwarding 127.0.0.1:{} -> {}:{}", port, fwd.target, fwd.remote_port);
+                        self.add_notification(&message, false);
+                    }
+                }
+                AppMessage::PortForwardFailed(id, e) => {
+                    self.port_forward_tasks.remove(&id);
+                    if let Some(fwd) = self.port_forwards.iter_mut().find(|f| f.id == id) {
+                        fwd.status = k8s::ForwardStatus::Failed(e.clone());
+                        self.add_notification(&format!("Port forward failed: {}", e), true);
+                    }
+                }
+                AppMessage::YamlLoaded(target, result) => {
+                    self.yaml_editor(target.kind).set_loaded(result);
+                }
+                AppMessage::YamlDiffed(target, result) => {
+                    self.yaml_editor(target.kind).set_diff(result);
+                }
+                AppMessage::YamlApplied(target, result) => {
+                    match result {
+                        Ok(()) => {
+                            self.add_notification(
+                                &format!("Applied {} {}", target.kind.as_str(), target.name),
+                                false,
+                            );
+                            self.handle_yaml_action(YamlAction::Load(target));
+                        }
+                        Err(e) => self.yaml_editor(target.kind).set_apply_error(e),
+                    }
+                }
+                AppMessage::SecretValueRevealed(target, key, result) => {
+                    self.config_view.secret_value_revealed(&target.namespace, &target.name, key, result);
+                }
+                AppMessage::SecretDataLoaded(target, result) => {
+                    self.config_view.secret_data_loaded(&target.namespace, &target.name, result);
+                }
                 AppMessage::CronJobHistoryLoaded(result) => {
                     match result {
                         Ok(jobs) => self.cronjobs_view.set_history(jobs),
@@ -773,13 +2582,24 @@ impl KubeDashboard {
                     match result {
                         Ok(msg) => {
                             self.add_notification(&msg, false);
-                            self.refresh_current_view();
+                            if !self.live_updates {
+                                self.refresh_current_view();
+                            }
                         }
                         Err(e) => {
                             self.add_notification(&format!("Error: {}", e), true);
                         }
                     }
                 }
+                AppMessage::BulkCompleted(title, results) => {
+                    let summary = BulkSummary::new(title, results);
+                    self.add_notification(&summary.headline(), summary.failures() > 0);
+                    if !self.live_updates {
+                        self.refresh_current_view();
+                    }
+                    self.bulk_summary = Some(summary);
+                }
+                AppMessage::Audited(entry) => self.record_audit(entry),
             }
         }
     }
@@ -801,18 +2621,58 @@ impl KubeDashboard {
             // Context selector
             ui.label(RichText::new("Context").strong());
             egui::ComboBox::from_id_salt("context_selector")
-                .selected_text(self.current_context.as_deref().unwrap_or("Select context..."))
+                .selected_text(
+                    self.current_context
+                        .as_deref()
+                        .map(|c| truncate_string(c, 24))
+                        .unwrap_or_else(|| "Select context...".to_string()),
+                )
                 .width(180.0)
                 .show_ui(ui, |ui| {
                     for ctx in &self.contexts.clone() {
                         let selected = self.current_context.as_ref() == Some(&ctx.name);
-                        if ui.selectable_label(selected, &ctx.name).clicked() {
+                        let label = match self.settings.context_profiles.get(&ctx.name) {
+                            Some(profile) => format!("{} ({})", ctx.name, profile.label()),
+                            None => ctx.name.clone(),
+                        };
+                        if ui
+                            .selectable_label(selected, label)
+                            .on_hover_text(format!(
+                                "Cluster: {}\nUser: {}\nNamespace: {}",
+                                ctx.cluster,
+                                ctx.user,
+                                ctx.namespace.as_deref().unwrap_or("default")
+                            ))
+                            .clicked()
+                        {
                             self.current_context = Some(ctx.name.clone());
                             self.switch_context(&ctx.name);
                         }
                     }
                 });
 
+            if let Some(context) = self.current_context.clone() {
+                let mut profile = self.context_profile();
+                ui.horizontal(|ui| {
+                    ui.label(RichText::new("Safety").weak());
+                    egui::ComboBox::from_id_salt("context_profile")
+                        .selected_text(RichText::new(profile.label()).color(profile.color()))
+                        .show_ui(ui, |ui| {
+                            for option in ContextProfile::ALL {
+                                ui.selectable_value(&mut profile, option, option.label());
+                            }
+                        })
+                        .response
+                        .on_hover_text("Protected contexts ask for the object's name before changes; read-only contexts allow none");
+                });
+                if profile != self.context_profile() {
+                    match profile {
+                        ContextProfile::Normal => self.settings.context_profiles.remove(&context),
+                        _ => self.settings.context_profiles.insert(context, profile),
+                    };
+                }
+            }
+
             ui.add_space(12.0);
 
             // Namespace selector
@@ -852,28 +2712,49 @@ impl KubeDashboard {
                 .clicked()
             {
                 self.current_view = View::Deployments;
-                self.load_deployments();
+                self.refresh_current_view();
+            }
+            if ui
+                .selectable_label(self.current_view == View::StatefulSets, "  StatefulSets")
+                .clicked()
+            {
+                self.current_view = View::StatefulSets;
+                self.refresh_current_view();
+            }
+            if ui
+                .selectable_label(self.current_view == View::DaemonSets, "  DaemonSets")
+                .clicked()
+            {
+                self.current_view = View::DaemonSets;
+                self.refresh_current_view();
+            }
+            if ui
+                .selectable_label(self.current_view == View::ReplicaSets, "  ReplicaSets")
+                .clicked()
+            {
+                self.current_view = View::ReplicaSets;
+                self.refresh_current_view();
             }
             if ui
                 .selectable_label(self.current_view == View::Pods, "  Pods")
                 .clicked()
             {
                 self.current_view = View::Pods;
-                self.load_pods();
+                self.refresh_current_view();
             }
             if ui
                 .selectable_label(self.current_view == View::Jobs, "  Jobs")
                 .clicked()
             {
                 self.current_view = View::Jobs;
-                self.load_jobs();
+                self.refresh_current_view();
             }
             if ui
                 .selectable_label(self.current_view == View::CronJobs, "  CronJobs")
                 .clicked()
             {
                 self.current_view = View::CronJobs;
-                self.load_cronjobs();
+                self.refresh_current_view();
             }
 
             ui.add_space(12.0);
@@ -883,8 +2764,20 @@ impl KubeDashboard {
                 .clicked()
             {
                 self.current_view = View::Services;
-                self.load_services();
-                self.load_ingresses();
+                self.refresh_current_view();
+            }
+
+            let forwards_label = if self.port_forwards.is_empty() {
+                "  Port Forwards".to_string()
+            } else {
+                format!("  Port Forwards ({})", self.port_forwards.len())
+            };
+            if ui
+                .selectable_label(self.current_view == View::PortForwards, forwards_label)
+                .clicked()
+            {
+                self.current_view = View::PortForwards;
+                self.refresh_current_view();
             }
 
             ui.add_space(12.0);
@@ -894,8 +2787,46 @@ impl KubeDashboard {
                 .clicked()
             {
                 self.current_view = View::Config;
-                self.load_configmaps();
-                self.load_secrets();
+                self.refresh_current_view();
+            }
+
+            ui.add_space(12.0);
+            ui.label(RichText::new("Cluster").strong().small());
+            if ui
+                .selectable_label(self.current_view == View::Nodes, "  Nodes")
+                .clicked()
+            {
+                self.current_view = View::Nodes;
+                self.refresh_current_view();
+            }
+            if ui
+                .selectable_label(self.current_view == View::Events, "  Events")
+                .clicked()
+            {
+                self.current_view = View::Events;
+                self.refresh_current_view();
+            }
+            if ui
+                .selectable_label(self.current_view == View::Topology, "  Topology")
+                .clicked()
+            {
+                self.current_view = View::Topology;
+                self.refresh_current_view();
+            }
+            if ui
+                .selectable_label(self.current_view == View::CustomResources, "  Custom Resources")
+                .clicked()
+            {
+                self.current_view = View::CustomResources;
+                self.refresh_current_view();
+            }
+            if ui
+                .selectable_label(self.current_view == View::Audit, "  Audit Log")
+                .on_hover_text("Changes made to clusters from this dashboard")
+                .clicked()
+            {
+                self.current_view = View::Audit;
+                self.refresh_current_view();
             }
 
             ui.with_layout(egui::Layout::bottom_up(egui::Align::LEFT), |ui| {
@@ -903,6 +2834,13 @@ impl KubeDashboard {
                 if ui.button("Refresh").clicked() {
                     self.refresh_current_view();
                 }
+                if ui
+                    .checkbox(&mut self.live_updates, "Live updates")
+                    .on_hover_text("Watch the cluster and update tables as objects change")
+                    .changed()
+                {
+                    self.refresh_current_view();
+                }
                 ui.add_space(8.0);
 
                 // Theme toggle
@@ -962,6 +2900,14 @@ impl eframe::App for KubeDashboard {
 
         // Show notifications
         self.show_notifications(ctx);
+        if self.bulk_summary.as_ref().is_some_and(|summary| !summary.show(ctx)) {
+            self.bulk_summary = None;
+        }
+
+        if self.settings_restored {
+            self.capture_settings(ctx);
+            self.save_settings(false);
+        }
 
         // Check initialization
         if let Some(error) = self.init_error.clone() {
@@ -1001,6 +2947,24 @@ impl eframe::App for KubeDashboard {
             return;
         }
 
+        let profile = self.context_profile();
+        profile.publish(ctx);
+
+        // Drawn before the panels so it takes Enter and the arrows ahead of the tables
+        if self.palette.handle_shortcut(ctx) {
+            let entries = self.palette_entries();
+            if let Some(command) = self.palette.show(ctx, &entries) {
+                self.handle_palette_command(command);
+            }
+        }
+
+        if profile != ContextProfile::Normal {
+            let context = self.current_context.clone().unwrap_or_default();
+            egui::TopBottomPanel::top("context_profile_banner")
+                .frame(egui::Frame::none())
+                .show(ctx, |ui| safety::banner(ui, &context, profile));
+        }
+
         // Sidebar
         egui::SidePanel::left("sidebar")
             .resizable(false)
@@ -1015,43 +2979,77 @@ impl eframe::App for KubeDashboard {
 
             match self.current_view {
                 View::Deployments => {
-                    if let Some(action) = self.deployments_view.show(
+                    for action in self.deployments_view.show(
                         ui,
                         &self.deployments,
                         self.loading_deployments,
                         self.error_deployments.as_deref(),
                     ) {
-                        self.handle_deployment_action(action);
+                        self.dispatch(action, Self::handle_deployment_action);
+                    }
+                }
+                View::StatefulSets => {
+                    for action in self.statefulsets_view.show(
+                        ui,
+                        &self.statefulsets,
+                        self.loading_statefulsets,
+                        self.error_statefulsets.as_deref(),
+                    ) {
+                        self.dispatch(action, Self::handle_statefulset_action);
+                    }
+                }
+                View::DaemonSets => {
+                    for action in self.daemonsets_view.show(
+                        ui,
+                        &self.daemonsets,
+                        self.loading_daemonsets,
+                        self.error_daemonsets.as_deref(),
+                    ) {
+                        self.dispatch(action, Self::handle_daemonset_action);
+                    }
+                }
+                View::ReplicaSets => {
+                    if let Some(action) = self.replicasets_view.show(
+                        ui,
+                        &self.replicasets,
+                        self.loading_replicasets,
+                        self.error_replicasets.as_deref(),
+                    ) {
+                        self.dispatch(action, Self::handle_replicaset_action);
                     }
                 }
                 View::Pods => {
                     if let Some(action) = self.pods_view.show(
                         ui,
                         &self.pods,
+                        self.metrics_available.then_some(&self.pod_usage),
                         self.loading_pods,
                         self.error_pods.as_deref(),
                     ) {
-                        self.handle_pod_action(action);
+                        self.dispatch(action, Self::handle_pod_action);
                     }
                 }
                 View::Services => {
-                    self.services_view.show(
+                    if let Some(action) = self.services_view.show(
                         ui,
                         &self.services,
                         &self.ingresses,
                         self.loading_services,
                         self.error_services.as_deref(),
-                    );
+                    ) {
+                        self.dispatch(action, Self::handle_service_action);
+                    }
                 }
                 View::Config => {
                     if let Some(action) = self.config_view.show(
                         ui,
                         &self.configmaps,
                         &self.secrets,
+                        self.selected_namespace.as_deref(),
                         self.loading_config,
                         self.error_config.as_deref(),
                     ) {
-                        self.handle_config_action(action);
+                        self.dispatch(action, Self::handle_config_action);
                     }
                 }
                 View::Jobs => {
@@ -1061,7 +3059,7 @@ impl eframe::App for KubeDashboard {
                         self.loading_jobs,
                         self.error_jobs.as_deref(),
                     ) {
-                        self.handle_job_action(action);
+                        self.dispatch(action, Self::handle_job_action);
                     }
                 }
                 View::CronJobs => {
@@ -1071,10 +3069,84 @@ impl eframe::App for KubeDashboard {
                         self.loading_cronjobs,
                         self.error_cronjobs.as_deref(),
                     ) {
-                        self.handle_cronjob_action(action);
+                        self.dispatch(action, Self::handle_cronjob_action);
+                    }
+                }
+                View::PortForwards => {
+                    if let Some(action) = self.portforwards_view.show(ui, &self.port_forwards) {
+                        self.handle_port_forward_action(action);
+                    }
+                }
+                View::Nodes => {
+                    if let Some(action) = self.nodes_view.show(
+                        ui,
+                        &self.nodes,
+                        &self.pods,
+                        self.metrics_available.then_some(&self.node_usage),
+                        self.loading_nodes,
+                        self.error_nodes.as_deref(),
+                    ) {
+                        self.dispatch(action, Self::handle_node_action);
+                    }
+                }
+                View::Events => {
+                    self.events_view.show(
+                        ui,
+                        &self.events,
+                        self.loading_events,
+                        self.error_events.as_deref(),
+                    );
+                }
+                View::Topology => {
+                    self.topology_view.show(
+                        ui,
+                        &self.topology,
+                        self.loading_topology,
+                        self.error_topology.as_deref(),
+                    );
+                }
+                View::CustomResources => {
+                    if let Some(action) = self.custom_view.show(
+                        ui,
+                        &self.custom_kinds,
+                        self.loading_custom_kinds,
+                        self.error_custom_kinds.as_deref(),
+                        &self.custom_objects,
+                        self.loading_custom_objects,
+                        self.error_custom_objects.as_deref(),
+                    ) {
+                        self.handle_custom_resource_action(action);
+                    }
+                }
+                View::Audit => {
+                    self.audit_view.show(ui, &self.audit_entries, self.audit_log_path.as_deref());
+                }
+            }
+
+            if let Some(held) = &self.held_change {
+                match confirm_dialog(ui, "Confirm Change", &held.change.message, "Confirm", &held.change.name) {
+                    Some(true) => {
+                        if let Some(held) = self.held_change.take() {
+                            (held.run)(self);
+                        }
                     }
+                    Some(false) => self.held_change = None,
+                    None => {}
                 }
             }
         });
+
+        if self.selector_bar_mut(self.current_view).is_some_and(|bar| bar.take_changed()) {
+            self.refresh_current_view();
+        }
+    }
+
+    fn on_exit(&mut self, _gl: Option<&eframe::glow::Context>) {
+        self.save_settings(true);
+        self.stop_all_port_forwards();
+        self.stop_following_logs();
+        self.stop_drain();
+        self.stop_following_rollout();
+        self.stop_watches();
     }
 }
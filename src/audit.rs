@@ -0,0 +1,235 @@
+use anyhow::{Context, Result};
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+use serde_json::Value;
+use std::collections::BTreeMap;
+use std::io::{BufRead, BufReader, Write};
+use std::path::{Path, PathBuf};
+
+/// Stands in for secret values, which never reach the audit log.
+const REDACTED: &str = "<redacted>";
+
+/// One change made to a cluster from the dashboard, stored as a line of
+/// `kubectl-dashboard/audit.jsonl` under the XDG state directory.
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+pub struct AuditEntry {
+    /// When the API server answered.
+    pub timestamp: DateTime<Utc>,
+    /// Local account that ran the dashboard.
+    pub user: String,
+    pub context: Option<String>,
+    /// Empty for cluster-scoped objects such as nodes.
+    pub namespace: String,
+    pub kind: String,
+    pub name: String,
+    /// What was done, e.g. "scale" or "delete".
+    pub action: String,
+    /// The change that was sent, where there is one to show.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub patch: Option<Value>,
+    pub result: AuditResult,
+}
+
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+#[serde(tag = "status", content = "error", rename_all = "lowercase")]
+pub enum AuditResult {
+    Succeeded,
+    Failed(String),
+}
+
+/// A change that has been started but not answered yet. Built when the action is
+/// dispatched, so the entry names the context it was sent to even if the user
+/// switches contexts meanwhile.
+#[derive(Clone, Debug)]
+pub struct PendingAudit {
+    context: Option<String>,
+    kind: String,
+    namespace: String,
+    name: String,
+    action: String,
+    patch: Option<Value>,
+}
+
+impl PendingAudit {
+    pub fn new(context: Option<String>, kind: &str, namespace: &str, name: &str, action: &str) -> Self {
+        Self {
+            context,
+            kind: kind.to_string(),
+            namespace: namespace.to_string(),
+            name: name.to_string(),
+            action: action.to_string(),
+            patch: None,
+        }
+    }
+
+    pub fn with_patch(mut self, patch: Value) -> Self {
+        self.patch = Some(patch);
+        self
+    }
+
+    /// The same change made to another object, for bulk actions.
+    pub fn for_object(&self, namespace: &str, name: &str) -> Self {
+        Self {
+            namespace: namespace.to_string(),
+            name: name.to_string(),
+            ..self.clone()
+        }
+    }
+
+    pub fn finish<T>(self, result: &Result<T, String>) -> AuditEntry {
+        AuditEntry {
+            timestamp: Utc::now(),
+            user: current_user(),
+            context: self.context,
+            namespace: self.namespace,
+            kind: self.kind,
+            name: self.name,
+            action: self.action,
+            patch: self.patch,
+            result: match result {
+                Ok(_) => AuditResult::Succeeded,
+                Err(e) => AuditResult::Failed(e.clone()),
+            },
+        }
+    }
+}
+
+fn current_user() -> String {
+    std::env::var("USER")
+        .or_else(|_| std::env::var("USERNAME"))
+        .unwrap_or_else(|_| "unknown".to_string())
+}
+
+/// Replaces the values under `data` and `stringData` of a secret (or a patch to
+/// one) so only its keys are recorded.
+pub fn redact_secret(mut value: Value) -> Value {
+    for field in ["data", "stringData"] {
+        if let Some(Value::Object(data)) = value.get_mut(field) {
+            for v in data.values_mut() {
+                *v = Value::String(REDACTED.to_string());
+            }
+        }
+    }
+    value
+}
+
+/// Values of a created configmap or secret as text; binary values are recorded
+/// by size only.
+pub fn printable_data(data: &BTreeMap<String, Vec<u8>>) -> BTreeMap<String, String> {
+    data.iter()
+        .map(|(key, value)| {
+            let text = match std::str::from_utf8(value) {
+                Ok(text) => text.to_string(),
+                Err(_) => format!("<{} bytes>", value.len()),
+            };
+            (key.clone(), text)
+        })
+        .collect()
+}
+
+/// `$XDG_STATE_HOME/kubectl-dashboard/audit.jsonl`, falling back to `~/.local/state`.
+pub fn log_path() -> Option<PathBuf> {
+    let state_dir = std::env::var_os("XDG_STATE_HOME")
+        .filter(|dir| !dir.is_empty())
+        .map(PathBuf::from)
+        .or_else(|| std::env::var_os("HOME").map(|home| PathBuf::from(home).join(".local").join("state")))?;
+    Some(state_dir.join("kubectl-dashboard").join("audit.jsonl"))
+}
+
+/// Adds `entry` as one line at the end of the log. Existing lines are never
+/// rewritten.
+pub fn append(path: &Path, entry: &AuditEntry) -> Result<()> {
+    if let Some(dir) = path.parent() {
+        std::fs::create_dir_all(dir).with_context(|| format!("Failed to create {:?}", dir))?;
+    }
+    let mut line = serde_json::to_string(entry).context("Failed to serialize audit entry")?;
+    line.push('\n');
+
+    let mut file = std::fs::OpenOptions::new()
+        .create(true)
+        .append(true)
+        .open(path)
+        .with_context(|| format!("Failed to open {:?}", path))?;
+    // A single write, so concurrent dashboards cannot interleave within a line
+    file.write_all(line.as_bytes()).with_context(|| format!("Failed to write {:?}", path))?;
+    Ok(())
+}
+
+/// The last `limit` entries of the log, oldest first. A missing log is empty;
+/// lines that do not parse are skipped.
+pub fn load_recent(path: &Path, limit: usize) -> Result<Vec<AuditEntry>> {
+    let file = match std::fs::File::open(path) {
+        Ok(file) => file,
+        Err(e) if e.kind() == std::io::ErrorKind::NotFound => return Ok(Vec::new()),
+        Err(e) => return Err(e).with_context(|| format!("Failed to open {:?}", path)),
+    };
+
+    let mut entries = std::collections::VecDeque::with_capacity(limit);
+    for line in BufReader::new(file).lines() {
+        let line = line.with_context(|| format!("Failed to read {:?}", path))?;
+        if line.trim().is_empty() {
+            continue;
+        }
+        match serde_json::from_str(&line) {
+            Ok(entry) => {
+                if entries.len() == limit {
+                    entries.pop_front();
+                }
+                entries.push_back(entry);
+            }
+            Err(e) => tracing::warn!("Skipping invalid audit log line in {:?}: {}", path, e),
+        }
+    }
+    Ok(entries.into())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use serde_json::json;
+
+    fn temp_log(name: &str) -> PathBuf {
+        let dir = std::env::temp_dir().join(format!("kubectl-dashboard-audit-{}-{}", std::process::id(), name));
+        let _ = std::fs::remove_dir_all(&dir);
+        dir.join("audit.jsonl")
+    }
+
+    #[test]
+    fn appends_one_line_per_entry_and_keeps_the_most_recent() {
+        let path = temp_log("append");
+        let scale = PendingAudit::new(Some("prod".to_string()), "Deployment", "shop", "web", "scale");
+        for replicas in 0..3 {
+            let result = if replicas == 1 { Err("forbidden".to_string()) } else { Ok(()) };
+            let entry = scale.clone().with_patch(json!({"spec": {"replicas": replicas}})).finish(&result);
+            append(&path, &entry).unwrap();
+        }
+        std::fs::OpenOptions::new().append(true).open(&path).unwrap().write_all(b"not json\n").unwrap();
+
+        let text = std::fs::read_to_string(&path).unwrap();
+        assert_eq!(text.lines().count(), 4);
+
+        let entries = load_recent(&path, 2).unwrap();
+        assert_eq!(entries.len(), 2);
+        assert_eq!(entries[0].result, AuditResult::Failed("forbidden".to_string()));
+        assert_eq!(entries[1].patch, Some(json!({"spec": {"replicas": 2}})));
+        assert_eq!(entries[1].context.as_deref(), Some("prod"));
+        assert_eq!(entries[1].result, AuditResult::Succeeded);
+
+        std::fs::remove_dir_all(path.parent().unwrap()).unwrap();
+    }
+
+    #[test]
+    fn missing_log_is_empty() {
+        assert!(load_recent(&temp_log("missing"), 10).unwrap().is_empty());
+    }
+
+    #[test]
+    fn secret_values_are_redacted() {
+        let patch = redact_secret(json!({"data": {"password": "aHVudGVyMg=="}, "stringData": {"token": "abc"}, "type": "Opaque"}));
+
+        assert_eq!(
+            patch,
+            json!({"data": {"password": REDACTED}, "stringData": {"token": REDACTED}, "type": "Opaque"})
+        );
+    }
+}
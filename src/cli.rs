@@ -0,0 +1,509 @@
+use crate::format::{format_bytes, truncate_string};
+use crate::k8s::{self, JobStatus, K8sClient};
+use anyhow::{Context, Result};
+use clap::{Parser, Subcommand, ValueEnum};
+use serde::Serialize;
+
+/// Without a subcommand the dashboard window opens; `get` prints the same summaries
+/// to stdout instead, for scripts and machines without a display.
+#[derive(Parser)]
+#[command(name = "kubectl-dashboard", version, about)]
+pub struct Cli {
+    #[command(subcommand)]
+    pub command: Option<Command>,
+}
+
+#[derive(Subcommand)]
+pub enum Command {
+    /// Print a table (or JSON/YAML) of one resource type, as summarized in the dashboard
+    Get {
+        resource: Resource,
+        /// Namespace to list; defaults to the context's namespace, then `default`
+        #[arg(short, long, conflicts_with = "all_namespaces")]
+        namespace: Option<String>,
+        /// List across all namespaces
+        #[arg(short = 'A', long)]
+        all_namespaces: bool,
+        /// Kubeconfig context to use instead of the current one
+        #[arg(long)]
+        context: Option<String>,
+        /// Label selector to filter on, e.g. `app=web,tier!=cache`
+        #[arg(short = 'l', long)]
+        selector: Option<String>,
+        /// Field selector to filter on, e.g. `status.phase=Running`
+        #[arg(long)]
+        field_selector: Option<String>,
+        /// Output format
+        #[arg(short, long, value_enum, default_value_t = Output::Table)]
+        output: Output,
+    },
+}
+
+#[derive(Clone, Copy, ValueEnum)]
+pub enum Resource {
+    #[value(alias = "pod", alias = "po")]
+    Pods,
+    #[value(alias = "deployment", alias = "deploy")]
+    Deployments,
+    #[value(alias = "statefulset", alias = "sts")]
+    Statefulsets,
+    #[value(alias = "daemonset", alias = "ds")]
+    Daemonsets,
+    #[value(alias = "replicaset", alias = "rs")]
+    Replicasets,
+    #[value(alias = "service", alias = "svc")]
+    Services,
+    #[value(alias = "ingress", alias = "ing")]
+    Ingresses,
+    #[value(alias = "configmap", alias = "cm")]
+    Configmaps,
+    #[value(alias = "secret")]
+    Secrets,
+    #[value(alias = "job")]
+    Jobs,
+    #[value(alias = "cronjob", alias = "cj")]
+    Cronjobs,
+    #[value(alias = "event", alias = "ev")]
+    Events,
+    #[value(alias = "node", alias = "no")]
+    Nodes,
+}
+
+#[derive(Clone, Copy, ValueEnum)]
+pub enum Output {
+    Table,
+    Json,
+    Yaml,
+}
+
+/// Runs a subcommand to completion and returns the process exit code.
+pub fn run(command: Command) -> i32 {
+    let runtime = match tokio::runtime::Runtime::new() {
+        Ok(runtime) => runtime,
+        Err(e) => {
+            eprintln!("error: Failed to create tokio runtime: {}", e);
+            return 1;
+        }
+    };
+
+    match runtime.block_on(run_command(command)) {
+        Ok(()) => 0,
+        Err(e) => {
+            eprintln!("error: {:#}", e);
+            1
+        }
+    }
+}
+
+async fn run_command(command: Command) -> Result<()> {
+    match command {
+        Command::Get { resource, namespace, all_namespaces, context, selector, field_selector, output } => {
+            let selectors = k8s::Selectors::new(
+                selector.as_deref().unwrap_or_default(),
+                field_selector.as_deref().unwrap_or_default(),
+            )?;
+            let client = K8sClient::new();
+            client.initialize().await?;
+            if let Some(context) = &context {
+                client.switch_context(context).await?;
+            }
+
+            let namespace = if all_namespaces {
+                None
+            } else {
+                match namespace {
+                    Some(ns) => Some(ns),
+                    None => Some(context_namespace(&client).await),
+                }
+            };
+            let c = client.get_client().await.context("No Kubernetes client available")?;
+            get(&c, resource, namespace.as_deref(), &selectors, output).await
+        }
+    }
+}
+
+/// The current context's default namespace, as kubectl picks it.
+async fn context_namespace(client: &K8sClient) -> String {
+    let current = client.get_current_context().await;
+    client
+        .list_contexts()
+        .await
+        .into_iter()
+        .find(|c| Some(&c.name) == current.as_ref())
+        .and_then(|c| c.namespace)
+        .unwrap_or_else(|| "default".to_string())
+}
+
+async fn get(
+    client: &kube::Client,
+    resource: Resource,
+    namespace: Option<&str>,
+    selectors: &k8s::Selectors,
+    output: Output,
+) -> Result<()> {
+    // Namespaced tables get a NAMESPACE column when listing across namespaces, like kubectl
+    let all = namespace.is_none();
+
+    match resource {
+        Resource::Pods => {
+            let pods = k8s::list_pods(client, namespace, selectors).await?;
+            print(output, &pods, all, &["NAME", "READY", "STATUS", "RESTARTS", "AGE", "IP", "NODE"], |p| {
+                (
+                    p.namespace.clone(),
+                    vec![
+                        p.name.clone(),
+                        p.ready.clone(),
+                        p.status.clone(),
+                        p.restarts.to_string(),
+                        p.age.clone(),
+                        or_none(&p.ip),
+                        or_none(&p.node),
+                    ],
+                )
+            })
+        }
+        Resource::Deployments => {
+            let deployments = k8s::list_deployments(client, namespace, selectors).await?;
+            print(output, &deployments, all, &["NAME", "READY", "UP-TO-DATE", "AVAILABLE", "AGE", "IMAGES"], |d| {
+                (
+                    d.namespace.clone(),
+                    vec![
+                        d.name.clone(),
+                        format!("{}/{}", d.ready, d.replicas),
+                        d.updated.to_string(),
+                        d.available.to_string(),
+                        d.age.clone(),
+                        d.images.join(","),
+                    ],
+                )
+            })
+        }
+        Resource::Statefulsets => {
+            let statefulsets = k8s::list_statefulsets(client, namespace, selectors).await?;
+            print(output, &statefulsets, all, &["NAME", "READY", "UP-TO-DATE", "SERVICE", "AGE"], |s| {
+                (
+                    s.namespace.clone(),
+                    vec![
+                        s.name.clone(),
+                        format!("{}/{}", s.ready, s.replicas),
+                        s.updated.to_string(),
+                        or_none(&s.service_name),
+                        s.age.clone(),
+                    ],
+                )
+            })
+        }
+        Resource::Daemonsets => {
+            let daemonsets = k8s::list_daemonsets(client, namespace, selectors).await?;
+            print(
+                output,
+                &daemonsets,
+                all,
+                &["NAME", "DESIRED", "CURRENT", "READY", "UP-TO-DATE", "AVAILABLE", "NODE SELECTOR", "AGE"],
+                |d| {
+                    (
+                        d.namespace.clone(),
+                        vec![
+                            d.name.clone(),
+                            d.desired.to_string(),
+                            d.current.to_string(),
+                            d.ready.to_string(),
+                            d.updated.to_string(),
+                            d.available.to_string(),
+                            labels(&d.node_selector),
+                            d.age.clone(),
+                        ],
+                    )
+                },
+            )
+        }
+        Resource::Replicasets => {
+            let replicasets = k8s::list_replicasets(client, namespace, selectors).await?;
+            print(output, &replicasets, all, &["NAME", "READY", "AVAILABLE", "OWNER", "AGE"], |r| {
+                (
+                    r.namespace.clone(),
+                    vec![
+                        r.name.clone(),
+                        format!("{}/{}", r.ready, r.replicas),
+                        r.available.to_string(),
+                        r.owner.clone().unwrap_or_else(|| "<none>".to_string()),
+                        r.age.clone(),
+                    ],
+                )
+            })
+        }
+        Resource::Services => {
+            let services = k8s::list_services(client, namespace, selectors).await?;
+            print(output, &services, all, &["NAME", "TYPE", "CLUSTER-IP", "EXTERNAL-IP", "PORTS", "AGE"], |s| {
+                (
+                    s.namespace.clone(),
+                    vec![
+                        s.name.clone(),
+                        s.service_type.clone(),
+                        or_none(&s.cluster_ip),
+                        or_none(&s.external_ip),
+                        s.ports.join(","),
+                        s.age.clone(),
+                    ],
+                )
+            })
+        }
+        Resource::Ingresses => {
+            let ingresses = k8s::list_ingresses(client, namespace, selectors).await?;
+            print(output, &ingresses, all, &["NAME", "HOSTS", "PATHS", "AGE"], |i| {
+                (
+                    i.namespace.clone(),
+                    vec![i.name.clone(), i.hosts.join(","), i.paths.join(","), i.age.clone()],
+                )
+            })
+        }
+        Resource::Configmaps => {
+            let configmaps = k8s::list_configmaps(client, namespace, selectors).await?;
+            print(output, &configmaps, all, &["NAME", "DATA", "AGE"], |c| {
+                (c.namespace.clone(), vec![c.name.clone(), c.data_count.to_string(), c.age.clone()])
+            })
+        }
+        Resource::Secrets => {
+            let secrets = k8s::list_secrets(client, namespace, selectors).await?;
+            print(output, &secrets, all, &["NAME", "TYPE", "DATA", "AGE"], |s| {
+                (
+                    s.namespace.clone(),
+                    vec![s.name.clone(), s.secret_type.clone(), s.data_count.to_string(), s.age.clone()],
+                )
+            })
+        }
+        Resource::Jobs => {
+            let jobs = k8s::list_jobs(client, namespace, selectors).await?;
+            print(output, &jobs, all, &["NAME", "STATUS", "COMPLETIONS", "DURATION", "AGE"], |j| {
+                (
+                    j.namespace.clone(),
+                    vec![
+                        j.name.clone(),
+                        job_status(&j.status).to_string(),
+                        j.completions.clone(),
+                        j.duration.clone(),
+                        j.age.clone(),
+                    ],
+                )
+            })
+        }
+        Resource::Cronjobs => {
+            let cronjobs = k8s::list_cronjobs(client, namespace, selectors).await?;
+            print(output, &cronjobs, all, &["NAME", "SCHEDULE", "SUSPEND", "ACTIVE", "LAST SCHEDULE", "AGE"], |c| {
+                (
+                    c.namespace.clone(),
+                    vec![
+                        c.name.clone(),
+                        c.schedule.clone(),
+                        c.suspend.to_string(),
+                        c.active.to_string(),
+                        c.last_schedule.clone().unwrap_or_else(|| "<none>".to_string()),
+                        c.age.clone(),
+                    ],
+                )
+            })
+        }
+        Resource::Events => {
+            let events = k8s::list_events(client, namespace, selectors).await?;
+            print(output, &events, all, &["LAST SEEN", "TYPE", "REASON", "OBJECT", "COUNT", "MESSAGE"], |e| {
+                (
+                    e.namespace.clone(),
+                    vec![
+                        e.last_seen.clone(),
+                        e.event_type.clone(),
+                        e.reason.clone(),
+                        format!("{}/{}", e.involved_kind.to_lowercase(), e.involved_name),
+                        e.count.to_string(),
+                        truncate_string(&e.message, 120),
+                    ],
+                )
+            })
+        }
+        Resource::Nodes => {
+            let nodes = k8s::list_nodes(client, selectors).await?;
+            // Nodes are cluster-scoped; never add a namespace column
+            print(output, &nodes, false, &["NAME", "STATUS", "ROLES", "VERSION", "CPU", "MEMORY", "AGE"], |n| {
+                let status = if n.unschedulable {
+                    format!("{},SchedulingDisabled", n.status)
+                } else {
+                    n.status.clone()
+                };
+                (
+                    String::new(),
+                    vec![
+                        n.name.clone(),
+                        status,
+                        if n.roles.is_empty() { "<none>".to_string() } else { n.roles.join(",") },
+                        n.kubelet_version.clone(),
+                        format!("{}/{}", n.cpu_allocatable, n.cpu_capacity),
+                        format!("{}/{}", format_bytes(n.memory_allocatable), format_bytes(n.memory_capacity)),
+                        n.age.clone(),
+                    ],
+                )
+            })
+        }
+    }
+}
+
+fn job_status(status: &JobStatus) -> &'static str {
+    match status {
+        JobStatus::Running => "Running",
+        JobStatus::Succeeded => "Succeeded",
+        JobStatus::Failed => "Failed",
+        JobStatus::Pending => "Pending",
+    }
+}
+
+fn or_none(value: &str) -> String {
+    if value.is_empty() {
+        "<none>".to_string()
+    } else {
+        value.to_string()
+    }
+}
+
+fn labels(map: &std::collections::BTreeMap<String, String>) -> String {
+    if map.is_empty() {
+        return "<none>".to_string();
+    }
+    map.iter().map(|(k, v)| format!("{}={}", k, v)).collect::<Vec<_>>().join(",")
+}
+
+/// Prints `items` to stdout as [`render`] formats them; an empty table is reported on
+/// stderr instead, like kubectl.
+fn print<T: Serialize>(
+    output: Output,
+    items: &[T],
+    with_namespace: bool,
+    headers: &[&str],
+    row: impl Fn(&T) -> (String, Vec<String>),
+) -> Result<()> {
+    if items.is_empty() && matches!(output, Output::Table) {
+        eprintln!("No resources found.");
+        return Ok(());
+    }
+    print!("{}", render(output, items, with_namespace, headers, row)?);
+    Ok(())
+}
+
+/// Formats `items` as JSON, YAML or a table. `row` returns an item's namespace and its
+/// cells for `headers`; the namespace becomes the first column when `with_namespace`.
+fn render<T: Serialize>(
+    output: Output,
+    items: &[T],
+    with_namespace: bool,
+    headers: &[&str],
+    row: impl Fn(&T) -> (String, Vec<String>),
+) -> Result<String> {
+    match output {
+        Output::Json => Ok(format!("{}\n", serde_json::to_string_pretty(items)?)),
+        Output::Yaml => Ok(serde_yaml::to_string(items)?),
+        Output::Table => {
+            let mut rows: Vec<Vec<String>> = Vec::with_capacity(items.len() + 1);
+            let mut header: Vec<String> = headers.iter().map(|h| h.to_string()).collect();
+            if with_namespace {
+                header.insert(0, "NAMESPACE".to_string());
+            }
+            rows.push(header);
+            for item in items {
+                let (namespace, mut cells) = row(item);
+                if with_namespace {
+                    cells.insert(0, namespace);
+                }
+                rows.push(cells);
+            }
+            Ok(format_table(&rows))
+        }
+    }
+}
+
+/// Left-aligned columns separated by three spaces, as kubectl prints them.
+fn format_table(rows: &[Vec<String>]) -> String {
+    let columns = rows.iter().map(Vec::len).max().unwrap_or(0);
+    let widths: Vec<usize> = (0..columns)
+        .map(|c| rows.iter().filter_map(|r| r.get(c)).map(|s| s.chars().count()).max().unwrap_or(0))
+        .collect();
+
+    let mut out = String::new();
+    for row in rows {
+        let line: Vec<String> = row
+            .iter()
+            .enumerate()
+            .map(|(c, cell)| {
+                if c + 1 == row.len() {
+                    cell.clone()
+                } else {
+                    format!("{:<width$}", cell, width = widths[c])
+                }
+            })
+            .collect();
+        out.push_str(&line.join("   "));
+        out.push('\n');
+    }
+    out
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use serde_json::json;
+
+    #[derive(Serialize)]
+    struct Item {
+        namespace: &'static str,
+        name: &'static str,
+        ready: bool,
+    }
+
+    const ITEMS: [Item; 2] = [
+        Item { namespace: "default", name: "web", ready: true },
+        Item { namespace: "kube-system", name: "coredns-5d78c9869d", ready: false },
+    ];
+
+    fn render_items(output: Output, with_namespace: bool) -> String {
+        render(output, &ITEMS, with_namespace, &["NAME", "READY"], |i| {
+            (i.namespace.to_string(), vec![i.name.to_string(), i.ready.to_string()])
+        })
+        .unwrap()
+    }
+
+    #[test]
+    fn table_pads_every_column_but_the_last() {
+        let rows = vec![
+            vec!["NAME".to_string(), "STATUS".to_string(), "AGE".to_string()],
+            vec!["web".to_string(), "Running".to_string(), "5d".to_string()],
+        ];
+
+        assert_eq!(format_table(&rows), "NAME   STATUS    AGE\nweb    Running   5d\n");
+    }
+
+    #[test]
+    fn table_adds_a_namespace_column_across_namespaces() {
+        assert_eq!(
+            render_items(Output::Table, false),
+            "NAME                 READY\nweb                  true\ncoredns-5d78c9869d   false\n"
+        );
+        assert_eq!(
+            render_items(Output::Table, true),
+            "NAMESPACE     NAME                 READY\n\
+             default       web                  true\n\
+             kube-system   coredns-5d78c9869d   false\n"
+        );
+    }
+
+    #[test]
+    fn json_and_yaml_list_every_item_in_full() {
+        let expected = json!([
+            { "namespace": "default", "name": "web", "ready": true },
+            { "namespace": "kube-system", "name": "coredns-5d78c9869d", "ready": false },
+        ]);
+
+        let json = render_items(Output::Json, false);
+        assert_eq!(serde_json::from_str::<serde_json::Value>(&json).unwrap(), expected);
+        assert!(json.ends_with("]\n"));
+
+        let yaml = render_items(Output::Yaml, false);
+        assert!(yaml.starts_with("- namespace: default\n  name: web\n"));
+        assert_eq!(serde_yaml::from_str::<serde_json::Value>(&yaml).unwrap(), expected);
+    }
+}
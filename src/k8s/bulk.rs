@@ -0,0 +1,67 @@
+use anyhow::Result;
+use futures::{stream, Future, StreamExt};
+use kube::Client;
+
+/// Requests in flight at once, so selecting a few hundred pods does not open a
+/// few hundred connections to the API server.
+const MAX_CONCURRENT: usize = 8;
+
+/// Outcome of a bulk action for one object.
+#[derive(Clone, Debug)]
+pub struct BulkItemResult {
+    pub namespace: String,
+    pub name: String,
+    pub result: Result<(), String>,
+}
+
+/// Runs `op` for every `(namespace, name)` target concurrently. One failure does
+/// not stop the others; results come back in the order of `targets`.
+pub async fn run_bulk<F, Fut>(client: &Client, targets: Vec<(String, String)>, op: F) -> Vec<BulkItemResult>
+where
+    F: Fn(Client, String, String) -> Fut,
+    Fut: Future<Output = Result<()>>,
+{
+    stream::iter(targets)
+        .map(|(namespace, name)| {
+            let request = op(client.clone(), namespace.clone(), name.clone());
+            async move {
+                let result = request.await.map_err(|e| format!("{:#}", e));
+                BulkItemResult { namespace, name, result }
+            }
+        })
+        .buffered(MAX_CONCURRENT)
+        .collect()
+        .await
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::k8s::fake::FakeApiServer;
+    use crate::k8s::{delete_pod, list_pods, Selectors};
+
+    #[tokio::test]
+    async fn reports_each_item_and_keeps_going_after_a_failure() {
+        let server = FakeApiServer::start(&["pods.yaml"]).await;
+        let targets = vec![
+            ("default".to_string(), "web-7d4b9c-abcde".to_string()),
+            ("default".to_string(), "missing".to_string()),
+            ("kube-system".to_string(), "coredns-5d78c9869d-q8xzt".to_string()),
+        ];
+
+        let results = run_bulk(&server.client(), targets, |c, ns, name| async move {
+            delete_pod(&c, &ns, &name).await
+        })
+        .await;
+
+        let names: Vec<_> = results.iter().map(|r| r.name.as_str()).collect();
+        assert_eq!(names, ["web-7d4b9c-abcde", "missing", "coredns-5d78c9869d-q8xzt"]);
+        assert!(results[0].result.is_ok());
+        assert!(results[1].result.as_ref().unwrap_err().contains("not found"));
+        assert!(results[2].result.is_ok());
+
+        let left = list_pods(&server.client(), None, &Selectors::default()).await.unwrap();
+        let left: Vec<_> = left.iter().map(|p| p.name.as_str()).collect();
+        assert_eq!(left, ["worker-5f6d7-xyz12"]);
+    }
+}
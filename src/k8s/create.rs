@@ -0,0 +1,210 @@
+use anyhow::{bail, Context, Result};
+use base64::Engine;
+use k8s_openapi::api::core::v1::{ConfigMap, Secret};
+use k8s_openapi::ByteString;
+use kube::{
+    api::{Api, ObjectMeta, PostParams},
+    Client,
+};
+use std::collections::BTreeMap;
+use std::path::Path;
+
+#[derive(Clone, Copy, Debug, PartialEq, Default)]
+pub enum SecretKind {
+    #[default]
+    Opaque,
+    Tls,
+    DockerConfigJson,
+    BasicAuth,
+}
+
+impl SecretKind {
+    pub const ALL: [SecretKind; 4] = [
+        SecretKind::Opaque,
+        SecretKind::Tls,
+        SecretKind::DockerConfigJson,
+        SecretKind::BasicAuth,
+    ];
+
+    pub fn type_name(&self) -> &'static str {
+        match self {
+            SecretKind::Opaque => "Opaque",
+            SecretKind::Tls => "kubernetes.io/tls",
+            SecretKind::DockerConfigJson => "kubernetes.io/dockerconfigjson",
+            SecretKind::BasicAuth => "kubernetes.io/basic-auth",
+        }
+    }
+}
+
+/// Checks a ConfigMap/Secret data key the way the API server does.
+pub fn validate_data_key(key: &str) -> Result<()> {
+    if key.is_empty() || key.len() > 253 {
+        bail!("Key '{}' must be 1-253 characters", key);
+    }
+    if key == "." || key == ".." {
+        bail!("Key '{}' is not allowed", key);
+    }
+    if !key.chars().all(|c| c.is_ascii_alphanumeric() || matches!(c, '-' | '_' | '.')) {
+        bail!("Key '{}' may only contain letters, digits, '-', '_' and '.'", key);
+    }
+    Ok(())
+}
+
+/// Reads data entries the way `kubectl create ... --from-file` does: `path` adds one
+/// key named after the file, `key=path` names it explicitly, and a directory adds
+/// every regular file directly inside it whose name is a valid key.
+pub fn read_file_source(source: &str) -> Result<BTreeMap<String, Vec<u8>>> {
+    let (key, path) = match source.split_once('=') {
+        Some((key, path)) => (Some(key.trim()), path.trim()),
+        None => (None, source.trim()),
+    };
+    let path = Path::new(path);
+
+    let mut data = BTreeMap::new();
+    if path.is_dir() {
+        if key.is_some() {
+            bail!("Cannot give a key name for directory {}", path.display());
+        }
+        let entries = std::fs::read_dir(path)
+            .with_context(|| format!("Failed to read directory {}", path.display()))?;
+        for entry in entries {
+            let entry = entry?;
+            let name = entry.file_name().to_string_lossy().into_owned();
+            if !entry.file_type()?.is_file() || validate_data_key(&name).is_err() {
+                continue;
+            }
+            let contents = std::fs::read(entry.path())
+                .with_context(|| format!("Failed to read {}", entry.path().display()))?;
+            data.insert(name, contents);
+        }
+        return Ok(data);
+    }
+
+    let key = match key {
+        Some(key) => key.to_string(),
+        None => path
+            .file_name()
+            .map(|n| n.to_string_lossy().into_owned())
+            .with_context(|| format!("No file name in {}", path.display()))?,
+    };
+    validate_data_key(&key)?;
+    let contents = std::fs::read(path).with_context(|| format!("Failed to read {}", path.display()))?;
+    data.insert(key, contents);
+    Ok(data)
+}
+
+/// Data for a `kubernetes.io/tls` secret from PEM files, like `kubectl create secret tls`.
+pub fn tls_secret_data(cert_path: &str, key_path: &str) -> Result<BTreeMap<String, Vec<u8>>> {
+    let cert = std::fs::read(cert_path.trim()).with_context(|| format!("Failed to read certificate {}", cert_path))?;
+    let key = std::fs::read(key_path.trim()).with_context(|| format!("Failed to read key {}", key_path))?;
+
+    if !String::from_utf8_lossy(&cert).contains("-----BEGIN CERTIFICATE-----") {
+        bail!("{} is not a PEM certificate", cert_path);
+    }
+    if !String::from_utf8_lossy(&key).contains("PRIVATE KEY-----") {
+        bail!("{} is not a PEM private key", key_path);
+    }
+
+    Ok(BTreeMap::from([
+        ("tls.crt".to_string(), cert),
+        ("tls.key".to_string(), key),
+    ]))
+}
+
+/// Data for a `kubernetes.io/dockerconfigjson` secret, like `kubectl create secret docker-registry`.
+pub fn docker_config_data(server: &str, username: &str, password: &str, email: &str) -> Result<BTreeMap<String, Vec<u8>>> {
+    if server.trim().is_empty() || username.is_empty() {
+        bail!("Registry server and username are required");
+    }
+
+    let auth = base64::engine::general_purpose::STANDARD.encode(format!("{}:{}", username, password));
+    let mut entry = serde_json::json!({
+        "username": username,
+        "password": password,
+        "auth": auth,
+    });
+    if !email.is_empty() {
+        entry["email"] = serde_json::json!(email);
+    }
+    let config = serde_json::json!({ "auths": { server.trim(): entry } });
+
+    Ok(BTreeMap::from([(
+        ".dockerconfigjson".to_string(),
+        serde_json::to_vec(&config)?,
+    )]))
+}
+
+/// Data for a `kubernetes.io/basic-auth` secret.
+pub fn basic_auth_data(username: &str, password: &str) -> Result<BTreeMap<String, Vec<u8>>> {
+    if username.is_empty() && password.is_empty() {
+        bail!("A username or password is required");
+    }
+    Ok(BTreeMap::from([
+        ("username".to_string(), username.as_bytes().to_vec()),
+        ("password".to_string(), password.as_bytes().to_vec()),
+    ]))
+}
+
+/// Creates a ConfigMap. Values that are not UTF-8 go to `binaryData`, as kubectl does.
+pub async fn create_configmap(client: &Client, namespace: &str, name: &str, data: BTreeMap<String, Vec<u8>>) -> Result<()> {
+    let configmaps: Api<ConfigMap> = Api::namespaced(client.clone(), namespace);
+
+    let mut text = BTreeMap::new();
+    let mut binary = BTreeMap::new();
+    for (key, value) in data {
+        match String::from_utf8(value) {
+            Ok(s) => {
+                text.insert(key, s);
+            }
+            Err(e) => {
+                binary.insert(key, ByteString(e.into_bytes()));
+            }
+        }
+    }
+
+    let configmap = ConfigMap {
+        metadata: ObjectMeta {
+            name: Some(name.to_string()),
+            namespace: Some(namespace.to_string()),
+            ..Default::default()
+        },
+        data: (!text.is_empty()).then_some(text),
+        binary_data: (!binary.is_empty()).then_some(binary),
+        ..Default::default()
+    };
+
+    configmaps
+        .create(&PostParams::default(), &configmap)
+        .await
+        .context("Failed to create configmap")?;
+
+    Ok(())
+}
+
+pub async fn create_secret(
+    client: &Client,
+    namespace: &str,
+    name: &str,
+    kind: SecretKind,
+    data: BTreeMap<String, Vec<u8>>,
+) -> Result<()> {
+    let secrets: Api<Secret> = Api::namespaced(client.clone(), namespace);
+
+    let secret = Secret {
+        metadata: ObjectMeta {
+            name: Some(name.to_string()),
+            namespace: Some(namespace.to_string()),
+            ..Default::default()
+        },
+        type_: Some(kind.type_name().to_string()),
+        data: Some(data.into_iter().map(|(k, v)| (k, ByteString(v))).collect()),
+        ..Default::default()
+    };
+
+    secrets
+        .create(&PostParams::default(), &secret)
+        .await
+        .context("Failed to create secret")?;
+
+    Ok(())
+}
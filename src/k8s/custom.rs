@@ -0,0 +1,346 @@
+use anyhow::{Context, Result};
+use chrono::{DateTime, Utc};
+use k8s_openapi::apiextensions_apiserver::pkg::apis::apiextensions::v1::CustomResourceDefinition;
+use k8s_openapi::apimachinery::pkg::apis::meta::v1::Time;
+use kube::{
+    api::{Api, ApiResource, DynamicObject, ListParams},
+    discovery::{verbs, Discovery, Scope},
+    Client, ResourceExt,
+};
+use serde_json::Value;
+use std::collections::HashMap;
+
+use super::resources::format_age;
+use super::selector::Selectors;
+
+#[derive(Clone, Debug, PartialEq)]
+pub struct PrinterColumn {
+    pub name: String,
+    pub json_path: String,
+    pub column_type: String,
+}
+
+/// A listable kind found through API discovery.
+#[derive(Clone, Debug)]
+pub struct CustomResourceKind {
+    pub resource: ApiResource,
+    pub namespaced: bool,
+    /// Served from a CustomResourceDefinition rather than built into the API server.
+    pub custom: bool,
+    pub printer_columns: Vec<PrinterColumn>,
+}
+
+impl CustomResourceKind {
+    pub fn group_label(&self) -> &str {
+        if self.resource.group.is_empty() {
+            "core"
+        } else {
+            &self.resource.group
+        }
+    }
+}
+
+impl PartialEq for CustomResourceKind {
+    fn eq(&self, other: &Self) -> bool {
+        self.resource == other.resource
+    }
+}
+
+#[derive(Clone, Debug)]
+pub struct DynamicObjectInfo {
+    pub name: String,
+    pub namespace: Option<String>,
+    pub age: String,
+    /// One value per printer column of the kind, in order.
+    pub columns: Vec<String>,
+}
+
+/// Printer columns per (group, plural, version), read from the cluster's CRDs.
+/// Only the default (priority 0) columns are kept, as `kubectl get` does.
+async fn crd_printer_columns(client: &Client) -> Result<HashMap<(String, String, String), Vec<PrinterColumn>>> {
+    let crds: Api<CustomResourceDefinition> = Api::all(client.clone());
+    let list = crds
+        .list(&ListParams::default())
+        .await
+        .context("Failed to list custom resource definitions")?;
+
+    let mut columns = HashMap::new();
+    for crd in list.items {
+        for version in crd.spec.versions {
+            let printer_columns = version
+                .additional_printer_columns
+                .unwrap_or_default()
+                .into_iter()
+                .filter(|c| c.priority.unwrap_or(0) == 0)
+                .map(|c| PrinterColumn {
+                    name: c.name,
+                    json_path: c.json_path,
+                    column_type: c.type_,
+                })
+                .collect();
+            columns.insert(
+                (crd.spec.group.clone(), crd.spec.names.plural.clone(), version.name),
+                printer_columns,
+            );
+        }
+    }
+    Ok(columns)
+}
+
+/// Discovers every kind that supports `list`, using each group's preferred version.
+/// Kinds backed by a CRD carry that CRD's printer columns.
+pub async fn discover_resource_kinds(client: &Client) -> Result<Vec<CustomResourceKind>> {
+    let discovery = Discovery::new(client.clone())
+        .run()
+        .await
+        .context("API discovery failed")?;
+
+    // Reading CRDs needs cluster-wide RBAC; without it kinds are still listed, just
+    // without printer columns.
+    let crd_columns = crd_printer_columns(client).await.unwrap_or_else(|e| {
+        tracing::warn!("Printer columns unavailable: {:#}", e);
+        HashMap::new()
+    });
+
+    let mut kinds: Vec<CustomResourceKind> = discovery
+        .groups()
+        .flat_map(|group| group.recommended_resources())
+        .filter(|(_, caps)| caps.supports_operation(verbs::LIST))
+        .map(|(resource, caps)| {
+            let key = (resource.group.clone(), resource.plural.clone(), resource.version.clone());
+            let printer_columns = crd_columns.get(&key);
+            CustomResourceKind {
+                namespaced: caps.scope == Scope::Namespaced,
+                custom: printer_columns.is_some(),
+                printer_columns: printer_columns.cloned().unwrap_or_default(),
+                resource,
+            }
+        })
+        .collect();
+
+    kinds.sort_by(|a, b| (a.group_label(), &a.resource.kind).cmp(&(b.group_label(), &b.resource.kind)));
+    Ok(kinds)
+}
+
+#[derive(Debug, PartialEq)]
+enum PathStep {
+    Field(String),
+    Index(usize),
+    All,
+    /// `[?(@.a.b=="value")]`
+    Filter(Vec<String>, String),
+}
+
+/// Parses the JSONPath subset used by `additionalPrinterColumns`: `.field`,
+/// `[n]`, `[*]` and `[?(@.field=="value")]`.
+fn parse_json_path(path: &str) -> Option<Vec<PathStep>> {
+    let path = path.trim().trim_start_matches('{').trim_end_matches('}');
+    let mut rest = path.strip_prefix('$').unwrap_or(path);
+    let mut steps = vec![];
+
+    while !rest.is_empty() {
+        if let Some(after) = rest.strip_prefix('.') {
+            let end = after.find(['.', '[']).unwrap_or(after.len());
+            if end > 0 {
+                steps.push(PathStep::Field(after[..end].to_string()));
+            }
+            rest = &after[end..];
+        } else if let Some(after) = rest.strip_prefix("[?(") {
+            let end = after.find(")]")?;
+            let (key, value) = after[..end].split_once("==")?;
+            let key = key.trim().strip_prefix("@.")?;
+            let value = value.trim().trim_matches(|c| c == '"' || c == '\'');
+            steps.push(PathStep::Filter(
+                key.split('.').map(str::to_string).collect(),
+                value.to_string(),
+            ));
+            rest = &after[end + 2..];
+        } else if let Some(after) = rest.strip_prefix('[') {
+            let end = after.find(']')?;
+            let inner = after[..end].trim().trim_matches(|c| c == '"' || c == '\'');
+            steps.push(match inner {
+                "*" => PathStep::All,
+                _ => match inner.parse() {
+                    Ok(index) => PathStep::Index(index),
+                    Err(_) => PathStep::Field(inner.to_string()),
+                },
+            });
+            rest = &after[end + 1..];
+        } else {
+            return None;
+        }
+    }
+    Some(steps)
+}
+
+fn value_at<'a>(value: &'a Value, keys: &[String]) -> Option<&'a Value> {
+    keys.iter().try_fold(value, |v, k| v.get(k))
+}
+
+fn json_path_values<'a>(root: &'a Value, steps: &[PathStep]) -> Vec<&'a Value> {
+    let mut current = vec![root];
+    for step in steps {
+        current = current
+            .into_iter()
+            .flat_map(|v| -> Vec<&Value> {
+                match step {
+                    PathStep::Field(name) => v.get(name).into_iter().collect(),
+                    PathStep::Index(i) => v.get(*i).into_iter().collect(),
+                    PathStep::All => match v {
+                        Value::Array(items) => items.iter().collect(),
+                        Value::Object(map) => map.values().collect(),
+                        _ => vec![],
+                    },
+                    PathStep::Filter(keys, expected) => v
+                        .as_array()
+                        .into_iter()
+                        .flatten()
+                        .filter(|item| match value_at(item, keys) {
+                            Some(Value::String(s)) => s == expected,
+                            Some(other) => serde_json::from_str::<Value>(expected).is_ok_and(|e| &e == other),
+                            None => false,
+                        })
+                        .collect(),
+                }
+            })
+            .collect();
+    }
+    current
+}
+
+fn format_column(column: &PrinterColumn, object: &Value) -> String {
+    let Some(steps) = parse_json_path(&column.json_path) else {
+        return "<invalid path>".to_string();
+    };
+
+    json_path_values(object, &steps)
+        .into_iter()
+        .map(|v| match v {
+            Value::String(s) if column.column_type == "date" => s
+                .parse::<DateTime<Utc>>()
+                .map(|t| format_age(Some(&Time(t))))
+                .unwrap_or_else(|_| s.clone()),
+            Value::String(s) => s.clone(),
+            other => other.to_string(),
+        })
+        .collect::<Vec<_>>()
+        .join(",")
+}
+
+fn dynamic_object_info(kind: &CustomResourceKind, object: &DynamicObject) -> DynamicObjectInfo {
+    let value = serde_json::to_value(object).unwrap_or(Value::Null);
+
+    DynamicObjectInfo {
+        name: object.name_any(),
+        namespace: object.namespace(),
+        age: format_age(object.metadata.creation_timestamp.as_ref()),
+        columns: kind.printer_columns.iter().map(|c| format_column(c, &value)).collect(),
+    }
+}
+
+pub async fn list_dynamic_objects(client: &Client, kind: &CustomResourceKind, namespace: Option<&str>, selectors: &Selectors) -> Result<Vec<DynamicObjectInfo>> {
+    let api: Api<DynamicObject> = match namespace {
+        Some(ns) if kind.namespaced => Api::namespaced_with(client.clone(), ns, &kind.resource),
+        _ => Api::all_with(client.clone(), &kind.resource),
+    };
+
+    let list = api
+        .list(&selectors.list_params())
+        .await
+        .with_context(|| format!("Failed to list {}", kind.resource.plural))?;
+
+    let mut objects: Vec<DynamicObjectInfo> = list.items.iter().map(|o| dynamic_object_info(kind, o)).collect();
+    objects.sort_by(|a, b| (&a.namespace, &a.name).cmp(&(&b.namespace, &b.name)));
+    Ok(objects)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use serde_json::json;
+
+    fn column(json_path: &str, column_type: &str) -> PrinterColumn {
+        PrinterColumn { name: "Column".to_string(), json_path: json_path.to_string(), column_type: column_type.to_string() }
+    }
+
+    fn object() -> Value {
+        json!({
+            "metadata": { "labels": { "app.kubernetes.io/name": "web" } },
+            "spec": { "hosts": ["a.example.com", "b.example.com"], "ports": [{ "port": 80 }, { "port": 443 }] },
+            "status": {
+                "replicas": 3,
+                "conditions": [
+                    { "type": "Synced", "status": "False" },
+                    { "type": "Ready", "status": "True" }
+                ]
+            }
+        })
+    }
+
+    #[test]
+    fn parses_printer_column_paths() {
+        let field = |s: &str| PathStep::Field(s.to_string());
+        assert_eq!(parse_json_path(".status.replicas"), Some(vec![field("status"), field("replicas")]));
+        assert_eq!(parse_json_path("{$.spec.hosts[0]}"), Some(vec![field("spec"), field("hosts"), PathStep::Index(0)]));
+        assert_eq!(parse_json_path(".spec.hosts[*]"), Some(vec![field("spec"), field("hosts"), PathStep::All]));
+        assert_eq!(
+            parse_json_path(".metadata.labels['app.kubernetes.io/name']"),
+            Some(vec![field("metadata"), field("labels"), field("app.kubernetes.io/name")])
+        );
+        assert_eq!(
+            parse_json_path(r#".status.conditions[?(@.type=="Ready")].status"#),
+            Some(vec![
+                field("status"),
+                field("conditions"),
+                PathStep::Filter(vec!["type".to_string()], "Ready".to_string()),
+                field("status"),
+            ])
+        );
+        assert_eq!(parse_json_path(""), Some(vec![]));
+    }
+
+    #[test]
+    fn rejects_malformed_paths() {
+        for path in [
+            "status.replicas",
+            ".spec.hosts[0",
+            ".status[?(@.type)]",
+            r#".status[?(type=="Ready")]"#,
+            r#".status[?(@.type=="Ready""#,
+        ] {
+            assert_eq!(parse_json_path(path), None, "{}", path);
+        }
+    }
+
+    #[test]
+    fn evaluates_paths_against_objects() {
+        let object = object();
+        let values = |path: &str| json_path_values(&object, &parse_json_path(path).unwrap());
+
+        assert_eq!(values(".status.replicas"), [&json!(3)]);
+        assert_eq!(values(".spec.hosts[1]"), [&json!("b.example.com")]);
+        assert_eq!(values(".spec.hosts[*]"), [&json!("a.example.com"), &json!("b.example.com")]);
+        assert_eq!(values(".spec.ports[?(@.port==443)].port"), [&json!(443)]);
+        assert_eq!(values(".metadata.labels[\"app.kubernetes.io/name\"]"), [&json!("web")]);
+        assert!(values(".status.missing").is_empty());
+        assert!(values(".spec.hosts[5]").is_empty());
+        assert!(values(".status.replicas[*]").is_empty());
+    }
+
+    #[test]
+    fn formats_column_values() {
+        let object = object();
+        assert_eq!(format_column(&column(".status.replicas", "integer"), &object), "3");
+        assert_eq!(format_column(&column(".spec.hosts[*]", "string"), &object), "a.example.com,b.example.com");
+        assert_eq!(format_column(&column(r#".status.conditions[?(@.type=="Ready")].status"#, "string"), &object), "True");
+        assert_eq!(format_column(&column(".spec.ports[0]", "string"), &object), r#"{"port":80}"#);
+        assert_eq!(format_column(&column(".status.missing", "string"), &object), "");
+        assert_eq!(format_column(&column("status", "string"), &object), "<invalid path>");
+
+        let created = "2024-01-02T03:04:05Z";
+        let expected = format_age(Some(&Time(created.parse().unwrap())));
+        let object = json!({ "metadata": { "creationTimestamp": created, "note": "not a date" } });
+        assert_eq!(format_column(&column(".metadata.creationTimestamp", "date"), &object), expected);
+        assert_eq!(format_column(&column(".metadata.note", "date"), &object), "not a date");
+    }
+}
@@ -0,0 +1,77 @@
+use anyhow::{Context, Result};
+use futures::SinkExt;
+use k8s_openapi::api::core::v1::Pod;
+use kube::{
+    api::{Api, AttachParams, TerminalSize},
+    Client,
+};
+use tokio::io::{AsyncReadExt, AsyncWriteExt};
+use tokio::sync::mpsc::UnboundedReceiver;
+
+#[derive(Clone, Debug)]
+pub enum ExecInput {
+    Data(Vec<u8>),
+    Resize { width: u16, height: u16 },
+}
+
+/// Runs `command` in a pod container with a TTY attached, forwarding `input` to the
+/// process and raw terminal output to `on_output`.
+///
+/// Returns when the process exits or when the sending half of `input` is dropped.
+pub async fn exec_in_pod(
+    client: &Client,
+    namespace: &str,
+    name: &str,
+    container: Option<&str>,
+    command: Vec<String>,
+    mut input: UnboundedReceiver<ExecInput>,
+    mut on_output: impl FnMut(Vec<u8>),
+) -> Result<()> {
+    let pods: Api<Pod> = Api::namespaced(client.clone(), namespace);
+
+    let mut params = AttachParams::interactive_tty();
+    if let Some(c) = container {
+        params = params.container(c);
+    }
+
+    let mut process = pods
+        .exec(name, command, &params)
+        .await
+        .context("Failed to exec into pod")?;
+
+    let mut stdin = process.stdin().context("Exec session has no stdin")?;
+    let mut stdout = process.stdout().context("Exec session has no stdout")?;
+    let mut resize = process.terminal_size();
+
+    let mut buf = vec![0u8; 8192];
+    loop {
+        tokio::select! {
+            read = stdout.read(&mut buf) => {
+                match read.context("Failed to read exec output")? {
+                    0 => break,
+                    n => on_output(buf[..n].to_vec()),
+                }
+            }
+            msg = input.recv() => match msg {
+                Some(ExecInput::Data(bytes)) => {
+                    stdin.write_all(&bytes).await.context("Failed to write exec input")?;
+                    stdin.flush().await.context("Failed to write exec input")?;
+                }
+                Some(ExecInput::Resize { width, height }) => {
+                    if let Some(tx) = resize.as_mut() {
+                        let _ = tx.send(TerminalSize { width, height }).await;
+                    }
+                }
+                None => {
+                    process.abort();
+                    return Ok(());
+                }
+            }
+        }
+    }
+
+    drop(stdin);
+    process.join().await.context("Exec session failed")?;
+
+    Ok(())
+}
@@ -0,0 +1,573 @@
+//! A minimal in-process stand-in for the Kubernetes API server, used by tests to
+//! exercise the functions in this module over real HTTP round trips.
+//!
+//! Objects are seeded from YAML fixtures under `tests/fixtures` and kept in memory,
+//! so a create or patch is visible to later reads. Supports list (with label and
+//! field selectors), get, create, replace, merge/JSON/apply patches, delete and pod
+//! logs; watches are rejected. Every request is recorded for assertions.
+
+use anyhow::{bail, Context, Result};
+use chrono::Utc;
+use kube::{Client, Config};
+use serde::Deserialize;
+use serde_json::{json, Value};
+use std::collections::BTreeMap;
+use std::net::SocketAddr;
+use std::sync::{Arc, Mutex};
+use tokio::io::{AsyncBufReadExt, AsyncReadExt, AsyncWriteExt, BufReader};
+use tokio::net::{TcpListener, TcpStream};
+use tokio::task::JoinHandle;
+
+const FIXTURE_DIR: &str = concat!(env!("CARGO_MANIFEST_DIR"), "/tests/fixtures");
+
+/// A request as the fake server saw it, with the query decoded and the body parsed.
+#[derive(Clone, Debug)]
+pub struct RecordedRequest {
+    pub method: String,
+    pub path: String,
+    pub query: BTreeMap<String, String>,
+    pub content_type: Option<String>,
+    pub body: Option<Value>,
+}
+
+#[derive(Default)]
+struct State {
+    /// Objects per collection, keyed by the cluster-wide collection path such as
+    /// `/apis/apps/v1/deployments`.
+    objects: BTreeMap<String, Vec<Value>>,
+    /// Log text per `(namespace, pod)`.
+    logs: BTreeMap<(String, String), String>,
+    requests: Vec<RecordedRequest>,
+    resource_version: u64,
+}
+
+impl State {
+    fn next_resource_version(&mut self) -> String {
+        self.resource_version += 1;
+        self.resource_version.to_string()
+    }
+}
+
+pub struct FakeApiServer {
+    addr: SocketAddr,
+    state: Arc<Mutex<State>>,
+    task: JoinHandle<()>,
+}
+
+impl FakeApiServer {
+    /// Loads the named fixture files and starts serving them on a free local port.
+    pub async fn start(fixtures: &[&str]) -> Self {
+        let mut state = State::default();
+        for file in fixtures {
+            for object in load_fixture(file).unwrap_or_else(|e| panic!("fixture {}: {:#}", file, e)) {
+                let collection = collection_of(&object).unwrap_or_else(|e| panic!("fixture {}: {:#}", file, e));
+                state.objects.entry(collection).or_default().push(object);
+            }
+        }
+        state.resource_version = 1000;
+
+        let listener = TcpListener::bind("127.0.0.1:0").await.expect("bind fake API server");
+        let addr = listener.local_addr().expect("fake API server address");
+        let state = Arc::new(Mutex::new(state));
+
+        let shared = state.clone();
+        let task = tokio::spawn(async move {
+            while let Ok((stream, _)) = listener.accept().await {
+                let state = shared.clone();
+                tokio::spawn(async move {
+                    if let Err(e) = serve_connection(stream, state).await {
+                        tracing::debug!("Fake API server connection failed: {:#}", e);
+                    }
+                });
+            }
+        });
+
+        Self { addr, state, task }
+    }
+
+    /// A client pointed at this server over plain HTTP.
+    pub fn client(&self) -> Client {
+        let url = format!("http://{}", self.addr).parse().expect("fake API server URL");
+        Client::try_from(Config::new(url)).expect("client for fake API server")
+    }
+
+    /// Serves the contents of a fixture file as the logs of `pod`.
+    pub fn add_log(&self, namespace: &str, pod: &str, file: &str) {
+        let text = std::fs::read_to_string(format!("{}/{}", FIXTURE_DIR, file))
+            .unwrap_or_else(|e| panic!("fixture {}: {}", file, e));
+        self.state
+            .lock()
+            .unwrap()
+            .logs
+            .insert((namespace.to_string(), pod.to_string()), text);
+    }
+
+    pub fn requests(&self) -> Vec<RecordedRequest> {
+        self.state.lock().unwrap().requests.clone()
+    }
+
+    /// Requests other than GET, in the order they arrived.
+    pub fn mutations(&self) -> Vec<RecordedRequest> {
+        self.requests().into_iter().filter(|r| r.method != "GET").collect()
+    }
+
+    /// The current state of the object at an API path such as
+    /// `/api/v1/namespaces/default/pods/web-0`.
+    pub fn object(&self, path: &str) -> Option<Value> {
+        let route = Route::parse(path)?;
+        let name = route.name.as_deref()?;
+        let state = self.state.lock().unwrap();
+        find(&state, &route, name).cloned()
+    }
+}
+
+impl Drop for FakeApiServer {
+    fn drop(&mut self) {
+        self.task.abort();
+    }
+}
+
+fn load_fixture(file: &str) -> Result<Vec<Value>> {
+    let text = std::fs::read_to_string(format!("{}/{}", FIXTURE_DIR, file)).context("Failed to read fixture")?;
+
+    let mut objects = Vec::new();
+    for document in serde_yaml::Deserializer::from_str(&text) {
+        let value = Value::deserialize(document).context("Invalid fixture YAML")?;
+        if !value.is_null() {
+            objects.push(value);
+        }
+    }
+    Ok(objects)
+}
+
+fn collection_of(object: &Value) -> Result<String> {
+    let (Some(api_version), Some(kind)) = (object["apiVersion"].as_str(), object["kind"].as_str()) else {
+        bail!("object is missing apiVersion or kind");
+    };
+    let prefix = if api_version.contains('/') { "apis" } else { "api" };
+    Ok(format!("/{}/{}/{}", prefix, api_version, plural_of(kind)))
+}
+
+fn plural_of(kind: &str) -> String {
+    let lower = kind.to_lowercase();
+    if lower.ends_with('s') {
+        format!("{}es", lower)
+    } else if let Some(stem) = lower.strip_suffix('y') {
+        format!("{}ies", stem)
+    } else {
+        format!("{}s", lower)
+    }
+}
+
+/// Where a request path points: a collection, optionally narrowed to a namespace,
+/// an object and a subresource.
+struct Route {
+    collection: String,
+    namespace: Option<String>,
+    name: Option<String>,
+    subresource: Option<String>,
+}
+
+impl Route {
+    fn parse(path: &str) -> Option<Self> {
+        let segments: Vec<&str> = path.trim_matches('/').split('/').collect();
+        let (prefix, rest) = match segments.as_slice() {
+            ["api", version, rest @ ..] => (format!("/api/{}", version), rest),
+            ["apis", group, version, rest @ ..] => (format!("/apis/{}/{}", group, version), rest),
+            _ => return None,
+        };
+
+        // `/namespaces/{ns}` alone addresses the Namespace object itself.
+        let (namespace, rest) = match rest {
+            ["namespaces", ns, rest @ ..] if !rest.is_empty() => (Some(ns.to_string()), rest),
+            _ => (None, rest),
+        };
+        let (plural, name, subresource) = match rest {
+            [plural] => (plural, None, None),
+            [plural, name] => (plural, Some(name.to_string()), None),
+            [plural, name, sub] => (plural, Some(name.to_string()), Some(sub.to_string())),
+            _ => return None,
+        };
+
+        Some(Self {
+            collection: format!("{}/{}", prefix, plural),
+            namespace,
+            name,
+            subresource,
+        })
+    }
+
+    fn in_namespace(&self, object: &Value) -> bool {
+        match &self.namespace {
+            Some(ns) => object["metadata"]["namespace"].as_str() == Some(ns.as_str()),
+            None => true,
+        }
+    }
+
+    fn matches(&self, object: &Value, name: &str) -> bool {
+        self.in_namespace(object) && object["metadata"]["name"].as_str() == Some(name)
+    }
+
+    fn kind_plural(&self) -> &str {
+        self.collection.rsplit('/').next().unwrap_or_default()
+    }
+}
+
+struct Response {
+    code: u16,
+    content_type: &'static str,
+    body: String,
+}
+
+impl Response {
+    fn json(code: u16, body: Value) -> Self {
+        Self { code, content_type: "application/json", body: body.to_string() }
+    }
+
+    fn status(code: u16, reason: &str, message: String) -> Self {
+        Self::json(
+            code,
+            json!({
+                "kind": "Status",
+                "apiVersion": "v1",
+                "metadata": {},
+                "status": "Failure",
+                "message": message,
+                "reason": reason,
+                "code": code,
+            }),
+        )
+    }
+
+    fn not_found(route: &Route, name: &str) -> Self {
+        Self::status(404, "NotFound", format!("{} \"{}\" not found", route.kind_plural(), name))
+    }
+}
+
+async fn serve_connection(stream: TcpStream, state: Arc<Mutex<State>>) -> Result<()> {
+    let mut reader = BufReader::new(stream);
+
+    let mut request_line = String::new();
+    reader.read_line(&mut request_line).await?;
+    let mut parts = request_line.split_whitespace();
+    let (Some(method), Some(target)) = (parts.next(), parts.next()) else {
+        bail!("malformed request line {:?}", request_line);
+    };
+    let (method, target) = (method.to_string(), target.to_string());
+
+    let mut headers = BTreeMap::new();
+    loop {
+        let mut line = String::new();
+        reader.read_line(&mut line).await?;
+        let line = line.trim_end();
+        if line.is_empty() {
+            break;
+        }
+        if let Some((key, value)) = line.split_once(':') {
+            headers.insert(key.trim().to_lowercase(), value.trim().to_string());
+        }
+    }
+
+    let length: usize = headers.get("content-length").and_then(|l| l.parse().ok()).unwrap_or(0);
+    let mut body = vec![0; length];
+    reader.read_exact(&mut body).await?;
+
+    let (path, query) = target.split_once('?').unwrap_or((target.as_str(), ""));
+    let request = RecordedRequest {
+        method,
+        path: path.to_string(),
+        query: parse_query(query),
+        content_type: headers.get("content-type").cloned(),
+        body: if body.is_empty() { None } else { serde_json::from_slice(&body).ok() },
+    };
+
+    let response = {
+        let mut state = state.lock().unwrap();
+        state.requests.push(request.clone());
+        handle(&mut state, &request)
+    };
+
+    let head = format!(
+        "HTTP/1.1 {} {}\r\nContent-Type: {}\r\nContent-Length: {}\r\nConnection: close\r\n\r\n",
+        response.code,
+        reason_phrase(response.code),
+        response.content_type,
+        response.body.len()
+    );
+    let mut stream = reader.into_inner();
+    stream.write_all(head.as_bytes()).await?;
+    stream.write_all(response.body.as_bytes()).await?;
+    stream.shutdown().await?;
+    Ok(())
+}
+
+fn reason_phrase(code: u16) -> &'static str {
+    match code {
+        200 => "OK",
+        201 => "Created",
+        400 => "Bad Request",
+        404 => "Not Found",
+        405 => "Method Not Allowed",
+        409 => "Conflict",
+        415 => "Unsupported Media Type",
+        _ => "Unknown",
+    }
+}
+
+fn handle(state: &mut State, request: &RecordedRequest) -> Response {
+    let Some(route) = Route::parse(&request.path) else {
+        return Response::status(404, "NotFound", format!("no route for {}", request.path));
+    };
+
+    match (request.method.as_str(), route.name.clone(), route.subresource.as_deref()) {
+        ("GET", None, _) if request.query.get("watch").map(String::as_str) == Some("true") => {
+            Response::status(405, "MethodNotAllowed", "watch is not supported by the fake API server".to_string())
+        }
+        ("GET", None, _) => list(state, &route, &request.query),
+        ("GET", Some(name), None) => get(state, &route, &name),
+        ("GET", Some(name), Some("log")) => logs(state, &route, &name, &request.query),
+        ("POST", None, _) => create(state, &route, request.body.clone()),
+        ("PUT", Some(name), None) => replace(state, &route, &name, request.body.clone()),
+        ("PATCH", Some(name), None) => patch(state, &route, &name, request),
+        ("DELETE", Some(name), None) => delete(state, &route, &name),
+        (method, _, _) => Response::status(405, "MethodNotAllowed", format!("{} {} is not supported", method, request.path)),
+    }
+}
+
+fn list(state: &State, route: &Route, query: &BTreeMap<String, String>) -> Response {
+    let items: Vec<Value> = state
+        .objects
+        .get(&route.collection)
+        .map(|objects| {
+            objects
+                .iter()
+                .filter(|o| route.in_namespace(o))
+                .filter(|o| query.get("labelSelector").is_none_or(|s| matches_labels(o, s)))
+                .filter(|o| query.get("fieldSelector").is_none_or(|s| matches_fields(o, s)))
+                .cloned()
+                .collect()
+        })
+        .unwrap_or_default();
+
+    let (api_version, kind) = match items.first() {
+        Some(item) => (
+            item["apiVersion"].as_str().unwrap_or_default().to_string(),
+            format!("{}List", item["kind"].as_str().unwrap_or_default()),
+        ),
+        None => (String::new(), "List".to_string()),
+    };
+
+    Response::json(
+        200,
+        json!({
+            "apiVersion": api_version,
+            "kind": kind,
+            "metadata": { "resourceVersion": state.resource_version.to_string() },
+            "items": items,
+        }),
+    )
+}
+
+fn get(state: &State, route: &Route, name: &str) -> Response {
+    match find(state, route, name) {
+        Some(object) => Response::json(200, object.clone()),
+        None => Response::not_found(route, name),
+    }
+}
+
+fn logs(state: &State, route: &Route, name: &str, query: &BTreeMap<String, String>) -> Response {
+    if find(state, route, name).is_none() {
+        return Response::not_found(route, name);
+    }
+
+    let namespace = route.namespace.clone().unwrap_or_default();
+    let text = state.logs.get(&(namespace, name.to_string())).cloned().unwrap_or_default();
+    let text = match query.get("tailLines").and_then(|n| n.parse::<usize>().ok()) {
+        Some(n) => {
+            let lines: Vec<&str> = text.lines().collect();
+            let mut tail = lines[lines.len().saturating_sub(n)..].join("\n");
+            tail.push('\n');
+            tail
+        }
+        None => text,
+    };
+
+    Response { code: 200, content_type: "text/plain", body: text }
+}
+
+fn create(state: &mut State, route: &Route, body: Option<Value>) -> Response {
+    let Some(mut object) = body else {
+        return Response::status(400, "BadRequest", "request body is not a JSON object".to_string());
+    };
+    let Some(name) = object["metadata"]["name"].as_str().map(str::to_string) else {
+        return Response::status(400, "BadRequest", "metadata.name is required".to_string());
+    };
+    if find(state, route, &name).is_some() {
+        return Response::status(409, "AlreadyExists", format!("{} \"{}\" already exists", route.kind_plural(), name));
+    }
+
+    let resource_version = state.next_resource_version();
+    let metadata = &mut object["metadata"];
+    if let Some(ns) = &route.namespace {
+        metadata["namespace"] = json!(ns);
+    }
+    metadata["uid"] = json!(format!("uid-{}", name));
+    metadata["resourceVersion"] = json!(resource_version);
+    metadata["creationTimestamp"] = json!(Utc::now().format("%Y-%m-%dT%H:%M:%SZ").to_string());
+
+    state.objects.entry(route.collection.clone()).or_default().push(object.clone());
+    Response::json(201, object)
+}
+
+fn replace(state: &mut State, route: &Route, name: &str, body: Option<Value>) -> Response {
+    let Some(mut object) = body else {
+        return Response::status(400, "BadRequest", "request body is not a JSON object".to_string());
+    };
+    let resource_version = state.next_resource_version();
+    let Some(existing) = find_mut(state, route, name) else {
+        return Response::not_found(route, name);
+    };
+
+    let sent = object["metadata"]["resourceVersion"].as_str();
+    if sent.is_some() && sent != existing["metadata"]["resourceVersion"].as_str() {
+        return Response::status(
+            409,
+            "Conflict",
+            format!("Operation cannot be fulfilled on {} \"{}\": the object has been modified", route.kind_plural(), name),
+        );
+    }
+
+    object["metadata"]["resourceVersion"] = json!(resource_version);
+    *existing = object.clone();
+    Response::json(200, object)
+}
+
+fn patch(state: &mut State, route: &Route, name: &str, request: &RecordedRequest) -> Response {
+    let Some(body) = &request.body else {
+        return Response::status(400, "BadRequest", "patch body is not JSON".to_string());
+    };
+    let content_type = request.content_type.as_deref().unwrap_or_default();
+
+    // Server-side apply creates the object when it does not exist yet.
+    if content_type == "application/apply-patch+yaml" && find(state, route, name).is_none() {
+        return create(state, route, Some(body.clone()));
+    }
+
+    let resource_version = state.next_resource_version();
+    let Some(existing) = find_mut(state, route, name) else {
+        return Response::not_found(route, name);
+    };
+
+    let mut patched = existing.clone();
+    match content_type {
+        // Strategic merge and apply are approximated by a plain merge, which is exact
+        // for the scalar and map fields the dashboard patches.
+        "application/merge-patch+json" | "application/strategic-merge-patch+json" | "application/apply-patch+yaml" => {
+            json_patch::merge(&mut patched, body);
+        }
+        "application/json-patch+json" => {
+            let operations: json_patch::Patch = match serde_json::from_value(body.clone()) {
+                Ok(operations) => operations,
+                Err(e) => return Response::status(400, "BadRequest", format!("invalid JSON patch: {}", e)),
+            };
+            if let Err(e) = json_patch::patch(&mut patched, &operations.0) {
+                return Response::status(422, "Invalid", format!("JSON patch failed: {}", e));
+            }
+        }
+        other => {
+            return Response::status(415, "UnsupportedMediaType", format!("unsupported patch type {:?}", other));
+        }
+    }
+
+    patched["metadata"]["resourceVersion"] = json!(resource_version);
+    *existing = patched.clone();
+    Response::json(200, patched)
+}
+
+fn delete(state: &mut State, route: &Route, name: &str) -> Response {
+    let Some(objects) = state.objects.get_mut(&route.collection) else {
+        return Response::not_found(route, name);
+    };
+    match objects.iter().position(|o| route.matches(o, name)) {
+        Some(index) => Response::json(200, objects.remove(index)),
+        None => Response::not_found(route, name),
+    }
+}
+
+fn find<'a>(state: &'a State, route: &Route, name: &str) -> Option<&'a Value> {
+    state.objects.get(&route.collection)?.iter().find(|o| route.matches(o, name))
+}
+
+fn find_mut<'a>(state: &'a mut State, route: &Route, name: &str) -> Option<&'a mut Value> {
+    state.objects.get_mut(&route.collection)?.iter_mut().find(|o| route.matches(o, name))
+}
+
+/// Equality-based label selectors: `k=v`, `k==v`, `k!=v`, `k` and `!k`.
+fn matches_labels(object: &Value, selector: &str) -> bool {
+    let labels = &object["metadata"]["labels"];
+    selector.split(',').filter(|t| !t.is_empty()).all(|term| {
+        if let Some((key, value)) = term.split_once("!=") {
+            labels[key.trim()].as_str() != Some(value.trim())
+        } else if let Some((key, value)) = term.split_once('=') {
+            let value = value.trim_start_matches('=');
+            labels[key.trim()].as_str() == Some(value.trim())
+        } else if let Some(key) = term.strip_prefix('!') {
+            labels.get(key.trim()).is_none()
+        } else {
+            labels.get(term.trim()).is_some()
+        }
+    })
+}
+
+/// Field selectors over dotted paths, e.g. `involvedObject.name=web-0`.
+fn matches_fields(object: &Value, selector: &str) -> bool {
+    let field = |path: &str| {
+        let value = path.trim().split('.').fold(object, |v, key| &v[key]);
+        match value {
+            Value::String(s) => s.clone(),
+            Value::Null => String::new(),
+            other => other.to_string(),
+        }
+    };
+    selector.split(',').filter(|t| !t.is_empty()).all(|term| {
+        if let Some((path, value)) = term.split_once("!=") {
+            field(path) != value.trim()
+        } else if let Some((path, value)) = term.split_once('=') {
+            field(path) == value.trim_start_matches('=').trim()
+        } else {
+            false
+        }
+    })
+}
+
+fn parse_query(query: &str) -> BTreeMap<String, String> {
+    query
+        .split('&')
+        .filter(|pair| !pair.is_empty())
+        .map(|pair| {
+            let (key, value) = pair.split_once('=').unwrap_or((pair, ""));
+            (percent_decode(key), percent_decode(value))
+        })
+        .collect()
+}
+
+fn percent_decode(text: &str) -> String {
+    let bytes = text.as_bytes();
+    let mut decoded = Vec::with_capacity(bytes.len());
+    let mut i = 0;
+    while i < bytes.len() {
+        match bytes[i] {
+            b'+' => decoded.push(b' '),
+            b'%' if i + 2 < bytes.len() => match std::str::from_utf8(&bytes[i + 1..i + 3]).ok().and_then(|h| u8::from_str_radix(h, 16).ok()) {
+                Some(byte) => {
+                    decoded.push(byte);
+                    i += 2;
+                }
+                None => decoded.push(b'%'),
+            },
+            byte => decoded.push(byte),
+        }
+        i += 1;
+    }
+    String::from_utf8_lossy(&decoded).into_owned()
+}
@@ -0,0 +1,152 @@
+use anyhow::{Context, Result};
+use kube::{
+    api::{Api, ApiResource, DynamicObject, ListParams},
+    Client, ResourceExt,
+};
+use serde_json::Value;
+use std::collections::VecDeque;
+
+use super::quantity::{parse_cpu_quantity, parse_memory_quantity};
+
+/// Samples kept per object; metrics-server refreshes roughly every 15s, so this
+/// covers the last ten minutes or so.
+const USAGE_HISTORY_LEN: usize = 40;
+
+#[derive(Clone, Copy, Debug, Default)]
+pub struct UsageSample {
+    pub cpu_millis: u64,
+    pub memory_bytes: u64,
+}
+
+#[derive(Clone, Debug)]
+pub struct PodUsage {
+    pub namespace: String,
+    pub name: String,
+    pub usage: UsageSample,
+}
+
+#[derive(Clone, Debug)]
+pub struct NodeUsage {
+    pub name: String,
+    pub usage: UsageSample,
+}
+
+/// Recent usage samples for one pod or node, oldest first.
+#[derive(Clone, Debug, Default)]
+pub struct UsageHistory {
+    samples: VecDeque<UsageSample>,
+}
+
+impl UsageHistory {
+    pub fn push(&mut self, sample: UsageSample) {
+        if self.samples.len() == USAGE_HISTORY_LEN {
+            self.samples.pop_front();
+        }
+        self.samples.push_back(sample);
+    }
+
+    pub fn latest(&self) -> Option<UsageSample> {
+        self.samples.back().copied()
+    }
+
+    pub fn cpu_series(&self) -> Vec<f64> {
+        self.samples.iter().map(|s| s.cpu_millis as f64).collect()
+    }
+
+    pub fn memory_series(&self) -> Vec<f64> {
+        self.samples.iter().map(|s| s.memory_bytes as f64).collect()
+    }
+}
+
+pub fn format_cpu_millis(millis: u64) -> String {
+    if millis >= 1000 && millis.is_multiple_of(100) {
+        format!("{}", millis as f64 / 1000.0)
+    } else {
+        format!("{}m", millis)
+    }
+}
+
+fn metrics_resource(kind: &str, plural: &str) -> ApiResource {
+    ApiResource {
+        group: "metrics.k8s.io".to_string(),
+        version: "v1beta1".to_string(),
+        api_version: "metrics.k8s.io/v1beta1".to_string(),
+        kind: kind.to_string(),
+        plural: plural.to_string(),
+    }
+}
+
+fn usage_sample(usage: Option<&Value>) -> UsageSample {
+    let quantity = |key: &str| usage.and_then(|u| u.get(key)).and_then(Value::as_str);
+    UsageSample {
+        cpu_millis: quantity("cpu").and_then(parse_cpu_quantity).unwrap_or(0),
+        memory_bytes: quantity("memory").and_then(parse_memory_quantity).unwrap_or(0),
+    }
+}
+
+/// The metrics API is an aggregated API served by metrics-server; it is absent (404)
+/// or unavailable (503) on clusters that don't run it.
+fn metrics_unavailable(error: &kube::Error) -> bool {
+    matches!(error, kube::Error::Api(e) if e.code == 404 || e.code == 503)
+}
+
+/// Current pod usage, summed over containers. `Ok(None)` when the cluster has no
+/// metrics API.
+pub async fn list_pod_metrics(client: &Client, namespace: Option<&str>) -> Result<Option<Vec<PodUsage>>> {
+    let resource = metrics_resource("PodMetrics", "pods");
+    let api: Api<DynamicObject> = match namespace {
+        Some(ns) => Api::namespaced_with(client.clone(), ns, &resource),
+        None => Api::all_with(client.clone(), &resource),
+    };
+
+    let list = match api.list(&ListParams::default()).await {
+        Ok(list) => list,
+        Err(e) if metrics_unavailable(&e) => return Ok(None),
+        Err(e) => return Err(e).context("Failed to list pod metrics"),
+    };
+
+    Ok(Some(
+        list.items
+            .iter()
+            .map(|m| {
+                let usage = m
+                    .data
+                    .get("containers")
+                    .and_then(Value::as_array)
+                    .into_iter()
+                    .flatten()
+                    .map(|c| usage_sample(c.get("usage")))
+                    .fold(UsageSample::default(), |total, c| UsageSample {
+                        cpu_millis: total.cpu_millis + c.cpu_millis,
+                        memory_bytes: total.memory_bytes + c.memory_bytes,
+                    });
+                PodUsage {
+                    namespace: m.namespace().unwrap_or_default(),
+                    name: m.name_any(),
+                    usage,
+                }
+            })
+            .collect(),
+    ))
+}
+
+/// Current node usage. `Ok(None)` when the cluster has no metrics API.
+pub async fn list_node_metrics(client: &Client) -> Result<Option<Vec<NodeUsage>>> {
+    let api: Api<DynamicObject> = Api::all_with(client.clone(), &metrics_resource("NodeMetrics", "nodes"));
+
+    let list = match api.list(&ListParams::default()).await {
+        Ok(list) => list,
+        Err(e) if metrics_unavailable(&e) => return Ok(None),
+        Err(e) => return Err(e).context("Failed to list node metrics"),
+    };
+
+    Ok(Some(
+        list.items
+            .iter()
+            .map(|m| NodeUsage {
+                name: m.name_any(),
+                usage: usage_sample(m.data.get("usage")),
+            })
+            .collect(),
+    ))
+}
@@ -1,5 +1,34 @@
+mod bulk;
 mod client;
+mod create;
+mod custom;
+mod exec;
+#[cfg(test)]
+mod fake;
+mod metrics;
+mod nodes;
+mod objects;
+mod portforward;
+mod quantity;
 mod resources;
+mod rollout;
+mod selector;
+mod topology;
+mod watch;
+mod workloads;
 
+pub use bulk::*;
 pub use client::*;
+pub use create::*;
+pub use custom::*;
+pub use exec::*;
+pub use metrics::*;
+pub use nodes::*;
+pub use objects::*;
+pub use portforward::*;
+pub use quantity::*;
 pub use resources::*;
+pub use rollout::*;
+pub use selector::*;
+pub use topology::*;
+pub use workloads::*;
@@ -0,0 +1,309 @@
+use anyhow::{Context, Result};
+use chrono::{DateTime, Utc};
+use k8s_openapi::api::core::v1::{Node, Pod};
+use kube::{
+    api::{Api, EvictParams, ListParams, Patch, PatchParams},
+    Client, ResourceExt,
+};
+use serde::Serialize;
+use std::collections::BTreeMap;
+use std::time::{Duration, Instant};
+
+use super::quantity::parse_memory_quantity;
+use super::resources::format_age;
+use super::selector::Selectors;
+use super::watch::watch_resources;
+
+/// How long a drain keeps retrying evictions refused by PodDisruptionBudgets and
+/// waiting for evicted pods to go away.
+const DRAIN_TIMEOUT: Duration = Duration::from_secs(300);
+const EVICTION_RETRY_INTERVAL: Duration = Duration::from_secs(5);
+const DELETION_POLL_INTERVAL: Duration = Duration::from_secs(1);
+
+#[derive(Clone, Debug, Serialize)]
+pub struct NodeInfo {
+    pub name: String,
+    pub status: String,
+    pub unschedulable: bool,
+    pub roles: Vec<String>,
+    pub kubelet_version: String,
+    pub internal_ip: String,
+    pub os_image: String,
+    pub cpu_capacity: String,
+    pub cpu_allocatable: String,
+    pub memory_capacity: u64,
+    pub memory_allocatable: u64,
+    pub pods_capacity: String,
+    pub conditions: Vec<NodeConditionInfo>,
+    pub taints: Vec<String>,
+    pub age: String,
+    pub created: Option<DateTime<Utc>>,
+    pub labels: BTreeMap<String, String>,
+    #[serde(skip)]
+    pub annotations: BTreeMap<String, String>,
+}
+
+#[derive(Clone, Debug, Serialize)]
+pub struct NodeConditionInfo {
+    pub condition_type: String,
+    pub status: String,
+    pub reason: String,
+    pub message: String,
+}
+
+#[derive(Clone, Debug, PartialEq)]
+pub enum EvictionStatus {
+    Pending,
+    /// Refused because it would violate a PodDisruptionBudget; retried until the drain times out.
+    Blocked(String),
+    /// Eviction accepted; waiting for the pod to be deleted.
+    Terminating,
+    Evicted,
+    Skipped(String),
+    Failed(String),
+}
+
+#[derive(Clone, Debug)]
+pub struct DrainPodStatus {
+    pub namespace: String,
+    pub name: String,
+    pub status: EvictionStatus,
+}
+
+fn node_info(n: &Node) -> NodeInfo {
+    let meta = &n.metadata;
+    let spec = n.spec.as_ref();
+    let status = n.status.as_ref();
+
+    let mut roles: Vec<String> = meta
+        .labels
+        .iter()
+        .flatten()
+        .filter_map(|(k, v)| match k.strip_prefix("node-role.kubernetes.io/") {
+            Some(role) if !role.is_empty() => Some(role.to_string()),
+            _ if k == "kubernetes.io/role" => Some(v.clone()),
+            _ => None,
+        })
+        .collect();
+    roles.sort();
+    roles.dedup();
+
+    let conditions: Vec<NodeConditionInfo> = status
+        .and_then(|s| s.conditions.as_ref())
+        .into_iter()
+        .flatten()
+        .map(|c| NodeConditionInfo {
+            condition_type: c.type_.clone(),
+            status: c.status.clone(),
+            reason: c.reason.clone().unwrap_or_default(),
+            message: c.message.clone().unwrap_or_default(),
+        })
+        .collect();
+
+    let ready = match conditions.iter().find(|c| c.condition_type == "Ready") {
+        Some(c) if c.status == "True" => "Ready",
+        Some(c) if c.status == "False" => "NotReady",
+        _ => "Unknown",
+    };
+
+    let quantity = |map: Option<&std::collections::BTreeMap<String, k8s_openapi::apimachinery::pkg::api::resource::Quantity>>, key: &str| {
+        map.and_then(|m| m.get(key)).map(|q| q.0.clone()).unwrap_or_default()
+    };
+    let capacity = status.and_then(|s| s.capacity.as_ref());
+    let allocatable = status.and_then(|s| s.allocatable.as_ref());
+
+    let node_info = status.and_then(|s| s.node_info.as_ref());
+
+    let internal_ip = status
+        .and_then(|s| s.addresses.as_ref())
+        .and_then(|addrs| addrs.iter().find(|a| a.type_ == "InternalIP"))
+        .map(|a| a.address.clone())
+        .unwrap_or_else(|| "-".to_string());
+
+    let taints = spec
+        .and_then(|s| s.taints.as_ref())
+        .into_iter()
+        .flatten()
+        .map(|t| match &t.value {
+            Some(v) => format!("{}={}:{}", t.key, v, t.effect),
+            None => format!("{}:{}", t.key, t.effect),
+        })
+        .collect();
+
+    NodeInfo {
+        name: meta.name.clone().unwrap_or_default(),
+        status: ready.to_string(),
+        unschedulable: spec.and_then(|s| s.unschedulable).unwrap_or(false),
+        roles,
+        kubelet_version: node_info.map(|i| i.kubelet_version.clone()).unwrap_or_default(),
+        internal_ip,
+        os_image: node_info.map(|i| i.os_image.clone()).unwrap_or_default(),
+        cpu_capacity: quantity(capacity, "cpu"),
+        cpu_allocatable: quantity(allocatable, "cpu"),
+        memory_capacity: parse_memory_quantity(&quantity(capacity, "memory")).unwrap_or(0),
+        memory_allocatable: parse_memory_quantity(&quantity(allocatable, "memory")).unwrap_or(0),
+        pods_capacity: quantity(allocatable, "pods"),
+        conditions,
+        taints,
+        age: format_age(meta.creation_timestamp.as_ref()),
+        created: meta.creation_timestamp.as_ref().map(|t| t.0),
+        labels: meta.labels.clone().unwrap_or_default(),
+        annotations: meta.annotations.clone().unwrap_or_default(),
+    }
+}
+
+pub async fn list_nodes(client: &Client, selectors: &Selectors) -> Result<Vec<NodeInfo>> {
+    let nodes: Api<Node> = Api::all(client.clone());
+
+    let list = nodes
+        .list(&selectors.list_params())
+        .await
+        .context("Failed to list nodes")?;
+
+    Ok(list.items.iter().map(node_info).collect())
+}
+
+pub async fn watch_nodes(client: &Client, selectors: &Selectors, on_update: impl FnMut(Result<Vec<NodeInfo>>)) {
+    let nodes: Api<Node> = Api::all(client.clone());
+
+    watch_resources(nodes, selectors.watcher_config(), "nodes", node_info, on_update).await;
+}
+
+/// Marks the node (un)schedulable.
+pub async fn cordon_node(client: &Client, name: &str, cordon: bool) -> Result<()> {
+    let nodes: Api<Node> = Api::all(client.clone());
+
+    let patch = serde_json::json!({
+        "spec": {
+            "unschedulable": cordon
+        }
+    });
+
+    nodes
+        .patch(name, &PatchParams::default(), &Patch::Merge(&patch))
+        .await
+        .with_context(|| format!("Failed to {} node", if cordon { "cordon" } else { "uncordon" }))?;
+
+    Ok(())
+}
+
+/// Why a pod on a draining node is left alone, mirroring `kubectl drain`'s defaults.
+fn drain_skip_reason(pod: &Pod) -> Option<&'static str> {
+    if pod.annotations().contains_key("kubernetes.io/config.mirror") {
+        return Some("Static (mirror) pod");
+    }
+    if pod.owner_references().iter().any(|o| o.kind == "DaemonSet") {
+        return Some("Managed by a DaemonSet");
+    }
+    if pod.metadata.deletion_timestamp.is_some() {
+        return Some("Already terminating");
+    }
+    None
+}
+
+/// Cordons the node and evicts its pods through the Eviction API so that
+/// PodDisruptionBudgets are honoured. Evictions refused by a budget are retried
+/// until `DRAIN_TIMEOUT`, and like `kubectl drain` a pod only counts as evicted once
+/// it has been deleted. Every per-pod status change is reported to `on_progress`.
+pub async fn drain_node(client: &Client, name: &str, mut on_progress: impl FnMut(DrainPodStatus)) -> Result<()> {
+    cordon_node(client, name, true).await?;
+
+    let pods: Api<Pod> = Api::all(client.clone());
+    let list = pods
+        .list(&ListParams::default().fields(&format!("spec.nodeName={}", name)))
+        .await
+        .context("Failed to list pods on node")?;
+
+    let mut remaining = vec![];
+    for pod in &list.items {
+        let (namespace, pod_name) = (pod.namespace().unwrap_or_default(), pod.name_any());
+        let status = match drain_skip_reason(pod) {
+            Some(reason) => EvictionStatus::Skipped(reason.to_string()),
+            None => {
+                remaining.push((namespace.clone(), pod_name.clone(), pod.uid().unwrap_or_default()));
+                EvictionStatus::Pending
+            }
+        };
+        on_progress(DrainPodStatus { namespace, name: pod_name, status });
+    }
+
+    let deadline = Instant::now() + DRAIN_TIMEOUT;
+    let mut failed = 0;
+    let mut terminating = vec![];
+
+    loop {
+        let mut blocked = vec![];
+        for (namespace, pod_name, uid) in remaining {
+            let api: Api<Pod> = Api::namespaced(client.clone(), &namespace);
+            let status = match api.evict(&pod_name, &EvictParams::default()).await {
+                Ok(_) => {
+                    terminating.push((namespace.clone(), pod_name.clone(), uid));
+                    EvictionStatus::Terminating
+                }
+                Err(kube::Error::Api(resp)) if resp.code == 404 => EvictionStatus::Evicted,
+                Err(kube::Error::Api(resp)) if resp.code == 429 => {
+                    blocked.push((namespace.clone(), pod_name.clone(), uid));
+                    EvictionStatus::Blocked(resp.message)
+                }
+                Err(e) => {
+                    failed += 1;
+                    EvictionStatus::Failed(e.to_string())
+                }
+            };
+            on_progress(DrainPodStatus { namespace, name: pod_name, status });
+        }
+
+        if blocked.is_empty() {
+            break;
+        }
+        if Instant::now() >= deadline {
+            anyhow::bail!("Timed out with {} pod(s) still blocked by PodDisruptionBudgets", blocked.len());
+        }
+        tokio::time::sleep(EVICTION_RETRY_INTERVAL).await;
+        remaining = blocked;
+    }
+
+    wait_for_deletion(client, terminating, deadline, &mut on_progress).await?;
+
+    if failed > 0 {
+        anyhow::bail!("{} pod(s) could not be evicted", failed);
+    }
+    Ok(())
+}
+
+/// Polls evicted pods until each is gone, or has been replaced by a new pod of the
+/// same name (a StatefulSet's, say), and reports it as evicted.
+async fn wait_for_deletion(
+    client: &Client,
+    mut pending: Vec<(String, String, String)>,
+    deadline: Instant,
+    on_progress: &mut impl FnMut(DrainPodStatus),
+) -> Result<()> {
+    while !pending.is_empty() {
+        let mut still_present = vec![];
+        for (namespace, pod_name, uid) in pending {
+            let api: Api<Pod> = Api::namespaced(client.clone(), &namespace);
+            let pod = api.get_opt(&pod_name).await.with_context(|| format!("Failed to check pod {}", pod_name))?;
+            if pod.is_some_and(|p| p.uid().as_deref() == Some(uid.as_str())) {
+                still_present.push((namespace, pod_name, uid));
+            } else {
+                on_progress(DrainPodStatus { namespace, name: pod_name, status: EvictionStatus::Evicted });
+            }
+        }
+        pending = still_present;
+
+        if pending.is_empty() {
+            break;
+        }
+        if Instant::now() >= deadline {
+            let count = pending.len();
+            for (namespace, pod_name, _) in pending {
+                let status = EvictionStatus::Failed("Still terminating when the drain timed out".to_string());
+                on_progress(DrainPodStatus { namespace, name: pod_name, status });
+            }
+            anyhow::bail!("Timed out waiting for {} evicted pod(s) to be deleted", count);
+        }
+        tokio::time::sleep(DELETION_POLL_INTERVAL).await;
+    }
+    Ok(())
+}
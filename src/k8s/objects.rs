@@ -0,0 +1,216 @@
+use anyhow::{Context, Result};
+use k8s_openapi::api::{
+    apps::v1::{DaemonSet, Deployment, ReplicaSet, StatefulSet},
+    batch::v1::{CronJob, Job},
+    core::v1::{ConfigMap, Pod, Secret, Service},
+    networking::v1::Ingress,
+};
+use kube::{
+    api::{Api, ApiResource, DynamicObject, Patch, PatchParams},
+    Client,
+};
+use serde_json::Value;
+
+/// Field manager used for every server-side apply issued by the dashboard.
+pub const FIELD_MANAGER: &str = "kubectl-dashboard";
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub enum ResourceKind {
+    Deployment,
+    StatefulSet,
+    DaemonSet,
+    ReplicaSet,
+    Pod,
+    Service,
+    Ingress,
+    ConfigMap,
+    Secret,
+    Job,
+    CronJob,
+}
+
+impl ResourceKind {
+    pub fn as_str(self) -> &'static str {
+        match self {
+            ResourceKind::Deployment => "Deployment",
+            ResourceKind::StatefulSet => "StatefulSet",
+            ResourceKind::DaemonSet => "DaemonSet",
+            ResourceKind::ReplicaSet => "ReplicaSet",
+            ResourceKind::Pod => "Pod",
+            ResourceKind::Service => "Service",
+            ResourceKind::Ingress => "Ingress",
+            ResourceKind::ConfigMap => "ConfigMap",
+            ResourceKind::Secret => "Secret",
+            ResourceKind::Job => "Job",
+            ResourceKind::CronJob => "CronJob",
+        }
+    }
+
+    pub fn api_resource(self) -> ApiResource {
+        match self {
+            ResourceKind::Deployment => ApiResource::erase::<Deployment>(&()),
+            ResourceKind::StatefulSet => ApiResource::erase::<StatefulSet>(&()),
+            ResourceKind::DaemonSet => ApiResource::erase::<DaemonSet>(&()),
+            ResourceKind::ReplicaSet => ApiResource::erase::<ReplicaSet>(&()),
+            ResourceKind::Pod => ApiResource::erase::<Pod>(&()),
+            ResourceKind::Service => ApiResource::erase::<Service>(&()),
+            ResourceKind::Ingress => ApiResource::erase::<Ingress>(&()),
+            ResourceKind::ConfigMap => ApiResource::erase::<ConfigMap>(&()),
+            ResourceKind::Secret => ApiResource::erase::<Secret>(&()),
+            ResourceKind::Job => ApiResource::erase::<Job>(&()),
+            ResourceKind::CronJob => ApiResource::erase::<CronJob>(&()),
+        }
+    }
+}
+
+/// Identifies a single namespaced object the UI is acting on.
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub struct ResourceRef {
+    pub kind: ResourceKind,
+    pub namespace: String,
+    pub name: String,
+}
+
+impl ResourceRef {
+    pub fn new(kind: ResourceKind, namespace: &str, name: &str) -> Self {
+        Self {
+            kind,
+            namespace: namespace.to_string(),
+            name: name.to_string(),
+        }
+    }
+}
+
+#[derive(Clone, Debug, thiserror::Error)]
+pub enum ApplyError {
+    /// The apply touched fields owned by another field manager.
+    #[error("{0}")]
+    Conflict(String),
+    #[error("{0}")]
+    Failed(String),
+}
+
+impl From<anyhow::Error> for ApplyError {
+    fn from(e: anyhow::Error) -> Self {
+        ApplyError::Failed(format!("{:#}", e))
+    }
+}
+
+fn dynamic_api(client: &Client, target: &ResourceRef) -> Api<DynamicObject> {
+    Api::namespaced_with(client.clone(), &target.namespace, &target.kind.api_resource())
+}
+
+fn strip_managed_fields(value: &mut Value) {
+    if let Some(meta) = value.get_mut("metadata").and_then(Value::as_object_mut) {
+        meta.remove("managedFields");
+    }
+}
+
+fn redact_secret_data(value: &mut Value) {
+    for field in ["data", "stringData"] {
+        if let Some(data) = value.get_mut(field).and_then(Value::as_object_mut) {
+            for v in data.values_mut() {
+                *v = Value::String("<redacted>".to_string());
+            }
+        }
+    }
+}
+
+/// Drops server-populated fields so the object can be sent as an apply
+/// configuration without claiming ownership of them.
+fn strip_for_apply(value: &mut Value) {
+    if let Some(obj) = value.as_object_mut() {
+        obj.remove("status");
+    }
+    if let Some(meta) = value.get_mut("metadata").and_then(Value::as_object_mut) {
+        for field in ["managedFields", "resourceVersion", "uid", "creationTimestamp", "generation", "selfLink"] {
+            meta.remove(field);
+        }
+    }
+}
+
+fn to_yaml(value: &Value) -> Result<String> {
+    serde_yaml::to_string(value).context("Failed to serialize object as YAML")
+}
+
+/// Fetches the full object as YAML, without `managedFields`. Secret values are redacted.
+pub async fn get_object_yaml(client: &Client, target: &ResourceRef) -> Result<String> {
+    get_dynamic_object_yaml(client, &target.kind.api_resource(), Some(&target.namespace), &target.name).await
+}
+
+/// Like [`get_object_yaml`] for any discovered kind; `namespace` is `None` for
+/// cluster-scoped kinds.
+pub async fn get_dynamic_object_yaml(client: &Client, resource: &ApiResource, namespace: Option<&str>, name: &str) -> Result<String> {
+    let api: Api<DynamicObject> = match namespace {
+        Some(ns) => Api::namespaced_with(client.clone(), ns, resource),
+        None => Api::all_with(client.clone(), resource),
+    };
+    let object = api
+        .get(name)
+        .await
+        .with_context(|| format!("Failed to get {} {}", resource.kind, name))?;
+
+    let mut value = serde_json::to_value(&object)?;
+    strip_managed_fields(&mut value);
+    if resource.group.is_empty() && resource.kind == "Secret" {
+        redact_secret_data(&mut value);
+    }
+    to_yaml(&value)
+}
+
+fn parse_apply_body(target: &ResourceRef, yaml: &str) -> Result<Value> {
+    if target.kind == ResourceKind::Secret {
+        anyhow::bail!("Secrets cannot be edited as YAML");
+    }
+
+    let mut value: Value = serde_yaml::from_str(yaml).context("Invalid YAML")?;
+
+    let kind = value.get("kind").and_then(Value::as_str).unwrap_or_default();
+    if kind != target.kind.as_str() {
+        anyhow::bail!("Expected kind {}, found '{}'", target.kind.as_str(), kind);
+    }
+    let name = value.pointer("/metadata/name").and_then(Value::as_str).unwrap_or_default();
+    if name != target.name {
+        anyhow::bail!("metadata.name must stay '{}' (found '{}')", target.name, name);
+    }
+
+    strip_for_apply(&mut value);
+    Ok(value)
+}
+
+async fn apply(client: &Client, target: &ResourceRef, body: &Value, params: &PatchParams) -> Result<DynamicObject, ApplyError> {
+    dynamic_api(client, target)
+        .patch(&target.name, params, &Patch::Apply(body))
+        .await
+        .map_err(|e| match e {
+            kube::Error::Api(resp) if resp.code == 409 => ApplyError::Conflict(resp.message),
+            e => ApplyError::Failed(format!("Apply failed: {}", e)),
+        })
+}
+
+/// Runs a server-side dry-run apply of `yaml` and returns the live object and the
+/// object as it would look afterwards, both as YAML, for diffing.
+pub async fn preview_apply(client: &Client, target: &ResourceRef, yaml: &str) -> Result<(String, String), ApplyError> {
+    let body = parse_apply_body(target, yaml)?;
+
+    let live = get_object_yaml(client, target).await?;
+    let result = apply(client, target, &body, &PatchParams::apply(FIELD_MANAGER).dry_run()).await?;
+
+    let mut value = serde_json::to_value(&result).map_err(anyhow::Error::from)?;
+    strip_managed_fields(&mut value);
+    Ok((live, to_yaml(&value)?))
+}
+
+/// Server-side applies `yaml` as the dashboard's field manager. Conflicts with
+/// other managers are reported unless `force` is set.
+pub async fn apply_object_yaml(client: &Client, target: &ResourceRef, yaml: &str, force: bool) -> Result<(), ApplyError> {
+    let body = parse_apply_body(target, yaml)?;
+
+    let mut params = PatchParams::apply(FIELD_MANAGER);
+    if force {
+        params = params.force();
+    }
+    apply(client, target, &body, &params).await?;
+
+    Ok(())
+}
@@ -0,0 +1,229 @@
+use anyhow::{Context, Result};
+use k8s_openapi::api::core::v1::{Pod, Service};
+use k8s_openapi::apimachinery::pkg::util::intstr::IntOrString;
+use kube::{
+    api::{Api, ListParams},
+    Client,
+};
+use std::future::Future;
+use std::sync::atomic::{AtomicU64, Ordering};
+use std::sync::Arc;
+use tokio::io::{AsyncRead, AsyncReadExt, AsyncWrite, AsyncWriteExt};
+use tokio::net::{TcpListener, TcpStream};
+use tokio::task::JoinSet;
+
+/// Live counters for a port-forward, shared between the forwarding task and the UI.
+#[derive(Debug, Default)]
+pub struct ForwardStats {
+    pub bytes_in: AtomicU64,
+    pub bytes_out: AtomicU64,
+    pub connections: AtomicU64,
+}
+
+#[derive(Clone, Debug, PartialEq)]
+pub enum ForwardStatus {
+    Starting,
+    Active,
+    Failed(String),
+}
+
+#[derive(Clone, Debug)]
+pub struct PortForwardInfo {
+    pub id: u64,
+    pub namespace: String,
+    /// `pod/<name>` or `svc/<name>`
+    pub target: String,
+    pub pod: Option<String>,
+    pub local_port: Option<u16>,
+    pub remote_port: u16,
+    pub status: ForwardStatus,
+    pub stats: Arc<ForwardStats>,
+}
+
+fn is_pod_ready(pod: &Pod) -> bool {
+    let status = pod.status.as_ref();
+    pod.metadata.deletion_timestamp.is_none()
+        && status.and_then(|s| s.phase.as_deref()) == Some("Running")
+        && status
+            .and_then(|s| s.conditions.as_ref())
+            .map(|conds| conds.iter().any(|c| c.type_ == "Ready" && c.status == "True"))
+            .unwrap_or(false)
+}
+
+/// Resolves a Service port to a ready backing pod and the container port traffic
+/// for that Service port is sent to.
+pub async fn resolve_service_target(client: &Client, namespace: &str, service: &str, port: u16) -> Result<(String, u16)> {
+    let services: Api<Service> = Api::namespaced(client.clone(), namespace);
+    let pods: Api<Pod> = Api::namespaced(client.clone(), namespace);
+
+    let svc = services.get(service).await.context("Failed to get service")?;
+    let spec = svc.spec.context("Service has no spec")?;
+
+    let selector = spec.selector.unwrap_or_default();
+    if selector.is_empty() {
+        anyhow::bail!("Service {} has no selector", service);
+    }
+    let label_selector = selector
+        .iter()
+        .map(|(k, v)| format!("{}={}", k, v))
+        .collect::<Vec<_>>()
+        .join(",");
+
+    let service_port = spec
+        .ports
+        .unwrap_or_default()
+        .into_iter()
+        .find(|p| p.port == i32::from(port))
+        .with_context(|| format!("Service {} has no port {}", service, port))?;
+
+    let pod_list = pods
+        .list(&ListParams::default().labels(&label_selector))
+        .await
+        .context("Failed to list pods for service")?;
+    let pod = pod_list
+        .items
+        .into_iter()
+        .find(is_pod_ready)
+        .with_context(|| format!("No ready pods behind service {}", service))?;
+    let pod_name = pod.metadata.name.clone().unwrap_or_default();
+
+    let target_port = match service_port.target_port {
+        None => port,
+        Some(IntOrString::Int(p)) => u16::try_from(p).context("Invalid target port")?,
+        Some(IntOrString::String(name)) => pod
+            .spec
+            .as_ref()
+            .into_iter()
+            .flat_map(|s| s.containers.iter())
+            .flat_map(|c| c.ports.iter().flatten())
+            .find(|p| p.name.as_deref() == Some(name.as_str()))
+            .and_then(|p| u16::try_from(p.container_port).ok())
+            .with_context(|| format!("Pod {} has no port named {}", pod_name, name))?,
+    };
+
+    Ok((pod_name, target_port))
+}
+
+/// Listens on `127.0.0.1:local_port` (0 picks a free port) and forwards every
+/// accepted connection to `pod_port` on the pod. `on_listening` receives the bound
+/// port once the listener is up. Runs until the task is aborted, which also closes
+/// the connections it accepted.
+pub async fn run_port_forward(
+    client: &Client,
+    namespace: &str,
+    pod: &str,
+    pod_port: u16,
+    local_port: u16,
+    stats: Arc<ForwardStats>,
+    on_listening: impl FnOnce(u16),
+) -> Result<()> {
+    let listener = TcpListener::bind(("127.0.0.1", local_port))
+        .await
+        .with_context(|| format!("Failed to bind local port {}", local_port))?;
+    on_listening(listener.local_addr()?.port());
+
+    let pods: Api<Pod> = Api::namespaced(client.clone(), namespace);
+    let pod = pod.to_string();
+
+    serve(listener, &stats, |socket, stats| {
+        let pods = pods.clone();
+        let pod = pod.clone();
+        async move {
+            if let Err(e) = forward_connection(pods, &pod, pod_port, socket, stats).await {
+                tracing::warn!("Port-forward connection to {}:{} failed: {:#}", pod, pod_port, e);
+            }
+        }
+    })
+    .await
+}
+
+/// Accepts connections and runs `handle` for each. The connections belong to this
+/// future, so dropping it closes them as well as the listener.
+async fn serve<F, Fut>(listener: TcpListener, stats: &Arc<ForwardStats>, handle: F) -> Result<()>
+where
+    F: Fn(TcpStream, Arc<ForwardStats>) -> Fut,
+    Fut: Future<Output = ()> + Send + 'static,
+{
+    let mut connections = JoinSet::new();
+    loop {
+        tokio::select! {
+            accepted = listener.accept() => {
+                let (socket, _) = accepted.context("Failed to accept connection")?;
+                stats.connections.fetch_add(1, Ordering::Relaxed);
+                connections.spawn(handle(socket, stats.clone()));
+            }
+            // Reap finished connections so the set only holds open ones
+            Some(_) = connections.join_next(), if !connections.is_empty() => {}
+        }
+    }
+}
+
+async fn forward_connection(pods: Api<Pod>, pod: &str, port: u16, socket: TcpStream, stats: Arc<ForwardStats>) -> Result<()> {
+    let mut forwarder = pods.portforward(pod, &[port]).await.context("Failed to open port-forward")?;
+    let upstream = forwarder.take_stream(port).context("Port-forward stream unavailable")?;
+
+    let (local_read, local_write) = tokio::io::split(socket);
+    let (remote_read, remote_write) = tokio::io::split(upstream);
+
+    tokio::try_join!(
+        copy_counted(local_read, remote_write, &stats.bytes_out),
+        copy_counted(remote_read, local_write, &stats.bytes_in),
+    )?;
+
+    forwarder.join().await.context("Port-forward closed with error")?;
+    Ok(())
+}
+
+async fn copy_counted(mut reader: impl AsyncRead + Unpin, mut writer: impl AsyncWrite + Unpin, counter: &AtomicU64) -> Result<()> {
+    let mut buf = vec![0u8; 16 * 1024];
+    loop {
+        let n = reader.read(&mut buf).await?;
+        if n == 0 {
+            writer.shutdown().await?;
+            return Ok(());
+        }
+        writer.write_all(&buf[..n]).await?;
+        counter.fetch_add(n as u64, Ordering::Relaxed);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::time::Duration;
+
+    #[tokio::test]
+    async fn stopping_the_forward_closes_open_connections() {
+        let listener = TcpListener::bind("127.0.0.1:0").await.unwrap();
+        let addr = listener.local_addr().unwrap();
+        let stats = Arc::new(ForwardStats::default());
+
+        let shared = stats.clone();
+        let task = tokio::spawn(async move {
+            serve(listener, &shared, |mut socket, _| async move {
+                // Stands in for a tunnel that stays open until the pod hangs up
+                let mut buf = [0u8; 64];
+                while matches!(socket.read(&mut buf).await, Ok(n) if n > 0) {}
+            })
+            .await
+        });
+
+        let mut client = TcpStream::connect(addr).await.unwrap();
+        tokio::time::timeout(Duration::from_secs(5), async {
+            while stats.connections.load(Ordering::Relaxed) == 0 {
+                tokio::time::sleep(Duration::from_millis(10)).await;
+            }
+        })
+        .await
+        .expect("connection accepted");
+
+        task.abort();
+        let _ = task.await;
+
+        let mut buf = [0u8; 64];
+        let read = tokio::time::timeout(Duration::from_secs(5), client.read(&mut buf))
+            .await
+            .expect("connection closed after the forward stopped");
+        assert!(matches!(read, Ok(0) | Err(_)), "unexpected read {:?}", read);
+    }
+}
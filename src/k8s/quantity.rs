@@ -0,0 +1,85 @@
+/// Parses a CPU quantity such as `250m`, `2` or `1500000n` into millicores.
+pub fn parse_cpu_quantity(quantity: &str) -> Option<u64> {
+    let quantity = quantity.trim();
+    let (number, divisor) = if let Some(n) = quantity.strip_suffix('n') {
+        (n, 1e6)
+    } else if let Some(n) = quantity.strip_suffix('u') {
+        (n, 1e3)
+    } else if let Some(n) = quantity.strip_suffix('m') {
+        (n, 1.0)
+    } else {
+        (quantity, 1e-3)
+    };
+    number.parse::<f64>().ok().map(|n| (n / divisor).round() as u64)
+}
+
+/// Parses a memory quantity such as `16384Ki` or `2G` into bytes.
+pub fn parse_memory_quantity(quantity: &str) -> Option<u64> {
+    const SUFFIXES: [(&str, f64); 12] = [
+        ("Ki", 1024.0),
+        ("Mi", 1024.0 * 1024.0),
+        ("Gi", 1024.0 * 1024.0 * 1024.0),
+        ("Ti", 1024.0 * 1024.0 * 1024.0 * 1024.0),
+        ("Pi", 1024.0 * 1024.0 * 1024.0 * 1024.0 * 1024.0),
+        ("Ei", 1024.0 * 1024.0 * 1024.0 * 1024.0 * 1024.0 * 1024.0),
+        ("k", 1e3),
+        ("M", 1e6),
+        ("G", 1e9),
+        ("T", 1e12),
+        ("P", 1e15),
+        ("E", 1e18),
+    ];
+
+    let quantity = quantity.trim();
+    for (suffix, multiplier) in SUFFIXES {
+        if let Some(number) = quantity.strip_suffix(suffix) {
+            return number.parse::<f64>().ok().map(|n| (n * multiplier) as u64);
+        }
+    }
+    quantity.parse::<f64>().ok().map(|n| n as u64)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn parses_cpu_quantities_into_millicores() {
+        assert_eq!(parse_cpu_quantity("250m"), Some(250));
+        assert_eq!(parse_cpu_quantity("2"), Some(2000));
+        assert_eq!(parse_cpu_quantity("0.5"), Some(500));
+        assert_eq!(parse_cpu_quantity("250000u"), Some(250));
+        assert_eq!(parse_cpu_quantity("1500000n"), Some(2));
+        assert_eq!(parse_cpu_quantity("12345678n"), Some(12));
+        assert_eq!(parse_cpu_quantity(" 100m "), Some(100));
+        for invalid in ["", "m", "abc", "1.5.2", "100k"] {
+            assert_eq!(parse_cpu_quantity(invalid), None, "{}", invalid);
+        }
+    }
+
+    #[test]
+    fn parses_binary_and_decimal_memory_suffixes() {
+        assert_eq!(parse_memory_quantity("1Ki"), Some(1024));
+        assert_eq!(parse_memory_quantity("1k"), Some(1000));
+        assert_eq!(parse_memory_quantity("128Mi"), Some(128 * 1024 * 1024));
+        assert_eq!(parse_memory_quantity("128M"), Some(128_000_000));
+        assert_eq!(parse_memory_quantity("2Gi"), Some(2 * 1024 * 1024 * 1024));
+        assert_eq!(parse_memory_quantity("2G"), Some(2_000_000_000));
+        assert_eq!(parse_memory_quantity("1.5Gi"), Some(1536 * 1024 * 1024));
+        assert_eq!(parse_memory_quantity("65536"), Some(65536));
+    }
+
+    #[test]
+    fn parses_exponent_memory_quantities() {
+        assert_eq!(parse_memory_quantity("129e6"), Some(129_000_000));
+        assert_eq!(parse_memory_quantity("1e3"), Some(1000));
+        assert_eq!(parse_memory_quantity("5E-1k"), Some(500));
+    }
+
+    #[test]
+    fn rejects_invalid_memory_quantities() {
+        for invalid in ["", "Mi", "abc", "12Qi", "1.2.3Gi", "Gi1"] {
+            assert_eq!(parse_memory_quantity(invalid), None, "{}", invalid);
+        }
+    }
+}
@@ -3,18 +3,27 @@ use chrono::{DateTime, Utc};
 use k8s_openapi::api::{
     apps::v1::Deployment,
     batch::v1::{CronJob, Job},
-    core::v1::{ConfigMap, Pod, Secret, Service},
+    core::v1::{ConfigMap, Event, Pod, Secret, Service},
     networking::v1::Ingress,
 };
-use k8s_openapi::apimachinery::pkg::apis::meta::v1::OwnerReference;
+use k8s_openapi::apimachinery::pkg::api::resource::Quantity;
+use k8s_openapi::ByteString;
+use k8s_openapi::apimachinery::pkg::apis::meta::v1::{OwnerReference, Time};
 use kube::{
     api::{Api, DeleteParams, ListParams, ObjectMeta, Patch, PatchParams, PostParams},
     Client,
 };
+use serde::Serialize;
+use std::collections::BTreeMap;
+
+use super::objects::ResourceRef;
+use super::quantity::{parse_cpu_quantity, parse_memory_quantity};
+use super::selector::Selectors;
+use super::watch::watch_resources;
 
 // Resource data structures for UI display
 
-#[derive(Clone, Debug)]
+#[derive(Clone, Debug, Serialize)]
 pub struct DeploymentInfo {
     pub name: String,
     pub namespace: String,
@@ -22,12 +31,18 @@ pub struct DeploymentInfo {
     pub available: i32,
     pub ready: i32,
     pub updated: i32,
+    pub paused: bool,
     pub age: String,
+    pub created: Option<DateTime<Utc>>,
     pub images: Vec<String>,
+    /// Container names, in the same order as `images`.
+    pub containers: Vec<String>,
     pub labels: std::collections::BTreeMap<String, String>,
+    #[serde(skip)]
+    pub annotations: std::collections::BTreeMap<String, String>,
 }
 
-#[derive(Clone, Debug)]
+#[derive(Clone, Debug, Serialize)]
 pub struct PodInfo {
     pub name: String,
     pub namespace: String,
@@ -35,12 +50,22 @@ pub struct PodInfo {
     pub ready: String,
     pub restarts: i32,
     pub age: String,
+    pub created: Option<DateTime<Utc>>,
     pub node: String,
     pub ip: String,
     pub containers: Vec<ContainerInfo>,
+    /// Summed over containers, in millicores.
+    pub cpu_request: u64,
+    /// `None` when any container runs without a limit.
+    pub cpu_limit: Option<u64>,
+    pub memory_request: u64,
+    pub memory_limit: Option<u64>,
+    pub labels: std::collections::BTreeMap<String, String>,
+    #[serde(skip)]
+    pub annotations: std::collections::BTreeMap<String, String>,
 }
 
-#[derive(Clone, Debug)]
+#[derive(Clone, Debug, Serialize)]
 pub struct ContainerInfo {
     pub name: String,
     pub image: String,
@@ -49,7 +74,7 @@ pub struct ContainerInfo {
     pub state: String,
 }
 
-#[derive(Clone, Debug)]
+#[derive(Clone, Debug, Serialize)]
 pub struct ServiceInfo {
     pub name: String,
     pub namespace: String,
@@ -58,49 +83,77 @@ pub struct ServiceInfo {
     pub external_ip: String,
     pub ports: Vec<String>,
     pub age: String,
+    pub created: Option<DateTime<Utc>>,
     pub selector: std::collections::BTreeMap<String, String>,
+    pub labels: std::collections::BTreeMap<String, String>,
+    #[serde(skip)]
+    pub annotations: std::collections::BTreeMap<String, String>,
 }
 
-#[derive(Clone, Debug)]
+#[derive(Clone, Debug, Serialize)]
 pub struct IngressInfo {
     pub name: String,
     pub namespace: String,
     pub hosts: Vec<String>,
     pub paths: Vec<String>,
     pub age: String,
+    pub created: Option<DateTime<Utc>>,
+    pub labels: std::collections::BTreeMap<String, String>,
+    #[serde(skip)]
+    pub annotations: std::collections::BTreeMap<String, String>,
 }
 
-#[derive(Clone, Debug)]
+#[derive(Clone, Debug, Serialize)]
 pub struct ConfigMapInfo {
     pub name: String,
     pub namespace: String,
     pub data_count: usize,
     pub age: String,
+    pub created: Option<DateTime<Utc>>,
     pub data: std::collections::BTreeMap<String, String>,
+    pub labels: std::collections::BTreeMap<String, String>,
+    #[serde(skip)]
+    pub annotations: std::collections::BTreeMap<String, String>,
 }
 
-#[derive(Clone, Debug)]
+#[derive(Clone, Debug, Serialize)]
 pub struct SecretInfo {
     pub name: String,
     pub namespace: String,
     pub secret_type: String,
     pub data_count: usize,
     pub age: String,
+    pub created: Option<DateTime<Utc>>,
     pub data_keys: Vec<String>,
+    pub labels: std::collections::BTreeMap<String, String>,
+    #[serde(skip)]
+    pub annotations: std::collections::BTreeMap<String, String>,
 }
 
+/// Decoded secret values being edited, along with the `resourceVersion` they were
+/// read at so the save can detect concurrent changes.
 #[derive(Clone, Debug)]
+pub struct SecretData {
+    pub data: BTreeMap<String, String>,
+    pub resource_version: Option<String>,
+}
+
+#[derive(Clone, Debug, Serialize)]
 pub struct JobInfo {
     pub name: String,
     pub namespace: String,
     pub completions: String,
     pub duration: String,
     pub age: String,
+    pub created: Option<DateTime<Utc>>,
     pub status: JobStatus,
     pub owner: Option<String>,
+    pub labels: std::collections::BTreeMap<String, String>,
+    #[serde(skip)]
+    pub annotations: std::collections::BTreeMap<String, String>,
 }
 
-#[derive(Clone, Debug, PartialEq)]
+#[derive(Clone, Debug, PartialEq, Serialize)]
 pub enum JobStatus {
     Running,
     Succeeded,
@@ -108,7 +161,7 @@ pub enum JobStatus {
     Pending,
 }
 
-#[derive(Clone, Debug)]
+#[derive(Clone, Debug, Serialize)]
 pub struct CronJobInfo {
     pub name: String,
     pub namespace: String,
@@ -117,9 +170,27 @@ pub struct CronJobInfo {
     pub active: i32,
     pub last_schedule: Option<String>,
     pub age: String,
+    pub created: Option<DateTime<Utc>>,
+    pub labels: std::collections::BTreeMap<String, String>,
+    #[serde(skip)]
+    pub annotations: std::collections::BTreeMap<String, String>,
+}
+
+#[derive(Clone, Debug, Serialize)]
+pub struct EventInfo {
+    pub namespace: String,
+    pub event_type: String,
+    pub reason: String,
+    pub involved_kind: String,
+    pub involved_name: String,
+    pub message: String,
+    pub count: i32,
+    pub source: String,
+    pub last_seen: String,
+    pub last_timestamp: Option<DateTime<Utc>>,
 }
 
-fn format_age(creation_timestamp: Option<&k8s_openapi::apimachinery::pkg::apis::meta::v1::Time>) -> String {
+pub(super) fn format_age(creation_timestamp: Option<&Time>) -> String {
     let Some(ts) = creation_timestamp else {
         return "Unknown".to_string();
     };
@@ -141,43 +212,52 @@ fn format_age(creation_timestamp: Option<&k8s_openapi::apimachinery::pkg::apis::
 
 // Deployment operations
 
-pub async fn list_deployments(client: &Client, namespace: Option<&str>) -> Result<Vec<DeploymentInfo>> {
+fn deployment_info(d: &Deployment) -> DeploymentInfo {
+    let spec = d.spec.as_ref();
+    let status = d.status.as_ref();
+    let meta = &d.metadata;
+
+    let containers = spec.and_then(|s| s.template.spec.as_ref()).map(|ps| ps.containers.as_slice()).unwrap_or_default();
+    let images: Vec<String> = containers.iter().map(|c| c.image.clone().unwrap_or_default()).collect();
+
+    DeploymentInfo {
+        name: meta.name.clone().unwrap_or_default(),
+        namespace: meta.namespace.clone().unwrap_or_default(),
+        replicas: spec.and_then(|s| s.replicas).unwrap_or(0),
+        available: status.and_then(|s| s.available_replicas).unwrap_or(0),
+        ready: status.and_then(|s| s.ready_replicas).unwrap_or(0),
+        updated: status.and_then(|s| s.updated_replicas).unwrap_or(0),
+        paused: spec.and_then(|s| s.paused).unwrap_or(false),
+        age: format_age(meta.creation_timestamp.as_ref()),
+        created: meta.creation_timestamp.as_ref().map(|t| t.0),
+        images,
+        containers: containers.iter().map(|c| c.name.clone()).collect(),
+        labels: meta.labels.clone().unwrap_or_default(),
+        annotations: meta.annotations.clone().unwrap_or_default(),
+    }
+}
+
+pub async fn list_deployments(client: &Client, namespace: Option<&str>, selectors: &Selectors) -> Result<Vec<DeploymentInfo>> {
     let deployments: Api<Deployment> = match namespace {
         Some(ns) => Api::namespaced(client.clone(), ns),
         None => Api::all(client.clone()),
     };
 
     let list = deployments
-        .list(&ListParams::default())
+        .list(&selectors.list_params())
         .await
         .context("Failed to list deployments")?;
 
-    Ok(list
-        .items
-        .into_iter()
-        .map(|d| {
-            let spec = d.spec.as_ref();
-            let status = d.status.as_ref();
-            let meta = &d.metadata;
-
-            let images: Vec<String> = spec
-                .and_then(|s| s.template.spec.as_ref())
-                .map(|ps| ps.containers.iter().map(|c| c.image.clone().unwrap_or_default()).collect())
-                .unwrap_or_default();
-
-            DeploymentInfo {
-                name: meta.name.clone().unwrap_or_default(),
-                namespace: meta.namespace.clone().unwrap_or_default(),
-                replicas: spec.and_then(|s| s.replicas).unwrap_or(0),
-                available: status.and_then(|s| s.available_replicas).unwrap_or(0),
-                ready: status.and_then(|s| s.ready_replicas).unwrap_or(0),
-                updated: status.and_then(|s| s.updated_replicas).unwrap_or(0),
-                age: format_age(meta.creation_timestamp.as_ref()),
-                images,
-                labels: meta.labels.clone().unwrap_or_default(),
-            }
-        })
-        .collect())
+    Ok(list.items.iter().map(deployment_info).collect())
+}
+
+pub async fn watch_deployments(client: &Client, namespace: Option<&str>, selectors: &Selectors, on_update: impl FnMut(Result<Vec<DeploymentInfo>>)) {
+    let deployments: Api<Deployment> = match namespace {
+        Some(ns) => Api::namespaced(client.clone(), ns),
+        None => Api::all(client.clone()),
+    };
+
+    watch_resources(deployments, selectors.watcher_config(), "deployments", deployment_info, on_update).await;
 }
 
 pub async fn scale_deployment(client: &Client, namespace: &str, name: &str, replicas: i32) -> Result<()> {
@@ -221,6 +301,24 @@ pub async fn restart_deployment(client: &Client, namespace: &str, name: &str) ->
     Ok(())
 }
 
+/// Pauses or resumes rollouts; template changes made while paused roll out on resume.
+pub async fn pause_deployment(client: &Client, namespace: &str, name: &str, paused: bool) -> Result<()> {
+    let deployments: Api<Deployment> = Api::namespaced(client.clone(), namespace);
+
+    let patch = serde_json::json!({
+        "spec": {
+            "paused": paused
+        }
+    });
+
+    deployments
+        .patch(name, &PatchParams::default(), &Patch::Merge(&patch))
+        .await
+        .context(if paused { "Failed to pause deployment" } else { "Failed to resume deployment" })?;
+
+    Ok(())
+}
+
 pub async fn delete_deployment(client: &Client, namespace: &str, name: &str) -> Result<()> {
     let deployments: Api<Deployment> = Api::namespaced(client.clone(), namespace);
     deployments
@@ -232,81 +330,114 @@ pub async fn delete_deployment(client: &Client, namespace: &str, name: &str) ->
 
 // Pod operations
 
-pub async fn list_pods(client: &Client, namespace: Option<&str>) -> Result<Vec<PodInfo>> {
+pub(super) fn pod_info(p: &Pod) -> PodInfo {
+    let meta = &p.metadata;
+    let spec = p.spec.as_ref();
+    let status = p.status.as_ref();
+
+    let containers: Vec<ContainerInfo> = spec
+        .map(|s| {
+            s.containers
+                .iter()
+                .map(|c| {
+                    let container_status = status
+                        .and_then(|st| st.container_statuses.as_ref())
+                        .and_then(|cs| cs.iter().find(|cs| cs.name == c.name));
+
+                    let state = container_status
+                        .and_then(|cs| cs.state.as_ref())
+                        .map(|s| {
+                            if s.running.is_some() {
+                                "Running".to_string()
+                            } else if let Some(w) = &s.waiting {
+                                w.reason.clone().unwrap_or_else(|| "Waiting".to_string())
+                            } else if let Some(t) = &s.terminated {
+                                t.reason.clone().unwrap_or_else(|| "Terminated".to_string())
+                            } else {
+                                "Unknown".to_string()
+                            }
+                        })
+                        .unwrap_or_else(|| "Unknown".to_string());
+
+                    ContainerInfo {
+                        name: c.name.clone(),
+                        image: c.image.clone().unwrap_or_default(),
+                        ready: container_status.map(|cs| cs.ready).unwrap_or(false),
+                        restarts: container_status.map(|cs| cs.restart_count).unwrap_or(0),
+                        state,
+                    }
+                })
+                .collect()
+        })
+        .unwrap_or_default();
+
+    let resources: Vec<_> = spec
+        .map(|s| s.containers.iter().map(|c| c.resources.as_ref()).collect())
+        .unwrap_or_default();
+    let quantity = |map: Option<&BTreeMap<String, Quantity>>, key: &str, parse: fn(&str) -> Option<u64>| {
+        map.and_then(|m| m.get(key)).and_then(|q| parse(&q.0))
+    };
+    let total_request = |key: &str, parse: fn(&str) -> Option<u64>| -> u64 {
+        resources
+            .iter()
+            .filter_map(|r| quantity(r.and_then(|r| r.requests.as_ref()), key, parse))
+            .sum()
+    };
+    let total_limit = |key: &str, parse: fn(&str) -> Option<u64>| -> Option<u64> {
+        resources
+            .iter()
+            .map(|r| quantity(r.and_then(|r| r.limits.as_ref()), key, parse))
+            .sum()
+    };
+
+    let total_restarts: i32 = containers.iter().map(|c| c.restarts).sum();
+    let ready_containers = containers.iter().filter(|c| c.ready).count();
+
+    let pod_status = status
+        .and_then(|s| s.phase.clone())
+        .unwrap_or_else(|| "Unknown".to_string());
+
+    PodInfo {
+        name: meta.name.clone().unwrap_or_default(),
+        namespace: meta.namespace.clone().unwrap_or_default(),
+        status: pod_status,
+        ready: format!("{}/{}", ready_containers, containers.len()),
+        restarts: total_restarts,
+        age: format_age(meta.creation_timestamp.as_ref()),
+        created: meta.creation_timestamp.as_ref().map(|t| t.0),
+        node: spec.and_then(|s| s.node_name.clone()).unwrap_or_default(),
+        ip: status.and_then(|s| s.pod_ip.clone()).unwrap_or_default(),
+        containers,
+        cpu_request: total_request("cpu", parse_cpu_quantity),
+        cpu_limit: total_limit("cpu", parse_cpu_quantity),
+        memory_request: total_request("memory", parse_memory_quantity),
+        memory_limit: total_limit("memory", parse_memory_quantity),
+        labels: meta.labels.clone().unwrap_or_default(),
+        annotations: meta.annotations.clone().unwrap_or_default(),
+    }
+}
+
+pub async fn list_pods(client: &Client, namespace: Option<&str>, selectors: &Selectors) -> Result<Vec<PodInfo>> {
     let pods: Api<Pod> = match namespace {
         Some(ns) => Api::namespaced(client.clone(), ns),
         None => Api::all(client.clone()),
     };
 
     let list = pods
-        .list(&ListParams::default())
+        .list(&selectors.list_params())
         .await
         .context("Failed to list pods")?;
 
-    Ok(list
-        .items
-        .into_iter()
-        .map(|p| {
-            let meta = &p.metadata;
-            let spec = p.spec.as_ref();
-            let status = p.status.as_ref();
-
-            let containers: Vec<ContainerInfo> = spec
-                .map(|s| {
-                    s.containers
-                        .iter()
-                        .map(|c| {
-                            let container_status = status
-                                .and_then(|st| st.container_statuses.as_ref())
-                                .and_then(|cs| cs.iter().find(|cs| cs.name == c.name));
-
-                            let state = container_status
-                                .and_then(|cs| cs.state.as_ref())
-                                .map(|s| {
-                                    if s.running.is_some() {
-                                        "Running".to_string()
-                                    } else if let Some(w) = &s.waiting {
-                                        w.reason.clone().unwrap_or_else(|| "Waiting".to_string())
-                                    } else if let Some(t) = &s.terminated {
-                                        t.reason.clone().unwrap_or_else(|| "Terminated".to_string())
-                                    } else {
-                                        "Unknown".to_string()
-                                    }
-                                })
-                                .unwrap_or_else(|| "Unknown".to_string());
-
-                            ContainerInfo {
-                                name: c.name.clone(),
-                                image: c.image.clone().unwrap_or_default(),
-                                ready: container_status.map(|cs| cs.ready).unwrap_or(false),
-                                restarts: container_status.map(|cs| cs.restart_count).unwrap_or(0),
-                                state,
-                            }
-                        })
-                        .collect()
-                })
-                .unwrap_or_default();
-
-            let total_restarts: i32 = containers.iter().map(|c| c.restarts).sum();
-            let ready_containers = containers.iter().filter(|c| c.ready).count();
-
-            let pod_status = status
-                .and_then(|s| s.phase.clone())
-                .unwrap_or_else(|| "Unknown".to_string());
-
-            PodInfo {
-                name: meta.name.clone().unwrap_or_default(),
-                namespace: meta.namespace.clone().unwrap_or_default(),
-                status: pod_status,
-                ready: format!("{}/{}", ready_containers, containers.len()),
-                restarts: total_restarts,
-                age: format_age(meta.creation_timestamp.as_ref()),
-                node: spec.and_then(|s| s.node_name.clone()).unwrap_or_default(),
-                ip: status.and_then(|s| s.pod_ip.clone()).unwrap_or_default(),
-                containers,
-            }
-        })
-        .collect())
+    Ok(list.items.iter().map(pod_info).collect())
+}
+
+pub async fn watch_pods(client: &Client, namespace: Option<&str>, selectors: &Selectors, on_update: impl FnMut(Result<Vec<PodInfo>>)) {
+    let pods: Api<Pod> = match namespace {
+        Some(ns) => Api::namespaced(client.clone(), ns),
+        None => Api::all(client.clone()),
+    };
+
+    watch_resources(pods, selectors.watcher_config(), "pods", pod_info, on_update).await;
 }
 
 pub async fn get_pod_logs(client: &Client, namespace: &str, name: &str, container: Option<&str>, tail_lines: Option<i64>) -> Result<String> {
@@ -330,6 +461,41 @@ pub async fn get_pod_logs(client: &Client, namespace: &str, name: &str, containe
     Ok(logs)
 }
 
+/// Streams log lines for a pod container until the container exits or the task is
+/// aborted, starting from the last `tail_lines` lines.
+pub async fn follow_pod_logs(
+    client: &Client,
+    namespace: &str,
+    name: &str,
+    container: Option<&str>,
+    tail_lines: Option<i64>,
+    mut on_line: impl FnMut(String),
+) -> Result<()> {
+    use futures::{AsyncBufReadExt, TryStreamExt};
+    use kube::api::LogParams;
+
+    let pods: Api<Pod> = Api::namespaced(client.clone(), namespace);
+
+    let params = LogParams {
+        container: container.map(|c| c.to_string()),
+        tail_lines,
+        follow: true,
+        ..Default::default()
+    };
+
+    let mut lines = pods
+        .log_stream(name, &params)
+        .await
+        .context("Failed to stream pod logs")?
+        .lines();
+
+    while let Some(line) = lines.try_next().await.context("Pod log stream interrupted")? {
+        on_line(line);
+    }
+
+    Ok(())
+}
+
 pub async fn delete_pod(client: &Client, namespace: &str, name: &str) -> Result<()> {
     let pods: Api<Pod> = Api::namespaced(client.clone(), namespace);
     pods.delete(name, &DeleteParams::default())
@@ -340,149 +506,179 @@ pub async fn delete_pod(client: &Client, namespace: &str, name: &str) -> Result<
 
 // Service operations
 
-pub async fn list_services(client: &Client, namespace: Option<&str>) -> Result<Vec<ServiceInfo>> {
+fn service_info(s: &Service) -> ServiceInfo {
+    let meta = &s.metadata;
+    let spec = s.spec.as_ref();
+
+    let ports: Vec<String> = spec
+        .and_then(|s| s.ports.as_ref())
+        .map(|ports| {
+            ports
+                .iter()
+                .map(|p| {
+                    let port_str = if let Some(np) = p.node_port {
+                        format!("{}:{}/{}", p.port, np, p.protocol.clone().unwrap_or_else(|| "TCP".to_string()))
+                    } else {
+                        format!("{}/{}", p.port, p.protocol.clone().unwrap_or_else(|| "TCP".to_string()))
+                    };
+                    port_str
+                })
+                .collect()
+        })
+        .unwrap_or_default();
+
+    let external_ips: String = spec
+        .and_then(|s| s.external_ips.as_ref())
+        .map(|ips| ips.join(", "))
+        .or_else(|| {
+            s.status
+                .as_ref()
+                .and_then(|st| st.load_balancer.as_ref())
+                .and_then(|lb| lb.ingress.as_ref())
+                .map(|ingress| {
+                    ingress
+                        .iter()
+                        .filter_map(|i| i.ip.clone().or_else(|| i.hostname.clone()))
+                        .collect::<Vec<_>>()
+                        .join(", ")
+                })
+        })
+        .unwrap_or_else(|| "<none>".to_string());
+
+    ServiceInfo {
+        name: meta.name.clone().unwrap_or_default(),
+        namespace: meta.namespace.clone().unwrap_or_default(),
+        service_type: spec.and_then(|s| s.type_.clone()).unwrap_or_else(|| "ClusterIP".to_string()),
+        cluster_ip: spec.and_then(|s| s.cluster_ip.clone()).unwrap_or_default(),
+        external_ip: external_ips,
+        ports,
+        age: format_age(meta.creation_timestamp.as_ref()),
+        created: meta.creation_timestamp.as_ref().map(|t| t.0),
+        selector: spec.and_then(|s| s.selector.clone()).unwrap_or_default(),
+        labels: meta.labels.clone().unwrap_or_default(),
+        annotations: meta.annotations.clone().unwrap_or_default(),
+    }
+}
+
+pub async fn list_services(client: &Client, namespace: Option<&str>, selectors: &Selectors) -> Result<Vec<ServiceInfo>> {
     let services: Api<Service> = match namespace {
         Some(ns) => Api::namespaced(client.clone(), ns),
         None => Api::all(client.clone()),
     };
 
     let list = services
-        .list(&ListParams::default())
+        .list(&selectors.list_params())
         .await
         .context("Failed to list services")?;
 
-    Ok(list
-        .items
-        .into_iter()
-        .map(|s| {
-            let meta = &s.metadata;
-            let spec = s.spec.as_ref();
+    Ok(list.items.iter().map(service_info).collect())
+}
 
-            let ports: Vec<String> = spec
-                .and_then(|s| s.ports.as_ref())
-                .map(|ports| {
-                    ports
-                        .iter()
-                        .map(|p| {
-                            let port_str = if let Some(np) = p.node_port {
-                                format!("{}:{}/{}", p.port, np, p.protocol.clone().unwrap_or_else(|| "TCP".to_string()))
-                            } else {
-                                format!("{}/{}", p.port, p.protocol.clone().unwrap_or_else(|| "TCP".to_string()))
-                            };
-                            port_str
-                        })
-                        .collect()
-                })
-                .unwrap_or_default();
-
-            let external_ips: String = spec
-                .and_then(|s| s.external_ips.as_ref())
-                .map(|ips| ips.join(", "))
-                .or_else(|| {
-                    s.status
-                        .as_ref()
-                        .and_then(|st| st.load_balancer.as_ref())
-                        .and_then(|lb| lb.ingress.as_ref())
-                        .map(|ingress| {
-                            ingress
-                                .iter()
-                                .filter_map(|i| i.ip.clone().or_else(|| i.hostname.clone()))
-                                .collect::<Vec<_>>()
-                                .join(", ")
-                        })
-                })
-                .unwrap_or_else(|| "<none>".to_string());
-
-            ServiceInfo {
-                name: meta.name.clone().unwrap_or_default(),
-                namespace: meta.namespace.clone().unwrap_or_default(),
-                service_type: spec.and_then(|s| s.type_.clone()).unwrap_or_else(|| "ClusterIP".to_string()),
-                cluster_ip: spec.and_then(|s| s.cluster_ip.clone()).unwrap_or_default(),
-                external_ip: external_ips,
-                ports,
-                age: format_age(meta.creation_timestamp.as_ref()),
-                selector: spec.and_then(|s| s.selector.clone()).unwrap_or_default(),
-            }
-        })
-        .collect())
+pub async fn watch_services(client: &Client, namespace: Option<&str>, selectors: &Selectors, on_update: impl FnMut(Result<Vec<ServiceInfo>>)) {
+    let services: Api<Service> = match namespace {
+        Some(ns) => Api::namespaced(client.clone(), ns),
+        None => Api::all(client.clone()),
+    };
+
+    watch_resources(services, selectors.watcher_config(), "services", service_info, on_update).await;
 }
 
 // Ingress operations
 
-pub async fn list_ingresses(client: &Client, namespace: Option<&str>) -> Result<Vec<IngressInfo>> {
+fn ingress_info(i: &Ingress) -> IngressInfo {
+    let meta = &i.metadata;
+    let spec = i.spec.as_ref();
+
+    let mut hosts = Vec::new();
+    let mut paths = Vec::new();
+
+    if let Some(rules) = spec.and_then(|s| s.rules.as_ref()) {
+        for rule in rules {
+            if let Some(host) = &rule.host {
+                hosts.push(host.clone());
+            }
+            if let Some(http) = &rule.http {
+                for path in &http.paths {
+                    paths.push(path.path.clone().unwrap_or_else(|| "/".to_string()));
+                }
+            }
+        }
+    }
+
+    IngressInfo {
+        name: meta.name.clone().unwrap_or_default(),
+        namespace: meta.namespace.clone().unwrap_or_default(),
+        hosts,
+        paths,
+        age: format_age(meta.creation_timestamp.as_ref()),
+        created: meta.creation_timestamp.as_ref().map(|t| t.0),
+        labels: meta.labels.clone().unwrap_or_default(),
+        annotations: meta.annotations.clone().unwrap_or_default(),
+    }
+}
+
+pub async fn list_ingresses(client: &Client, namespace: Option<&str>, selectors: &Selectors) -> Result<Vec<IngressInfo>> {
     let ingresses: Api<Ingress> = match namespace {
         Some(ns) => Api::namespaced(client.clone(), ns),
         None => Api::all(client.clone()),
     };
 
     let list = ingresses
-        .list(&ListParams::default())
+        .list(&selectors.list_params())
         .await
         .context("Failed to list ingresses")?;
 
-    Ok(list
-        .items
-        .into_iter()
-        .map(|i| {
-            let meta = &i.metadata;
-            let spec = i.spec.as_ref();
-
-            let mut hosts = Vec::new();
-            let mut paths = Vec::new();
+    Ok(list.items.iter().map(ingress_info).collect())
+}
 
-            if let Some(rules) = spec.and_then(|s| s.rules.as_ref()) {
-                for rule in rules {
-                    if let Some(host) = &rule.host {
-                        hosts.push(host.clone());
-                    }
-                    if let Some(http) = &rule.http {
-                        for path in &http.paths {
-                            paths.push(path.path.clone().unwrap_or_else(|| "/".to_string()));
-                        }
-                    }
-                }
-            }
+pub async fn watch_ingresses(client: &Client, namespace: Option<&str>, selectors: &Selectors, on_update: impl FnMut(Result<Vec<IngressInfo>>)) {
+    let ingresses: Api<Ingress> = match namespace {
+        Some(ns) => Api::namespaced(client.clone(), ns),
+        None => Api::all(client.clone()),
+    };
 
-            IngressInfo {
-                name: meta.name.clone().unwrap_or_default(),
-                namespace: meta.namespace.clone().unwrap_or_default(),
-                hosts,
-                paths,
-                age: format_age(meta.creation_timestamp.as_ref()),
-            }
-        })
-        .collect())
+    watch_resources(ingresses, selectors.watcher_config(), "ingresses", ingress_info, on_update).await;
 }
 
 // ConfigMap operations
 
-pub async fn list_configmaps(client: &Client, namespace: Option<&str>) -> Result<Vec<ConfigMapInfo>> {
+fn configmap_info(cm: &ConfigMap) -> ConfigMapInfo {
+    let meta = &cm.metadata;
+    let data = cm.data.clone().unwrap_or_default();
+
+    ConfigMapInfo {
+        name: meta.name.clone().unwrap_or_default(),
+        namespace: meta.namespace.clone().unwrap_or_default(),
+        data_count: data.len(),
+        age: format_age(meta.creation_timestamp.as_ref()),
+        created: meta.creation_timestamp.as_ref().map(|t| t.0),
+        data,
+        labels: meta.labels.clone().unwrap_or_default(),
+        annotations: meta.annotations.clone().unwrap_or_default(),
+    }
+}
+
+pub async fn list_configmaps(client: &Client, namespace: Option<&str>, selectors: &Selectors) -> Result<Vec<ConfigMapInfo>> {
     let configmaps: Api<ConfigMap> = match namespace {
         Some(ns) => Api::namespaced(client.clone(), ns),
         None => Api::all(client.clone()),
     };
 
     let list = configmaps
-        .list(&ListParams::default())
+        .list(&selectors.list_params())
         .await
         .context("Failed to list configmaps")?;
 
-    Ok(list
-        .items
-        .into_iter()
-        .map(|cm| {
-            let meta = &cm.metadata;
-            let data = cm.data.clone().unwrap_or_default();
-
-            ConfigMapInfo {
-                name: meta.name.clone().unwrap_or_default(),
-                namespace: meta.namespace.clone().unwrap_or_default(),
-                data_count: data.len(),
-                age: format_age(meta.creation_timestamp.as_ref()),
-                data,
-            }
-        })
-        .collect())
+    Ok(list.items.iter().map(configmap_info).collect())
+}
+
+pub async fn watch_configmaps(client: &Client, namespace: Option<&str>, selectors: &Selectors, on_update: impl FnMut(Result<Vec<ConfigMapInfo>>)) {
+    let configmaps: Api<ConfigMap> = match namespace {
+        Some(ns) => Api::namespaced(client.clone(), ns),
+        None => Api::all(client.clone()),
+    };
+
+    watch_resources(configmaps, selectors.watcher_config(), "configmaps", configmap_info, on_update).await;
 }
 
 pub async fn update_configmap(client: &Client, namespace: &str, name: &str, data: std::collections::BTreeMap<String, String>) -> Result<()> {
@@ -502,99 +698,173 @@ pub async fn update_configmap(client: &Client, namespace: &str, name: &str, data
 
 // Secret operations
 
-pub async fn list_secrets(client: &Client, namespace: Option<&str>) -> Result<Vec<SecretInfo>> {
+fn secret_info(s: &Secret) -> SecretInfo {
+    let meta = &s.metadata;
+    let data_keys: Vec<String> = s.data.as_ref()
+        .map(|d| d.keys().cloned().collect())
+        .unwrap_or_default();
+
+    SecretInfo {
+        name: meta.name.clone().unwrap_or_default(),
+        namespace: meta.namespace.clone().unwrap_or_default(),
+        secret_type: s.type_.clone().unwrap_or_else(|| "Opaque".to_string()),
+        data_count: data_keys.len(),
+        age: format_age(meta.creation_timestamp.as_ref()),
+        created: meta.creation_timestamp.as_ref().map(|t| t.0),
+        data_keys,
+        labels: meta.labels.clone().unwrap_or_default(),
+        annotations: meta.annotations.clone().unwrap_or_default(),
+    }
+}
+
+pub async fn list_secrets(client: &Client, namespace: Option<&str>, selectors: &Selectors) -> Result<Vec<SecretInfo>> {
     let secrets: Api<Secret> = match namespace {
         Some(ns) => Api::namespaced(client.clone(), ns),
         None => Api::all(client.clone()),
     };
 
     let list = secrets
-        .list(&ListParams::default())
+        .list(&selectors.list_params())
         .await
         .context("Failed to list secrets")?;
 
-    Ok(list
-        .items
-        .into_iter()
-        .map(|s| {
-            let meta = &s.metadata;
-            let data_keys: Vec<String> = s.data.as_ref()
-                .map(|d| d.keys().cloned().collect())
-                .unwrap_or_default();
-
-            SecretInfo {
-                name: meta.name.clone().unwrap_or_default(),
-                namespace: meta.namespace.clone().unwrap_or_default(),
-                secret_type: s.type_.clone().unwrap_or_else(|| "Opaque".to_string()),
-                data_count: data_keys.len(),
-                age: format_age(meta.creation_timestamp.as_ref()),
-                data_keys,
-            }
-        })
-        .collect())
+    Ok(list.items.iter().map(secret_info).collect())
+}
+
+pub async fn watch_secrets(client: &Client, namespace: Option<&str>, selectors: &Selectors, on_update: impl FnMut(Result<Vec<SecretInfo>>)) {
+    let secrets: Api<Secret> = match namespace {
+        Some(ns) => Api::namespaced(client.clone(), ns),
+        None => Api::all(client.clone()),
+    };
+
+    watch_resources(secrets, selectors.watcher_config(), "secrets", secret_info, on_update).await;
+}
+
+fn secret_text(key: &str, value: &ByteString) -> Result<String> {
+    String::from_utf8(value.0.clone())
+        .map_err(|_| anyhow::anyhow!("Value of '{}' is binary ({} bytes)", key, value.0.len()))
+}
+
+/// Fetches and decodes a single value. Callers must not keep it around longer than
+/// it is on screen.
+pub async fn get_secret_value(client: &Client, namespace: &str, name: &str, key: &str) -> Result<String> {
+    let secrets: Api<Secret> = Api::namespaced(client.clone(), namespace);
+    let secret = secrets.get(name).await.context("Failed to get secret")?;
+
+    let value = secret
+        .data
+        .as_ref()
+        .and_then(|d| d.get(key))
+        .with_context(|| format!("Secret has no key '{}'", key))?;
+    secret_text(key, value)
+}
+
+/// Fetches every value decoded as text, for editing. Fails on binary values, which
+/// cannot round-trip through a text editor.
+pub async fn get_secret_data(client: &Client, namespace: &str, name: &str) -> Result<SecretData> {
+    let secrets: Api<Secret> = Api::namespaced(client.clone(), namespace);
+    let secret = secrets.get(name).await.context("Failed to get secret")?;
+
+    let data = secret
+        .data
+        .unwrap_or_default()
+        .iter()
+        .map(|(k, v)| Ok((k.clone(), secret_text(k, v)?)))
+        .collect::<Result<_>>()?;
+    Ok(SecretData { data, resource_version: secret.metadata.resource_version })
+}
+
+/// Replaces the secret's data with `data.data`, base64-encoding each value. Keys
+/// missing from it are removed; the write fails if the secret changed since
+/// `data.resource_version` was read.
+pub async fn update_secret(client: &Client, namespace: &str, name: &str, data: SecretData) -> Result<()> {
+    let secrets: Api<Secret> = Api::namespaced(client.clone(), namespace);
+    let mut secret = secrets.get(name).await.context("Failed to get secret")?;
+
+    secret.metadata.resource_version = data.resource_version;
+    secret.data = Some(data.data.into_iter().map(|(k, v)| (k, ByteString(v.into_bytes()))).collect());
+    secret.string_data = None;
+
+    match secrets.replace(name, &PostParams::default(), &secret).await {
+        Ok(_) => Ok(()),
+        Err(kube::Error::Api(resp)) if resp.code == 409 => {
+            anyhow::bail!("Secret '{}' changed since it was opened for editing; reopen it and try again", name)
+        }
+        Err(e) => Err(e).context("Failed to update secret"),
+    }
 }
 
 // Job operations
 
-pub async fn list_jobs(client: &Client, namespace: Option<&str>) -> Result<Vec<JobInfo>> {
+pub(super) fn job_info(j: &Job) -> JobInfo {
+    let meta = &j.metadata;
+    let spec = j.spec.as_ref();
+    let status = j.status.as_ref();
+
+    let completions = format!(
+        "{}/{}",
+        status.and_then(|s| s.succeeded).unwrap_or(0),
+        spec.and_then(|s| s.completions).unwrap_or(1)
+    );
+
+    let job_status = if status.and_then(|s| s.succeeded).unwrap_or(0) > 0 {
+        JobStatus::Succeeded
+    } else if status.and_then(|s| s.failed).unwrap_or(0) > 0 {
+        JobStatus::Failed
+    } else if status.and_then(|s| s.active).unwrap_or(0) > 0 {
+        JobStatus::Running
+    } else {
+        JobStatus::Pending
+    };
+
+    let duration = status
+        .and_then(|s| {
+            let start = s.start_time.as_ref()?;
+            let end = s.completion_time.as_ref().map(|t| t.0).unwrap_or_else(Utc::now);
+            let dur = end.signed_duration_since(start.0);
+            Some(format!("{}s", dur.num_seconds()))
+        })
+        .unwrap_or_else(|| "-".to_string());
+
+    let owner = meta.owner_references.as_ref()
+        .and_then(|owners| owners.first())
+        .map(|o| o.name.clone());
+
+    JobInfo {
+        name: meta.name.clone().unwrap_or_default(),
+        namespace: meta.namespace.clone().unwrap_or_default(),
+        completions,
+        duration,
+        age: format_age(meta.creation_timestamp.as_ref()),
+        created: meta.creation_timestamp.as_ref().map(|t| t.0),
+        status: job_status,
+        owner,
+        labels: meta.labels.clone().unwrap_or_default(),
+        annotations: meta.annotations.clone().unwrap_or_default(),
+    }
+}
+
+pub async fn list_jobs(client: &Client, namespace: Option<&str>, selectors: &Selectors) -> Result<Vec<JobInfo>> {
     let jobs: Api<Job> = match namespace {
         Some(ns) => Api::namespaced(client.clone(), ns),
         None => Api::all(client.clone()),
     };
 
     let list = jobs
-        .list(&ListParams::default())
+        .list(&selectors.list_params())
         .await
         .context("Failed to list jobs")?;
 
-    Ok(list
-        .items
-        .into_iter()
-        .map(|j| {
-            let meta = &j.metadata;
-            let spec = j.spec.as_ref();
-            let status = j.status.as_ref();
-
-            let completions = format!(
-                "{}/{}",
-                status.and_then(|s| s.succeeded).unwrap_or(0),
-                spec.and_then(|s| s.completions).unwrap_or(1)
-            );
-
-            let job_status = if status.and_then(|s| s.succeeded).unwrap_or(0) > 0 {
-                JobStatus::Succeeded
-            } else if status.and_then(|s| s.failed).unwrap_or(0) > 0 {
-                JobStatus::Failed
-            } else if status.and_then(|s| s.active).unwrap_or(0) > 0 {
-                JobStatus::Running
-            } else {
-                JobStatus::Pending
-            };
-
-            let duration = status
-                .and_then(|s| {
-                    let start = s.start_time.as_ref()?;
-                    let end = s.completion_time.as_ref().map(|t| t.0).unwrap_or_else(Utc::now);
-                    let dur = end.signed_duration_since(start.0);
-                    Some(format!("{}s", dur.num_seconds()))
-                })
-                .unwrap_or_else(|| "-".to_string());
-
-            let owner = meta.owner_references.as_ref()
-                .and_then(|owners| owners.first())
-                .map(|o| o.name.clone());
-
-            JobInfo {
-                name: meta.name.clone().unwrap_or_default(),
-                namespace: meta.namespace.clone().unwrap_or_default(),
-                completions,
-                duration,
-                age: format_age(meta.creation_timestamp.as_ref()),
-                status: job_status,
-                owner,
-            }
-        })
-        .collect())
+    Ok(list.items.iter().map(job_info).collect())
+}
+
+pub async fn watch_jobs(client: &Client, namespace: Option<&str>, selectors: &Selectors, on_update: impl FnMut(Result<Vec<JobInfo>>)) {
+    let jobs: Api<Job> = match namespace {
+        Some(ns) => Api::namespaced(client.clone(), ns),
+        None => Api::all(client.clone()),
+    };
+
+    watch_resources(jobs, selectors.watcher_config(), "jobs", job_info, on_update).await;
 }
 
 pub async fn delete_job(client: &Client, namespace: &str, name: &str) -> Result<()> {
@@ -607,40 +877,50 @@ pub async fn delete_job(client: &Client, namespace: &str, name: &str) -> Result<
 
 // CronJob operations
 
-pub async fn list_cronjobs(client: &Client, namespace: Option<&str>) -> Result<Vec<CronJobInfo>> {
+fn cronjob_info(cj: &CronJob) -> CronJobInfo {
+    let meta = &cj.metadata;
+    let spec = cj.spec.as_ref();
+    let status = cj.status.as_ref();
+
+    let last_schedule = status
+        .and_then(|s| s.last_schedule_time.as_ref())
+        .map(|t| format_age(Some(t)));
+
+    CronJobInfo {
+        name: meta.name.clone().unwrap_or_default(),
+        namespace: meta.namespace.clone().unwrap_or_default(),
+        schedule: spec.map(|s| s.schedule.clone()).unwrap_or_default(),
+        suspend: spec.and_then(|s| s.suspend).unwrap_or(false),
+        active: status.and_then(|s| s.active.as_ref()).map(|a| a.len() as i32).unwrap_or(0),
+        last_schedule,
+        age: format_age(meta.creation_timestamp.as_ref()),
+        created: meta.creation_timestamp.as_ref().map(|t| t.0),
+        labels: meta.labels.clone().unwrap_or_default(),
+        annotations: meta.annotations.clone().unwrap_or_default(),
+    }
+}
+
+pub async fn list_cronjobs(client: &Client, namespace: Option<&str>, selectors: &Selectors) -> Result<Vec<CronJobInfo>> {
     let cronjobs: Api<CronJob> = match namespace {
         Some(ns) => Api::namespaced(client.clone(), ns),
         None => Api::all(client.clone()),
     };
 
     let list = cronjobs
-        .list(&ListParams::default())
+        .list(&selectors.list_params())
         .await
         .context("Failed to list cronjobs")?;
 
-    Ok(list
-        .items
-        .into_iter()
-        .map(|cj| {
-            let meta = &cj.metadata;
-            let spec = cj.spec.as_ref();
-            let status = cj.status.as_ref();
-
-            let last_schedule = status
-                .and_then(|s| s.last_schedule_time.as_ref())
-                .map(|t| format_age(Some(t)));
-
-            CronJobInfo {
-                name: meta.name.clone().unwrap_or_default(),
-                namespace: meta.namespace.clone().unwrap_or_default(),
-                schedule: spec.map(|s| s.schedule.clone()).unwrap_or_default(),
-                suspend: spec.and_then(|s| s.suspend).unwrap_or(false),
-                active: status.and_then(|s| s.active.as_ref()).map(|a| a.len() as i32).unwrap_or(0),
-                last_schedule,
-                age: format_age(meta.creation_timestamp.as_ref()),
-            }
-        })
-        .collect())
+    Ok(list.items.iter().map(cronjob_info).collect())
+}
+
+pub async fn watch_cronjobs(client: &Client, namespace: Option<&str>, selectors: &Selectors, on_update: impl FnMut(Result<Vec<CronJobInfo>>)) {
+    let cronjobs: Api<CronJob> = match namespace {
+        Some(ns) => Api::namespaced(client.clone(), ns),
+        None => Api::all(client.clone()),
+    };
+
+    watch_resources(cronjobs, selectors.watcher_config(), "cronjobs", cronjob_info, on_update).await;
 }
 
 pub async fn trigger_cronjob(client: &Client, namespace: &str, cronjob_name: &str) -> Result<String> {
@@ -705,10 +985,436 @@ pub async fn suspend_cronjob(client: &Client, namespace: &str, name: &str, suspe
 }
 
 pub async fn get_cronjob_history(client: &Client, namespace: &str, cronjob_name: &str) -> Result<Vec<JobInfo>> {
-    let jobs = list_jobs(client, Some(namespace)).await?;
+    let jobs = list_jobs(client, Some(namespace), &Selectors::default()).await?;
 
     Ok(jobs
         .into_iter()
         .filter(|j| j.owner.as_ref().map(|o| o == cronjob_name).unwrap_or(false))
         .collect())
 }
+
+// Event operations
+
+fn event_info(e: &Event) -> EventInfo {
+    let meta = &e.metadata;
+
+    let last_seen = e
+        .last_timestamp
+        .clone()
+        .or_else(|| e.event_time.as_ref().map(|t| Time(t.0)))
+        .or_else(|| e.first_timestamp.clone())
+        .or_else(|| meta.creation_timestamp.clone());
+
+    let source = e
+        .source
+        .as_ref()
+        .and_then(|s| s.component.clone())
+        .or_else(|| e.reporting_component.clone())
+        .unwrap_or_default();
+
+    EventInfo {
+        namespace: meta.namespace.clone().unwrap_or_default(),
+        event_type: e.type_.clone().unwrap_or_else(|| "Normal".to_string()),
+        reason: e.reason.clone().unwrap_or_default(),
+        involved_kind: e.involved_object.kind.clone().unwrap_or_default(),
+        involved_name: e.involved_object.name.clone().unwrap_or_default(),
+        message: e.message.clone().unwrap_or_default(),
+        count: e.count.unwrap_or(1),
+        source,
+        last_seen: format_age(last_seen.as_ref()),
+        last_timestamp: last_seen.map(|t| t.0),
+    }
+}
+
+fn newest_first(mut events: Vec<EventInfo>) -> Vec<EventInfo> {
+    events.sort_by_key(|e| std::cmp::Reverse(e.last_timestamp));
+    events
+}
+
+pub async fn list_events(client: &Client, namespace: Option<&str>, selectors: &Selectors) -> Result<Vec<EventInfo>> {
+    let events: Api<Event> = match namespace {
+        Some(ns) => Api::namespaced(client.clone(), ns),
+        None => Api::all(client.clone()),
+    };
+
+    let list = events
+        .list(&selectors.list_params())
+        .await
+        .context("Failed to list events")?;
+
+    Ok(newest_first(list.items.iter().map(event_info).collect()))
+}
+
+pub async fn watch_events(client: &Client, namespace: Option<&str>, selectors: &Selectors, mut on_update: impl FnMut(Result<Vec<EventInfo>>)) {
+    let events: Api<Event> = match namespace {
+        Some(ns) => Api::namespaced(client.clone(), ns),
+        None => Api::all(client.clone()),
+    };
+
+    watch_resources(events, selectors.watcher_config(), "events", event_info, |result| on_update(result.map(newest_first))).await;
+}
+
+/// Events whose `involvedObject` is the given object, newest first.
+pub async fn list_object_events(client: &Client, target: &ResourceRef) -> Result<Vec<EventInfo>> {
+    let events: Api<Event> = Api::namespaced(client.clone(), &target.namespace);
+
+    let selector = format!(
+        "involvedObject.kind={},involvedObject.name={}",
+        target.kind.as_str(),
+        target.name
+    );
+    let list = events
+        .list(&ListParams::default().fields(&selector))
+        .await
+        .context("Failed to list events")?;
+
+    Ok(newest_first(list.items.iter().map(event_info).collect()))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::k8s::fake::FakeApiServer;
+    use crate::k8s::ResourceKind;
+    use serde_json::json;
+
+    #[tokio::test]
+    async fn list_pods_summarises_containers_and_resources() {
+        let server = FakeApiServer::start(&["pods.yaml"]).await;
+
+        let pods = list_pods(&server.client(), Some("default"), &Selectors::default()).await.unwrap();
+
+        let names: Vec<_> = pods.iter().map(|p| p.name.as_str()).collect();
+        assert_eq!(names, ["web-7d4b9c-abcde", "worker-5f6d7-xyz12"]);
+
+        let web = &pods[0];
+        assert_eq!(web.namespace, "default");
+        assert_eq!(web.status, "Running");
+        assert_eq!(web.ready, "1/2");
+        assert_eq!(web.restarts, 3);
+        assert_eq!(web.node, "node-1");
+        assert_eq!(web.ip, "10.0.0.12");
+        assert_eq!(web.containers[1].state, "CrashLoopBackOff");
+        assert_eq!(web.cpu_request, 350);
+        assert_eq!(web.cpu_limit, None, "sidecar has no CPU limit");
+        assert_eq!(web.memory_request, 192 * 1024 * 1024);
+
+        let worker = &pods[1];
+        assert_eq!(worker.status, "Pending");
+        assert_eq!(worker.ready, "0/1");
+        assert_eq!(worker.containers[0].state, "Unknown");
+
+        let requests = server.requests();
+        assert_eq!(requests.len(), 1);
+        assert_eq!(requests[0].method, "GET");
+        assert_eq!(requests[0].path, "/api/v1/namespaces/default/pods");
+    }
+
+    #[tokio::test]
+    async fn list_pods_without_namespace_lists_cluster_wide() {
+        let server = FakeApiServer::start(&["pods.yaml"]).await;
+
+        let pods = list_pods(&server.client(), None, &Selectors::default()).await.unwrap();
+
+        assert_eq!(pods.len(), 3);
+        assert!(pods.iter().any(|p| p.namespace == "kube-system"));
+        assert_eq!(server.requests()[0].path, "/api/v1/pods");
+    }
+
+    #[tokio::test]
+    async fn list_pods_passes_selectors_to_the_server() {
+        let server = FakeApiServer::start(&["pods.yaml"]).await;
+        let selectors = Selectors::new("app=web", "spec.nodeName=node-1").unwrap();
+
+        let pods = list_pods(&server.client(), None, &selectors).await.unwrap();
+
+        let names: Vec<_> = pods.iter().map(|p| p.name.as_str()).collect();
+        assert_eq!(names, ["web-7d4b9c-abcde"]);
+        let query = &server.requests()[0].query;
+        assert_eq!(query.get("labelSelector").map(String::as_str), Some("app=web"));
+        assert_eq!(query.get("fieldSelector").map(String::as_str), Some("spec.nodeName=node-1"));
+    }
+
+    #[tokio::test]
+    async fn get_pod_logs_passes_container_and_tail() {
+        let server = FakeApiServer::start(&["pods.yaml"]).await;
+        server.add_log("default", "web-7d4b9c-abcde", "web-app.log");
+
+        let logs = get_pod_logs(&server.client(), "default", "web-7d4b9c-abcde", Some("app"), Some(2))
+            .await
+            .unwrap();
+
+        assert_eq!(logs, "2024-01-02T10:00:05Z GET /healthz 200\n2024-01-02T10:00:10Z GET / 200\n");
+        let request = &server.requests()[0];
+        assert_eq!(request.path, "/api/v1/namespaces/default/pods/web-7d4b9c-abcde/log");
+        assert_eq!(request.query.get("container").map(String::as_str), Some("app"));
+        assert_eq!(request.query.get("tailLines").map(String::as_str), Some("2"));
+    }
+
+    #[tokio::test]
+    async fn follow_pod_logs_yields_each_line() {
+        let server = FakeApiServer::start(&["pods.yaml"]).await;
+        server.add_log("default", "web-7d4b9c-abcde", "web-app.log");
+
+        let mut lines = Vec::new();
+        follow_pod_logs(&server.client(), "default", "web-7d4b9c-abcde", None, Some(10), |line| lines.push(line))
+            .await
+            .unwrap();
+
+        assert_eq!(lines.len(), 4);
+        assert_eq!(lines[0], "2024-01-02T10:00:00Z starting nginx");
+        assert_eq!(server.requests()[0].query.get("follow").map(String::as_str), Some("true"));
+    }
+
+    #[tokio::test]
+    async fn delete_pod_removes_it_and_reports_missing_pods() {
+        let server = FakeApiServer::start(&["pods.yaml"]).await;
+        let client = server.client();
+
+        delete_pod(&client, "default", "worker-5f6d7-xyz12").await.unwrap();
+
+        assert!(server.object("/api/v1/namespaces/default/pods/worker-5f6d7-xyz12").is_none());
+        assert_eq!(server.mutations()[0].method, "DELETE");
+
+        let err = delete_pod(&client, "default", "worker-5f6d7-xyz12").await.unwrap_err();
+        assert_eq!(err.to_string(), "Failed to delete pod");
+        assert!(format!("{:#}", err).contains("not found"));
+    }
+
+    #[tokio::test]
+    async fn list_deployments_reads_spec_and_status() {
+        let server = FakeApiServer::start(&["deployments.yaml"]).await;
+
+        let deployments = list_deployments(&server.client(), Some("default"), &Selectors::default()).await.unwrap();
+
+        assert_eq!(deployments.len(), 1);
+        let web = &deployments[0];
+        assert_eq!((web.replicas, web.ready, web.available, web.updated), (3, 2, 2, 3));
+        assert_eq!(web.images, ["nginx:1.25", "envoyproxy/envoy:v1.29"]);
+        assert_eq!(web.containers, ["app", "sidecar"]);
+        assert_eq!(web.labels.get("tier").map(String::as_str), Some("frontend"));
+        assert!(!web.paused);
+    }
+
+    #[tokio::test]
+    async fn scale_deployment_sends_merge_patch() {
+        let server = FakeApiServer::start(&["deployments.yaml"]).await;
+
+        scale_deployment(&server.client(), "default", "web", 5).await.unwrap();
+
+        let mutations = server.mutations();
+        assert_eq!(mutations.len(), 1);
+        assert_eq!(mutations[0].method, "PATCH");
+        assert_eq!(mutations[0].path, "/apis/apps/v1/namespaces/default/deployments/web");
+        assert_eq!(mutations[0].content_type.as_deref(), Some("application/merge-patch+json"));
+        assert_eq!(mutations[0].body, Some(json!({ "spec": { "replicas": 5 } })));
+
+        let web = server.object("/apis/apps/v1/namespaces/default/deployments/web").unwrap();
+        assert_eq!(web["spec"]["replicas"], 5);
+        assert_eq!(web["spec"]["template"]["spec"]["containers"][0]["image"], "nginx:1.25");
+    }
+
+    #[tokio::test]
+    async fn scale_deployment_fails_for_unknown_deployment() {
+        let server = FakeApiServer::start(&["deployments.yaml"]).await;
+
+        let err = scale_deployment(&server.client(), "default", "api", 2).await.unwrap_err();
+
+        assert_eq!(err.to_string(), "Failed to scale deployment");
+        assert!(format!("{:#}", err).contains("deployments \"api\" not found"));
+    }
+
+    #[tokio::test]
+    async fn restart_deployment_stamps_pod_template() {
+        let server = FakeApiServer::start(&["deployments.yaml"]).await;
+
+        restart_deployment(&server.client(), "default", "web").await.unwrap();
+
+        let web = server.object("/apis/apps/v1/namespaces/default/deployments/web").unwrap();
+        let restarted_at = web["spec"]["template"]["metadata"]["annotations"]["kubectl.kubernetes.io/restartedAt"]
+            .as_str()
+            .unwrap();
+        assert!(DateTime::parse_from_rfc3339(restarted_at).is_ok());
+        assert_eq!(web["spec"]["template"]["metadata"]["labels"]["app"], "web");
+    }
+
+    #[tokio::test]
+    async fn pause_deployment_toggles_paused() {
+        let server = FakeApiServer::start(&["deployments.yaml"]).await;
+        let client = server.client();
+        let path = "/apis/apps/v1/namespaces/default/deployments/web";
+
+        pause_deployment(&client, "default", "web", true).await.unwrap();
+        assert_eq!(server.object(path).unwrap()["spec"]["paused"], true);
+
+        pause_deployment(&client, "default", "web", false).await.unwrap();
+        assert_eq!(server.object(path).unwrap()["spec"]["paused"], false);
+    }
+
+    #[tokio::test]
+    async fn trigger_cronjob_creates_owned_job_from_template() {
+        let server = FakeApiServer::start(&["cronjobs.yaml", "jobs.yaml"]).await;
+
+        let job_name = trigger_cronjob(&server.client(), "default", "backup").await.unwrap();
+
+        assert!(job_name.starts_with("backup-manual-"));
+        let mutations = server.mutations();
+        assert_eq!(mutations.len(), 1);
+        assert_eq!(mutations[0].method, "POST");
+        assert_eq!(mutations[0].path, "/apis/batch/v1/namespaces/default/jobs");
+
+        let job = server
+            .object(&format!("/apis/batch/v1/namespaces/default/jobs/{}", job_name))
+            .unwrap();
+        assert_eq!(job["metadata"]["labels"], json!({ "app": "backup" }));
+        assert_eq!(job["metadata"]["annotations"], json!({ "team": "storage" }));
+        assert_eq!(
+            job["metadata"]["ownerReferences"],
+            json!([{
+                "apiVersion": "batch/v1",
+                "kind": "CronJob",
+                "name": "backup",
+                "uid": "6f1c2a4e-0003-4000-8000-000000000001",
+                "controller": true,
+                "blockOwnerDeletion": true,
+            }])
+        );
+        assert_eq!(job["spec"]["backoffLimit"], 2);
+        assert_eq!(job["spec"]["template"]["spec"]["containers"][0]["image"], "backup-tool:2.1");
+    }
+
+    #[tokio::test]
+    async fn trigger_cronjob_fails_without_creating_for_unknown_cronjob() {
+        let server = FakeApiServer::start(&["cronjobs.yaml"]).await;
+
+        let err = trigger_cronjob(&server.client(), "default", "nightly").await.unwrap_err();
+
+        assert_eq!(err.to_string(), "Failed to get cronjob");
+        assert!(server.mutations().is_empty());
+    }
+
+    #[tokio::test]
+    async fn suspend_cronjob_patches_suspend_flag() {
+        let server = FakeApiServer::start(&["cronjobs.yaml"]).await;
+        let client = server.client();
+
+        suspend_cronjob(&client, "default", "backup", true).await.unwrap();
+
+        assert_eq!(server.mutations()[0].body, Some(json!({ "spec": { "suspend": true } })));
+        let cronjobs = list_cronjobs(&client, Some("default"), &Selectors::default()).await.unwrap();
+        assert!(cronjobs[0].suspend);
+        assert_eq!(cronjobs[0].schedule, "0 * * * *");
+    }
+
+    #[tokio::test]
+    async fn get_cronjob_history_keeps_only_owned_jobs() {
+        let server = FakeApiServer::start(&["jobs.yaml"]).await;
+
+        let history = get_cronjob_history(&server.client(), "default", "backup").await.unwrap();
+
+        let summary: Vec<_> = history.iter().map(|j| (j.name.as_str(), j.status.clone())).collect();
+        assert_eq!(
+            summary,
+            [("backup-28411200", JobStatus::Succeeded), ("backup-28411260", JobStatus::Failed)]
+        );
+        assert_eq!(history[0].duration, "42s");
+        assert_eq!(history[0].completions, "1/1");
+        assert_eq!(server.requests()[0].path, "/apis/batch/v1/namespaces/default/jobs");
+    }
+
+    #[tokio::test]
+    async fn list_jobs_derives_status() {
+        let server = FakeApiServer::start(&["jobs.yaml"]).await;
+
+        let jobs = list_jobs(&server.client(), Some("default"), &Selectors::default()).await.unwrap();
+
+        let migrate = jobs.iter().find(|j| j.name == "migrate-schema").unwrap();
+        assert_eq!(migrate.status, JobStatus::Running);
+        assert_eq!(migrate.owner, None);
+    }
+
+    #[tokio::test]
+    async fn delete_job_removes_it() {
+        let server = FakeApiServer::start(&["jobs.yaml"]).await;
+
+        delete_job(&server.client(), "default", "migrate-schema").await.unwrap();
+
+        assert!(server.object("/apis/batch/v1/namespaces/default/jobs/migrate-schema").is_none());
+    }
+
+    #[tokio::test]
+    async fn update_configmap_merges_data() {
+        let server = FakeApiServer::start(&["configmaps.yaml"]).await;
+        let data = BTreeMap::from([("LOG_LEVEL".to_string(), "debug".to_string())]);
+
+        update_configmap(&server.client(), "default", "web-config", data).await.unwrap();
+
+        let cm = server.object("/api/v1/namespaces/default/configmaps/web-config").unwrap();
+        assert_eq!(cm["data"], json!({ "LOG_LEVEL": "debug", "FEATURE_FLAGS": "search,checkout" }));
+    }
+
+    #[tokio::test]
+    async fn get_secret_value_decodes_text_and_rejects_binary() {
+        let server = FakeApiServer::start(&["secrets.yaml"]).await;
+        let client = server.client();
+
+        assert_eq!(get_secret_value(&client, "default", "db-credentials", "password").await.unwrap(), "s3cr3t");
+
+        let err = get_secret_value(&client, "default", "db-credentials", "token").await.unwrap_err();
+        assert_eq!(err.to_string(), "Secret has no key 'token'");
+
+        let err = get_secret_data(&client, "default", "tls-keystore").await.unwrap_err();
+        assert_eq!(err.to_string(), "Value of 'keystore.p12' is binary (6 bytes)");
+    }
+
+    #[tokio::test]
+    async fn update_secret_replaces_data_with_resource_version() {
+        let server = FakeApiServer::start(&["secrets.yaml"]).await;
+        let mut edit = get_secret_data(&server.client(), "default", "db-credentials").await.unwrap();
+        edit.data = BTreeMap::from([("username".to_string(), "root".to_string())]);
+
+        update_secret(&server.client(), "default", "db-credentials", edit).await.unwrap();
+
+        let put = &server.mutations()[0];
+        assert_eq!(put.method, "PUT");
+        assert_eq!(put.body.as_ref().unwrap()["metadata"]["resourceVersion"], "601");
+
+        let secret = server.object("/api/v1/namespaces/default/secrets/db-credentials").unwrap();
+        assert_eq!(secret["data"], json!({ "username": "cm9vdA==" }));
+    }
+
+    #[tokio::test]
+    async fn update_secret_rejects_changes_made_since_it_was_read() {
+        let server = FakeApiServer::start(&["secrets.yaml"]).await;
+        let stale = SecretData {
+            data: BTreeMap::from([("username".to_string(), "root".to_string())]),
+            resource_version: Some("600".to_string()),
+        };
+
+        let err = update_secret(&server.client(), "default", "db-credentials", stale).await.unwrap_err();
+
+        assert_eq!(
+            err.to_string(),
+            "Secret 'db-credentials' changed since it was opened for editing; reopen it and try again"
+        );
+        let secret = server.object("/api/v1/namespaces/default/secrets/db-credentials").unwrap();
+        assert_eq!(secret["metadata"]["resourceVersion"], "601");
+    }
+
+    #[tokio::test]
+    async fn list_object_events_selects_by_involved_object() {
+        let server = FakeApiServer::start(&["events.yaml"]).await;
+        let target = ResourceRef::new(ResourceKind::Pod, "default", "web-7d4b9c-abcde");
+
+        let events = list_object_events(&server.client(), &target).await.unwrap();
+
+        let reasons: Vec<_> = events.iter().map(|e| e.reason.as_str()).collect();
+        assert_eq!(reasons, ["BackOff", "Pulled"], "newest first, deployment event excluded");
+        assert_eq!(events[0].count, 7);
+        assert_eq!(events[0].source, "kubelet");
+        assert_eq!(
+            server.requests()[0].query.get("fieldSelector").map(String::as_str),
+            Some("involvedObject.kind=Pod,involvedObject.name=web-7d4b9c-abcde")
+        );
+    }
+}
@@ -0,0 +1,473 @@
+use anyhow::{anyhow, bail, Context, Result};
+use chrono::{DateTime, Utc};
+use futures::StreamExt;
+use k8s_openapi::api::apps::v1::{Deployment, ReplicaSet};
+use kube::{
+    api::{Api, ListParams, Patch, PatchParams},
+    runtime::watcher,
+    Client, ResourceExt,
+};
+
+use super::resources::format_age;
+use super::workloads::{selector_string, template_images};
+
+const REVISION_ANNOTATION: &str = "deployment.kubernetes.io/revision";
+const CHANGE_CAUSE_ANNOTATION: &str = "kubernetes.io/change-cause";
+/// Added by the deployment controller to every ReplicaSet's template; it differs
+/// between all revisions, so it is left out of templates compared or rolled back to.
+const POD_TEMPLATE_HASH_LABEL: &str = "pod-template-hash";
+
+/// One revision of a Deployment, backed by the ReplicaSet that carries its pod template.
+#[derive(Clone, Debug)]
+pub struct RevisionInfo {
+    pub revision: i64,
+    pub replicaset: String,
+    pub replicas: i32,
+    pub images: Vec<String>,
+    pub change_cause: Option<String>,
+    pub age: String,
+    pub current: bool,
+    /// The pod template as YAML, for diffing revisions.
+    pub template_yaml: String,
+}
+
+fn revision_of(rs: &ReplicaSet) -> Option<i64> {
+    rs.annotations().get(REVISION_ANNOTATION)?.parse().ok()
+}
+
+/// The ReplicaSets controlled by `deployment`, with their revision numbers.
+async fn deployment_replicasets(client: &Client, deployment: &Deployment) -> Result<Vec<(i64, ReplicaSet)>> {
+    let namespace = deployment.namespace().unwrap_or_default();
+    let replicasets: Api<ReplicaSet> = Api::namespaced(client.clone(), &namespace);
+
+    let selector = deployment
+        .spec
+        .as_ref()
+        .map(|s| selector_string(&s.selector))
+        .unwrap_or_default();
+    let list = replicasets
+        .list(&ListParams::default().labels(&selector))
+        .await
+        .context("Failed to list replicasets")?;
+
+    let uid = deployment.uid();
+    Ok(list
+        .items
+        .into_iter()
+        .filter(|rs| rs.owner_references().iter().any(|o| o.controller == Some(true) && Some(&o.uid) == uid.as_ref()))
+        .filter_map(|rs| Some((revision_of(&rs)?, rs)))
+        .collect())
+}
+
+fn revision_template(rs: &ReplicaSet) -> Option<k8s_openapi::api::core::v1::PodTemplateSpec> {
+    let mut template = rs.spec.as_ref()?.template.clone()?;
+    if let Some(labels) = template.metadata.as_mut().and_then(|m| m.labels.as_mut()) {
+        labels.remove(POD_TEMPLATE_HASH_LABEL);
+    }
+    Some(template)
+}
+
+/// Lists a deployment's revisions, newest first.
+pub async fn list_deployment_revisions(client: &Client, namespace: &str, name: &str) -> Result<Vec<RevisionInfo>> {
+    let deployments: Api<Deployment> = Api::namespaced(client.clone(), namespace);
+    let deployment = deployments.get(name).await.context("Failed to get deployment")?;
+    let current = deployment
+        .annotations()
+        .get(REVISION_ANNOTATION)
+        .and_then(|r| r.parse::<i64>().ok());
+
+    let mut revisions: Vec<RevisionInfo> = deployment_replicasets(client, &deployment)
+        .await?
+        .into_iter()
+        .map(|(revision, rs)| {
+            let template = revision_template(&rs);
+            RevisionInfo {
+                revision,
+                replicaset: rs.name_any(),
+                replicas: rs.spec.as_ref().and_then(|s| s.replicas).unwrap_or(0),
+                images: template_images(template.as_ref()),
+                change_cause: rs.annotations().get(CHANGE_CAUSE_ANNOTATION).cloned(),
+                age: format_age(rs.metadata.creation_timestamp.as_ref()),
+                current: Some(revision) == current,
+                template_yaml: template
+                    .map(|t| serde_yaml::to_string(&t).unwrap_or_default())
+                    .unwrap_or_default(),
+            }
+        })
+        .collect();
+
+    revisions.sort_by_key(|r| std::cmp::Reverse(r.revision));
+    Ok(revisions)
+}
+
+/// Rolls a deployment back to the pod template of `revision`, like `kubectl rollout undo --to-revision`.
+/// The controller then records it as a new revision.
+pub async fn rollback_deployment(client: &Client, namespace: &str, name: &str, revision: i64) -> Result<()> {
+    let deployments: Api<Deployment> = Api::namespaced(client.clone(), namespace);
+    let deployment = deployments.get(name).await.context("Failed to get deployment")?;
+
+    if deployment.spec.as_ref().and_then(|s| s.paused) == Some(true) {
+        bail!("Cannot roll back a paused deployment; resume it first");
+    }
+
+    let template = deployment_replicasets(client, &deployment)
+        .await?
+        .into_iter()
+        .find(|(r, _)| *r == revision)
+        .and_then(|(_, rs)| revision_template(&rs))
+        .with_context(|| format!("Revision {} of deployment {} not found", revision, name))?;
+
+    let patch: json_patch::Patch = serde_json::from_value(serde_json::json!([
+        { "op": "replace", "path": "/spec/template", "value": template }
+    ]))?;
+
+    deployments
+        .patch(name, &PatchParams::default(), &Patch::Json::<()>(patch))
+        .await
+        .context("Failed to roll back deployment")?;
+
+    Ok(())
+}
+
+/// Checks an image reference against the `[registry[:port]/]path[:tag][@digest]`
+/// grammar used by container runtimes, so typos fail before a rollout starts.
+pub fn validate_image_reference(image: &str) -> Result<()> {
+    if image.is_empty() {
+        bail!("Image is required");
+    }
+    if image.chars().any(char::is_whitespace) {
+        bail!("Image '{}' must not contain whitespace", image);
+    }
+
+    let (rest, digest) = match image.split_once('@') {
+        Some((rest, digest)) => (rest, Some(digest)),
+        None => (image, None),
+    };
+    if let Some(digest) = digest {
+        let (algorithm, hex) = digest
+            .split_once(':')
+            .with_context(|| format!("Digest '{}' must look like sha256:<hex>", digest))?;
+        let algorithm_ok = !algorithm.is_empty()
+            && algorithm.split(['+', '.', '_', '-']).all(|p| !p.is_empty() && p.chars().all(|c| c.is_ascii_lowercase() || c.is_ascii_digit()));
+        if !algorithm_ok || hex.len() < 32 || !hex.chars().all(|c| c.is_ascii_hexdigit()) {
+            bail!("Digest '{}' must look like sha256:<hex>", digest);
+        }
+    }
+
+    // A ':' after the last '/' separates the tag; before it, it can only be a registry port
+    let last_slash = rest.rfind('/').map_or(0, |i| i + 1);
+    let (name, tag) = match rest[last_slash..].rfind(':') {
+        Some(i) => (&rest[..last_slash + i], Some(&rest[last_slash + i + 1..])),
+        None => (rest, None),
+    };
+    if let Some(tag) = tag {
+        let valid = !tag.is_empty()
+            && tag.len() <= 128
+            && tag.chars().all(|c| c.is_ascii_alphanumeric() || matches!(c, '_' | '.' | '-'))
+            && !tag.starts_with(['.', '-']);
+        if !valid {
+            bail!("Tag '{}' may only contain letters, digits, '_', '.' and '-' (up to 128, not starting with '.' or '-')", tag);
+        }
+    }
+
+    if name.is_empty() {
+        bail!("Image '{}' has no repository name", image);
+    }
+    if name.len() > 255 {
+        bail!("Repository name in '{}' is longer than 255 characters", image);
+    }
+    let mut components: Vec<&str> = name.split('/').collect();
+    let first = components[0];
+    if components.len() > 1 && (first.contains(['.', ':']) || first == "localhost") {
+        let (host, port) = match first.split_once(':') {
+            Some((host, port)) => (host, Some(port)),
+            None => (first, None),
+        };
+        let host_ok = host
+            .split('.')
+            .all(|l| !l.is_empty() && !l.starts_with('-') && !l.ends_with('-') && l.chars().all(|c| c.is_ascii_alphanumeric() || c == '-'));
+        let port_ok = port.is_none_or(|p| !p.is_empty() && p.chars().all(|c| c.is_ascii_digit()));
+        if !host_ok || !port_ok {
+            bail!("Registry '{}' is not a valid host[:port]", first);
+        }
+        components.remove(0);
+    }
+    for component in components {
+        if !valid_path_component(component) {
+            bail!(
+                "Repository component '{}' must be lowercase letters and digits, separated by '.', '_', '__' or '-'",
+                component
+            );
+        }
+    }
+    Ok(())
+}
+
+fn valid_path_component(component: &str) -> bool {
+    let alnum = |c: char| c.is_ascii_lowercase() || c.is_ascii_digit();
+    let mut chars = component.chars().peekable();
+    loop {
+        // A run of lowercase letters and digits...
+        let mut run = 0;
+        while chars.peek().is_some_and(|c| alnum(*c)) {
+            chars.next();
+            run += 1;
+        }
+        if run == 0 {
+            return false;
+        }
+        // ...then the end, or one separator followed by another run
+        let mut separator = String::new();
+        while let Some(c) = chars.peek().copied().filter(|c| !alnum(*c)) {
+            separator.push(c);
+            chars.next();
+        }
+        if separator.is_empty() {
+            return chars.peek().is_none();
+        }
+        if !matches!(separator.as_str(), "." | "_" | "__") && !separator.chars().all(|c| c == '-') {
+            return false;
+        }
+    }
+}
+
+/// The strategic merge patch sent by [`set_deployment_image`].
+pub fn set_image_patch(container: &str, image: &str, change_cause: &str) -> serde_json::Value {
+    // Strategic merge keys the containers list by name, so only this container changes
+    serde_json::json!({
+        "metadata": {
+            "annotations": {
+                CHANGE_CAUSE_ANNOTATION: change_cause
+            }
+        },
+        "spec": {
+            "template": {
+                "spec": {
+                    "containers": [{ "name": container, "image": image }]
+                }
+            }
+        }
+    })
+}
+
+/// Changes one container's image in a deployment's pod template, like `kubectl set image`,
+/// recording `change_cause` on the deployment so it shows up in the revision history.
+pub async fn set_deployment_image(
+    client: &Client,
+    namespace: &str,
+    name: &str,
+    container: &str,
+    image: &str,
+    change_cause: &str,
+) -> Result<()> {
+    validate_image_reference(image)?;
+
+    let deployments: Api<Deployment> = Api::namespaced(client.clone(), namespace);
+    let deployment = deployments.get(name).await.context("Failed to get deployment")?;
+
+    let has_container = deployment
+        .spec
+        .as_ref()
+        .and_then(|s| s.template.spec.as_ref())
+        .is_some_and(|ps| ps.containers.iter().any(|c| c.name == container));
+    if !has_container {
+        bail!("Deployment {} has no container named {}", name, container);
+    }
+
+    let patch = set_image_patch(container, image, change_cause);
+    deployments
+        .patch(name, &PatchParams::default(), &Patch::Strategic(&patch))
+        .await
+        .context("Failed to set deployment image")?;
+
+    Ok(())
+}
+
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum RolloutPhase {
+    /// The controller has not observed the latest spec yet.
+    Pending,
+    Progressing,
+    Paused,
+    Complete,
+    /// The controller gave up after `progressDeadlineSeconds` without progress.
+    Stuck,
+}
+
+#[derive(Clone, Debug)]
+pub struct DeploymentConditionInfo {
+    pub condition_type: String,
+    pub status: String,
+    pub reason: String,
+    pub message: String,
+}
+
+/// Snapshot of a deployment rollout, judged the way `kubectl rollout status` does.
+#[derive(Clone, Debug)]
+pub struct DeploymentRolloutStatus {
+    pub phase: RolloutPhase,
+    pub message: String,
+    pub desired: i32,
+    pub updated: i32,
+    pub ready: i32,
+    pub available: i32,
+    /// Pods of all revisions, including old ones still terminating.
+    pub total: i32,
+    pub conditions: Vec<DeploymentConditionInfo>,
+    pub progress_deadline_seconds: Option<i32>,
+    /// When the `Progressing` condition last changed, i.e. the last time the rollout moved.
+    pub last_progress: Option<DateTime<Utc>>,
+}
+
+fn rollout_status(d: &Deployment) -> DeploymentRolloutStatus {
+    let spec = d.spec.as_ref();
+    let status = d.status.as_ref();
+
+    let desired = spec.and_then(|s| s.replicas).unwrap_or(1);
+    let updated = status.and_then(|s| s.updated_replicas).unwrap_or(0);
+    let ready = status.and_then(|s| s.ready_replicas).unwrap_or(0);
+    let available = status.and_then(|s| s.available_replicas).unwrap_or(0);
+    let total = status.and_then(|s| s.replicas).unwrap_or(0);
+
+    let raw_conditions = status.and_then(|s| s.conditions.as_ref());
+    let progressing = raw_conditions.and_then(|c| c.iter().find(|c| c.type_ == "Progressing"));
+    let conditions = raw_conditions
+        .into_iter()
+        .flatten()
+        .map(|c| DeploymentConditionInfo {
+            condition_type: c.type_.clone(),
+            status: c.status.clone(),
+            reason: c.reason.clone().unwrap_or_default(),
+            message: c.message.clone().unwrap_or_default(),
+        })
+        .collect();
+
+    let observed = status.and_then(|s| s.observed_generation) >= d.metadata.generation;
+    let (phase, message) = if !observed {
+        (RolloutPhase::Pending, "Waiting for the deployment spec update to be observed".to_string())
+    } else if progressing.and_then(|c| c.reason.as_deref()) == Some("ProgressDeadlineExceeded") {
+        let reason = progressing.and_then(|c| c.message.clone()).unwrap_or_default();
+        (RolloutPhase::Stuck, format!("Rollout exceeded its progress deadline: {}", reason))
+    } else if spec.and_then(|s| s.paused) == Some(true) {
+        (RolloutPhase::Paused, format!("Rollout paused with {} of {} replicas updated", updated, desired))
+    } else if updated < desired {
+        (RolloutPhase::Progressing, format!("{} of {} new replicas have been updated", updated, desired))
+    } else if total > updated {
+        (RolloutPhase::Progressing, format!("{} old replicas are pending termination", total - updated))
+    } else if available < updated {
+        (RolloutPhase::Progressing, format!("{} of {} updated replicas are available", available, updated))
+    } else {
+        (RolloutPhase::Complete, "Successfully rolled out".to_string())
+    };
+
+    DeploymentRolloutStatus {
+        phase,
+        message,
+        desired,
+        updated,
+        ready,
+        available,
+        total,
+        conditions,
+        progress_deadline_seconds: spec.and_then(|s| s.progress_deadline_seconds),
+        last_progress: progressing.and_then(|c| c.last_update_time.as_ref()).map(|t| t.0),
+    }
+}
+
+/// Follows one deployment's rollout, reporting every change, until it completes or is
+/// stuck past its progress deadline.
+pub async fn watch_deployment_rollout(
+    client: &Client,
+    namespace: &str,
+    name: &str,
+    mut on_update: impl FnMut(Result<DeploymentRolloutStatus>),
+) {
+    let deployments: Api<Deployment> = Api::namespaced(client.clone(), namespace);
+    let mut stream = watcher::watch_object(deployments, name).boxed();
+
+    while let Some(event) = stream.next().await {
+        match event {
+            Ok(Some(deployment)) => {
+                let status = rollout_status(&deployment);
+                let done = matches!(status.phase, RolloutPhase::Complete | RolloutPhase::Stuck);
+                on_update(Ok(status));
+                if done {
+                    return;
+                }
+            }
+            Ok(None) => {
+                on_update(Err(anyhow!("Deployment {} was deleted", name)));
+                return;
+            }
+            Err(e) => on_update(Err(anyhow!("Failed to watch deployment {}: {}", name, e))),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const SHA: &str = "sha256:4c0fdd5c6f5e3b4e8a1d2c3b4a5f6e7d8c9b0a1f2e3d4c5b6a7f8e9d0c1b2a3f";
+
+    #[test]
+    fn accepts_image_references() {
+        let digest_only = format!("nginx@{}", SHA);
+        let tag_and_digest = format!("ghcr.io/org/app:v1.2.3@{}", SHA);
+        for image in [
+            "nginx",
+            "nginx:1.25-alpine",
+            "library/nginx:latest",
+            "registry.example.com:5000/team/app:1.2",
+            "localhost:5000/app",
+            "localhost/app",
+            "my-registry:443/app",
+            "team/my_app__v2/app-name--x:TAG_1",
+            digest_only.as_str(),
+            tag_and_digest.as_str(),
+        ] {
+            assert!(validate_image_reference(image).is_ok(), "{}", image);
+        }
+    }
+
+    #[test]
+    fn rejects_malformed_image_references() {
+        let long_tag = format!("nginx:{}", "a".repeat(129));
+        for image in [
+            "",
+            "nginx latest",
+            "Nginx",
+            "team/App:1.0",
+            "a..b",
+            "a___b",
+            "team/a._b",
+            "nginx:",
+            "nginx:-rc1",
+            "nginx:1.0:2",
+            "-nginx",
+            "nginx-",
+            "nginx_",
+            "/nginx",
+            "team/",
+            "registry.example.com:port/app",
+            "-registry.io/app",
+            ":5000/app",
+            "nginx@sha256",
+            "nginx@sha256:xyz",
+            "nginx@sha256:abc123",
+            long_tag.as_str(),
+        ] {
+            assert!(validate_image_reference(image).is_err(), "{}", image);
+        }
+    }
+
+    #[test]
+    fn path_components_allow_single_separators_between_runs() {
+        for component in ["a", "nginx", "a.b", "a_b", "a__b", "a-b", "a---b", "v2.1_x-y"] {
+            assert!(valid_path_component(component), "{}", component);
+        }
+        for component in ["", "A", "a..b", "a___b", "a._b", "a-_b", ".a", "a.", "_a", "a-", "a/b"] {
+            assert!(!valid_path_component(component), "{}", component);
+        }
+    }
+}
@@ -0,0 +1,231 @@
+use anyhow::{bail, Result};
+use kube::{api::ListParams, runtime::watcher};
+
+/// Server-side label and field selectors applied to a list or watch. Empty
+/// strings select everything.
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct Selectors {
+    pub labels: String,
+    pub fields: String,
+}
+
+impl Selectors {
+    /// Checks both selectors with the API server's syntax rules, so typos are
+    /// reported before a request is made rather than as a 400 from the list.
+    pub fn new(labels: &str, fields: &str) -> Result<Self> {
+        let (labels, fields) = (labels.trim(), fields.trim());
+        validate_label_selector(labels)?;
+        validate_field_selector(fields)?;
+        Ok(Self {
+            labels: labels.to_string(),
+            fields: fields.to_string(),
+        })
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.labels.is_empty() && self.fields.is_empty()
+    }
+
+    pub fn list_params(&self) -> ListParams {
+        let mut params = ListParams::default();
+        if !self.labels.is_empty() {
+            params = params.labels(&self.labels);
+        }
+        if !self.fields.is_empty() {
+            params = params.fields(&self.fields);
+        }
+        params
+    }
+
+    pub fn watcher_config(&self) -> watcher::Config {
+        let mut config = watcher::Config::default();
+        if !self.labels.is_empty() {
+            config = config.labels(&self.labels);
+        }
+        if !self.fields.is_empty() {
+            config = config.fields(&self.fields);
+        }
+        config
+    }
+}
+
+/// Validates a label selector such as `app=web,tier!=cache,env in (prod,staging),!canary`.
+pub fn validate_label_selector(selector: &str) -> Result<()> {
+    for term in split_terms(selector)? {
+        let term = term.trim();
+        if term.is_empty() {
+            bail!("Empty term in label selector '{}'", selector);
+        }
+        if let Some(key) = term.strip_prefix('!') {
+            validate_label_key(key.trim())?;
+        } else if let Some((key, values)) = set_term(term) {
+            validate_label_key(key)?;
+            let values: Vec<&str> = values.split(',').map(str::trim).collect();
+            if values.iter().all(|v| v.is_empty()) {
+                bail!("'{}' needs at least one value in parentheses", term);
+            }
+            for value in values {
+                validate_label_value(value)?;
+            }
+        } else if let Some((key, value)) = ["!=", "==", "="].iter().find_map(|op| term.split_once(op)) {
+            validate_label_key(key.trim())?;
+            validate_label_value(value.trim())?;
+        } else if let Some((key, value)) = term.split_once('>').or_else(|| term.split_once('<')) {
+            validate_label_key(key.trim())?;
+            if value.trim().parse::<i64>().is_err() {
+                bail!("'{}' compares with '{}', which is not an integer", term, value.trim());
+            }
+        } else {
+            validate_label_key(term)?;
+        }
+    }
+    Ok(())
+}
+
+/// Validates a field selector such as `status.phase=Running,spec.nodeName!=node-1`.
+pub fn validate_field_selector(selector: &str) -> Result<()> {
+    if selector.is_empty() {
+        return Ok(());
+    }
+    for term in selector.split(',') {
+        let term = term.trim();
+        let Some((field, _)) = ["!=", "==", "="].iter().find_map(|op| term.split_once(op)) else {
+            bail!("Field selector term '{}' needs an operator: =, == or !=", term);
+        };
+        let field = field.trim();
+        if field.is_empty() || !field.chars().all(|c| c.is_ascii_alphanumeric() || matches!(c, '.' | '-' | '_')) {
+            bail!("Invalid field '{}' in field selector", field);
+        }
+    }
+    Ok(())
+}
+
+/// Splits on the commas between terms, leaving those inside `in (...)` lists alone.
+fn split_terms(selector: &str) -> Result<Vec<&str>> {
+    if selector.is_empty() {
+        return Ok(vec![]);
+    }
+    let mut terms = vec![];
+    let (mut depth, mut start) = (0, 0);
+    for (i, c) in selector.char_indices() {
+        match c {
+            '(' if depth == 0 => depth = 1,
+            '(' => bail!("Nested parentheses in label selector '{}'", selector),
+            ')' if depth == 0 => bail!("Unbalanced ')' in label selector '{}'", selector),
+            ')' => depth = 0,
+            ',' if depth == 0 => {
+                terms.push(&selector[start..i]);
+                start = i + 1;
+            }
+            _ => {}
+        }
+    }
+    if depth != 0 {
+        bail!("Missing ')' in label selector '{}'", selector);
+    }
+    terms.push(&selector[start..]);
+    Ok(terms)
+}
+
+/// Splits `key in (a,b)` or `key notin (a,b)` into the key and the values inside
+/// the parentheses.
+fn set_term(term: &str) -> Option<(&str, &str)> {
+    let (head, values) = term.strip_suffix(')')?.split_once('(')?;
+    let mut words = head.split_whitespace();
+    let (key, op) = (words.next()?, words.next()?);
+    (matches!(op, "in" | "notin") && words.next().is_none()).then_some((key, values))
+}
+
+/// A label key: an optional DNS subdomain prefix and a slash, then a name of at
+/// most 63 characters.
+fn validate_label_key(key: &str) -> Result<()> {
+    let (prefix, name) = match key.rsplit_once('/') {
+        Some((prefix, name)) => (Some(prefix), name),
+        None => (None, key),
+    };
+    if let Some(prefix) = prefix {
+        let valid = !prefix.is_empty()
+            && prefix.len() <= 253
+            && prefix.split('.').all(|part| {
+                !part.is_empty()
+                    && part.chars().all(|c| c.is_ascii_lowercase() || c.is_ascii_digit() || c == '-')
+                    && !part.starts_with('-')
+                    && !part.ends_with('-')
+            });
+        if !valid {
+            bail!("Invalid prefix '{}' in label key '{}': must be a DNS subdomain", prefix, key);
+        }
+    }
+    if name.is_empty() || !is_label_name(name) {
+        bail!(
+            "Invalid label key '{}': names are at most 63 characters of letters, digits, '-', '_' or '.', starting and ending with a letter or digit",
+            key
+        );
+    }
+    Ok(())
+}
+
+fn validate_label_value(value: &str) -> Result<()> {
+    if !value.is_empty() && !is_label_name(value) {
+        bail!(
+            "Invalid label value '{}': values are at most 63 characters of letters, digits, '-', '_' or '.', starting and ending with a letter or digit",
+            value
+        );
+    }
+    Ok(())
+}
+
+fn is_label_name(s: &str) -> bool {
+    s.len() <= 63
+        && s.chars().all(|c| c.is_ascii_alphanumeric() || matches!(c, '-' | '_' | '.'))
+        && s.starts_with(|c: char| c.is_ascii_alphanumeric())
+        && s.ends_with(|c: char| c.is_ascii_alphanumeric())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn accepts_kubectl_label_selectors() {
+        for selector in [
+            "",
+            "app=web",
+            "app==web,tier!=cache",
+            "app.kubernetes.io/name=web",
+            "env in (prod, staging),!canary",
+            "tier notin (cache)",
+            "release",
+            "generation>3",
+            "empty=",
+        ] {
+            assert!(validate_label_selector(selector).is_ok(), "{}", selector);
+        }
+    }
+
+    #[test]
+    fn rejects_malformed_label_selectors() {
+        let too_long = format!("app={}", "x".repeat(64));
+        for selector in [
+            "app=web,",
+            "app=-web",
+            "env in (prod",
+            "env in ()",
+            "Example.com/app=web",
+            "bad key=web",
+            "generation>three",
+            too_long.as_str(),
+        ] {
+            assert!(validate_label_selector(selector).is_err(), "{}", selector);
+        }
+    }
+
+    #[test]
+    fn validates_field_selectors() {
+        assert!(validate_field_selector("status.phase=Running,spec.nodeName!=node-1").is_ok());
+        assert!(validate_field_selector("metadata.name==web").is_ok());
+        assert!(validate_field_selector("status.phase").is_err());
+        assert!(validate_field_selector("=Running").is_err());
+        assert_eq!(Selectors::new(" app=web ", "").unwrap().labels, "app=web");
+    }
+}
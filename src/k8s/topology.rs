@@ -0,0 +1,287 @@
+use anyhow::{Context, Result};
+use k8s_openapi::api::{
+    apps::v1::{DaemonSet, Deployment, ReplicaSet, StatefulSet},
+    batch::v1::{CronJob, Job},
+    core::v1::{Pod, Service},
+    networking::v1::Ingress,
+};
+use k8s_openapi::apimachinery::pkg::apis::meta::v1::ObjectMeta;
+use kube::{
+    api::{Api, ListParams},
+    Client,
+};
+use std::collections::{BTreeMap, HashMap};
+
+use super::objects::ResourceKind;
+use super::resources::{job_info, pod_info, JobStatus};
+
+/// One object in the topology graph.
+#[derive(Clone, Debug)]
+pub struct TopologyNode {
+    pub kind: ResourceKind,
+    pub namespace: String,
+    pub name: String,
+    /// Short status, e.g. `Running`, `CrashLoopBackOff` or `Ready`.
+    pub status: String,
+    /// Replica or pod counts, shown on hover.
+    pub detail: String,
+    pub healthy: bool,
+}
+
+/// Objects of a namespace linked by `ownerReferences`, Service selectors and Ingress
+/// backends. Edges point from the owner or router to its target.
+#[derive(Clone, Debug, Default)]
+pub struct Topology {
+    pub nodes: Vec<TopologyNode>,
+    pub edges: Vec<(usize, usize)>,
+}
+
+impl TopologyNode {
+    fn new(kind: ResourceKind, meta: &ObjectMeta, status: &str, detail: String, healthy: bool) -> Self {
+        Self {
+            kind,
+            namespace: meta.namespace.clone().unwrap_or_default(),
+            name: meta.name.clone().unwrap_or_default(),
+            status: status.to_string(),
+            detail,
+            healthy,
+        }
+    }
+
+    /// A replicated workload: healthy once every desired replica is ready.
+    fn replicated(kind: ResourceKind, meta: &ObjectMeta, desired: i32, ready: i32) -> Self {
+        let status = if desired == 0 {
+            "Scaled down"
+        } else if ready >= desired {
+            "Ready"
+        } else if ready > 0 {
+            "Pending"
+        } else {
+            "Failed"
+        };
+        Self::new(kind, meta, status, format!("{}/{} ready", ready, desired), ready >= desired)
+    }
+}
+
+fn pod_node(pod: &Pod) -> TopologyNode {
+    let info = pod_info(pod);
+    let all_ready = info.containers.iter().all(|c| c.ready);
+
+    // The phase stays Running while a container crash-loops; surface the container's reason instead
+    let status = match info.containers.iter().find(|c| c.state != "Running") {
+        Some(c) if info.status == "Running" => c.state.clone(),
+        _ => info.status.clone(),
+    };
+    let healthy = info.status == "Succeeded" || (info.status == "Running" && all_ready);
+    let detail = format!("{} containers ready, {} restarts", info.ready, info.restarts);
+    TopologyNode::new(ResourceKind::Pod, &pod.metadata, &status, detail, healthy)
+}
+
+fn job_node(job: &Job) -> TopologyNode {
+    let info = job_info(job);
+    let status = match info.status {
+        JobStatus::Running => "Running",
+        JobStatus::Succeeded => "Succeeded",
+        JobStatus::Failed => "Failed",
+        JobStatus::Pending => "Pending",
+    };
+    let detail = format!("{} completions, took {}", info.completions, info.duration);
+    TopologyNode::new(ResourceKind::Job, &job.metadata, status, detail, info.status != JobStatus::Failed)
+}
+
+fn cronjob_node(cronjob: &CronJob) -> TopologyNode {
+    let suspended = cronjob.spec.as_ref().and_then(|s| s.suspend).unwrap_or(false);
+    let active = cronjob.status.as_ref().and_then(|s| s.active.as_ref()).map_or(0, |a| a.len());
+    let schedule = cronjob.spec.as_ref().map(|s| s.schedule.as_str()).unwrap_or_default();
+    let status = if suspended { "Suspended" } else { "Active" };
+    TopologyNode::new(
+        ResourceKind::CronJob,
+        &cronjob.metadata,
+        status,
+        format!("{} · {} running", schedule, active),
+        true,
+    )
+}
+
+fn ingress_backends(ingress: &Ingress) -> Vec<String> {
+    let spec = ingress.spec.as_ref();
+    let default = spec
+        .and_then(|s| s.default_backend.as_ref())
+        .and_then(|b| b.service.as_ref())
+        .map(|s| s.name.clone());
+    let rules = spec
+        .and_then(|s| s.rules.as_ref())
+        .into_iter()
+        .flatten()
+        .filter_map(|r| r.http.as_ref())
+        .flat_map(|http| &http.paths)
+        .filter_map(|p| p.backend.service.as_ref().map(|s| s.name.clone()));
+
+    let mut backends: Vec<String> = default.into_iter().chain(rules).collect();
+    backends.sort();
+    backends.dedup();
+    backends
+}
+
+fn selects(selector: &BTreeMap<String, String>, labels: Option<&BTreeMap<String, String>>) -> bool {
+    !selector.is_empty() && selector.iter().all(|(k, v)| labels.and_then(|l| l.get(k)) == Some(v))
+}
+
+fn api<K>(client: &Client, namespace: Option<&str>) -> Api<K>
+where
+    K: kube::Resource<Scope = k8s_openapi::NamespaceResourceScope>,
+    <K as kube::Resource>::DynamicType: Default,
+{
+    match namespace {
+        Some(ns) => Api::namespaced(client.clone(), ns),
+        None => Api::all(client.clone()),
+    }
+}
+
+/// Builds the topology of `namespace` (or every namespace): Deployment → ReplicaSet → Pod,
+/// CronJob → Job → Pod and the other owner links, Service → Pod by selector, and
+/// Ingress → Service by backend.
+pub async fn namespace_topology(client: &Client, namespace: Option<&str>) -> Result<Topology> {
+    let lp = ListParams::default();
+    let (deployments, replicasets, statefulsets, daemonsets, cronjobs, jobs, pods, services, ingresses) = futures::try_join!(
+        async { api::<Deployment>(client, namespace).list(&lp).await.context("Failed to list deployments") },
+        async { api::<ReplicaSet>(client, namespace).list(&lp).await.context("Failed to list replicasets") },
+        async { api::<StatefulSet>(client, namespace).list(&lp).await.context("Failed to list statefulsets") },
+        async { api::<DaemonSet>(client, namespace).list(&lp).await.context("Failed to list daemonsets") },
+        async { api::<CronJob>(client, namespace).list(&lp).await.context("Failed to list cronjobs") },
+        async { api::<Job>(client, namespace).list(&lp).await.context("Failed to list jobs") },
+        async { api::<Pod>(client, namespace).list(&lp).await.context("Failed to list pods") },
+        async { api::<Service>(client, namespace).list(&lp).await.context("Failed to list services") },
+        async { api::<Ingress>(client, namespace).list(&lp).await.context("Failed to list ingresses") },
+    )?;
+
+    let mut topology = Topology::default();
+    // Owners are resolved by uid; selectors and backends by namespace and name
+    let mut by_uid: HashMap<String, usize> = HashMap::new();
+    let mut add = |topology: &mut Topology, meta: &ObjectMeta, node: TopologyNode| {
+        if let Some(uid) = &meta.uid {
+            by_uid.insert(uid.clone(), topology.nodes.len());
+        }
+        topology.nodes.push(node);
+    };
+
+    for d in &deployments.items {
+        let desired = d.spec.as_ref().and_then(|s| s.replicas).unwrap_or(1);
+        let ready = d.status.as_ref().and_then(|s| s.ready_replicas).unwrap_or(0);
+        add(&mut topology, &d.metadata, TopologyNode::replicated(ResourceKind::Deployment, &d.metadata, desired, ready));
+    }
+    for rs in &replicasets.items {
+        let desired = rs.spec.as_ref().and_then(|s| s.replicas).unwrap_or(1);
+        // Old revisions are kept scaled to zero; they only add noise to the graph
+        if desired == 0 && rs.metadata.owner_references.as_ref().is_some_and(|o| !o.is_empty()) {
+            continue;
+        }
+        let ready = rs.status.as_ref().and_then(|s| s.ready_replicas).unwrap_or(0);
+        add(&mut topology, &rs.metadata, TopologyNode::replicated(ResourceKind::ReplicaSet, &rs.metadata, desired, ready));
+    }
+    for ss in &statefulsets.items {
+        let desired = ss.spec.as_ref().and_then(|s| s.replicas).unwrap_or(1);
+        let ready = ss.status.as_ref().and_then(|s| s.ready_replicas).unwrap_or(0);
+        add(&mut topology, &ss.metadata, TopologyNode::replicated(ResourceKind::StatefulSet, &ss.metadata, desired, ready));
+    }
+    for ds in &daemonsets.items {
+        let status = ds.status.as_ref();
+        let desired = status.map(|s| s.desired_number_scheduled).unwrap_or(0);
+        let ready = status.map(|s| s.number_ready).unwrap_or(0);
+        add(&mut topology, &ds.metadata, TopologyNode::replicated(ResourceKind::DaemonSet, &ds.metadata, desired, ready));
+    }
+    for cj in &cronjobs.items {
+        add(&mut topology, &cj.metadata, cronjob_node(cj));
+    }
+    for job in &jobs.items {
+        add(&mut topology, &job.metadata, job_node(job));
+    }
+    for pod in &pods.items {
+        add(&mut topology, &pod.metadata, pod_node(pod));
+    }
+
+    // Owner links, for every object added so far
+    let owned = replicasets
+        .items
+        .iter()
+        .map(|o| &o.metadata)
+        .chain(jobs.items.iter().map(|o| &o.metadata))
+        .chain(pods.items.iter().map(|o| &o.metadata));
+    for meta in owned {
+        let Some(child) = meta.uid.as_ref().and_then(|uid| by_uid.get(uid)) else {
+            continue;
+        };
+        for owner in meta.owner_references.iter().flatten() {
+            if let Some(parent) = by_uid.get(&owner.uid) {
+                topology.edges.push((*parent, *child));
+            }
+        }
+    }
+
+    let pod_indices: Vec<(usize, &Pod)> = pods
+        .items
+        .iter()
+        .filter_map(|p| Some((*by_uid.get(p.metadata.uid.as_ref()?)?, p)))
+        .collect();
+
+    let mut services_by_name: HashMap<(String, String), usize> = HashMap::new();
+    for svc in &services.items {
+        let selector = svc.spec.as_ref().and_then(|s| s.selector.clone()).unwrap_or_default();
+        let namespace = svc.metadata.namespace.clone().unwrap_or_default();
+        let targets: Vec<usize> = pod_indices
+            .iter()
+            .filter(|(_, p)| p.metadata.namespace.as_deref() == Some(namespace.as_str()))
+            .filter(|(_, p)| selects(&selector, p.metadata.labels.as_ref()))
+            .map(|(i, _)| *i)
+            .collect();
+
+        // A selector that matches nothing leaves the service without endpoints
+        let (status, healthy) = if selector.is_empty() {
+            ("Active", true)
+        } else if targets.is_empty() {
+            ("No endpoints", false)
+        } else {
+            ("Active", true)
+        };
+        let service_type = svc.spec.as_ref().and_then(|s| s.type_.clone()).unwrap_or_else(|| "ClusterIP".to_string());
+        let detail = if selector.is_empty() {
+            format!("{}, no selector", service_type)
+        } else {
+            format!("{}, {} pods selected", service_type, targets.len())
+        };
+
+        let index = topology.nodes.len();
+        topology.nodes.push(TopologyNode::new(ResourceKind::Service, &svc.metadata, status, detail, healthy));
+        services_by_name.insert((namespace, svc.metadata.name.clone().unwrap_or_default()), index);
+        topology.edges.extend(targets.into_iter().map(|t| (index, t)));
+    }
+
+    for ingress in &ingresses.items {
+        let namespace = ingress.metadata.namespace.clone().unwrap_or_default();
+        let backends = ingress_backends(ingress);
+        let missing: Vec<&String> = backends
+            .iter()
+            .filter(|b| !services_by_name.contains_key(&(namespace.clone(), (*b).clone())))
+            .collect();
+
+        let (status, detail) = if missing.is_empty() {
+            ("Active", format!("{} backend services", backends.len()))
+        } else {
+            (
+                "Missing backend",
+                format!("Backend services not found: {}", missing.iter().map(|s| s.as_str()).collect::<Vec<_>>().join(", ")),
+            )
+        };
+        let index = topology.nodes.len();
+        topology
+            .nodes
+            .push(TopologyNode::new(ResourceKind::Ingress, &ingress.metadata, status, detail, missing.is_empty()));
+        for backend in &backends {
+            if let Some(service) = services_by_name.get(&(namespace.clone(), backend.clone())) {
+                topology.edges.push((index, *service));
+            }
+        }
+    }
+
+    Ok(topology)
+}
@@ -0,0 +1,68 @@
+use anyhow::{anyhow, Result};
+use futures::StreamExt;
+use kube::{
+    api::Api,
+    runtime::{reflector, watcher, WatchStreamExt},
+    Resource, ResourceExt,
+};
+use serde::de::DeserializeOwned;
+use std::fmt::Debug;
+use std::time::Duration;
+
+/// How often the current snapshot is re-sent while the cluster is quiet, so that
+/// derived fields such as `age` keep moving without a watch event.
+const RESYNC_INTERVAL: Duration = Duration::from_secs(30);
+
+/// Keeps a reflector store for `api`, narrowed by the selectors in `config`, in
+/// sync with the cluster and hands every new snapshot (sorted by namespace and
+/// name) to `on_update` after converting each object with `convert`.
+///
+/// Runs until the surrounding task is aborted. Watch errors are reported through
+/// `on_update` and the watcher retries with backoff.
+pub async fn watch_resources<K, T>(
+    api: Api<K>,
+    config: watcher::Config,
+    what: &str,
+    convert: impl Fn(&K) -> T,
+    mut on_update: impl FnMut(Result<Vec<T>>),
+) where
+    K: Resource + Clone + DeserializeOwned + Debug + Send + Sync + 'static,
+    K::DynamicType: Default + Eq + std::hash::Hash + Clone,
+{
+    let (reader, writer) = reflector::store();
+    let mut stream = reflector(writer, watcher(api, config))
+        .default_backoff()
+        .boxed();
+
+    let mut resync = tokio::time::interval(RESYNC_INTERVAL);
+    resync.tick().await;
+
+    let mut ready = false;
+
+    loop {
+        tokio::select! {
+            event = stream.next() => {
+                match event {
+                    Some(Ok(watcher::Event::Init | watcher::Event::InitApply(_))) => continue,
+                    Some(Ok(_)) => ready = true,
+                    Some(Err(e)) => {
+                        on_update(Err(anyhow!("Failed to watch {}: {}", what, e)));
+                        continue;
+                    }
+                    None => return,
+                }
+            }
+            _ = resync.tick() => {
+                if !ready {
+                    continue;
+                }
+            }
+        }
+
+        let mut objects = reader.state();
+        objects.sort_by(|a, b| {
+            (a.namespace(), a.name_any()).cmp(&(b.namespace(), b.name_any()))
+        });
+        on_update(Ok(objects.iter().map(|o| convert(o)).collect()));
+    }
+}
@@ -0,0 +1,482 @@
+use anyhow::{Context, Result};
+use chrono::{DateTime, Utc};
+use k8s_openapi::api::{
+    apps::v1::{ControllerRevision, DaemonSet, ReplicaSet, StatefulSet},
+    core::v1::{Pod, PodTemplateSpec},
+};
+use k8s_openapi::apimachinery::pkg::apis::meta::v1::LabelSelector;
+use kube::{
+    api::{Api, DeleteParams, ListParams, Patch, PatchParams},
+    Client, ResourceExt,
+};
+use serde::Serialize;
+use std::collections::BTreeMap;
+
+use super::resources::format_age;
+use super::selector::Selectors;
+use super::watch::watch_resources;
+
+#[derive(Clone, Debug, Serialize)]
+pub struct StatefulSetInfo {
+    pub name: String,
+    pub namespace: String,
+    pub replicas: i32,
+    pub ready: i32,
+    pub updated: i32,
+    pub current_revision: String,
+    pub update_revision: String,
+    pub service_name: String,
+    pub age: String,
+    pub created: Option<DateTime<Utc>>,
+    pub images: Vec<String>,
+    pub labels: BTreeMap<String, String>,
+    #[serde(skip)]
+    pub annotations: BTreeMap<String, String>,
+}
+
+#[derive(Clone, Debug, Serialize)]
+pub struct DaemonSetInfo {
+    pub name: String,
+    pub namespace: String,
+    pub desired: i32,
+    pub current: i32,
+    pub ready: i32,
+    pub updated: i32,
+    pub available: i32,
+    pub node_selector: BTreeMap<String, String>,
+    pub age: String,
+    pub created: Option<DateTime<Utc>>,
+    pub images: Vec<String>,
+    pub labels: BTreeMap<String, String>,
+    #[serde(skip)]
+    pub annotations: BTreeMap<String, String>,
+}
+
+#[derive(Clone, Debug, Serialize)]
+pub struct ReplicaSetInfo {
+    pub name: String,
+    pub namespace: String,
+    pub replicas: i32,
+    pub ready: i32,
+    pub available: i32,
+    pub owner: Option<String>,
+    pub age: String,
+    pub created: Option<DateTime<Utc>>,
+    pub images: Vec<String>,
+    pub labels: BTreeMap<String, String>,
+    #[serde(skip)]
+    pub annotations: BTreeMap<String, String>,
+}
+
+/// Rollout state of one pod slot of a StatefulSet (by ordinal) or DaemonSet (by node).
+#[derive(Clone, Debug)]
+pub struct RolloutPodStatus {
+    pub slot: String,
+    pub pod: Option<String>,
+    pub status: String,
+    pub ready: bool,
+    pub updated: bool,
+}
+
+pub(super) fn template_images(template: Option<&PodTemplateSpec>) -> Vec<String> {
+    template
+        .and_then(|t| t.spec.as_ref())
+        .map(|ps| ps.containers.iter().map(|c| c.image.clone().unwrap_or_default()).collect())
+        .unwrap_or_default()
+}
+
+pub(super) fn selector_string(selector: &LabelSelector) -> String {
+    selector
+        .match_labels
+        .iter()
+        .flatten()
+        .map(|(k, v)| format!("{}={}", k, v))
+        .collect::<Vec<_>>()
+        .join(",")
+}
+
+fn restart_patch() -> serde_json::Value {
+    serde_json::json!({
+        "spec": {
+            "template": {
+                "metadata": {
+                    "annotations": {
+                        "kubectl.kubernetes.io/restartedAt": Utc::now().to_rfc3339()
+                    }
+                }
+            }
+        }
+    })
+}
+
+fn pod_ready(pod: &Pod) -> bool {
+    pod.status
+        .as_ref()
+        .and_then(|s| s.conditions.as_ref())
+        .is_some_and(|conds| conds.iter().any(|c| c.type_ == "Ready" && c.status == "True"))
+}
+
+fn pod_phase(pod: &Pod) -> String {
+    if pod.metadata.deletion_timestamp.is_some() {
+        return "Terminating".to_string();
+    }
+    pod.status
+        .as_ref()
+        .and_then(|s| s.phase.clone())
+        .unwrap_or_else(|| "Unknown".to_string())
+}
+
+/// Pods matching `selector` that are controlled by the object with `owner_uid`.
+async fn owned_pods(client: &Client, namespace: &str, selector: Option<&LabelSelector>, owner_uid: Option<&str>) -> Result<Vec<Pod>> {
+    let pods: Api<Pod> = Api::namespaced(client.clone(), namespace);
+    let labels = selector.map(selector_string).unwrap_or_default();
+
+    let list = pods
+        .list(&ListParams::default().labels(&labels))
+        .await
+        .context("Failed to list pods")?;
+
+    Ok(list
+        .items
+        .into_iter()
+        .filter(|p| p.owner_references().iter().any(|o| Some(o.uid.as_str()) == owner_uid))
+        .collect())
+}
+
+// StatefulSet operations
+
+fn statefulset_info(s: &StatefulSet) -> StatefulSetInfo {
+    let spec = s.spec.as_ref();
+    let status = s.status.as_ref();
+    let meta = &s.metadata;
+
+    StatefulSetInfo {
+        name: meta.name.clone().unwrap_or_default(),
+        namespace: meta.namespace.clone().unwrap_or_default(),
+        replicas: spec.and_then(|s| s.replicas).unwrap_or(1),
+        ready: status.and_then(|s| s.ready_replicas).unwrap_or(0),
+        updated: status.and_then(|s| s.updated_replicas).unwrap_or(0),
+        current_revision: status.and_then(|s| s.current_revision.clone()).unwrap_or_default(),
+        update_revision: status.and_then(|s| s.update_revision.clone()).unwrap_or_default(),
+        service_name: spec.map(|s| s.service_name.clone()).unwrap_or_default(),
+        age: format_age(meta.creation_timestamp.as_ref()),
+        created: meta.creation_timestamp.as_ref().map(|t| t.0),
+        images: template_images(spec.map(|s| &s.template)),
+        labels: meta.labels.clone().unwrap_or_default(),
+        annotations: meta.annotations.clone().unwrap_or_default(),
+    }
+}
+
+pub async fn list_statefulsets(client: &Client, namespace: Option<&str>, selectors: &Selectors) -> Result<Vec<StatefulSetInfo>> {
+    let statefulsets: Api<StatefulSet> = match namespace {
+        Some(ns) => Api::namespaced(client.clone(), ns),
+        None => Api::all(client.clone()),
+    };
+
+    let list = statefulsets
+        .list(&selectors.list_params())
+        .await
+        .context("Failed to list statefulsets")?;
+
+    Ok(list.items.iter().map(statefulset_info).collect())
+}
+
+pub async fn watch_statefulsets(client: &Client, namespace: Option<&str>, selectors: &Selectors, on_update: impl FnMut(Result<Vec<StatefulSetInfo>>)) {
+    let statefulsets: Api<StatefulSet> = match namespace {
+        Some(ns) => Api::namespaced(client.clone(), ns),
+        None => Api::all(client.clone()),
+    };
+
+    watch_resources(statefulsets, selectors.watcher_config(), "statefulsets", statefulset_info, on_update).await;
+}
+
+pub async fn scale_statefulset(client: &Client, namespace: &str, name: &str, replicas: i32) -> Result<()> {
+    let statefulsets: Api<StatefulSet> = Api::namespaced(client.clone(), namespace);
+
+    let patch = serde_json::json!({
+        "spec": {
+            "replicas": replicas
+        }
+    });
+
+    statefulsets
+        .patch(name, &PatchParams::default(), &Patch::Merge(&patch))
+        .await
+        .context("Failed to scale statefulset")?;
+
+    Ok(())
+}
+
+pub async fn restart_statefulset(client: &Client, namespace: &str, name: &str) -> Result<()> {
+    let statefulsets: Api<StatefulSet> = Api::namespaced(client.clone(), namespace);
+
+    statefulsets
+        .patch(name, &PatchParams::default(), &Patch::Merge(&restart_patch()))
+        .await
+        .context("Failed to restart statefulset")?;
+
+    Ok(())
+}
+
+pub async fn delete_statefulset(client: &Client, namespace: &str, name: &str) -> Result<()> {
+    let statefulsets: Api<StatefulSet> = Api::namespaced(client.clone(), namespace);
+    statefulsets
+        .delete(name, &DeleteParams::default())
+        .await
+        .context("Failed to delete statefulset")?;
+    Ok(())
+}
+
+/// Rollout state of every ordinal `0..replicas`. A pod is updated when its
+/// `controller-revision-hash` matches the StatefulSet's update revision.
+pub async fn get_statefulset_rollout(client: &Client, namespace: &str, name: &str) -> Result<Vec<RolloutPodStatus>> {
+    let statefulsets: Api<StatefulSet> = Api::namespaced(client.clone(), namespace);
+    let sts = statefulsets.get(name).await.context("Failed to get statefulset")?;
+
+    let spec = sts.spec.as_ref();
+    let replicas = spec.and_then(|s| s.replicas).unwrap_or(1).max(0) as usize;
+    let update_revision = sts.status.as_ref().and_then(|s| s.update_revision.clone()).unwrap_or_default();
+
+    let pods = owned_pods(client, namespace, spec.map(|s| &s.selector), sts.metadata.uid.as_deref()).await?;
+
+    let prefix = format!("{}-", name);
+    let mut by_ordinal: BTreeMap<usize, Pod> = pods
+        .into_iter()
+        .filter_map(|p| {
+            let ordinal = p.name_any().strip_prefix(&prefix)?.parse().ok()?;
+            Some((ordinal, p))
+        })
+        .collect();
+
+    let slots = replicas.max(by_ordinal.keys().next_back().map_or(0, |o| o + 1));
+    Ok((0..slots)
+        .map(|ordinal| match by_ordinal.remove(&ordinal) {
+            Some(pod) => RolloutPodStatus {
+                slot: ordinal.to_string(),
+                pod: Some(pod.name_any()),
+                status: pod_phase(&pod),
+                ready: pod_ready(&pod),
+                updated: pod.labels().get("controller-revision-hash") == Some(&update_revision),
+            },
+            None => RolloutPodStatus {
+                slot: ordinal.to_string(),
+                pod: None,
+                status: "Missing".to_string(),
+                ready: false,
+                updated: false,
+            },
+        })
+        .collect())
+}
+
+// DaemonSet operations
+
+fn daemonset_info(d: &DaemonSet) -> DaemonSetInfo {
+    let spec = d.spec.as_ref();
+    let status = d.status.as_ref();
+    let meta = &d.metadata;
+
+    DaemonSetInfo {
+        name: meta.name.clone().unwrap_or_default(),
+        namespace: meta.namespace.clone().unwrap_or_default(),
+        desired: status.map(|s| s.desired_number_scheduled).unwrap_or(0),
+        current: status.map(|s| s.current_number_scheduled).unwrap_or(0),
+        ready: status.map(|s| s.number_ready).unwrap_or(0),
+        updated: status.and_then(|s| s.updated_number_scheduled).unwrap_or(0),
+        available: status.and_then(|s| s.number_available).unwrap_or(0),
+        node_selector: spec
+            .and_then(|s| s.template.spec.as_ref())
+            .and_then(|ps| ps.node_selector.clone())
+            .unwrap_or_default(),
+        age: format_age(meta.creation_timestamp.as_ref()),
+        created: meta.creation_timestamp.as_ref().map(|t| t.0),
+        images: template_images(spec.map(|s| &s.template)),
+        labels: meta.labels.clone().unwrap_or_default(),
+        annotations: meta.annotations.clone().unwrap_or_default(),
+    }
+}
+
+pub async fn list_daemonsets(client: &Client, namespace: Option<&str>, selectors: &Selectors) -> Result<Vec<DaemonSetInfo>> {
+    let daemonsets: Api<DaemonSet> = match namespace {
+        Some(ns) => Api::namespaced(client.clone(), ns),
+        None => Api::all(client.clone()),
+    };
+
+    let list = daemonsets
+        .list(&selectors.list_params())
+        .await
+        .context("Failed to list daemonsets")?;
+
+    Ok(list.items.iter().map(daemonset_info).collect())
+}
+
+pub async fn watch_daemonsets(client: &Client, namespace: Option<&str>, selectors: &Selectors, on_update: impl FnMut(Result<Vec<DaemonSetInfo>>)) {
+    let daemonsets: Api<DaemonSet> = match namespace {
+        Some(ns) => Api::namespaced(client.clone(), ns),
+        None => Api::all(client.clone()),
+    };
+
+    watch_resources(daemonsets, selectors.watcher_config(), "daemonsets", daemonset_info, on_update).await;
+}
+
+pub async fn restart_daemonset(client: &Client, namespace: &str, name: &str) -> Result<()> {
+    let daemonsets: Api<DaemonSet> = Api::namespaced(client.clone(), namespace);
+
+    daemonsets
+        .patch(name, &PatchParams::default(), &Patch::Merge(&restart_patch()))
+        .await
+        .context("Failed to restart daemonset")?;
+
+    Ok(())
+}
+
+pub async fn delete_daemonset(client: &Client, namespace: &str, name: &str) -> Result<()> {
+    let daemonsets: Api<DaemonSet> = Api::namespaced(client.clone(), namespace);
+    daemonsets
+        .delete(name, &DeleteParams::default())
+        .await
+        .context("Failed to delete daemonset")?;
+    Ok(())
+}
+
+/// Hash of the DaemonSet's newest ControllerRevision, which its up-to-date pods
+/// carry as their `controller-revision-hash` label.
+async fn daemonset_update_hash(client: &Client, namespace: &str, ds: &DaemonSet) -> Result<Option<String>> {
+    let revisions: Api<ControllerRevision> = Api::namespaced(client.clone(), namespace);
+    let labels = ds.spec.as_ref().map(|s| selector_string(&s.selector)).unwrap_or_default();
+
+    let list = revisions
+        .list(&ListParams::default().labels(&labels))
+        .await
+        .context("Failed to list controller revisions")?;
+
+    let uid = ds.metadata.uid.as_deref();
+    Ok(list
+        .items
+        .iter()
+        .filter(|r| r.owner_references().iter().any(|o| Some(o.uid.as_str()) == uid))
+        .max_by_key(|r| r.revision)
+        .and_then(|r| r.labels().get("controller-revision-hash").cloned()))
+}
+
+/// Rollout state of the DaemonSet's pod on each node, sorted by node. A pod is
+/// updated when its `controller-revision-hash` label matches the DaemonSet's newest
+/// revision.
+pub async fn get_daemonset_rollout(client: &Client, namespace: &str, name: &str) -> Result<Vec<RolloutPodStatus>> {
+    let daemonsets: Api<DaemonSet> = Api::namespaced(client.clone(), namespace);
+    let ds = daemonsets.get(name).await.context("Failed to get daemonset")?;
+
+    let update_hash = daemonset_update_hash(client, namespace, &ds).await?;
+    let pods = owned_pods(client, namespace, ds.spec.as_ref().map(|s| &s.selector), ds.metadata.uid.as_deref()).await?;
+
+    let mut slots: Vec<RolloutPodStatus> = pods
+        .iter()
+        .map(|pod| RolloutPodStatus {
+            slot: pod
+                .spec
+                .as_ref()
+                .and_then(|s| s.node_name.clone())
+                .unwrap_or_else(|| "<unscheduled>".to_string()),
+            pod: Some(pod.name_any()),
+            status: pod_phase(pod),
+            ready: pod_ready(pod),
+            updated: update_hash.is_some() && pod.labels().get("controller-revision-hash") == update_hash.as_ref(),
+        })
+        .collect();
+    slots.sort_by(|a, b| a.slot.cmp(&b.slot));
+
+    Ok(slots)
+}
+
+// ReplicaSet operations
+
+fn replicaset_info(r: &ReplicaSet) -> ReplicaSetInfo {
+    let spec = r.spec.as_ref();
+    let status = r.status.as_ref();
+    let meta = &r.metadata;
+
+    let owner = meta
+        .owner_references
+        .as_ref()
+        .and_then(|refs| refs.first())
+        .map(|o| format!("{}/{}", o.kind, o.name));
+
+    ReplicaSetInfo {
+        name: meta.name.clone().unwrap_or_default(),
+        namespace: meta.namespace.clone().unwrap_or_default(),
+        replicas: spec.and_then(|s| s.replicas).unwrap_or(1),
+        ready: status.and_then(|s| s.ready_replicas).unwrap_or(0),
+        available: status.and_then(|s| s.available_replicas).unwrap_or(0),
+        owner,
+        age: format_age(meta.creation_timestamp.as_ref()),
+        created: meta.creation_timestamp.as_ref().map(|t| t.0),
+        images: template_images(spec.and_then(|s| s.template.as_ref())),
+        labels: meta.labels.clone().unwrap_or_default(),
+        annotations: meta.annotations.clone().unwrap_or_default(),
+    }
+}
+
+pub async fn list_replicasets(client: &Client, namespace: Option<&str>, selectors: &Selectors) -> Result<Vec<ReplicaSetInfo>> {
+    let replicasets: Api<ReplicaSet> = match namespace {
+        Some(ns) => Api::namespaced(client.clone(), ns),
+        None => Api::all(client.clone()),
+    };
+
+    let list = replicasets
+        .list(&selectors.list_params())
+        .await
+        .context("Failed to list replicasets")?;
+
+    Ok(list.items.iter().map(replicaset_info).collect())
+}
+
+pub async fn watch_replicasets(client: &Client, namespace: Option<&str>, selectors: &Selectors, on_update: impl FnMut(Result<Vec<ReplicaSetInfo>>)) {
+    let replicasets: Api<ReplicaSet> = match namespace {
+        Some(ns) => Api::namespaced(client.clone(), ns),
+        None => Api::all(client.clone()),
+    };
+
+    watch_resources(replicasets, selectors.watcher_config(), "replicasets", replicaset_info, on_update).await;
+}
+
+pub async fn scale_replicaset(client: &Client, namespace: &str, name: &str, replicas: i32) -> Result<()> {
+    let replicasets: Api<ReplicaSet> = Api::namespaced(client.clone(), namespace);
+
+    let patch = serde_json::json!({
+        "spec": {
+            "replicas": replicas
+        }
+    });
+
+    replicasets
+        .patch(name, &PatchParams::default(), &Patch::Merge(&patch))
+        .await
+        .context("Failed to scale replicaset")?;
+
+    Ok(())
+}
+
+pub async fn delete_replicaset(client: &Client, namespace: &str, name: &str) -> Result<()> {
+    let replicasets: Api<ReplicaSet> = Api::namespaced(client.clone(), namespace);
+    replicasets
+        .delete(name, &DeleteParams::default())
+        .await
+        .context("Failed to delete replicaset")?;
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::k8s::fake::FakeApiServer;
+
+    #[tokio::test]
+    async fn daemonset_rollout_compares_pods_with_the_newest_revision() {
+        let server = FakeApiServer::start(&["daemonsets.yaml"]).await;
+
+        let slots = get_daemonset_rollout(&server.client(), "default", "log-agent").await.unwrap();
+
+        let updated: Vec<_> = slots.iter().map(|s| (s.slot.as_str(), s.updated)).collect();
+        assert_eq!(updated, [("node-1", true), ("node-2", false)]);
+    }
+}
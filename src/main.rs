@@ -1,8 +1,13 @@
 mod app;
+mod audit;
+mod cli;
+mod format;
 mod k8s;
+mod settings;
 mod views;
 
 use app::KubeDashboard;
+use clap::Parser;
 use eframe::egui;
 
 fn main() -> eframe::Result<()> {
@@ -11,19 +16,31 @@ fn main() -> eframe::Result<()> {
             tracing_subscriber::EnvFilter::from_default_env()
                 .add_directive(tracing::Level::INFO.into()),
         )
+        // Keep stdout clean for `get` output
+        .with_writer(std::io::stderr)
         .init();
 
+    if let Some(command) = cli::Cli::parse().command {
+        std::process::exit(cli::run(command));
+    }
+
+    let settings = settings::Settings::load();
+
+    let mut viewport = egui::ViewportBuilder::default()
+        .with_inner_size(settings.window.size)
+        .with_min_inner_size([800.0, 600.0])
+        .with_title("Kubectl Dashboard");
+    if let Some(position) = settings.window.position {
+        viewport = viewport.with_position(position);
+    }
     let options = eframe::NativeOptions {
-        viewport: egui::ViewportBuilder::default()
-            .with_inner_size([1400.0, 900.0])
-            .with_min_inner_size([800.0, 600.0])
-            .with_title("Kubectl Dashboard"),
+        viewport,
         ..Default::default()
     };
 
     eframe::run_native(
         "Kubectl Dashboard",
         options,
-        Box::new(|cc| Ok(Box::new(KubeDashboard::new(cc)))),
+        Box::new(|cc| Ok(Box::new(KubeDashboard::new(cc, settings)))),
     )
 }
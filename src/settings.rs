@@ -0,0 +1,203 @@
+use crate::app::View;
+use crate::views::columns::TableLayout;
+use crate::views::safety::ContextProfile;
+use anyhow::{Context, Result};
+use serde::{Deserialize, Serialize};
+use std::collections::BTreeMap;
+use std::path::PathBuf;
+
+const DEFAULT_WINDOW_SIZE: [f32; 2] = [1400.0, 900.0];
+
+/// Preferences and session state restored on the next launch, stored as
+/// `kubectl-dashboard/settings.toml` under the XDG config directory.
+///
+/// Missing fields fall back to their defaults, so older files keep loading as
+/// settings are added.
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct Settings {
+    pub last_context: Option<String>,
+    /// Last selected namespace per context; `None` means all namespaces.
+    #[serde(with = "namespace_map")]
+    pub namespaces: BTreeMap<String, Option<String>>,
+    pub view: View,
+    pub dark_mode: bool,
+    /// Search box contents per view; empty filters are not stored.
+    pub search_filters: BTreeMap<View, String>,
+    pub log_tail_lines: i64,
+    pub window: WindowGeometry,
+    /// Hidden columns, sort order and label/annotation columns per table;
+    /// tables left as they come are not stored.
+    pub tables: BTreeMap<String, TableLayout>,
+    /// Safety profile per context; normal contexts are not stored.
+    pub context_profiles: BTreeMap<String, ContextProfile>,
+}
+
+#[derive(Clone, Copy, Debug, PartialEq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct WindowGeometry {
+    /// Outer position in logical points; `None` lets the window manager choose.
+    pub position: Option<[f32; 2]>,
+    /// Inner size in logical points.
+    pub size: [f32; 2],
+}
+
+/// TOML has no null, so "all namespaces" is stored as an empty namespace name,
+/// which Kubernetes never allows.
+mod namespace_map {
+    use serde::{Deserialize, Deserializer, Serialize, Serializer};
+    use std::collections::BTreeMap;
+
+    pub fn serialize<S: Serializer>(namespaces: &BTreeMap<String, Option<String>>, serializer: S) -> Result<S::Ok, S::Error> {
+        namespaces
+            .iter()
+            .map(|(context, namespace)| (context, namespace.as_deref().unwrap_or("")))
+            .collect::<BTreeMap<_, _>>()
+            .serialize(serializer)
+    }
+
+    pub fn deserialize<'de, D: Deserializer<'de>>(deserializer: D) -> Result<BTreeMap<String, Option<String>>, D::Error> {
+        let namespaces = BTreeMap::<String, String>::deserialize(deserializer)?;
+        Ok(namespaces
+            .into_iter()
+            .map(|(context, namespace)| (context, Some(namespace).filter(|ns| !ns.is_empty())))
+            .collect())
+    }
+}
+
+impl Default for Settings {
+    fn default() -> Self {
+        Self {
+            last_context: None,
+            namespaces: BTreeMap::new(),
+            view: View::default(),
+            dark_mode: true,
+            search_filters: BTreeMap::new(),
+            log_tail_lines: 100,
+            window: WindowGeometry::default(),
+            tables: BTreeMap::new(),
+            context_profiles: BTreeMap::new(),
+        }
+    }
+}
+
+impl Default for WindowGeometry {
+    fn default() -> Self {
+        Self {
+            position: None,
+            size: DEFAULT_WINDOW_SIZE,
+        }
+    }
+}
+
+impl Settings {
+    /// `$XDG_CONFIG_HOME/kubectl-dashboard/settings.toml`, falling back to `~/.config`.
+    pub fn path() -> Option<PathBuf> {
+        let config_dir = std::env::var_os("XDG_CONFIG_HOME")
+            .filter(|dir| !dir.is_empty())
+            .map(PathBuf::from)
+            .or_else(|| std::env::var_os("HOME").map(|home| PathBuf::from(home).join(".config")))?;
+        Some(config_dir.join("kubectl-dashboard").join("settings.toml"))
+    }
+
+    /// Reads the settings file, starting from defaults when it is missing or unreadable.
+    pub fn load() -> Self {
+        let Some(path) = Self::path() else {
+            return Self::default();
+        };
+        let text = match std::fs::read_to_string(&path) {
+            Ok(text) => text,
+            Err(e) if e.kind() == std::io::ErrorKind::NotFound => return Self::default(),
+            Err(e) => {
+                tracing::warn!("Failed to read settings from {:?}: {}", path, e);
+                return Self::default();
+            }
+        };
+        match toml::from_str(&text) {
+            Ok(settings) => settings,
+            Err(e) => {
+                tracing::warn!("Ignoring invalid settings in {:?}: {}", path, e);
+                Self::default()
+            }
+        }
+    }
+
+    /// Writes the settings file, replacing it atomically so a crash mid-write cannot
+    /// leave a truncated file behind.
+    pub fn save(&self) -> Result<()> {
+        let path = Self::path().context("No config directory: neither XDG_CONFIG_HOME nor HOME is set")?;
+        if let Some(dir) = path.parent() {
+            std::fs::create_dir_all(dir).with_context(|| format!("Failed to create {:?}", dir))?;
+        }
+
+        let text = toml::to_string(self).context("Failed to serialize settings")?;
+        let tmp = path.with_extension("toml.tmp");
+        std::fs::write(&tmp, text).with_context(|| format!("Failed to write {:?}", tmp))?;
+        std::fs::rename(&tmp, &path).with_context(|| format!("Failed to replace {:?}", path))?;
+        Ok(())
+    }
+
+    /// The remembered namespace for `context`: `None` if nothing is remembered,
+    /// `Some(None)` for all namespaces.
+    pub fn namespace_for(&self, context: &str) -> Option<Option<String>> {
+        self.namespaces.get(context).cloned()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::views::columns::{ExtraColumn, SortBy};
+
+    #[test]
+    fn partial_file_keeps_defaults_for_missing_fields() {
+        let text = r#"
+last_context = "prod"
+view = "Pods"
+
+[namespaces]
+prod = ""
+
+[search_filters]
+Pods = "web"
+"#;
+        let settings: Settings = toml::from_str(text).unwrap();
+
+        assert_eq!(settings.last_context.as_deref(), Some("prod"));
+        assert_eq!(settings.view, View::Pods);
+        assert_eq!(settings.namespace_for("prod"), Some(None));
+        assert_eq!(settings.search_filters.get(&View::Pods).map(String::as_str), Some("web"));
+        assert!(settings.dark_mode);
+        assert_eq!(settings.log_tail_lines, 100);
+        assert_eq!(settings.window, WindowGeometry::default());
+    }
+
+    #[test]
+    fn round_trips_through_toml() {
+        let mut settings = Settings {
+            last_context: Some("staging".to_string()),
+            view: View::CronJobs,
+            dark_mode: false,
+            log_tail_lines: 500,
+            window: WindowGeometry { position: Some([40.0, 60.0]), size: [1200.0, 800.0] },
+            ..Default::default()
+        };
+        settings.namespaces.insert("staging".to_string(), Some("payments".to_string()));
+        settings.namespaces.insert("prod".to_string(), None);
+        settings.tables.insert(
+            "pods".to_string(),
+            TableLayout {
+                hidden: ["Node".to_string()].into(),
+                sort: Some(SortBy { column: "Restarts".to_string(), descending: true }),
+                extra: vec![ExtraColumn::Label("app".to_string())],
+            },
+        );
+        settings.context_profiles.insert("prod".to_string(), ContextProfile::ReadOnly);
+
+        let text = toml::to_string(&settings).unwrap();
+
+        assert_eq!(toml::from_str::<Settings>(&text).unwrap(), settings);
+        assert_eq!(settings.namespace_for("prod"), Some(None));
+        assert_eq!(settings.namespace_for("dev"), None);
+    }
+}
@@ -0,0 +1,136 @@
+use crate::audit::{AuditEntry, AuditResult};
+use crate::views::common::*;
+use egui::{RichText, Ui};
+use egui_extras::{Column, TableBuilder};
+use std::path::Path;
+
+/// Changes made from this dashboard, newest first, read from the local audit log.
+#[derive(Default)]
+pub struct AuditView {
+    pub search_filter: String,
+    pub context_filter: Option<String>,
+    pub failures_only: bool,
+}
+
+impl AuditView {
+    pub fn show(&mut self, ui: &mut Ui, entries: &[AuditEntry], log_path: Option<&Path>) {
+        let mut contexts: Vec<String> = entries.iter().filter_map(|e| e.context.clone()).collect();
+        contexts.sort();
+        contexts.dedup();
+
+        ui.horizontal(|ui| {
+            section_header(ui, "Audit Log");
+            ui.with_layout(egui::Layout::right_to_left(egui::Align::Center), |ui| {
+                search_bar(ui, &mut self.search_filter, "Search changes...");
+                egui::ComboBox::from_id_salt("audit_context_filter")
+                    .selected_text(self.context_filter.as_deref().unwrap_or("All contexts"))
+                    .show_ui(ui, |ui| {
+                        ui.selectable_value(&mut self.context_filter, None, "All contexts");
+                        for context in &contexts {
+                            ui.selectable_value(&mut self.context_filter, Some(context.clone()), context);
+                        }
+                    });
+                ui.checkbox(&mut self.failures_only, "Failures only");
+            });
+        });
+        match log_path {
+            Some(path) => {
+                let path = path.display().to_string();
+                ui.horizontal(|ui| {
+                    ui.label(RichText::new("Recorded in").weak());
+                    if ui.link(&path).on_hover_text("Copy path").clicked() {
+                        ui.ctx().copy_text(path.clone());
+                    }
+                });
+            }
+            None => {
+                error_label(ui, "No state directory: neither XDG_STATE_HOME nor HOME is set, so changes are not recorded");
+            }
+        }
+
+        let search = self.search_filter.to_lowercase();
+        let filtered: Vec<_> = entries
+            .iter()
+            .rev()
+            .filter(|e| self.context_filter.is_none() || e.context == self.context_filter)
+            .filter(|e| !self.failures_only || matches!(e.result, AuditResult::Failed(_)))
+            .filter(|e| {
+                search.is_empty()
+                    || e.name.to_lowercase().contains(&search)
+                    || e.namespace.to_lowercase().contains(&search)
+                    || e.kind.to_lowercase().contains(&search)
+                    || e.action.to_lowercase().contains(&search)
+                    || e.user.to_lowercase().contains(&search)
+            })
+            .collect();
+
+        if filtered.is_empty() {
+            empty_state(ui, "No changes recorded");
+            return;
+        }
+
+        let available_height = ui.available_height();
+
+        TableBuilder::new(ui)
+            .striped(true)
+            .resizable(true)
+            .cell_layout(egui::Layout::left_to_right(egui::Align::Center))
+            .column(Column::auto().at_least(140.0)) // Time
+            .column(Column::auto().at_least(80.0))  // User
+            .column(Column::auto().at_least(120.0)) // Context
+            .column(Column::auto().at_least(220.0)) // Object
+            .column(Column::auto().at_least(100.0)) // Namespace
+            .column(Column::auto().at_least(90.0))  // Action
+            .column(Column::auto().at_least(80.0))  // Result
+            .column(Column::remainder().at_least(240.0)) // Patch
+            .min_scrolled_height(0.0)
+            .max_scroll_height(available_height - 50.0)
+            .header(25.0, |mut header| {
+                header.col(|ui| { ui.strong("Time"); });
+                header.col(|ui| { ui.strong("User"); });
+                header.col(|ui| { ui.strong("Context"); });
+                header.col(|ui| { ui.strong("Object"); });
+                header.col(|ui| { ui.strong("Namespace"); });
+                header.col(|ui| { ui.strong("Action"); });
+                header.col(|ui| { ui.strong("Result"); });
+                header.col(|ui| { ui.strong("Patch"); });
+            })
+            .body(|mut body| {
+                for entry in &filtered {
+                    body.row(30.0, |mut row| {
+                        row.col(|ui| {
+                            let local = entry.timestamp.with_timezone(&chrono::Local);
+                            ui.label(local.format("%Y-%m-%d %H:%M:%S").to_string());
+                        });
+                        row.col(|ui| { ui.label(&entry.user); });
+                        row.col(|ui| { ui.label(entry.context.as_deref().unwrap_or("-")); });
+                        row.col(|ui| { ui.label(format!("{}/{}", entry.kind, entry.name)); });
+                        row.col(|ui| {
+                            ui.label(if entry.namespace.is_empty() { "-" } else { entry.namespace.as_str() });
+                        });
+                        row.col(|ui| { ui.label(&entry.action); });
+                        row.col(|ui| match &entry.result {
+                            AuditResult::Succeeded => status_badge(ui, "Succeeded", status_color("succeeded")),
+                            AuditResult::Failed(e) => {
+                                status_badge(ui, "Failed", status_color("failed"));
+                                ui.label(RichText::new("ⓘ").weak()).on_hover_text(e);
+                            }
+                        });
+                        row.col(|ui| match &entry.patch {
+                            Some(patch) => {
+                                let pretty = serde_json::to_string_pretty(patch).unwrap_or_default();
+                                let text = RichText::new(truncate_string(&patch.to_string(), 80)).monospace();
+                                let hint = format!("{}\n\nClick to copy", pretty);
+                                if ui.add(egui::Label::new(text).sense(egui::Sense::click())).on_hover_text(hint).clicked() {
+                                    ui.ctx().copy_text(pretty);
+                                }
+                            }
+                            None => {
+                                ui.label(RichText::new("-").weak());
+                            }
+                        });
+                    });
+                }
+            });
+    }
+}
@@ -0,0 +1,206 @@
+use crate::k8s::BulkItemResult;
+use egui::{Color32, RichText, ScrollArea, Ui};
+use std::collections::BTreeSet;
+
+/// Checkbox selection of table rows. Rows are keyed by namespace and name, so the
+/// selection survives reloads and re-sorting; `rows` passed to the methods below
+/// are the visible rows in display order.
+#[derive(Default)]
+pub struct RowSelection {
+    selected: BTreeSet<(String, String)>,
+    /// Row last clicked without shift, where a shift-click range starts. Kept by key
+    /// so the range still starts there after the rows are re-sorted or reloaded.
+    anchor: Option<(String, String)>,
+}
+
+impl RowSelection {
+    /// Draws the checkbox of row `index`; shift-click selects every row from the
+    /// previous click to this one.
+    pub fn checkbox(&mut self, ui: &mut Ui, rows: &[(&str, &str)], index: usize) {
+        let (namespace, name) = rows[index];
+        let mut checked = self.contains(namespace, name);
+        if ui.add(egui::Checkbox::without_text(&mut checked)).changed() {
+            let shift = ui.input(|i| i.modifiers.shift);
+            self.click(rows, index, shift);
+        }
+    }
+
+    /// Checkbox for the header: selects all visible rows, or clears them when all
+    /// are already selected.
+    pub fn header_checkbox(&mut self, ui: &mut Ui, rows: &[(&str, &str)]) {
+        let selected = rows.iter().filter(|(ns, name)| self.contains(ns, name)).count();
+        let mut all = !rows.is_empty() && selected == rows.len();
+        let indeterminate = selected > 0 && !all;
+        let response = ui
+            .add(egui::Checkbox::without_text(&mut all).indeterminate(indeterminate))
+            .on_hover_text("Select all shown");
+        if response.changed() {
+            for (ns, name) in rows {
+                self.set(ns, name, all);
+            }
+            self.anchor = None;
+        }
+    }
+
+    fn click(&mut self, rows: &[(&str, &str)], index: usize, shift: bool) {
+        let anchor = self
+            .anchor
+            .as_ref()
+            .filter(|_| shift)
+            .and_then(|(anchor_ns, anchor_name)| rows.iter().position(|(ns, name)| ns == anchor_ns && name == anchor_name));
+        match anchor {
+            Some(anchor) => {
+                for &(ns, name) in &rows[anchor.min(index)..=anchor.max(index)] {
+                    self.set(ns, name, true);
+                }
+            }
+            None => {
+                let (ns, name) = rows[index];
+                self.set(ns, name, !self.contains(ns, name));
+                self.anchor = Some((ns.to_string(), name.to_string()));
+            }
+        }
+    }
+
+    fn set(&mut self, namespace: &str, name: &str, selected: bool) {
+        let key = (namespace.to_string(), name.to_string());
+        if selected {
+            self.selected.insert(key);
+        } else {
+            self.selected.remove(&key);
+        }
+    }
+
+    pub fn contains(&self, namespace: &str, name: &str) -> bool {
+        self.selected.contains(&(namespace.to_string(), name.to_string()))
+    }
+
+    /// The selected rows that are still shown, in display order. Rows hidden by
+    /// the search or gone since selecting them are left out.
+    pub fn targets(&self, rows: &[(&str, &str)]) -> Vec<(String, String)> {
+        rows.iter()
+            .filter(|(ns, name)| self.contains(ns, name))
+            .map(|(ns, name)| (ns.to_string(), name.to_string()))
+            .collect()
+    }
+
+    pub fn clear(&mut self) {
+        self.selected.clear();
+        self.anchor = None;
+    }
+
+    /// While rows are selected, shows how many, the bulk actions drawn by
+    /// `actions` for them and a button to clear the selection.
+    pub fn bar(&mut self, ui: &mut Ui, rows: &[(&str, &str)], actions: impl FnOnce(&mut Ui, &[(String, String)])) {
+        let targets = self.targets(rows);
+        if targets.is_empty() {
+            return;
+        }
+        ui.horizontal(|ui| {
+            ui.label(RichText::new(format!("{} selected", targets.len())).strong());
+            actions(ui, &targets);
+            if ui.small_button("Clear").clicked() {
+                self.clear();
+            }
+        });
+    }
+}
+
+/// Per-object results of one bulk action, shown in a window until dismissed.
+pub struct BulkSummary {
+    title: String,
+    results: Vec<BulkItemResult>,
+}
+
+impl BulkSummary {
+    pub fn new(title: String, results: Vec<BulkItemResult>) -> Self {
+        Self { title, results }
+    }
+
+    /// "Deleted pods: 9 of 10 succeeded", for the notification.
+    pub fn headline(&self) -> String {
+        format!("{}: {} of {} succeeded", self.title, self.results.len() - self.failures(), self.results.len())
+    }
+
+    pub fn failures(&self) -> usize {
+        self.results.iter().filter(|r| r.result.is_err()).count()
+    }
+
+    /// Returns false once the window is closed.
+    pub fn show(&self, ctx: &egui::Context) -> bool {
+        let mut open = true;
+        egui::Window::new(&self.title)
+            .id(egui::Id::new("bulk_summary"))
+            .open(&mut open)
+            .resizable(true)
+            .default_width(520.0)
+            .show(ctx, |ui| {
+                ui.label(self.headline());
+                ui.separator();
+                ScrollArea::vertical().max_height(360.0).show(ui, |ui| {
+                    for item in &self.results {
+                        ui.horizontal(|ui| match &item.result {
+                            Ok(()) => {
+                                ui.label(RichText::new("✔").color(Color32::from_rgb(34, 197, 94)));
+                                ui.label(format!("{}/{}", item.namespace, item.name));
+                            }
+                            Err(e) => {
+                                ui.label(RichText::new("✖").color(Color32::from_rgb(239, 68, 68)));
+                                ui.label(format!("{}/{}", item.namespace, item.name));
+                                ui.label(RichText::new(e).color(Color32::from_rgb(239, 68, 68)));
+                            }
+                        });
+                    }
+                });
+            });
+        open
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const ROWS: [(&str, &str); 4] = [("default", "a"), ("default", "b"), ("default", "c"), ("prod", "d")];
+
+    fn names(selection: &RowSelection) -> Vec<String> {
+        selection.targets(&ROWS).into_iter().map(|(_, name)| name).collect()
+    }
+
+    #[test]
+    fn click_toggles_and_shift_click_selects_a_range() {
+        let mut selection = RowSelection::default();
+        selection.click(&ROWS, 1, false);
+        selection.click(&ROWS, 3, true);
+        assert_eq!(names(&selection), ["b", "c", "d"]);
+
+        selection.click(&ROWS, 2, false);
+        assert_eq!(names(&selection), ["b", "d"]);
+
+        selection.click(&ROWS, 0, true);
+        assert_eq!(names(&selection), ["a", "b", "c", "d"]);
+    }
+
+    #[test]
+    fn shift_click_range_starts_at_the_anchor_row_after_re_sorting() {
+        let mut selection = RowSelection::default();
+        selection.click(&ROWS, 1, false);
+
+        let resorted = [ROWS[3], ROWS[2], ROWS[1], ROWS[0]];
+        selection.click(&resorted, 0, true);
+        assert_eq!(names(&selection), ["b", "c", "d"]);
+
+        // Once the anchor row is gone, shift-click toggles like a plain click
+        selection.click(&ROWS[2..], 1, true);
+        assert_eq!(names(&selection), ["b", "c"]);
+    }
+
+    #[test]
+    fn targets_skip_rows_no_longer_shown() {
+        let mut selection = RowSelection::default();
+        selection.click(&ROWS, 0, false);
+        selection.click(&ROWS, 3, false);
+
+        assert_eq!(selection.targets(&ROWS[1..]), [("prod".to_string(), "d".to_string())]);
+    }
+}
@@ -0,0 +1,326 @@
+use crate::views::common::truncate_string;
+use chrono::{DateTime, Utc};
+use egui::{RichText, Ui};
+use egui_extras::{Column, TableBuilder, TableRow};
+use serde::{Deserialize, Serialize};
+use std::cmp::Ordering;
+use std::collections::{BTreeMap, BTreeSet};
+
+/// Value a row is sorted by for one column.
+pub enum SortKey {
+    Text(String),
+    Number(f64),
+    /// Creation time; a smaller age means created later.
+    Age(Option<DateTime<Utc>>),
+}
+
+impl SortKey {
+    fn compare(&self, other: &SortKey) -> Ordering {
+        match (self, other) {
+            (SortKey::Text(a), SortKey::Text(b)) => a.to_lowercase().cmp(&b.to_lowercase()),
+            (SortKey::Number(a), SortKey::Number(b)) => a.partial_cmp(b).unwrap_or(Ordering::Equal),
+            (SortKey::Age(a), SortKey::Age(b)) => b.cmp(a),
+            _ => Ordering::Equal,
+        }
+    }
+}
+
+/// `ready / total`, so "1/2" sorts below "3/3"; empty workloads count as ready.
+pub fn ready_ratio(ready: i32, total: i32) -> SortKey {
+    SortKey::Number(if total > 0 { ready as f64 / total as f64 } else { 1.0 })
+}
+
+/// A row of a table with configurable columns.
+///
+/// The `*Info` structs implement this by keeping their creation time, so Age sorts
+/// by real age rather than by the formatted string, and their annotations for
+/// annotation columns. Annotations are left out of `get` output since they often
+/// hold large values such as the last applied configuration.
+pub trait TableItem {
+    /// The key for the column with this header, `None` if it cannot be sorted.
+    fn sort_key(&self, column: &str) -> Option<SortKey>;
+    fn labels(&self) -> &BTreeMap<String, String>;
+    fn annotations(&self) -> &BTreeMap<String, String>;
+}
+
+/// A user-defined column showing one label or annotation value.
+#[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
+pub enum ExtraColumn {
+    Label(String),
+    Annotation(String),
+}
+
+impl ExtraColumn {
+    fn key(&self) -> &str {
+        match self {
+            ExtraColumn::Label(key) | ExtraColumn::Annotation(key) => key,
+        }
+    }
+}
+
+#[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
+pub struct SortBy {
+    pub column: String,
+    pub descending: bool,
+}
+
+/// The persisted part of a table's column setup.
+#[derive(Clone, Debug, Default, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct TableLayout {
+    /// Headers of the built-in columns the user turned off.
+    pub hidden: BTreeSet<String>,
+    pub sort: Option<SortBy>,
+    pub extra: Vec<ExtraColumn>,
+}
+
+/// Column chooser, click-to-sort headers and label/annotation columns for one
+/// table. Columns are identified by their header text.
+pub struct TableColumns {
+    /// Built-in columns that can be hidden; the name and actions always show.
+    optional: &'static [&'static str],
+    sortable: &'static [&'static str],
+    pub layout: TableLayout,
+    new_key: String,
+}
+
+impl TableColumns {
+    pub fn new(optional: &'static [&'static str], sortable: &'static [&'static str]) -> Self {
+        Self {
+            optional,
+            sortable,
+            layout: TableLayout::default(),
+            new_key: String::new(),
+        }
+    }
+
+    pub fn shows(&self, column: &str) -> bool {
+        !self.layout.hidden.contains(column)
+    }
+
+    /// Adds `width` to the table if `column` is shown.
+    pub fn add<'a>(&self, table: TableBuilder<'a>, column: &str, width: Column) -> TableBuilder<'a> {
+        if self.shows(column) {
+            table.column(width)
+        } else {
+            table
+        }
+    }
+
+    pub fn add_extra<'a>(&self, mut table: TableBuilder<'a>) -> TableBuilder<'a> {
+        for _ in &self.layout.extra {
+            table = table.column(Column::auto().at_least(80.0));
+        }
+        table
+    }
+
+    /// Draws the header of `column` if shown; sortable headers toggle the sort
+    /// order when clicked.
+    pub fn header(&mut self, header: &mut TableRow<'_, '_>, column: &str) {
+        self.header_with_hint(header, column, "");
+    }
+
+    /// Like [`TableColumns::header`], with a tooltip explaining the column.
+    pub fn header_with_hint(&mut self, header: &mut TableRow<'_, '_>, column: &str, hint: &str) {
+        if !self.shows(column) {
+            return;
+        }
+        if !self.sortable.contains(&column) {
+            header.col(|ui| {
+                let response = ui.strong(column);
+                if !hint.is_empty() {
+                    response.on_hover_text(hint);
+                }
+            });
+            return;
+        }
+
+        let arrow = match &self.layout.sort {
+            Some(sort) if sort.column == column && sort.descending => " ⏷",
+            Some(sort) if sort.column == column => " ⏶",
+            _ => "",
+        };
+        header.col(|ui| {
+            let text = RichText::new(format!("{}{}", column, arrow)).strong();
+            let hint = if hint.is_empty() { "Sort" } else { hint };
+            if ui.add(egui::Label::new(text).sense(egui::Sense::click())).on_hover_text(hint).clicked() {
+                self.layout.sort = match self.layout.sort.take() {
+                    Some(sort) if sort.column == column && !sort.descending => Some(SortBy { descending: true, ..sort }),
+                    Some(sort) if sort.column == column => None,
+                    _ => Some(SortBy { column: column.to_string(), descending: false }),
+                };
+            }
+        });
+    }
+
+    pub fn extra_headers(&self, header: &mut TableRow<'_, '_>) {
+        for extra in &self.layout.extra {
+            header.col(|ui| {
+                let (kind, key) = match extra {
+                    ExtraColumn::Label(key) => ("Label", key),
+                    ExtraColumn::Annotation(key) => ("Annotation", key),
+                };
+                ui.strong(key).on_hover_text(format!("{} {}", kind, key));
+            });
+        }
+    }
+
+    pub fn extra_cells(&self, row: &mut TableRow<'_, '_>, item: &impl TableItem) {
+        for extra in &self.layout.extra {
+            let value = match extra {
+                ExtraColumn::Label(key) => item.labels().get(key),
+                ExtraColumn::Annotation(key) => item.annotations().get(key),
+            };
+            row.col(|ui| match value {
+                Some(value) => {
+                    ui.label(truncate_string(value, 40)).on_hover_text(value);
+                }
+                None => {
+                    ui.label(RichText::new("-").weak());
+                }
+            });
+        }
+    }
+
+    /// Orders `rows` by the selected column; the order is left alone otherwise.
+    pub fn sort<T: TableItem>(&self, rows: &mut Vec<&T>) {
+        let Some(sort) = &self.layout.sort else {
+            return;
+        };
+        let mut keyed: Vec<(Option<SortKey>, &T)> = rows.drain(..).map(|r| (r.sort_key(&sort.column), r)).collect();
+        keyed.sort_by(|(a, _), (b, _)| {
+            let order = match (a, b) {
+                (Some(a), Some(b)) => a.compare(b),
+                _ => Ordering::Equal,
+            };
+            if sort.descending { order.reverse() } else { order }
+        });
+        rows.extend(keyed.into_iter().map(|(_, r)| r));
+    }
+
+    /// "Columns" menu to hide built-in columns and add or remove label and
+    /// annotation columns.
+    pub fn chooser(&mut self, ui: &mut Ui) {
+        ui.menu_button("Columns", |ui| {
+            for &column in self.optional {
+                let mut shown = self.shows(column);
+                if ui.checkbox(&mut shown, column).changed() {
+                    if shown {
+                        self.layout.hidden.remove(column);
+                    } else {
+                        self.layout.hidden.insert(column.to_string());
+                    }
+                }
+            }
+
+            if !self.layout.extra.is_empty() {
+                ui.separator();
+            }
+            let mut remove = None;
+            for (i, extra) in self.layout.extra.iter().enumerate() {
+                ui.horizontal(|ui| {
+                    let kind = match extra {
+                        ExtraColumn::Label(_) => "label",
+                        ExtraColumn::Annotation(_) => "annotation",
+                    };
+                    ui.label(format!("{} ({})", extra.key(), kind));
+                    if ui.small_button("✕").on_hover_text("Remove column").clicked() {
+                        remove = Some(i);
+                    }
+                });
+            }
+            if let Some(i) = remove {
+                self.layout.extra.remove(i);
+            }
+
+            ui.separator();
+            ui.add(
+                egui::TextEdit::singleline(&mut self.new_key)
+                    .hint_text("app.kubernetes.io/version")
+                    .desired_width(200.0),
+            );
+            let key = self.new_key.trim().to_string();
+            ui.horizontal(|ui| {
+                let mut added = None;
+                if ui.add_enabled(!key.is_empty(), egui::Button::new("Add label")).clicked() {
+                    added = Some(ExtraColumn::Label(key.clone()));
+                }
+                if ui.add_enabled(!key.is_empty(), egui::Button::new("Add annotation")).clicked() {
+                    added = Some(ExtraColumn::Annotation(key.clone()));
+                }
+                if let Some(column) = added {
+                    if !self.layout.extra.contains(&column) {
+                        self.layout.extra.push(column);
+                    }
+                    self.new_key.clear();
+                }
+            });
+        });
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use chrono::TimeZone;
+
+    static NO_METADATA: BTreeMap<String, String> = BTreeMap::new();
+
+    struct Row {
+        name: &'static str,
+        restarts: i32,
+        created: Option<DateTime<Utc>>,
+    }
+
+    impl TableItem for Row {
+        fn sort_key(&self, column: &str) -> Option<SortKey> {
+            match column {
+                "Name" => Some(SortKey::Text(self.name.to_string())),
+                "Restarts" => Some(SortKey::Number(self.restarts as f64)),
+                "Age" => Some(SortKey::Age(self.created)),
+                _ => None,
+            }
+        }
+
+        fn labels(&self) -> &BTreeMap<String, String> {
+            &NO_METADATA
+        }
+
+        fn annotations(&self) -> &BTreeMap<String, String> {
+            &NO_METADATA
+        }
+    }
+
+    fn sorted<'a>(columns: &TableColumns, rows: &'a [Row]) -> Vec<&'a str> {
+        let mut refs: Vec<&Row> = rows.iter().collect();
+        columns.sort(&mut refs);
+        refs.iter().map(|r| r.name).collect()
+    }
+
+    #[test]
+    fn sorts_by_number_name_and_real_age() {
+        let at = |day| Some(Utc.with_ymd_and_hms(2024, 5, day, 0, 0, 0).unwrap());
+        let rows = [
+            Row { name: "web", restarts: 2, created: at(1) },
+            Row { name: "api", restarts: 10, created: at(10) },
+            Row { name: "Cache", restarts: 0, created: at(5) },
+        ];
+        let mut columns = TableColumns::new(&["Restarts", "Age"], &["Name", "Restarts", "Age"]);
+        assert_eq!(sorted(&columns, &rows), ["web", "api", "Cache"]);
+
+        columns.layout.sort = Some(SortBy { column: "Restarts".to_string(), descending: false });
+        assert_eq!(sorted(&columns, &rows), ["Cache", "web", "api"]);
+
+        columns.layout.sort = Some(SortBy { column: "Name".to_string(), descending: true });
+        assert_eq!(sorted(&columns, &rows), ["web", "Cache", "api"]);
+
+        columns.layout.sort = Some(SortBy { column: "Age".to_string(), descending: false });
+        assert_eq!(sorted(&columns, &rows), ["api", "Cache", "web"]);
+    }
+
+    #[test]
+    fn ready_ratio_orders_partially_ready_first() {
+        assert_eq!(ready_ratio(1, 2).compare(&ready_ratio(3, 3)), Ordering::Less);
+        assert_eq!(ready_ratio(0, 0).compare(&ready_ratio(2, 2)), Ordering::Equal);
+    }
+}
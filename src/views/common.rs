@@ -1,3 +1,5 @@
+pub use crate::format::{format_bytes, truncate_string};
+use crate::views::safety::ContextProfile;
 use egui::{Color32, RichText, Ui, Vec2};
 
 pub fn status_badge(ui: &mut Ui, status: &str, color: Color32) {
@@ -36,6 +38,28 @@ pub fn info_row(ui: &mut Ui, label: &str, value: &str) {
     });
 }
 
+/// Draws `values` as a small line chart scaled between zero and the largest value.
+pub fn sparkline(ui: &mut Ui, values: &[f64], color: Color32) {
+    let (rect, _) = ui.allocate_exact_size(Vec2::new(160.0, 28.0), egui::Sense::hover());
+    let painter = ui.painter();
+    painter.rect_filled(rect, 2.0, ui.visuals().extreme_bg_color);
+
+    if values.len() < 2 {
+        return;
+    }
+    let max = values.iter().cloned().fold(0.0, f64::max).max(1.0);
+    let step = rect.width() / (values.len() - 1) as f32;
+    let points: Vec<egui::Pos2> = values
+        .iter()
+        .enumerate()
+        .map(|(i, v)| {
+            let y = rect.bottom() - 2.0 - (v / max) as f32 * (rect.height() - 4.0);
+            egui::pos2(rect.left() + i as f32 * step, y)
+        })
+        .collect();
+    painter.add(egui::Shape::line(points, egui::Stroke::new(1.5, color)));
+}
+
 pub fn action_button(ui: &mut Ui, text: &str, color: Color32) -> bool {
     let button = egui::Button::new(RichText::new(text).color(Color32::WHITE))
         .fill(color)
@@ -81,8 +105,14 @@ pub fn empty_state(ui: &mut Ui, message: &str) {
     });
 }
 
-pub fn confirm_dialog(ui: &mut Ui, title: &str, message: &str, confirm_text: &str) -> Option<bool> {
+/// Asks to confirm a change to `name`. In a protected context the confirm button
+/// stays disabled until `name` is typed; in a read-only one it stays disabled.
+pub fn confirm_dialog(ui: &mut Ui, title: &str, message: &str, confirm_text: &str, name: &str) -> Option<bool> {
     let mut result = None;
+    let profile = ContextProfile::current(ui.ctx());
+    // What was typed so far, kept across frames until the dialog closes
+    let typed_id = egui::Id::new("confirm_dialog_typed").with(title);
+    let mut typed: String = ui.ctx().data_mut(|d| d.get_temp(typed_id)).unwrap_or_default();
 
     egui::Window::new(title)
         .collapsible(false)
@@ -90,21 +120,116 @@ pub fn confirm_dialog(ui: &mut Ui, title: &str, message: &str, confirm_text: &st
         .anchor(egui::Align2::CENTER_CENTER, [0.0, 0.0])
         .show(ui.ctx(), |ui| {
             ui.label(message);
+            match profile {
+                ContextProfile::Normal => {}
+                ContextProfile::Protected => {
+                    ui.add_space(8.0);
+                    ui.label(RichText::new(format!("This context is protected. Type {} to confirm.", name)).color(profile.color()));
+                    ui.add(egui::TextEdit::singleline(&mut typed).hint_text(name).desired_width(240.0));
+                }
+                ContextProfile::ReadOnly => {
+                    ui.add_space(8.0);
+                    ui.label(RichText::new("This context is read-only.").color(profile.color()));
+                }
+            }
             ui.add_space(16.0);
             ui.horizontal(|ui| {
                 if ui.button("Cancel").clicked() {
                     result = Some(false);
                 }
                 ui.add_space(8.0);
-                if danger_button(ui, confirm_text) {
+                let confirmed = match profile {
+                    ContextProfile::Normal => true,
+                    ContextProfile::Protected => typed.trim() == name,
+                    ContextProfile::ReadOnly => false,
+                };
+                if ui.add_enabled_ui(confirmed, |ui| danger_button(ui, confirm_text)).inner {
                     result = Some(true);
                 }
             });
         });
 
+    ui.ctx().data_mut(|d| match result {
+        Some(_) => d.remove::<String>(typed_id),
+        None => d.insert_temp(typed_id, typed),
+    });
     result
 }
 
+/// What a key pressed on the highlighted table row asks for.
+#[derive(Clone, Copy, PartialEq, Debug)]
+pub enum RowCommand {
+    Open,
+    Logs,
+    Delete,
+}
+
+/// Keyboard cursor over a table's rows, k9s style: `j`/`k` or the arrow keys move
+/// it, `g`/`G` jump to the ends, Enter, `l` and `d` act on the highlighted row.
+#[derive(Default)]
+pub struct RowCursor {
+    row: usize,
+    /// Set on the frame the cursor moved, so the table scrolls it into view.
+    moved: bool,
+}
+
+impl RowCursor {
+    /// Reads navigation keys for a table of `len` rows and returns the command for
+    /// the highlighted row, if any. Keys are left alone while a text field has focus
+    /// or while `enabled` is false, e.g. when a dialog or detail window is open.
+    pub fn handle_keys(&mut self, ui: &Ui, len: usize, enabled: bool) -> Option<(usize, RowCommand)> {
+        self.moved = false;
+        if len == 0 {
+            return None;
+        }
+        self.row = self.row.min(len - 1);
+        if !enabled || ui.ctx().memory(|m| m.focused().is_some()) {
+            return None;
+        }
+
+        let pressed = |modifiers, key| ui.input_mut(|i| i.consume_key(modifiers, key));
+        let last = len - 1;
+        let row = if pressed(egui::Modifiers::NONE, egui::Key::J) || pressed(egui::Modifiers::NONE, egui::Key::ArrowDown) {
+            (self.row + 1).min(last)
+        } else if pressed(egui::Modifiers::NONE, egui::Key::K) || pressed(egui::Modifiers::NONE, egui::Key::ArrowUp) {
+            self.row.saturating_sub(1)
+        } else if pressed(egui::Modifiers::SHIFT, egui::Key::G) {
+            last
+        } else if pressed(egui::Modifiers::NONE, egui::Key::G) {
+            0
+        } else {
+            self.row
+        };
+        self.moved = row != self.row;
+        self.row = row;
+
+        let command = if pressed(egui::Modifiers::NONE, egui::Key::Enter) {
+            RowCommand::Open
+        } else if pressed(egui::Modifiers::NONE, egui::Key::L) {
+            RowCommand::Logs
+        } else if pressed(egui::Modifiers::NONE, egui::Key::D) {
+            RowCommand::Delete
+        } else {
+            return None;
+        };
+        Some((self.row, command))
+    }
+
+    pub fn is_current(&self, row: usize) -> bool {
+        self.row == row
+    }
+
+    /// Moves the cursor to a row picked some other way, e.g. with the mouse.
+    pub fn set(&mut self, row: usize) {
+        self.row = row;
+    }
+
+    /// The row to scroll to this frame, if the cursor just moved.
+    pub fn scroll_target(&self) -> Option<usize> {
+        self.moved.then_some(self.row)
+    }
+}
+
 pub fn search_bar(ui: &mut Ui, search_text: &mut String, placeholder: &str) -> bool {
     let response = ui.add(
         egui::TextEdit::singleline(search_text)
@@ -114,10 +239,3 @@ pub fn search_bar(ui: &mut Ui, search_text: &mut String, placeholder: &str) -> b
     response.changed()
 }
 
-pub fn truncate_string(s: &str, max_len: usize) -> String {
-    if s.len() <= max_len {
-        s.to_string()
-    } else {
-        format!("{}...", &s[..max_len.saturating_sub(3)])
-    }
-}
@@ -1,8 +1,17 @@
-use crate::k8s::{ConfigMapInfo, SecretInfo};
+use crate::k8s::{ConfigMapInfo, ResourceKind, ResourceRef, SecretData, SecretInfo, SecretKind};
+use crate::views::create::{CreateDialog, CreateTarget};
+use crate::views::columns::{SortKey, TableColumns, TableItem};
 use crate::views::common::*;
+use crate::views::safety::{changes_allowed, GuardedAction, UnconfirmedChange};
+use crate::views::selector::SelectorBar;
+use crate::views::yaml::{detail_tabs, DetailTab, YamlAction, YamlEditor};
 use egui::{RichText, Ui, ScrollArea};
 use egui_extras::{Column, TableBuilder};
-use std::collections::BTreeMap;
+use std::collections::{BTreeMap, HashMap, HashSet};
+use std::time::{Duration, Instant};
+
+/// Revealed secret values are hidden again after this long.
+const REVEAL_TIMEOUT: Duration = Duration::from_secs(30);
 
 pub struct ConfigView {
     pub search_filter: String,
@@ -13,7 +22,24 @@ pub struct ConfigView {
     pub edit_data: BTreeMap<String, String>,
     pub new_key: String,
     pub new_value: String,
-    pub pending_action: Option<ConfigAction>,
+    pub configmap_tab: DetailTab,
+    pub configmap_yaml: YamlEditor,
+    pub secret_tab: DetailTab,
+    pub secret_yaml: YamlEditor,
+    pub editing_secret: bool,
+    pub secret_edit_data: Option<Result<SecretData, String>>,
+    pub secret_new_key: String,
+    pub secret_new_value: String,
+    // Decoded values of the selected secret, fetched on demand and never written
+    // back into `SecretInfo`
+    revealed: HashMap<String, (Result<String, String>, Instant)>,
+    revealing: HashSet<String>,
+    pub create: Option<CreateDialog>,
+    pub configmap_cursor: RowCursor,
+    pub secret_cursor: RowCursor,
+    pub selector: SelectorBar,
+    pub configmap_columns: TableColumns,
+    pub secret_columns: TableColumns,
 }
 
 #[derive(Clone, Copy, PartialEq, Default)]
@@ -26,6 +52,26 @@ pub enum ConfigTab {
 #[derive(Clone)]
 pub enum ConfigAction {
     UpdateConfigMap(String, String, BTreeMap<String, String>),
+    RevealSecretKey(String, String, String),
+    EditSecret(String, String),
+    UpdateSecret(String, String, SecretData),
+    CreateConfigMap(String, String, BTreeMap<String, Vec<u8>>),
+    CreateSecret(String, String, SecretKind, BTreeMap<String, Vec<u8>>),
+    Yaml(YamlAction),
+}
+
+impl GuardedAction for ConfigAction {
+    fn unconfirmed_change(&self) -> Option<UnconfirmedChange> {
+        let (message, name) = match self {
+            ConfigAction::UpdateConfigMap(_, name, _) => (format!("Save changes to configmap '{}'?", name), name),
+            ConfigAction::UpdateSecret(_, name, _) => (format!("Save changes to secret '{}'?", name), name),
+            ConfigAction::CreateConfigMap(ns, name, _) => (format!("Create configmap '{}' in {}?", name, ns), name),
+            ConfigAction::CreateSecret(ns, name, ..) => (format!("Create secret '{}' in {}?", name, ns), name),
+            ConfigAction::Yaml(action) => return action.unconfirmed_change(),
+            ConfigAction::RevealSecretKey(..) | ConfigAction::EditSecret(..) => return None,
+        };
+        Some(UnconfirmedChange::new(message, name))
+    }
 }
 
 impl Default for ConfigView {
@@ -39,7 +85,25 @@ impl Default for ConfigView {
             edit_data: BTreeMap::new(),
             new_key: String::new(),
             new_value: String::new(),
-            pending_action: None,
+            configmap_tab: DetailTab::default(),
+            configmap_yaml: YamlEditor::default(),
+            secret_tab: DetailTab::default(),
+            secret_yaml: YamlEditor::default(),
+            editing_secret: false,
+            secret_edit_data: None,
+            secret_new_key: String::new(),
+            secret_new_value: String::new(),
+            revealed: HashMap::new(),
+            revealing: HashSet::new(),
+            create: None,
+            configmap_cursor: RowCursor::default(),
+            secret_cursor: RowCursor::default(),
+            selector: SelectorBar::default(),
+            configmap_columns: TableColumns::new(&["Namespace", "Data", "Age"], &["Name", "Namespace", "Data", "Age"]),
+            secret_columns: TableColumns::new(
+                &["Namespace", "Type", "Data", "Age"],
+                &["Name", "Namespace", "Type", "Data", "Age"],
+            ),
         }
     }
 }
@@ -50,11 +114,10 @@ impl ConfigView {
         ui: &mut Ui,
         configmaps: &[ConfigMapInfo],
         secrets: &[SecretInfo],
+        namespace: Option<&str>,
         loading: bool,
         error: Option<&str>,
     ) -> Option<ConfigAction> {
-        let mut action = None;
-
         ui.horizontal(|ui| {
             if ui.selectable_label(self.active_tab == ConfigTab::ConfigMaps, "ConfigMaps").clicked() {
                 self.active_tab = ConfigTab::ConfigMaps;
@@ -64,10 +127,41 @@ impl ConfigView {
             }
             ui.with_layout(egui::Layout::right_to_left(egui::Align::Center), |ui| {
                 search_bar(ui, &mut self.search_filter, "Search...");
+                match self.active_tab {
+                    ConfigTab::ConfigMaps => self.configmap_columns.chooser(ui),
+                    ConfigTab::Secrets => self.secret_columns.chooser(ui),
+                }
+                let (label, target) = match self.active_tab {
+                    ConfigTab::ConfigMaps => ("New ConfigMap", CreateTarget::ConfigMap),
+                    ConfigTab::Secrets => ("New Secret", CreateTarget::Secret),
+                };
+                if ui.add_enabled(changes_allowed(ui), egui::Button::new(label)).clicked() {
+                    self.create = Some(CreateDialog::new(target, namespace));
+                }
             });
         });
+        self.selector.show(ui);
         ui.separator();
 
+        if let Some(dialog) = &mut self.create {
+            if let Some(submitted) = dialog.show(ui) {
+                self.create = None;
+                if let Some(request) = submitted {
+                    return Some(match request.target {
+                        CreateTarget::ConfigMap => {
+                            ConfigAction::CreateConfigMap(request.namespace, request.name, request.data)
+                        }
+                        CreateTarget::Secret => ConfigAction::CreateSecret(
+                            request.namespace,
+                            request.name,
+                            request.secret_kind,
+                            request.data,
+                        ),
+                    });
+                }
+            }
+        }
+
         if loading {
             loading_spinner(ui);
             return None;
@@ -79,17 +173,15 @@ impl ConfigView {
         }
 
         match self.active_tab {
-            ConfigTab::ConfigMaps => action = self.show_configmaps(ui, configmaps),
+            ConfigTab::ConfigMaps => self.show_configmaps(ui, configmaps),
             ConfigTab::Secrets => self.show_secrets(ui, secrets),
         }
-
-        action
     }
 
     fn show_configmaps(&mut self, ui: &mut Ui, configmaps: &[ConfigMapInfo]) -> Option<ConfigAction> {
         let mut action = None;
 
-        let filtered: Vec<_> = configmaps
+        let mut filtered: Vec<_> = configmaps
             .iter()
             .filter(|cm| {
                 self.search_filter.is_empty()
@@ -102,37 +194,62 @@ impl ConfigView {
             empty_state(ui, "No ConfigMaps found");
             return None;
         }
+        self.configmap_columns.sort(&mut filtered);
+
+        let keys_enabled = self.selected_configmap.is_none() && self.create.is_none();
+        if let Some((i, command)) = self.configmap_cursor.handle_keys(ui, filtered.len(), keys_enabled) {
+            let cm = filtered[i];
+            match command {
+                RowCommand::Open => self.selected_configmap = Some(cm.clone()),
+                RowCommand::Logs | RowCommand::Delete => {}
+            }
+        }
 
         let available_height = ui.available_height();
 
-        TableBuilder::new(ui)
+        let mut table = TableBuilder::new(ui)
             .striped(true)
             .resizable(true)
             .cell_layout(egui::Layout::left_to_right(egui::Align::Center))
             .column(Column::auto().at_least(200.0)) // Name
-            .column(Column::auto().at_least(120.0)) // Namespace
-            .column(Column::auto().at_least(80.0))  // Data
-            .column(Column::remainder().at_least(60.0)) // Age
             .min_scrolled_height(0.0)
-            .max_scroll_height(available_height - 50.0)
+            .max_scroll_height(available_height - 50.0);
+        table = self.configmap_columns.add(table, "Namespace", Column::auto().at_least(120.0));
+        table = self.configmap_columns.add(table, "Data", Column::auto().at_least(80.0));
+        table = self.configmap_columns.add(table, "Age", Column::auto().at_least(60.0));
+        table = self.configmap_columns.add_extra(table);
+        if let Some(row) = self.configmap_cursor.scroll_target() {
+            table = table.scroll_to_row(row, None);
+        }
+
+        table
             .header(25.0, |mut header| {
-                header.col(|ui| { ui.strong("Name"); });
-                header.col(|ui| { ui.strong("Namespace"); });
-                header.col(|ui| { ui.strong("Data"); });
-                header.col(|ui| { ui.strong("Age"); });
+                for column in ["Name", "Namespace", "Data", "Age"] {
+                    self.configmap_columns.header(&mut header, column);
+                }
+                self.configmap_columns.extra_headers(&mut header);
             })
             .body(|mut body| {
-                for cm in &filtered {
+                for (i, cm) in filtered.iter().enumerate() {
                     body.row(30.0, |mut row| {
+                        row.set_selected(self.configmap_cursor.is_current(i));
                         row.col(|ui| {
                             if ui.link(&cm.name).clicked() {
+                                self.configmap_cursor.set(i);
                                 self.selected_configmap = Some((*cm).clone());
                                 self.editing_configmap = false;
                             }
                         });
-                        row.col(|ui| { ui.label(&cm.namespace); });
-                        row.col(|ui| { ui.label(cm.data_count.to_string()); });
-                        row.col(|ui| { ui.label(&cm.age); });
+                        if self.configmap_columns.shows("Namespace") {
+                            row.col(|ui| { ui.label(&cm.namespace); });
+                        }
+                        if self.configmap_columns.shows("Data") {
+                            row.col(|ui| { ui.label(cm.data_count.to_string()); });
+                        }
+                        if self.configmap_columns.shows("Age") {
+                            row.col(|ui| { ui.label(&cm.age); });
+                        }
+                        self.configmap_columns.extra_cells(&mut row, *cm);
                     });
                 }
             });
@@ -147,18 +264,29 @@ impl ConfigView {
                         if ui.button("Close").clicked() {
                             self.selected_configmap = None;
                             self.editing_configmap = false;
+                            self.configmap_tab = DetailTab::Overview;
                         }
-                        if !self.editing_configmap {
-                            if ui.button("Edit").clicked() {
-                                self.editing_configmap = true;
-                                self.edit_data = cm.data.clone();
-                                self.new_key.clear();
-                                self.new_value.clear();
-                            }
+                        if self.configmap_tab == DetailTab::Overview
+                            && !self.editing_configmap
+                            && ui.add_enabled(changes_allowed(ui), egui::Button::new("Edit")).clicked()
+                        {
+                            self.editing_configmap = true;
+                            self.edit_data = cm.data.clone();
+                            self.new_key.clear();
+                            self.new_value.clear();
                         }
                     });
                     ui.separator();
 
+                    detail_tabs(ui, &mut self.configmap_tab);
+                    if self.configmap_tab == DetailTab::Yaml {
+                        let target = ResourceRef::new(ResourceKind::ConfigMap, &cm.namespace, &cm.name);
+                        if let Some(a) = self.configmap_yaml.show(ui, target) {
+                            action = Some(ConfigAction::Yaml(a));
+                        }
+                        return;
+                    }
+
                     info_row(ui, "Name", &cm.name);
                     info_row(ui, "Namespace", &cm.namespace);
                     info_row(ui, "Age", &cm.age);
@@ -168,60 +296,14 @@ impl ConfigView {
                         ui.label(RichText::new("Edit Data:").strong());
                         ui.separator();
 
-                        let mut keys_to_remove = Vec::new();
-
-                        ScrollArea::vertical()
-                            .max_height(300.0)
-                            .show(ui, |ui| {
-                                for (key, value) in self.edit_data.clone() {
-                                    ui.horizontal(|ui| {
-                                        ui.label(RichText::new(&key).strong());
-                                        if ui.small_button("×").clicked() {
-                                            keys_to_remove.push(key.clone());
-                                        }
-                                    });
-                                    let mut val = value.clone();
-                                    if ui.add(
-                                        egui::TextEdit::multiline(&mut val)
-                                            .font(egui::TextStyle::Monospace)
-                                            .desired_width(f32::INFINITY)
-                                            .desired_rows(3)
-                                    ).changed() {
-                                        self.edit_data.insert(key, val);
-                                    }
-                                    ui.add_space(8.0);
-                                }
-                            });
-
-                        for key in keys_to_remove {
-                            self.edit_data.remove(&key);
-                        }
-
-                        ui.separator();
-                        ui.label(RichText::new("Add New Key:").strong());
-                        ui.horizontal(|ui| {
-                            ui.label("Key:");
-                            ui.text_edit_singleline(&mut self.new_key);
-                        });
-                        ui.label("Value:");
-                        ui.add(
-                            egui::TextEdit::multiline(&mut self.new_value)
-                                .font(egui::TextStyle::Monospace)
-                                .desired_width(f32::INFINITY)
-                                .desired_rows(2)
-                        );
-                        if ui.button("Add Key").clicked() && !self.new_key.is_empty() {
-                            self.edit_data.insert(self.new_key.clone(), self.new_value.clone());
-                            self.new_key.clear();
-                            self.new_value.clear();
-                        }
+                        data_editor(ui, &mut self.edit_data, &mut self.new_key, &mut self.new_value);
 
                         ui.add_space(16.0);
                         ui.horizontal(|ui| {
                             if ui.button("Cancel").clicked() {
                                 self.editing_configmap = false;
                             }
-                            if success_button(ui, "Save") {
+                            if ui.add_enabled_ui(changes_allowed(ui), |ui| success_button(ui, "Save")).inner {
                                 action = Some(ConfigAction::UpdateConfigMap(
                                     cm.namespace.clone(),
                                     cm.name.clone(),
@@ -254,8 +336,10 @@ impl ConfigView {
         action
     }
 
-    fn show_secrets(&mut self, ui: &mut Ui, secrets: &[SecretInfo]) {
-        let filtered: Vec<_> = secrets
+    fn show_secrets(&mut self, ui: &mut Ui, secrets: &[SecretInfo]) -> Option<ConfigAction> {
+        let mut action = None;
+
+        let mut filtered: Vec<_> = secrets
             .iter()
             .filter(|s| {
                 self.search_filter.is_empty()
@@ -266,41 +350,71 @@ impl ConfigView {
 
         if filtered.is_empty() {
             empty_state(ui, "No Secrets found");
-            return;
+            return None;
+        }
+        self.secret_columns.sort(&mut filtered);
+
+        let keys_enabled = self.selected_secret.is_none() && self.create.is_none();
+        if let Some((i, command)) = self.secret_cursor.handle_keys(ui, filtered.len(), keys_enabled) {
+            let secret = filtered[i];
+            match command {
+                RowCommand::Open => self.selected_secret = Some(secret.clone()),
+                RowCommand::Logs | RowCommand::Delete => {}
+            }
         }
 
         let available_height = ui.available_height();
 
-        TableBuilder::new(ui)
+        let mut table = TableBuilder::new(ui)
             .striped(true)
             .resizable(true)
             .cell_layout(egui::Layout::left_to_right(egui::Align::Center))
             .column(Column::auto().at_least(200.0)) // Name
-            .column(Column::auto().at_least(120.0)) // Namespace
-            .column(Column::auto().at_least(150.0)) // Type
-            .column(Column::auto().at_least(80.0))  // Data
-            .column(Column::remainder().at_least(60.0)) // Age
             .min_scrolled_height(0.0)
-            .max_scroll_height(available_height - 50.0)
+            .max_scroll_height(available_height - 50.0);
+        table = self.secret_columns.add(table, "Namespace", Column::auto().at_least(120.0));
+        table = self.secret_columns.add(table, "Type", Column::auto().at_least(150.0));
+        table = self.secret_columns.add(table, "Data", Column::auto().at_least(80.0));
+        table = self.secret_columns.add(table, "Age", Column::auto().at_least(60.0));
+        table = self.secret_columns.add_extra(table);
+        if let Some(row) = self.secret_cursor.scroll_target() {
+            table = table.scroll_to_row(row, None);
+        }
+
+        table
             .header(25.0, |mut header| {
-                header.col(|ui| { ui.strong("Name"); });
-                header.col(|ui| { ui.strong("Namespace"); });
-                header.col(|ui| { ui.strong("Type"); });
-                header.col(|ui| { ui.strong("Data"); });
-                header.col(|ui| { ui.strong("Age"); });
+                for column in ["Name", "Namespace", "Type", "Data", "Age"] {
+                    self.secret_columns.header(&mut header, column);
+                }
+                self.secret_columns.extra_headers(&mut header);
             })
             .body(|mut body| {
-                for secret in &filtered {
+                for (i, secret) in filtered.iter().enumerate() {
                     body.row(30.0, |mut row| {
+                        row.set_selected(self.secret_cursor.is_current(i));
                         row.col(|ui| {
                             if ui.link(&secret.name).clicked() {
+                                self.secret_cursor.set(i);
                                 self.selected_secret = Some((*secret).clone());
+                                self.editing_secret = false;
+                                self.secret_edit_data = None;
+                                self.revealed.clear();
+                                self.revealing.clear();
                             }
                         });
-                        row.col(|ui| { ui.label(&secret.namespace); });
-                        row.col(|ui| { ui.label(&secret.secret_type); });
-                        row.col(|ui| { ui.label(secret.data_count.to_string()); });
-                        row.col(|ui| { ui.label(&secret.age); });
+                        if self.secret_columns.shows("Namespace") {
+                            row.col(|ui| { ui.label(&secret.namespace); });
+                        }
+                        if self.secret_columns.shows("Type") {
+                            row.col(|ui| { ui.label(&secret.secret_type); });
+                        }
+                        if self.secret_columns.shows("Data") {
+                            row.col(|ui| { ui.label(secret.data_count.to_string()); });
+                        }
+                        if self.secret_columns.shows("Age") {
+                            row.col(|ui| { ui.label(&secret.age); });
+                        }
+                        self.secret_columns.extra_cells(&mut row, *secret);
                     });
                 }
             });
@@ -310,28 +424,243 @@ impl ConfigView {
             let mut close_details = false;
             egui::Window::new(format!("Secret: {}", secret.name))
                 .resizable(true)
-                .default_width(400.0)
+                .default_width(500.0)
                 .show(ui.ctx(), |ui| {
-                    if ui.button("Close").clicked() {
-                        close_details = true;
-                    }
+                    ui.horizontal(|ui| {
+                        if ui.button("Close").clicked() {
+                            close_details = true;
+                        }
+                        if self.secret_tab == DetailTab::Overview
+                            && !self.editing_secret
+                            && ui.add_enabled(changes_allowed(ui), egui::Button::new("Edit")).clicked()
+                        {
+                            self.editing_secret = true;
+                            self.secret_edit_data = None;
+                            self.secret_new_key.clear();
+                            self.secret_new_value.clear();
+                            action = Some(ConfigAction::EditSecret(secret.namespace.clone(), secret.name.clone()));
+                        }
+                    });
                     ui.separator();
+                    detail_tabs(ui, &mut self.secret_tab);
+                    if self.secret_tab == DetailTab::Yaml {
+                        let target = ResourceRef::new(ResourceKind::Secret, &secret.namespace, &secret.name);
+                        if let Some(a) = self.secret_yaml.show(ui, target) {
+                            action = Some(ConfigAction::Yaml(a));
+                        }
+                        return;
+                    }
 
                     info_row(ui, "Name", &secret.name);
                     info_row(ui, "Namespace", &secret.namespace);
                     info_row(ui, "Type", &secret.secret_type);
                     info_row(ui, "Age", &secret.age);
-
                     ui.add_space(8.0);
-                    ui.label(RichText::new("Keys:").strong());
-                    ui.label(RichText::new("(Values hidden for security)").small().weak());
-                    for key in &secret.data_keys {
-                        ui.label(format!("  • {}", key));
+
+                    if self.editing_secret {
+                        ui.label(RichText::new("Edit Data:").strong());
+                        ui.label(RichText::new("Values are shown decoded and re-encoded on save").small().weak());
+                        ui.separator();
+
+                        let mut cancel = false;
+                        match &mut self.secret_edit_data {
+                            None => loading_spinner(ui),
+                            Some(Err(e)) => {
+                                error_label(ui, e);
+                                cancel = ui.button("Back").clicked();
+                            }
+                            Some(Ok(edit)) => {
+                                data_editor(ui, &mut edit.data, &mut self.secret_new_key, &mut self.secret_new_value);
+
+                                ui.add_space(16.0);
+                                ui.horizontal(|ui| {
+                                    if ui.button("Cancel").clicked() {
+                                        cancel = true;
+                                    }
+                                    if ui.add_enabled_ui(changes_allowed(ui), |ui| success_button(ui, "Save")).inner {
+                                        action = Some(ConfigAction::UpdateSecret(
+                                            secret.namespace.clone(),
+                                            secret.name.clone(),
+                                            edit.clone(),
+                                        ));
+                                        cancel = true;
+                                    }
+                                });
+                            }
+                        }
+                        if cancel {
+                            self.editing_secret = false;
+                            self.secret_edit_data = None;
+                        }
+                    } else {
+                        self.revealed.retain(|_, (_, at)| at.elapsed() < REVEAL_TIMEOUT);
+
+                        ui.label(RichText::new("Data:").strong());
+                        ui.separator();
+
+                        ScrollArea::vertical()
+                            .max_height(400.0)
+                            .show(ui, |ui| {
+                                for key in &secret.data_keys {
+                                    ui.horizontal(|ui| {
+                                        ui.label(RichText::new(key).strong());
+                                        if let Some((_, at)) = self.revealed.get(key) {
+                                            let left = REVEAL_TIMEOUT.saturating_sub(at.elapsed()).as_secs();
+                                            if ui.small_button("Hide").clicked() {
+                                                self.revealed.remove(key);
+                                            }
+                                            ui.label(RichText::new(format!("hides in {}s", left)).small().weak());
+                                        } else if self.revealing.contains(key) {
+                                            ui.spinner();
+                                        } else if ui.small_button("Reveal").on_hover_text("Fetch and decode this value").clicked() {
+                                            self.revealing.insert(key.clone());
+                                            action = Some(ConfigAction::RevealSecretKey(
+                                                secret.namespace.clone(),
+                                                secret.name.clone(),
+                                                key.clone(),
+                                            ));
+                                        }
+                                    });
+                                    match self.revealed.get(key) {
+                                        Some((Ok(value), _)) => {
+                                            ui.horizontal(|ui| {
+                                                ui.add(
+                                                    egui::TextEdit::multiline(&mut value.as_str())
+                                                        .font(egui::TextStyle::Monospace)
+                                                        .desired_width(ui.available_width() - 50.0)
+                                                        .desired_rows(1)
+                                                );
+                                                if ui.small_button("Copy").clicked() {
+                                                    ui.ctx().copy_text(value.clone());
+                                                }
+                                            });
+                                        }
+                                        Some((Err(e), _)) => error_label(ui, e),
+                                        None => {
+                                            ui.label(RichText::new("••••••••").weak());
+                                        }
+                                    }
+                                    ui.add_space(6.0);
+                                }
+                            });
                     }
                 });
             if close_details {
                 self.selected_secret = None;
+                self.secret_tab = DetailTab::Overview;
+                self.editing_secret = false;
+                self.secret_edit_data = None;
+                self.revealed.clear();
+                self.revealing.clear();
             }
         }
+
+        action
+    }
+
+    fn is_selected_secret(&self, namespace: &str, name: &str) -> bool {
+        self.selected_secret
+            .as_ref()
+            .is_some_and(|s| s.namespace == namespace && s.name == name)
+    }
+
+    pub fn secret_value_revealed(&mut self, namespace: &str, name: &str, key: String, result: Result<String, String>) {
+        if !self.is_selected_secret(namespace, name) || !self.revealing.remove(&key) {
+            return;
+        }
+        self.revealed.insert(key, (result, Instant::now()));
+    }
+
+    pub fn secret_data_loaded(&mut self, namespace: &str, name: &str, result: Result<SecretData, String>) {
+        if self.editing_secret && self.is_selected_secret(namespace, name) {
+            self.secret_edit_data = Some(result);
+        }
+    }
+}
+
+/// Key/value editor shared by the ConfigMap and Secret edit modes.
+fn data_editor(ui: &mut Ui, data: &mut BTreeMap<String, String>, new_key: &mut String, new_value: &mut String) {
+    let mut keys_to_remove = Vec::new();
+
+    ScrollArea::vertical()
+        .max_height(300.0)
+        .show(ui, |ui| {
+            for (key, value) in data.iter_mut() {
+                ui.horizontal(|ui| {
+                    ui.label(RichText::new(key).strong());
+                    if ui.small_button("×").clicked() {
+                        keys_to_remove.push(key.clone());
+                    }
+                });
+                ui.add(
+                    egui::TextEdit::multiline(value)
+                        .font(egui::TextStyle::Monospace)
+                        .desired_width(f32::INFINITY)
+                        .desired_rows(3)
+                );
+                ui.add_space(8.0);
+            }
+        });
+
+    for key in keys_to_remove {
+        data.remove(&key);
+    }
+
+    ui.separator();
+    ui.label(RichText::new("Add New Key:").strong());
+    ui.horizontal(|ui| {
+        ui.label("Key:");
+        ui.text_edit_singleline(new_key);
+    });
+    ui.label("Value:");
+    ui.add(
+        egui::TextEdit::multiline(new_value)
+            .font(egui::TextStyle::Monospace)
+            .desired_width(f32::INFINITY)
+            .desired_rows(2)
+    );
+    if ui.button("Add Key").clicked() && !new_key.is_empty() {
+        data.insert(std::mem::take(new_key), std::mem::take(new_value));
+    }
+}
+
+impl TableItem for ConfigMapInfo {
+    fn sort_key(&self, column: &str) -> Option<SortKey> {
+        Some(match column {
+            "Name" => SortKey::Text(self.name.clone()),
+            "Namespace" => SortKey::Text(self.namespace.clone()),
+            "Data" => SortKey::Number(self.data_count as f64),
+            "Age" => SortKey::Age(self.created),
+            _ => return None,
+        })
+    }
+
+    fn labels(&self) -> &BTreeMap<String, String> {
+        &self.labels
+    }
+
+    fn annotations(&self) -> &BTreeMap<String, String> {
+        &self.annotations
+    }
+}
+
+impl TableItem for SecretInfo {
+    fn sort_key(&self, column: &str) -> Option<SortKey> {
+        Some(match column {
+            "Name" => SortKey::Text(self.name.clone()),
+            "Namespace" => SortKey::Text(self.namespace.clone()),
+            "Type" => SortKey::Text(self.secret_type.clone()),
+            "Data" => SortKey::Number(self.data_count as f64),
+            "Age" => SortKey::Age(self.created),
+            _ => return None,
+        })
+    }
+
+    fn labels(&self) -> &BTreeMap<String, String> {
+        &self.labels
+    }
+
+    fn annotations(&self) -> &BTreeMap<String, String> {
+        &self.annotations
     }
 }
@@ -0,0 +1,245 @@
+use crate::k8s::{
+    basic_auth_data, docker_config_data, read_file_source, tls_secret_data, validate_data_key,
+    SecretKind,
+};
+use crate::views::common::*;
+use anyhow::{bail, Result};
+use egui::{RichText, Ui};
+use std::collections::BTreeMap;
+
+#[derive(Clone, Copy, PartialEq)]
+pub enum CreateTarget {
+    ConfigMap,
+    Secret,
+}
+
+/// Form state for creating a ConfigMap or Secret, from literals and/or local files.
+pub struct CreateDialog {
+    pub target: CreateTarget,
+    pub secret_kind: SecretKind,
+    pub namespace: String,
+    pub name: String,
+    pub literals: Vec<(String, String)>,
+    /// One `--from-file` source per line: a file, `key=file` or a directory.
+    pub file_sources: String,
+    pub tls_cert: String,
+    pub tls_key: String,
+    pub docker_server: String,
+    pub docker_email: String,
+    pub username: String,
+    pub password: String,
+    pub error: Option<String>,
+}
+
+/// Emitted when the form is submitted: namespace, name, secret kind (for secrets) and data.
+pub struct CreateRequest {
+    pub target: CreateTarget,
+    pub secret_kind: SecretKind,
+    pub namespace: String,
+    pub name: String,
+    pub data: BTreeMap<String, Vec<u8>>,
+}
+
+impl CreateDialog {
+    pub fn new(target: CreateTarget, namespace: Option<&str>) -> Self {
+        Self {
+            target,
+            secret_kind: SecretKind::default(),
+            namespace: namespace.unwrap_or("default").to_string(),
+            name: String::new(),
+            literals: vec![(String::new(), String::new())],
+            file_sources: String::new(),
+            tls_cert: String::new(),
+            tls_key: String::new(),
+            docker_server: "https://index.docker.io/v1/".to_string(),
+            docker_email: String::new(),
+            username: String::new(),
+            password: String::new(),
+            error: None,
+        }
+    }
+
+    /// Returns `Some(None)` when cancelled and `Some(Some(request))` when submitted.
+    pub fn show(&mut self, ui: &mut Ui) -> Option<Option<CreateRequest>> {
+        let mut result = None;
+        let title = match self.target {
+            CreateTarget::ConfigMap => "Create ConfigMap",
+            CreateTarget::Secret => "Create Secret",
+        };
+
+        egui::Window::new(title)
+            .collapsible(false)
+            .resizable(true)
+            .default_width(520.0)
+            .anchor(egui::Align2::CENTER_CENTER, [0.0, 0.0])
+            .show(ui.ctx(), |ui| {
+                egui::Grid::new("create_config_form").num_columns(2).spacing([8.0, 6.0]).show(ui, |ui| {
+                    ui.label("Namespace:");
+                    ui.text_edit_singleline(&mut self.namespace);
+                    ui.end_row();
+                    ui.label("Name:");
+                    ui.text_edit_singleline(&mut self.name);
+                    ui.end_row();
+                    if self.target == CreateTarget::Secret {
+                        ui.label("Type:");
+                        egui::ComboBox::from_id_salt("secret_kind")
+                            .selected_text(self.secret_kind.type_name())
+                            .show_ui(ui, |ui| {
+                                for kind in SecretKind::ALL {
+                                    ui.selectable_value(&mut self.secret_kind, kind, kind.type_name());
+                                }
+                            });
+                        ui.end_row();
+                    }
+                });
+                ui.separator();
+
+                match (self.target, self.secret_kind) {
+                    (CreateTarget::ConfigMap, _) | (CreateTarget::Secret, SecretKind::Opaque) => {
+                        self.show_generic_fields(ui)
+                    }
+                    (CreateTarget::Secret, SecretKind::Tls) => {
+                        egui::Grid::new("tls_form").num_columns(2).show(ui, |ui| {
+                            ui.label("Certificate file:");
+                            ui.add(egui::TextEdit::singleline(&mut self.tls_cert).hint_text("/path/to/tls.crt"));
+                            ui.end_row();
+                            ui.label("Key file:");
+                            ui.add(egui::TextEdit::singleline(&mut self.tls_key).hint_text("/path/to/tls.key"));
+                            ui.end_row();
+                        });
+                    }
+                    (CreateTarget::Secret, SecretKind::DockerConfigJson) => {
+                        egui::Grid::new("docker_form").num_columns(2).show(ui, |ui| {
+                            ui.label("Registry server:");
+                            ui.text_edit_singleline(&mut self.docker_server);
+                            ui.end_row();
+                            ui.label("Username:");
+                            ui.text_edit_singleline(&mut self.username);
+                            ui.end_row();
+                            ui.label("Password:");
+                            ui.add(egui::TextEdit::singleline(&mut self.password).password(true));
+                            ui.end_row();
+                            ui.label("Email:");
+                            ui.add(egui::TextEdit::singleline(&mut self.docker_email).hint_text("optional"));
+                            ui.end_row();
+                        });
+                    }
+                    (CreateTarget::Secret, SecretKind::BasicAuth) => {
+                        egui::Grid::new("basic_auth_form").num_columns(2).show(ui, |ui| {
+                            ui.label("Username:");
+                            ui.text_edit_singleline(&mut self.username);
+                            ui.end_row();
+                            ui.label("Password:");
+                            ui.add(egui::TextEdit::singleline(&mut self.password).password(true));
+                            ui.end_row();
+                        });
+                    }
+                }
+
+                if let Some(err) = &self.error {
+                    ui.add_space(8.0);
+                    error_label(ui, err);
+                }
+
+                ui.add_space(16.0);
+                ui.horizontal(|ui| {
+                    if ui.button("Cancel").clicked() {
+                        result = Some(None);
+                    }
+                    if primary_button(ui, "Create") {
+                        match self.build() {
+                            Ok(request) => result = Some(Some(request)),
+                            Err(e) => self.error = Some(format!("{:#}", e)),
+                        }
+                    }
+                });
+            });
+
+        result
+    }
+
+    fn show_generic_fields(&mut self, ui: &mut Ui) {
+        ui.label(RichText::new("Literals:").strong());
+        let mut remove = None;
+        egui::Grid::new("literal_rows").num_columns(3).show(ui, |ui| {
+            for (i, (key, value)) in self.literals.iter_mut().enumerate() {
+                ui.add(egui::TextEdit::singleline(key).hint_text("key").desired_width(140.0));
+                ui.add(egui::TextEdit::singleline(value).hint_text("value").desired_width(260.0));
+                if ui.small_button("×").clicked() {
+                    remove = Some(i);
+                }
+                ui.end_row();
+            }
+        });
+        if let Some(i) = remove {
+            self.literals.remove(i);
+        }
+        if ui.small_button("+ Add literal").clicked() {
+            self.literals.push((String::new(), String::new()));
+        }
+
+        ui.add_space(8.0);
+        ui.label(RichText::new("From files:").strong());
+        ui.label(
+            RichText::new("One per line: a file, key=file, or a directory (every file inside it)")
+                .small()
+                .weak(),
+        );
+        ui.add(
+            egui::TextEdit::multiline(&mut self.file_sources)
+                .font(egui::TextStyle::Monospace)
+                .desired_width(f32::INFINITY)
+                .desired_rows(3),
+        );
+    }
+
+    fn build(&self) -> Result<CreateRequest> {
+        let namespace = self.namespace.trim();
+        let name = self.name.trim();
+        if namespace.is_empty() || name.is_empty() {
+            bail!("Namespace and name are required");
+        }
+
+        let data = match (self.target, self.secret_kind) {
+            (CreateTarget::ConfigMap, _) | (CreateTarget::Secret, SecretKind::Opaque) => self.generic_data()?,
+            (CreateTarget::Secret, SecretKind::Tls) => tls_secret_data(&self.tls_cert, &self.tls_key)?,
+            (CreateTarget::Secret, SecretKind::DockerConfigJson) => {
+                docker_config_data(&self.docker_server, &self.username, &self.password, &self.docker_email)?
+            }
+            (CreateTarget::Secret, SecretKind::BasicAuth) => basic_auth_data(&self.username, &self.password)?,
+        };
+
+        Ok(CreateRequest {
+            target: self.target,
+            secret_kind: self.secret_kind,
+            namespace: namespace.to_string(),
+            name: name.to_string(),
+            data,
+        })
+    }
+
+    fn generic_data(&self) -> Result<BTreeMap<String, Vec<u8>>> {
+        let mut data = BTreeMap::new();
+        let mut add = |key: String, value: Vec<u8>| -> Result<()> {
+            if data.insert(key.clone(), value).is_some() {
+                bail!("Key '{}' is given more than once", key);
+            }
+            Ok(())
+        };
+
+        for (key, value) in &self.literals {
+            // Leave untouched blank rows out
+            if key.is_empty() && value.is_empty() {
+                continue;
+            }
+            validate_data_key(key)?;
+            add(key.clone(), value.clone().into_bytes())?;
+        }
+        for source in self.file_sources.lines().filter(|l| !l.trim().is_empty()) {
+            for (key, value) in read_file_source(source)? {
+                add(key, value)?;
+            }
+        }
+        Ok(data)
+    }
+}
@@ -1,7 +1,14 @@
-use crate::k8s::{CronJobInfo, JobInfo, JobStatus};
+use crate::k8s::{CronJobInfo, JobInfo, JobStatus, ResourceKind, ResourceRef};
+use crate::views::bulk::RowSelection;
+use crate::views::columns::{SortKey, TableColumns, TableItem};
 use crate::views::common::*;
+use crate::views::safety::{change_button, changes_allowed, GuardedAction, UnconfirmedChange};
+use crate::views::selector::SelectorBar;
+use crate::views::events::ObjectEvents;
+use crate::views::yaml::{detail_tabs, DetailTab, YamlAction, YamlEditor};
 use egui::{Color32, RichText, Ui};
 use egui_extras::{Column, TableBuilder};
+use std::collections::BTreeMap;
 
 pub struct CronJobsView {
     pub search_filter: String,
@@ -9,14 +16,13 @@ pub struct CronJobsView {
     pub show_history: bool,
     pub history_jobs: Vec<JobInfo>,
     pub history_loading: bool,
-    pub pending_action: Option<CronJobAction>,
-}
-
-#[derive(Clone)]
-pub enum CronJobAction {
-    Trigger(String, String),
-    Suspend(String, String, bool),
-    GetHistory(String, String),
+    pub detail_tab: DetailTab,
+    pub yaml: YamlEditor,
+    pub events: ObjectEvents,
+    pub cursor: RowCursor,
+    pub selector: SelectorBar,
+    pub columns: TableColumns,
+    pub selection: RowSelection,
 }
 
 impl Default for CronJobsView {
@@ -27,7 +33,49 @@ impl Default for CronJobsView {
             show_history: false,
             history_jobs: Vec::new(),
             history_loading: false,
-            pending_action: None,
+            detail_tab: DetailTab::default(),
+            yaml: YamlEditor::default(),
+            events: ObjectEvents::default(),
+            cursor: RowCursor::default(),
+            selector: SelectorBar::default(),
+            columns: TableColumns::new(
+                &["Namespace", "Schedule", "Suspend", "Active", "Last Schedule", "Age"],
+                &["Name", "Namespace", "Suspend", "Active", "Age"],
+            ),
+            selection: RowSelection::default(),
+        }
+    }
+}
+
+#[derive(Clone)]
+pub enum CronJobAction {
+    Trigger(String, String),
+    Suspend(String, String, bool),
+    /// Suspends (`true`) or resumes every `(namespace, name)` cronjob at once.
+    SuspendMany(Vec<(String, String)>, bool),
+    GetHistory(String, String),
+    Yaml(YamlAction),
+    LoadEvents(ResourceRef),
+}
+
+impl GuardedAction for CronJobAction {
+    fn unconfirmed_change(&self) -> Option<UnconfirmedChange> {
+        let verb = |suspend: bool| if suspend { "Suspend" } else { "Resume" };
+        match self {
+            CronJobAction::Trigger(_, name) => Some(UnconfirmedChange::new(
+                format!("Run cronjob '{}' now? A job is created from its template.", name),
+                name,
+            )),
+            CronJobAction::Suspend(_, name, suspend) => Some(UnconfirmedChange::new(
+                format!("{} cronjob '{}'?", verb(*suspend), name),
+                name,
+            )),
+            CronJobAction::SuspendMany(targets, suspend) => Some(UnconfirmedChange::new(
+                format!("{} {} cronjobs?", verb(*suspend), targets.len()),
+                &format!("{} cronjobs", targets.len()),
+            )),
+            CronJobAction::Yaml(action) => action.unconfirmed_change(),
+            CronJobAction::GetHistory(..) | CronJobAction::LoadEvents(_) => None,
         }
     }
 }
@@ -46,8 +94,10 @@ impl CronJobsView {
             section_header(ui, "CronJobs");
             ui.with_layout(egui::Layout::right_to_left(egui::Align::Center), |ui| {
                 search_bar(ui, &mut self.search_filter, "Search cronjobs...");
+                self.columns.chooser(ui);
             });
         });
+        self.selector.show(ui);
 
         if loading {
             loading_spinner(ui);
@@ -59,7 +109,7 @@ impl CronJobsView {
             return None;
         }
 
-        let filtered: Vec<_> = cronjobs
+        let mut filtered: Vec<_> = cronjobs
             .iter()
             .filter(|cj| {
                 self.search_filter.is_empty()
@@ -72,69 +122,113 @@ impl CronJobsView {
             empty_state(ui, "No cronjobs found");
             return None;
         }
+        self.columns.sort(&mut filtered);
+
+        if let Some((i, command)) = self.cursor.handle_keys(ui, filtered.len(), self.selected_cronjob.is_none()) {
+            let cj = filtered[i];
+            match command {
+                RowCommand::Open => self.selected_cronjob = Some(cj.clone()),
+                RowCommand::Logs | RowCommand::Delete => {}
+            }
+        }
+
+        let keys: Vec<(&str, &str)> = filtered.iter().map(|cj| (cj.namespace.as_str(), cj.name.as_str())).collect();
+        let mut bulk = None;
+        self.selection.bar(ui, &keys, |ui, targets| {
+            if change_button(ui, "Suspend selected").clicked() {
+                bulk = Some(CronJobAction::SuspendMany(targets.to_vec(), true));
+            }
+            if change_button(ui, "Resume selected").clicked() {
+                bulk = Some(CronJobAction::SuspendMany(targets.to_vec(), false));
+            }
+        });
+        if bulk.is_some() {
+            self.selection.clear();
+            action = bulk;
+        }
 
         let available_height = ui.available_height();
 
-        TableBuilder::new(ui)
+        let mut table = TableBuilder::new(ui)
             .striped(true)
             .resizable(true)
             .cell_layout(egui::Layout::left_to_right(egui::Align::Center))
+            .column(Column::exact(24.0)) // Select
             .column(Column::auto().at_least(180.0)) // Name
-            .column(Column::auto().at_least(100.0)) // Namespace
-            .column(Column::auto().at_least(120.0)) // Schedule
-            .column(Column::auto().at_least(80.0))  // Suspend
-            .column(Column::auto().at_least(60.0))  // Active
-            .column(Column::auto().at_least(100.0)) // Last Schedule
-            .column(Column::auto().at_least(60.0))  // Age
-            .column(Column::remainder().at_least(200.0)) // Actions
             .min_scrolled_height(0.0)
-            .max_scroll_height(available_height - 50.0)
+            .max_scroll_height(available_height - 50.0);
+        table = self.columns.add(table, "Namespace", Column::auto().at_least(100.0));
+        table = self.columns.add(table, "Schedule", Column::auto().at_least(120.0));
+        table = self.columns.add(table, "Suspend", Column::auto().at_least(80.0));
+        table = self.columns.add(table, "Active", Column::auto().at_least(60.0));
+        table = self.columns.add(table, "Last Schedule", Column::auto().at_least(100.0));
+        table = self.columns.add(table, "Age", Column::auto().at_least(60.0));
+        table = self.columns.add_extra(table).column(Column::remainder().at_least(200.0)); // Actions
+        if let Some(row) = self.cursor.scroll_target() {
+            table = table.scroll_to_row(row, None);
+        }
+
+        table
             .header(25.0, |mut header| {
-                header.col(|ui| { ui.strong("Name"); });
-                header.col(|ui| { ui.strong("Namespace"); });
-                header.col(|ui| { ui.strong("Schedule"); });
-                header.col(|ui| { ui.strong("Suspend"); });
-                header.col(|ui| { ui.strong("Active"); });
-                header.col(|ui| { ui.strong("Last Schedule"); });
-                header.col(|ui| { ui.strong("Age"); });
+                header.col(|ui| self.selection.header_checkbox(ui, &keys));
+                for column in ["Name", "Namespace", "Schedule", "Suspend", "Active", "Last Schedule", "Age"] {
+                    self.columns.header(&mut header, column);
+                }
+                self.columns.extra_headers(&mut header);
                 header.col(|ui| { ui.strong("Actions"); });
             })
             .body(|mut body| {
-                for cj in &filtered {
+                for (i, cj) in filtered.iter().enumerate() {
                     body.row(30.0, |mut row| {
+                        row.set_selected(self.cursor.is_current(i));
+                        row.col(|ui| self.selection.checkbox(ui, &keys, i));
                         row.col(|ui| {
                             if ui.link(&cj.name).clicked() {
+                                self.cursor.set(i);
                                 self.selected_cronjob = Some((*cj).clone());
                             }
                         });
-                        row.col(|ui| { ui.label(&cj.namespace); });
-                        row.col(|ui| { ui.label(&cj.schedule); });
-                        row.col(|ui| {
-                            if cj.suspend {
-                                ui.label(RichText::new("Yes").color(Color32::from_rgb(234, 179, 8)));
-                            } else {
-                                ui.label(RichText::new("No").color(Color32::from_rgb(34, 197, 94)));
-                            }
-                        });
-                        row.col(|ui| { ui.label(cj.active.to_string()); });
-                        row.col(|ui| {
-                            if let Some(last) = &cj.last_schedule {
-                                ui.label(format!("{} ago", last));
-                            } else {
-                                ui.label("-");
-                            }
-                        });
-                        row.col(|ui| { ui.label(&cj.age); });
+                        if self.columns.shows("Namespace") {
+                            row.col(|ui| { ui.label(&cj.namespace); });
+                        }
+                        if self.columns.shows("Schedule") {
+                            row.col(|ui| { ui.label(&cj.schedule); });
+                        }
+                        if self.columns.shows("Suspend") {
+                            row.col(|ui| {
+                                if cj.suspend {
+                                    ui.label(RichText::new("Yes").color(Color32::from_rgb(234, 179, 8)));
+                                } else {
+                                    ui.label(RichText::new("No").color(Color32::from_rgb(34, 197, 94)));
+                                }
+                            });
+                        }
+                        if self.columns.shows("Active") {
+                            row.col(|ui| { ui.label(cj.active.to_string()); });
+                        }
+                        if self.columns.shows("Last Schedule") {
+                            row.col(|ui| {
+                                if let Some(last) = &cj.last_schedule {
+                                    ui.label(format!("{} ago", last));
+                                } else {
+                                    ui.label("-");
+                                }
+                            });
+                        }
+                        if self.columns.shows("Age") {
+                            row.col(|ui| { ui.label(&cj.age); });
+                        }
+                        self.columns.extra_cells(&mut row, *cj);
                         row.col(|ui| {
                             ui.horizontal(|ui| {
-                                if success_button(ui, "Run Now") {
+                                if ui.add_enabled_ui(changes_allowed(ui), |ui| success_button(ui, "Run Now")).inner {
                                     action = Some(CronJobAction::Trigger(
                                         cj.namespace.clone(),
                                         cj.name.clone(),
                                     ));
                                 }
                                 if cj.suspend {
-                                    if ui.small_button("Resume").clicked() {
+                                    if change_button(ui, "Resume").clicked() {
                                         action = Some(CronJobAction::Suspend(
                                             cj.namespace.clone(),
                                             cj.name.clone(),
@@ -142,7 +236,7 @@ impl CronJobsView {
                                         ));
                                     }
                                 } else {
-                                    if warning_button(ui, "Suspend") {
+                                    if ui.add_enabled_ui(changes_allowed(ui), |ui| warning_button(ui, "Suspend")).inner {
                                         action = Some(CronJobAction::Suspend(
                                             cj.namespace.clone(),
                                             cj.name.clone(),
@@ -236,6 +330,14 @@ impl CronJobsView {
                             close_details = true;
                         }
                         ui.separator();
+                        detail_tabs(ui, &mut self.detail_tab);
+                        if self.detail_tab == DetailTab::Yaml {
+                            let target = ResourceRef::new(ResourceKind::CronJob, &cj.namespace, &cj.name);
+                            if let Some(a) = self.yaml.show(ui, target) {
+                                action = Some(CronJobAction::Yaml(a));
+                            }
+                            return;
+                        }
 
                         info_row(ui, "Name", &cj.name);
                         info_row(ui, "Namespace", &cj.namespace);
@@ -247,9 +349,17 @@ impl CronJobsView {
                         if let Some(last) = &cj.last_schedule {
                             info_row(ui, "Last Schedule", &format!("{} ago", last));
                         }
+
+                        ui.add_space(12.0);
+                        let target = ResourceRef::new(ResourceKind::CronJob, &cj.namespace, &cj.name);
+                        if let Some(target) = self.events.show(ui, target) {
+                            action = Some(CronJobAction::LoadEvents(target));
+                        }
                     });
                 if close_details {
                     self.selected_cronjob = None;
+                    self.detail_tab = DetailTab::Overview;
+                    self.events = ObjectEvents::default();
                 }
             }
         }
@@ -262,3 +372,24 @@ impl CronJobsView {
         self.history_loading = false;
     }
 }
+
+impl TableItem for CronJobInfo {
+    fn sort_key(&self, column: &str) -> Option<SortKey> {
+        Some(match column {
+            "Name" => SortKey::Text(self.name.clone()),
+            "Namespace" => SortKey::Text(self.namespace.clone()),
+            "Suspend" => SortKey::Number(self.suspend as u8 as f64),
+            "Active" => SortKey::Number(self.active as f64),
+            "Age" => SortKey::Age(self.created),
+            _ => return None,
+        })
+    }
+
+    fn labels(&self) -> &BTreeMap<String, String> {
+        &self.labels
+    }
+
+    fn annotations(&self) -> &BTreeMap<String, String> {
+        &self.annotations
+    }
+}
@@ -0,0 +1,332 @@
+use crate::k8s::{CustomResourceKind, DynamicObjectInfo};
+use crate::views::common::*;
+use crate::views::selector::SelectorBar;
+use crate::views::yaml::highlight_yaml;
+use egui::{RichText, ScrollArea, Ui};
+use egui_extras::{Column, TableBuilder};
+
+#[derive(Default)]
+pub struct CustomResourcesView {
+    pub kind_filter: String,
+    pub include_builtin: bool,
+    pub selected_kind: Option<CustomResourceKind>,
+    pub search_filter: String,
+    pub selected_object: Option<DynamicObjectInfo>,
+    yaml: Option<Result<String, String>>,
+    pub cursor: RowCursor,
+    pub selector: SelectorBar,
+}
+
+#[derive(Clone)]
+pub enum CustomResourceAction {
+    Discover,
+    List(CustomResourceKind),
+    LoadYaml(CustomResourceKind, Option<String>, String),
+}
+
+impl CustomResourcesView {
+    #[allow(clippy::too_many_arguments)]
+    pub fn show(
+        &mut self,
+        ui: &mut Ui,
+        kinds: &[CustomResourceKind],
+        kinds_loading: bool,
+        kinds_error: Option<&str>,
+        objects: &[DynamicObjectInfo],
+        loading: bool,
+        error: Option<&str>,
+    ) -> Option<CustomResourceAction> {
+        let mut action = None;
+
+        egui::SidePanel::left("custom_resource_kinds")
+            .resizable(true)
+            .default_width(240.0)
+            .show_inside(ui, |ui| {
+                if let Some(a) = self.show_kinds(ui, kinds, kinds_loading, kinds_error) {
+                    action = Some(a);
+                }
+            });
+
+        egui::CentralPanel::default().show_inside(ui, |ui| {
+            if let Some(a) = self.show_objects(ui, objects, loading, error) {
+                action = Some(a);
+            }
+        });
+
+        action
+    }
+
+    fn show_kinds(
+        &mut self,
+        ui: &mut Ui,
+        kinds: &[CustomResourceKind],
+        loading: bool,
+        error: Option<&str>,
+    ) -> Option<CustomResourceAction> {
+        let mut action = None;
+
+        ui.horizontal(|ui| {
+            ui.strong("Kinds");
+            ui.with_layout(egui::Layout::right_to_left(egui::Align::Center), |ui| {
+                if ui.small_button("Rediscover").clicked() {
+                    action = Some(CustomResourceAction::Discover);
+                }
+            });
+        });
+        search_bar(ui, &mut self.kind_filter, "Filter kinds...");
+        ui.checkbox(&mut self.include_builtin, "Include built-in APIs");
+        ui.separator();
+
+        if loading {
+            loading_spinner(ui);
+            return action;
+        }
+
+        if let Some(err) = error {
+            error_label(ui, err);
+            return action;
+        }
+
+        let filter = self.kind_filter.to_lowercase();
+        let visible: Vec<_> = kinds
+            .iter()
+            .filter(|k| self.include_builtin || k.custom)
+            .filter(|k| {
+                filter.is_empty()
+                    || k.resource.kind.to_lowercase().contains(&filter)
+                    || k.resource.plural.contains(&filter)
+                    || k.resource.group.contains(&filter)
+            })
+            .collect();
+
+        if visible.is_empty() {
+            ui.label(RichText::new("No kinds found").weak());
+            return action;
+        }
+
+        ScrollArea::vertical().auto_shrink([false, false]).show(ui, |ui| {
+            let mut current_group = None;
+            for kind in visible {
+                if current_group != Some(kind.group_label()) {
+                    current_group = Some(kind.group_label());
+                    ui.add_space(6.0);
+                    ui.label(RichText::new(kind.group_label()).small().strong());
+                }
+                let selected = self.selected_kind.as_ref() == Some(kind);
+                if ui
+                    .selectable_label(selected, format!("  {}", kind.resource.kind))
+                    .on_hover_text(format!(
+                        "{}\n{}",
+                        kind.resource.api_version,
+                        if kind.namespaced { "Namespaced" } else { "Cluster-scoped" }
+                    ))
+                    .clicked()
+                    && !selected
+                {
+                    self.selected_kind = Some(kind.clone());
+                    self.selected_object = None;
+                    action = Some(CustomResourceAction::List(kind.clone()));
+                }
+            }
+        });
+
+        action
+    }
+
+    fn show_objects(
+        &mut self,
+        ui: &mut Ui,
+        objects: &[DynamicObjectInfo],
+        loading: bool,
+        error: Option<&str>,
+    ) -> Option<CustomResourceAction> {
+        let mut action = None;
+
+        let Some(kind) = self.selected_kind.clone() else {
+            empty_state(ui, "Select a kind to list its objects");
+            return None;
+        };
+
+        ui.horizontal(|ui| {
+            section_header(ui, &kind.resource.kind);
+            ui.label(RichText::new(&kind.resource.api_version).weak());
+            ui.with_layout(egui::Layout::right_to_left(egui::Align::Center), |ui| {
+                search_bar(ui, &mut self.search_filter, "Search...");
+            });
+        });
+        self.selector.show(ui);
+
+        if loading {
+            loading_spinner(ui);
+            return None;
+        }
+
+        if let Some(err) = error {
+            error_label(ui, err);
+            return None;
+        }
+
+        let filtered: Vec<_> = objects
+            .iter()
+            .filter(|o| {
+                self.search_filter.is_empty()
+                    || o.name.to_lowercase().contains(&self.search_filter.to_lowercase())
+                    || o.namespace.as_deref().unwrap_or_default().to_lowercase().contains(&self.search_filter.to_lowercase())
+            })
+            .collect();
+
+        if filtered.is_empty() {
+            empty_state(ui, &format!("No {} found", kind.resource.plural));
+            return None;
+        }
+
+        if let Some((i, RowCommand::Open)) = self.cursor.handle_keys(ui, filtered.len(), self.selected_object.is_none()) {
+            let object = filtered[i];
+            self.selected_object = Some(object.clone());
+            self.yaml = None;
+            action = Some(CustomResourceAction::LoadYaml(
+                kind.clone(),
+                object.namespace.clone(),
+                object.name.clone(),
+            ));
+        }
+
+        // Most CRDs already declare an Age column
+        let show_age = !kind.printer_columns.iter().any(|c| c.name == "Age");
+
+        let available_height = ui.available_height();
+
+        let mut table = TableBuilder::new(ui)
+            .striped(true)
+            .resizable(true)
+            .cell_layout(egui::Layout::left_to_right(egui::Align::Center))
+            .column(Column::auto().at_least(200.0)); // Name
+        if kind.namespaced {
+            table = table.column(Column::auto().at_least(100.0)); // Namespace
+        }
+        for _ in &kind.printer_columns {
+            table = table.column(Column::auto().at_least(80.0));
+        }
+        if show_age {
+            table = table.column(Column::auto().at_least(60.0)); // Age
+        }
+        table = table
+            .column(Column::remainder())
+            .min_scrolled_height(0.0)
+            .max_scroll_height(available_height - 50.0);
+        if let Some(row) = self.cursor.scroll_target() {
+            table = table.scroll_to_row(row, None);
+        }
+
+        table
+            .header(25.0, |mut header| {
+                header.col(|ui| { ui.strong("Name"); });
+                if kind.namespaced {
+                    header.col(|ui| { ui.strong("Namespace"); });
+                }
+                for column in &kind.printer_columns {
+                    header.col(|ui| { ui.strong(&column.name).on_hover_text(&column.json_path); });
+                }
+                if show_age {
+                    header.col(|ui| { ui.strong("Age"); });
+                }
+                header.col(|_| {});
+            })
+            .body(|mut body| {
+                for (i, object) in filtered.iter().enumerate() {
+                    body.row(30.0, |mut row| {
+                        row.set_selected(self.cursor.is_current(i));
+                        row.col(|ui| {
+                            if ui.link(&object.name).clicked() {
+                                self.cursor.set(i);
+                                self.selected_object = Some((*object).clone());
+                                self.yaml = None;
+                                action = Some(CustomResourceAction::LoadYaml(
+                                    kind.clone(),
+                                    object.namespace.clone(),
+                                    object.name.clone(),
+                                ));
+                            }
+                        });
+                        if kind.namespaced {
+                            row.col(|ui| { ui.label(object.namespace.as_deref().unwrap_or_default()); });
+                        }
+                        for value in &object.columns {
+                            row.col(|ui| {
+                                ui.label(truncate_string(value, 60)).on_hover_text(value);
+                            });
+                        }
+                        if show_age {
+                            row.col(|ui| { ui.label(&object.age); });
+                        }
+                        row.col(|_| {});
+                    });
+                }
+            });
+
+        // Detail panel
+        if let Some(object) = self.selected_object.clone() {
+            let mut close_details = false;
+            egui::Window::new(format!("{} Details", kind.resource.kind))
+                .resizable(true)
+                .default_width(600.0)
+                .default_height(500.0)
+                .show(ui.ctx(), |ui| {
+                    ui.horizontal(|ui| {
+                        if ui.button("Close").clicked() {
+                            close_details = true;
+                        }
+                        if ui.button("Reload").clicked() {
+                            self.yaml = None;
+                            action = Some(CustomResourceAction::LoadYaml(
+                                kind.clone(),
+                                object.namespace.clone(),
+                                object.name.clone(),
+                            ));
+                        }
+                    });
+                    ui.separator();
+
+                    info_row(ui, "Name", &object.name);
+                    if let Some(ns) = &object.namespace {
+                        info_row(ui, "Namespace", ns);
+                    }
+                    info_row(ui, "API Version", &kind.resource.api_version);
+                    info_row(ui, "Age", &object.age);
+                    ui.separator();
+
+                    match &self.yaml {
+                        None => loading_spinner(ui),
+                        Some(Err(e)) => error_label(ui, e),
+                        Some(Ok(text)) => {
+                            let mut layouter = |ui: &Ui, text: &str, wrap_width: f32| {
+                                let mut job = highlight_yaml(ui, text);
+                                job.wrap.max_width = wrap_width;
+                                ui.fonts(|f| f.layout_job(job))
+                            };
+                            ScrollArea::both().auto_shrink([false, false]).show(ui, |ui| {
+                                ui.add(
+                                    egui::TextEdit::multiline(&mut text.as_str())
+                                        .code_editor()
+                                        .desired_width(f32::INFINITY)
+                                        .layouter(&mut layouter),
+                                );
+                            });
+                        }
+                    }
+                });
+            if close_details {
+                self.selected_object = None;
+                self.yaml = None;
+            }
+        }
+
+        action
+    }
+
+    pub fn set_yaml(&mut self, name: &str, result: Result<String, String>) {
+        if self.selected_object.as_ref().is_some_and(|o| o.name == name) {
+            self.yaml = Some(result);
+        }
+    }
+}
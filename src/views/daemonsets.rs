@@ -0,0 +1,327 @@
+use crate::k8s::{DaemonSetInfo, ResourceKind, ResourceRef};
+use crate::views::columns::{ready_ratio, SortKey, TableColumns, TableItem};
+use crate::views::common::*;
+use crate::views::safety::{change_button, GuardedAction, UnconfirmedChange};
+use crate::views::selector::SelectorBar;
+use crate::views::events::ObjectEvents;
+use crate::views::rollout::PodRollout;
+use crate::views::yaml::{detail_tabs, DetailTab, YamlAction, YamlEditor};
+use egui::{Color32, RichText, Ui};
+use egui_extras::{Column, TableBuilder};
+use std::collections::BTreeMap;
+
+pub struct DaemonSetsView {
+    pub search_filter: String,
+    pub selected_daemonset: Option<DaemonSetInfo>,
+    pub show_delete_dialog: bool,
+    pub detail_tab: DetailTab,
+    pub yaml: YamlEditor,
+    pub events: ObjectEvents,
+    pub rollout: PodRollout,
+    pub cursor: RowCursor,
+    pub selector: SelectorBar,
+    pub columns: TableColumns,
+}
+
+impl Default for DaemonSetsView {
+    fn default() -> Self {
+        Self {
+            search_filter: String::new(),
+            selected_daemonset: None,
+            show_delete_dialog: false,
+            detail_tab: DetailTab::default(),
+            yaml: YamlEditor::default(),
+            events: ObjectEvents::default(),
+            rollout: PodRollout::default(),
+            cursor: RowCursor::default(),
+            selector: SelectorBar::default(),
+            columns: TableColumns::new(
+                &["Namespace", "Desired", "Ready", "Up-to-date", "Available", "Node Selector", "Age"],
+                &["Name", "Namespace", "Desired", "Ready", "Up-to-date", "Available", "Age"],
+            ),
+        }
+    }
+}
+
+#[derive(Clone)]
+pub enum DaemonSetAction {
+    Restart(String, String),
+    Delete(String, String),
+    Yaml(YamlAction),
+    LoadEvents(ResourceRef),
+    LoadRollout(ResourceRef),
+}
+
+impl GuardedAction for DaemonSetAction {
+    fn unconfirmed_change(&self) -> Option<UnconfirmedChange> {
+        match self {
+            DaemonSetAction::Restart(_, name) => Some(UnconfirmedChange::new(
+                format!("Restart daemonset '{}'? Its pods are replaced node by node.", name),
+                name,
+            )),
+            DaemonSetAction::Yaml(action) => action.unconfirmed_change(),
+            _ => None,
+        }
+    }
+}
+
+impl DaemonSetsView {
+    pub fn show(
+        &mut self,
+        ui: &mut Ui,
+        daemonsets: &[DaemonSetInfo],
+        loading: bool,
+        error: Option<&str>,
+    ) -> Vec<DaemonSetAction> {
+        let mut actions = Vec::new();
+
+        ui.horizontal(|ui| {
+            section_header(ui, "DaemonSets");
+            ui.with_layout(egui::Layout::right_to_left(egui::Align::Center), |ui| {
+                search_bar(ui, &mut self.search_filter, "Search daemonsets...");
+                self.columns.chooser(ui);
+            });
+        });
+        self.selector.show(ui);
+
+        if loading {
+            loading_spinner(ui);
+            return actions;
+        }
+
+        if let Some(err) = error {
+            error_label(ui, err);
+            return actions;
+        }
+
+        let mut filtered: Vec<_> = daemonsets
+            .iter()
+            .filter(|d| {
+                self.search_filter.is_empty()
+                    || d.name.to_lowercase().contains(&self.search_filter.to_lowercase())
+                    || d.namespace.to_lowercase().contains(&self.search_filter.to_lowercase())
+            })
+            .collect();
+
+        if filtered.is_empty() {
+            empty_state(ui, "No daemonsets found");
+            return actions;
+        }
+        self.columns.sort(&mut filtered);
+
+        if let Some((i, command)) = self.cursor.handle_keys(ui, filtered.len(), self.selected_daemonset.is_none()) {
+            let ds = filtered[i];
+            match command {
+                RowCommand::Open => self.selected_daemonset = Some(ds.clone()),
+                RowCommand::Delete => {
+                    self.selected_daemonset = Some(ds.clone());
+                    self.show_delete_dialog = true;
+                }
+                RowCommand::Logs => {}
+            }
+        }
+
+        let available_height = ui.available_height();
+
+        let mut table = TableBuilder::new(ui)
+            .striped(true)
+            .resizable(true)
+            .cell_layout(egui::Layout::left_to_right(egui::Align::Center))
+            .column(Column::auto().at_least(150.0)) // Name
+            .min_scrolled_height(0.0)
+            .max_scroll_height(available_height - 50.0);
+        table = self.columns.add(table, "Namespace", Column::auto().at_least(100.0));
+        table = self.columns.add(table, "Desired", Column::auto().at_least(70.0));
+        table = self.columns.add(table, "Ready", Column::auto().at_least(70.0));
+        table = self.columns.add(table, "Up-to-date", Column::auto().at_least(80.0));
+        table = self.columns.add(table, "Available", Column::auto().at_least(70.0));
+        table = self.columns.add(table, "Node Selector", Column::auto().at_least(140.0));
+        table = self.columns.add(table, "Age", Column::auto().at_least(60.0));
+        table = self.columns.add_extra(table).column(Column::remainder().at_least(150.0)); // Actions
+        if let Some(row) = self.cursor.scroll_target() {
+            table = table.scroll_to_row(row, None);
+        }
+
+        table
+            .header(25.0, |mut header| {
+                for column in ["Name", "Namespace", "Desired", "Ready", "Up-to-date", "Available", "Node Selector", "Age"] {
+                    self.columns.header(&mut header, column);
+                }
+                self.columns.extra_headers(&mut header);
+                header.col(|ui| { ui.strong("Actions"); });
+            })
+            .body(|mut body| {
+                for (i, ds) in filtered.iter().enumerate() {
+                    body.row(30.0, |mut row| {
+                        row.set_selected(self.cursor.is_current(i));
+                        row.col(|ui| {
+                            if ui.link(&ds.name).clicked() {
+                                self.cursor.set(i);
+                                self.selected_daemonset = Some((*ds).clone());
+                            }
+                        });
+                        if self.columns.shows("Namespace") {
+                            row.col(|ui| { ui.label(&ds.namespace); });
+                        }
+                        if self.columns.shows("Desired") {
+                            row.col(|ui| { ui.label(ds.desired.to_string()); });
+                        }
+                        if self.columns.shows("Ready") {
+                            row.col(|ui| {
+                                let color = if ds.ready == ds.desired {
+                                    Color32::from_rgb(34, 197, 94)
+                                } else if ds.ready > 0 {
+                                    Color32::from_rgb(234, 179, 8)
+                                } else {
+                                    Color32::from_rgb(239, 68, 68)
+                                };
+                                ui.label(RichText::new(ds.ready.to_string()).color(color));
+                            });
+                        }
+                        if self.columns.shows("Up-to-date") {
+                            row.col(|ui| { ui.label(ds.updated.to_string()); });
+                        }
+                        if self.columns.shows("Available") {
+                            row.col(|ui| { ui.label(ds.available.to_string()); });
+                        }
+                        if self.columns.shows("Node Selector") {
+                            row.col(|ui| {
+                                let selector = ds
+                                    .node_selector
+                                    .iter()
+                                    .map(|(k, v)| format!("{}={}", k, v))
+                                    .collect::<Vec<_>>()
+                                    .join(",");
+                                ui.label(if selector.is_empty() { "<none>".to_string() } else { selector });
+                            });
+                        }
+                        if self.columns.shows("Age") {
+                            row.col(|ui| { ui.label(&ds.age); });
+                        }
+                        self.columns.extra_cells(&mut row, *ds);
+                        row.col(|ui| {
+                            ui.horizontal(|ui| {
+                                if change_button(ui, "Restart").on_hover_text("Rolling restart").clicked() {
+                                    actions.push(DaemonSetAction::Restart(
+                                        ds.namespace.clone(),
+                                        ds.name.clone(),
+                                    ));
+                                }
+                                if change_button(ui, "Delete").on_hover_text("Delete daemonset").clicked() {
+                                    self.selected_daemonset = Some((*ds).clone());
+                                    self.show_delete_dialog = true;
+                                }
+                            });
+                        });
+                    });
+                }
+            });
+
+        // Delete dialog
+        if self.show_delete_dialog {
+            if let Some(ds) = &self.selected_daemonset {
+                match confirm_dialog(
+                    ui,
+                    "Confirm Delete",
+                    &format!("Are you sure you want to delete daemonset '{}'?", ds.name),
+                    "Delete",
+                    &ds.name,
+                ) {
+                    Some(true) => {
+                        actions.push(DaemonSetAction::Delete(ds.namespace.clone(), ds.name.clone()));
+                        self.show_delete_dialog = false;
+                    }
+                    Some(false) => self.show_delete_dialog = false,
+                    None => {}
+                }
+            }
+        }
+
+        // Detail panel
+        if let Some(ds) = self.selected_daemonset.clone() {
+            if !self.show_delete_dialog {
+                let mut close_details = false;
+                egui::Window::new("DaemonSet Details")
+                    .resizable(true)
+                    .default_width(450.0)
+                    .show(ui.ctx(), |ui| {
+                        if ui.button("Close").clicked() {
+                            close_details = true;
+                        }
+                        ui.separator();
+                        detail_tabs(ui, &mut self.detail_tab);
+                        let target = ResourceRef::new(ResourceKind::DaemonSet, &ds.namespace, &ds.name);
+                        if self.detail_tab == DetailTab::Yaml {
+                            if let Some(a) = self.yaml.show(ui, target) {
+                                actions.push(DaemonSetAction::Yaml(a));
+                            }
+                            return;
+                        }
+
+                        info_row(ui, "Name", &ds.name);
+                        info_row(ui, "Namespace", &ds.namespace);
+                        info_row(ui, "Scheduled", &format!("{}/{}", ds.current, ds.desired));
+                        info_row(ui, "Ready", &ds.ready.to_string());
+                        info_row(ui, "Age", &ds.age);
+
+                        if !ds.images.is_empty() {
+                            ui.add_space(8.0);
+                            ui.label(RichText::new("Images:").strong());
+                            for image in &ds.images {
+                                ui.label(format!("  • {}", image));
+                            }
+                        }
+
+                        if !ds.labels.is_empty() {
+                            ui.add_space(8.0);
+                            ui.label(RichText::new("Labels:").strong());
+                            for (k, v) in &ds.labels {
+                                ui.label(format!("  {}={}", k, v));
+                            }
+                        }
+
+                        ui.add_space(12.0);
+                        if let Some(target) = self.rollout.show(ui, target.clone(), "Node") {
+                            actions.push(DaemonSetAction::LoadRollout(target));
+                        }
+
+                        ui.add_space(12.0);
+                        if let Some(target) = self.events.show(ui, target) {
+                            actions.push(DaemonSetAction::LoadEvents(target));
+                        }
+                    });
+                if close_details {
+                    self.selected_daemonset = None;
+                    self.detail_tab = DetailTab::Overview;
+                    self.events = ObjectEvents::default();
+                    self.rollout = PodRollout::default();
+                }
+            }
+        }
+
+        actions
+    }
+}
+
+impl TableItem for DaemonSetInfo {
+    fn sort_key(&self, column: &str) -> Option<SortKey> {
+        Some(match column {
+            "Name" => SortKey::Text(self.name.clone()),
+            "Namespace" => SortKey::Text(self.namespace.clone()),
+            "Desired" => SortKey::Number(self.desired as f64),
+            "Ready" => ready_ratio(self.ready, self.desired),
+            "Up-to-date" => SortKey::Number(self.updated as f64),
+            "Available" => SortKey::Number(self.available as f64),
+            "Age" => SortKey::Age(self.created),
+            _ => return None,
+        })
+    }
+
+    fn labels(&self) -> &BTreeMap<String, String> {
+        &self.labels
+    }
+
+    fn annotations(&self) -> &BTreeMap<String, String> {
+        &self.annotations
+    }
+}
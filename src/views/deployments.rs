@@ -1,7 +1,15 @@
-use crate::k8s::DeploymentInfo;
+use crate::k8s::{validate_image_reference, DeploymentInfo, DeploymentRolloutStatus, ResourceKind, ResourceRef, RolloutPhase};
+use crate::views::bulk::RowSelection;
+use crate::views::columns::{ready_ratio, SortKey, TableColumns, TableItem};
 use crate::views::common::*;
+use crate::views::safety::{change_button, changes_allowed, GuardedAction, UnconfirmedChange};
+use crate::views::selector::SelectorBar;
+use crate::views::events::ObjectEvents;
+use crate::views::history::{HistoryAction, RolloutHistory};
+use crate::views::yaml::{detail_tabs, DetailTab, YamlAction, YamlEditor};
 use egui::{Color32, RichText, Ui};
 use egui_extras::{Column, TableBuilder};
+use std::collections::BTreeMap;
 
 pub struct DeploymentsView {
     pub search_filter: String,
@@ -9,14 +17,117 @@ pub struct DeploymentsView {
     pub scale_replicas: i32,
     pub show_scale_dialog: bool,
     pub show_delete_dialog: bool,
-    pub pending_action: Option<DeploymentAction>,
+    pub detail_tab: DetailTab,
+    pub yaml: YamlEditor,
+    pub events: ObjectEvents,
+    pub history: RolloutHistory,
+    pub rollout: Option<RolloutPanel>,
+    pub set_image: Option<SetImageDialog>,
+    pub cursor: RowCursor,
+    pub selector: SelectorBar,
+    pub columns: TableColumns,
+    pub selection: RowSelection,
+    /// Selected deployments waiting for the bulk restart to be confirmed.
+    pub bulk_restart: Option<Vec<(String, String)>>,
+    /// Selected deployments the scale dialog is open for; uses `scale_replicas`.
+    pub bulk_scale: Option<Vec<(String, String)>>,
+}
+
+/// Live progress of one deployment's rollout, fed by a watch until it completes or stalls.
+pub struct RolloutPanel {
+    pub namespace: String,
+    pub name: String,
+    status: Option<Result<DeploymentRolloutStatus, String>>,
+}
+
+impl RolloutPanel {
+    fn new(namespace: &str, name: &str) -> Self {
+        Self {
+            namespace: namespace.to_string(),
+            name: name.to_string(),
+            status: None,
+        }
+    }
+}
+
+/// Form for changing one container's image.
+pub struct SetImageDialog {
+    pub namespace: String,
+    pub name: String,
+    pub container: String,
+    pub current: String,
+    pub image: String,
+    /// Recorded as the new revision's change cause; a `kubectl set image` style
+    /// description is used when left blank.
+    pub change_cause: String,
+}
+
+impl SetImageDialog {
+    fn new(dep: &DeploymentInfo, container: &str, current: &str) -> Self {
+        Self {
+            namespace: dep.namespace.clone(),
+            name: dep.name.clone(),
+            container: container.to_string(),
+            current: current.to_string(),
+            image: current.to_string(),
+            change_cause: String::new(),
+        }
+    }
+
+    fn default_change_cause(&self) -> String {
+        format!("set image deployment/{} {}={}", self.name, self.container, self.image.trim())
+    }
 }
 
 #[derive(Clone)]
 pub enum DeploymentAction {
     Scale(String, String, i32),
     Restart(String, String),
+    /// Restarts or scales every `(namespace, name)` deployment at once.
+    RestartMany(Vec<(String, String)>),
+    ScaleMany(Vec<(String, String)>, i32),
+    Rollback(String, String, i64),
+    SetImage {
+        namespace: String,
+        name: String,
+        container: String,
+        image: String,
+        change_cause: String,
+    },
+    Pause(String, String),
+    Resume(String, String),
+    WatchRollout(String, String),
+    StopWatchingRollout,
     Delete(String, String),
+    Yaml(YamlAction),
+    LoadEvents(ResourceRef),
+    LoadHistory(ResourceRef),
+}
+
+impl GuardedAction for DeploymentAction {
+    fn unconfirmed_change(&self) -> Option<UnconfirmedChange> {
+        let (message, name) = match self {
+            DeploymentAction::Scale(_, name, replicas) => {
+                (format!("Scale deployment '{}' to {} replicas?", name, replicas), name.clone())
+            }
+            DeploymentAction::Restart(_, name) => {
+                (format!("Restart deployment '{}'? Its pods are replaced by a rolling update.", name), name.clone())
+            }
+            DeploymentAction::ScaleMany(targets, replicas) => (
+                format!("Scale {} deployments to {} replicas?", targets.len(), replicas),
+                format!("{} deployments", targets.len()),
+            ),
+            DeploymentAction::SetImage { name, container, image, .. } => (
+                format!("Set the image of container '{}' in deployment '{}' to {}?", container, name, image),
+                name.clone(),
+            ),
+            DeploymentAction::Pause(_, name) => (format!("Pause rollouts of deployment '{}'?", name), name.clone()),
+            DeploymentAction::Resume(_, name) => (format!("Resume the rollout of deployment '{}'?", name), name.clone()),
+            DeploymentAction::Yaml(action) => return action.unconfirmed_change(),
+            _ => return None,
+        };
+        Some(UnconfirmedChange::new(message, &name))
+    }
 }
 
 impl Default for DeploymentsView {
@@ -27,7 +138,21 @@ impl Default for DeploymentsView {
             scale_replicas: 1,
             show_scale_dialog: false,
             show_delete_dialog: false,
-            pending_action: None,
+            detail_tab: DetailTab::default(),
+            yaml: YamlEditor::default(),
+            events: ObjectEvents::default(),
+            history: RolloutHistory::default(),
+            rollout: None,
+            set_image: None,
+            cursor: RowCursor::default(),
+            selector: SelectorBar::default(),
+            columns: TableColumns::new(
+                &["Namespace", "Ready", "Up-to-date", "Available", "Age"],
+                &["Name", "Namespace", "Ready", "Up-to-date", "Available", "Age"],
+            ),
+            selection: RowSelection::default(),
+            bulk_restart: None,
+            bulk_scale: None,
         }
     }
 }
@@ -39,27 +164,29 @@ impl DeploymentsView {
         deployments: &[DeploymentInfo],
         loading: bool,
         error: Option<&str>,
-    ) -> Option<DeploymentAction> {
-        let mut action = None;
+    ) -> Vec<DeploymentAction> {
+        let mut actions: Vec<_> = self.show_rollout_panel(ui).into_iter().collect();
 
         ui.horizontal(|ui| {
             section_header(ui, "Deployments");
             ui.with_layout(egui::Layout::right_to_left(egui::Align::Center), |ui| {
                 search_bar(ui, &mut self.search_filter, "Search deployments...");
+                self.columns.chooser(ui);
             });
         });
+        self.selector.show(ui);
 
         if loading {
             loading_spinner(ui);
-            return None;
+            return actions;
         }
 
         if let Some(err) = error {
             error_label(ui, err);
-            return None;
+            return actions;
         }
 
-        let filtered: Vec<_> = deployments
+        let mut filtered: Vec<_> = deployments
             .iter()
             .filter(|d| {
                 self.search_filter.is_empty()
@@ -70,70 +197,129 @@ impl DeploymentsView {
 
         if filtered.is_empty() {
             empty_state(ui, "No deployments found");
-            return None;
+            return actions;
+        }
+        self.columns.sort(&mut filtered);
+
+        let keys_enabled = self.selected_deployment.is_none()
+            && self.set_image.is_none()
+            && self.bulk_restart.is_none()
+            && self.bulk_scale.is_none();
+        if let Some((i, command)) = self.cursor.handle_keys(ui, filtered.len(), keys_enabled) {
+            let dep = filtered[i];
+            match command {
+                RowCommand::Open => self.selected_deployment = Some(dep.clone()),
+                RowCommand::Delete => {
+                    self.selected_deployment = Some(dep.clone());
+                    self.show_delete_dialog = true;
+                }
+                RowCommand::Logs => {}
+            }
         }
 
+        let keys: Vec<(&str, &str)> = filtered.iter().map(|d| (d.namespace.as_str(), d.name.as_str())).collect();
+        self.selection.bar(ui, &keys, |ui, targets| {
+            if change_button(ui, "Restart selected").clicked() {
+                self.bulk_restart = Some(targets.to_vec());
+            }
+            if change_button(ui, "Scale selected").clicked() {
+                self.bulk_scale = Some(targets.to_vec());
+            }
+        });
+
         let available_height = ui.available_height();
 
-        TableBuilder::new(ui)
+        let mut table = TableBuilder::new(ui)
             .striped(true)
             .resizable(true)
             .cell_layout(egui::Layout::left_to_right(egui::Align::Center))
+            .column(Column::exact(24.0)) // Select
             .column(Column::auto().at_least(150.0)) // Name
-            .column(Column::auto().at_least(100.0)) // Namespace
-            .column(Column::auto().at_least(80.0))  // Ready
-            .column(Column::auto().at_least(80.0))  // Up-to-date
-            .column(Column::auto().at_least(80.0))  // Available
-            .column(Column::auto().at_least(60.0))  // Age
-            .column(Column::remainder().at_least(200.0)) // Actions
             .min_scrolled_height(0.0)
-            .max_scroll_height(available_height - 50.0)
+            .max_scroll_height(available_height - 50.0);
+        table = self.columns.add(table, "Namespace", Column::auto().at_least(100.0));
+        table = self.columns.add(table, "Ready", Column::auto().at_least(80.0));
+        table = self.columns.add(table, "Up-to-date", Column::auto().at_least(80.0));
+        table = self.columns.add(table, "Available", Column::auto().at_least(80.0));
+        table = self.columns.add(table, "Age", Column::auto().at_least(60.0));
+        table = self.columns.add_extra(table).column(Column::remainder().at_least(200.0)); // Actions
+        if let Some(row) = self.cursor.scroll_target() {
+            table = table.scroll_to_row(row, None);
+        }
+
+        table
             .header(25.0, |mut header| {
-                header.col(|ui| { ui.strong("Name"); });
-                header.col(|ui| { ui.strong("Namespace"); });
-                header.col(|ui| { ui.strong("Ready"); });
-                header.col(|ui| { ui.strong("Up-to-date"); });
-                header.col(|ui| { ui.strong("Available"); });
-                header.col(|ui| { ui.strong("Age"); });
+                header.col(|ui| self.selection.header_checkbox(ui, &keys));
+                for column in ["Name", "Namespace", "Ready", "Up-to-date", "Available", "Age"] {
+                    self.columns.header(&mut header, column);
+                }
+                self.columns.extra_headers(&mut header);
                 header.col(|ui| { ui.strong("Actions"); });
             })
             .body(|mut body| {
-                for deployment in &filtered {
+                for (i, deployment) in filtered.iter().enumerate() {
                     body.row(30.0, |mut row| {
+                        row.set_selected(self.cursor.is_current(i));
+                        row.col(|ui| self.selection.checkbox(ui, &keys, i));
                         row.col(|ui| {
                             if ui.link(&deployment.name).clicked() {
+                                self.cursor.set(i);
                                 self.selected_deployment = Some((*deployment).clone());
                             }
                         });
-                        row.col(|ui| { ui.label(&deployment.namespace); });
-                        row.col(|ui| {
-                            let ready_text = format!("{}/{}", deployment.ready, deployment.replicas);
-                            let color = if deployment.ready == deployment.replicas {
-                                Color32::from_rgb(34, 197, 94)
-                            } else if deployment.ready > 0 {
-                                Color32::from_rgb(234, 179, 8)
-                            } else {
-                                Color32::from_rgb(239, 68, 68)
-                            };
-                            ui.label(RichText::new(ready_text).color(color));
-                        });
-                        row.col(|ui| { ui.label(deployment.updated.to_string()); });
-                        row.col(|ui| { ui.label(deployment.available.to_string()); });
-                        row.col(|ui| { ui.label(&deployment.age); });
+                        if self.columns.shows("Namespace") {
+                            row.col(|ui| { ui.label(&deployment.namespace); });
+                        }
+                        if self.columns.shows("Ready") {
+                            row.col(|ui| {
+                                let ready_text = format!("{}/{}", deployment.ready, deployment.replicas);
+                                let color = if deployment.ready == deployment.replicas {
+                                    Color32::from_rgb(34, 197, 94)
+                                } else if deployment.ready > 0 {
+                                    Color32::from_rgb(234, 179, 8)
+                                } else {
+                                    Color32::from_rgb(239, 68, 68)
+                                };
+                                ui.label(RichText::new(ready_text).color(color));
+                            });
+                        }
+                        if self.columns.shows("Up-to-date") {
+                            row.col(|ui| { ui.label(deployment.updated.to_string()); });
+                        }
+                        if self.columns.shows("Available") {
+                            row.col(|ui| { ui.label(deployment.available.to_string()); });
+                        }
+                        if self.columns.shows("Age") {
+                            row.col(|ui| { ui.label(&deployment.age); });
+                        }
+                        self.columns.extra_cells(&mut row, *deployment);
                         row.col(|ui| {
                             ui.horizontal(|ui| {
-                                if ui.small_button("Scale").clicked() {
+                                if change_button(ui, "Scale").clicked() {
                                     self.selected_deployment = Some((*deployment).clone());
                                     self.scale_replicas = deployment.replicas;
                                     self.show_scale_dialog = true;
                                 }
-                                if ui.small_button("Restart").clicked() {
-                                    action = Some(DeploymentAction::Restart(
+                                if change_button(ui, "Restart").clicked() {
+                                    actions.push(DeploymentAction::Restart(
+                                        deployment.namespace.clone(),
+                                        deployment.name.clone(),
+                                    ));
+                                }
+                                if deployment.paused {
+                                    if change_button(ui, "Resume").on_hover_text("Resume the paused rollout").clicked() {
+                                        actions.push(DeploymentAction::Resume(
+                                            deployment.namespace.clone(),
+                                            deployment.name.clone(),
+                                        ));
+                                    }
+                                } else if change_button(ui, "Pause").on_hover_text("Pause rollouts of template changes").clicked() {
+                                    actions.push(DeploymentAction::Pause(
                                         deployment.namespace.clone(),
                                         deployment.name.clone(),
                                     ));
                                 }
-                                if ui.small_button("Delete").on_hover_text("Delete deployment").clicked() {
+                                if change_button(ui, "Delete").on_hover_text("Delete deployment").clicked() {
                                     self.selected_deployment = Some((*deployment).clone());
                                     self.show_delete_dialog = true;
                                 }
@@ -163,7 +349,7 @@ impl DeploymentsView {
                                 self.show_scale_dialog = false;
                             }
                             if primary_button(ui, "Scale") {
-                                action = Some(DeploymentAction::Scale(
+                                actions.push(DeploymentAction::Scale(
                                     dep.namespace.clone(),
                                     dep.name.clone(),
                                     self.scale_replicas,
@@ -175,58 +361,120 @@ impl DeploymentsView {
             }
         }
 
+        if let Some(targets) = &self.bulk_restart {
+            match confirm_dialog(
+                ui,
+                "Confirm Restart",
+                &format!("Restart {} deployments? Their pods are replaced by a rolling update.", targets.len()),
+                "Restart",
+                &format!("{} deployments", targets.len()),
+            ) {
+                Some(true) => {
+                    actions.push(DeploymentAction::RestartMany(targets.clone()));
+                    self.bulk_restart = None;
+                    self.selection.clear();
+                }
+                Some(false) => self.bulk_restart = None,
+                None => {}
+            }
+        }
+
+        if let Some(targets) = &self.bulk_scale {
+            let mut close = false;
+            egui::Window::new("Scale Deployments")
+                .collapsible(false)
+                .resizable(false)
+                .anchor(egui::Align2::CENTER_CENTER, [0.0, 0.0])
+                .show(ui.ctx(), |ui| {
+                    ui.label(format!("Scale {} deployments to the same replica count", targets.len()));
+                    ui.add_space(8.0);
+                    ui.horizontal(|ui| {
+                        ui.label("Replicas:");
+                        ui.add(egui::DragValue::new(&mut self.scale_replicas).range(0..=100));
+                    });
+                    ui.add_space(16.0);
+                    ui.horizontal(|ui| {
+                        if ui.button("Cancel").clicked() {
+                            close = true;
+                        }
+                        if primary_button(ui, "Scale") {
+                            actions.push(DeploymentAction::ScaleMany(targets.clone(), self.scale_replicas));
+                            self.selection.clear();
+                            close = true;
+                        }
+                    });
+                });
+            if close {
+                self.bulk_scale = None;
+            }
+        }
+
         // Delete dialog
         if self.show_delete_dialog {
             if let Some(dep) = &self.selected_deployment {
-                egui::Window::new("Confirm Delete")
-                    .collapsible(false)
-                    .resizable(false)
-                    .anchor(egui::Align2::CENTER_CENTER, [0.0, 0.0])
-                    .show(ui.ctx(), |ui| {
-                        ui.label(format!(
-                            "Are you sure you want to delete deployment '{}'?",
-                            dep.name
-                        ));
-                        ui.label("This action cannot be undone.");
-                        ui.add_space(16.0);
-                        ui.horizontal(|ui| {
-                            if ui.button("Cancel").clicked() {
-                                self.show_delete_dialog = false;
-                            }
-                            if danger_button(ui, "Delete") {
-                                action = Some(DeploymentAction::Delete(
-                                    dep.namespace.clone(),
-                                    dep.name.clone(),
-                                ));
-                                self.show_delete_dialog = false;
-                            }
-                        });
-                    });
+                match confirm_dialog(
+                    ui,
+                    "Confirm Delete",
+                    &format!("Are you sure you want to delete deployment '{}'? This action cannot be undone.", dep.name),
+                    "Delete",
+                    &dep.name,
+                ) {
+                    Some(true) => {
+                        actions.push(DeploymentAction::Delete(dep.namespace.clone(), dep.name.clone()));
+                        self.show_delete_dialog = false;
+                    }
+                    Some(false) => self.show_delete_dialog = false,
+                    None => {}
+                }
             }
         }
 
+        if let Some(a) = self.show_set_image_dialog(ui) {
+            actions.push(a);
+        }
+
         // Detail panel
         if let Some(dep) = self.selected_deployment.clone() {
             if !self.show_scale_dialog && !self.show_delete_dialog {
                 let mut close_details = false;
                 egui::Window::new("Deployment Details")
                     .resizable(true)
-                    .default_width(400.0)
+                    .default_width(500.0)
                     .show(ui.ctx(), |ui| {
                         if ui.button("Close").clicked() {
                             close_details = true;
                         }
                         ui.separator();
+                        detail_tabs(ui, &mut self.detail_tab);
+                        if self.detail_tab == DetailTab::Yaml {
+                            let target = ResourceRef::new(ResourceKind::Deployment, &dep.namespace, &dep.name);
+                            if let Some(a) = self.yaml.show(ui, target) {
+                                actions.push(DeploymentAction::Yaml(a));
+                            }
+                            return;
+                        }
+
                         info_row(ui, "Name", &dep.name);
                         info_row(ui, "Namespace", &dep.namespace);
                         info_row(ui, "Replicas", &format!("{}/{}", dep.ready, dep.replicas));
                         info_row(ui, "Age", &dep.age);
+                        if dep.paused {
+                            info_row(ui, "Rollout", "Paused");
+                        }
+                        if ui.button("Rollout status").clicked() {
+                            actions.push(DeploymentAction::WatchRollout(dep.namespace.clone(), dep.name.clone()));
+                        }
 
                         if !dep.images.is_empty() {
                             ui.add_space(8.0);
                             ui.label(RichText::new("Images:").strong());
-                            for image in &dep.images {
-                                ui.label(format!("  • {}", image));
+                            for (container, image) in dep.containers.iter().zip(&dep.images) {
+                                ui.horizontal(|ui| {
+                                    ui.label(format!("  • {}: {}", container, image));
+                                    if change_button(ui, "Set image").clicked() {
+                                        self.set_image = Some(SetImageDialog::new(&dep, container, image));
+                                    }
+                                });
                             }
                         }
 
@@ -237,13 +485,239 @@ impl DeploymentsView {
                                 ui.label(format!("  {}={}", k, v));
                             }
                         }
+
+                        ui.add_space(12.0);
+                        let target = ResourceRef::new(ResourceKind::Deployment, &dep.namespace, &dep.name);
+                        match self.history.show(ui, target.clone()) {
+                            Some(HistoryAction::Load(target)) => actions.push(DeploymentAction::LoadHistory(target)),
+                            Some(HistoryAction::Rollback(target, revision)) => {
+                                actions.push(DeploymentAction::Rollback(target.namespace, target.name, revision));
+                            }
+                            None => {}
+                        }
+
+                        ui.add_space(12.0);
+                        if let Some(target) = self.events.show(ui, target) {
+                            actions.push(DeploymentAction::LoadEvents(target));
+                        }
                     });
                 if close_details {
                     self.selected_deployment = None;
+                    self.detail_tab = DetailTab::Overview;
+                    self.events = ObjectEvents::default();
+                    self.history = RolloutHistory::default();
+                    self.set_image = None;
                 }
             }
         }
 
+        actions
+    }
+
+    fn show_set_image_dialog(&mut self, ui: &mut Ui) -> Option<DeploymentAction> {
+        let dialog = self.set_image.as_mut()?;
+        let mut action = None;
+        let mut close = false;
+
+        egui::Window::new("Set Image")
+            .collapsible(false)
+            .resizable(false)
+            .anchor(egui::Align2::CENTER_CENTER, [0.0, 0.0])
+            .show(ui.ctx(), |ui| {
+                info_row(ui, "Deployment", &format!("{}/{}", dialog.namespace, dialog.name));
+                info_row(ui, "Container", &dialog.container);
+                info_row(ui, "Current image", &dialog.current);
+                ui.add_space(8.0);
+
+                egui::Grid::new("set_image_form").num_columns(2).spacing([8.0, 6.0]).show(ui, |ui| {
+                    ui.label("New image:");
+                    ui.add(
+                        egui::TextEdit::singleline(&mut dialog.image)
+                            .font(egui::TextStyle::Monospace)
+                            .desired_width(360.0),
+                    );
+                    ui.end_row();
+                    ui.label("Change cause:");
+                    let hint = dialog.default_change_cause();
+                    ui.add(egui::TextEdit::singleline(&mut dialog.change_cause).hint_text(hint).desired_width(360.0));
+                    ui.end_row();
+                });
+
+                let image = dialog.image.trim();
+                let validation = validate_image_reference(image);
+                if let Err(e) = &validation {
+                    ui.add_space(4.0);
+                    error_label(ui, &e.to_string());
+                } else if image == dialog.current {
+                    ui.add_space(4.0);
+                    ui.label(RichText::new("Same as the current image; nothing would roll out.").weak());
+                }
+
+                ui.add_space(16.0);
+                ui.horizontal(|ui| {
+                    if ui.button("Cancel").clicked() {
+                        close = true;
+                    }
+                    let ready = validation.is_ok() && image != dialog.current && changes_allowed(ui);
+                    if ui.add_enabled_ui(ready, |ui| primary_button(ui, "Set image")).inner {
+                        let change_cause = match dialog.change_cause.trim() {
+                            "" => dialog.default_change_cause(),
+                            cause => cause.to_string(),
+                        };
+                        action = Some(DeploymentAction::SetImage {
+                            namespace: dialog.namespace.clone(),
+                            name: dialog.name.clone(),
+                            container: dialog.container.clone(),
+                            image: image.to_string(),
+                            change_cause,
+                        });
+                        close = true;
+                    }
+                });
+            });
+
+        if close {
+            self.set_image = None;
+        }
+        action
+    }
+
+    fn show_rollout_panel(&mut self, ui: &mut Ui) -> Option<DeploymentAction> {
+        let panel = self.rollout.as_ref()?;
+        let mut action = None;
+        let mut open = true;
+
+        egui::Window::new(format!("Rollout - {}", panel.name))
+            .open(&mut open)
+            .resizable(true)
+            .default_width(420.0)
+            .show(ui.ctx(), |ui| {
+                let status = match &panel.status {
+                    None => {
+                        loading_spinner(ui);
+                        return;
+                    }
+                    Some(Err(e)) => {
+                        error_label(ui, e);
+                        return;
+                    }
+                    Some(Ok(status)) => status,
+                };
+
+                let (label, color) = match status.phase {
+                    RolloutPhase::Pending | RolloutPhase::Progressing => ("Progressing", Color32::from_rgb(59, 130, 246)),
+                    RolloutPhase::Paused => ("Paused", Color32::from_rgb(156, 163, 175)),
+                    RolloutPhase::Complete => ("Complete", Color32::from_rgb(34, 197, 94)),
+                    RolloutPhase::Stuck => ("Stuck", Color32::from_rgb(239, 68, 68)),
+                };
+                ui.horizontal(|ui| {
+                    status_badge(ui, label, color);
+                    ui.label(&status.message);
+                });
+                ui.add_space(8.0);
+
+                let fraction = if status.desired > 0 {
+                    status.available.min(status.updated) as f32 / status.desired as f32
+                } else {
+                    1.0
+                };
+                ui.add(egui::ProgressBar::new(fraction).show_percentage());
+                ui.label(format!(
+                    "Updated {}/{} · Ready {}/{} · Available {}/{} · Total pods {}",
+                    status.updated, status.desired, status.ready, status.desired, status.available, status.desired, status.total
+                ));
+
+                // The controller only flags a stuck rollout once the deadline passes; warn as it approaches
+                if matches!(status.phase, RolloutPhase::Pending | RolloutPhase::Progressing) {
+                    if let (Some(deadline), Some(since)) = (status.progress_deadline_seconds, status.last_progress) {
+                        let idle = (chrono::Utc::now() - since).num_seconds().max(0);
+                        let text = format!("No progress for {}s (deadline {}s)", idle, deadline);
+                        let color = if idle >= deadline as i64 {
+                            Color32::from_rgb(239, 68, 68)
+                        } else if idle * 5 >= deadline as i64 * 4 {
+                            Color32::from_rgb(234, 179, 8)
+                        } else {
+                            ui.visuals().weak_text_color()
+                        };
+                        ui.label(RichText::new(text).small().color(color));
+                    }
+                }
+
+                if status.phase == RolloutPhase::Stuck {
+                    ui.add_space(8.0);
+                    ui.label(
+                        RichText::new("⚠ Rollout is stuck. Check the pods' events, or undo to a previous revision.")
+                            .strong()
+                            .color(Color32::from_rgb(239, 68, 68)),
+                    );
+                }
+
+                ui.add_space(8.0);
+                ui.label(RichText::new("Conditions:").strong());
+                for cond in &status.conditions {
+                    ui.horizontal(|ui| {
+                        let color = if cond.status == "True" { status_color("ready") } else { status_color("failed") };
+                        ui.colored_label(color, "●");
+                        ui.strong(&cond.condition_type);
+                        ui.label(RichText::new(&cond.reason).weak());
+                    })
+                    .response
+                    .on_hover_text(&cond.message);
+                }
+
+                ui.add_space(8.0);
+                match status.phase {
+                    RolloutPhase::Paused => {
+                        if ui.add_enabled(changes_allowed(ui), egui::Button::new("Resume")).clicked() {
+                            action = Some(DeploymentAction::Resume(panel.namespace.clone(), panel.name.clone()));
+                        }
+                    }
+                    RolloutPhase::Pending | RolloutPhase::Progressing => {
+                        if ui.add_enabled(changes_allowed(ui), egui::Button::new("Pause")).clicked() {
+                            action = Some(DeploymentAction::Pause(panel.namespace.clone(), panel.name.clone()));
+                        }
+                    }
+                    RolloutPhase::Complete | RolloutPhase::Stuck => {}
+                }
+            });
+
+        if !open {
+            self.rollout = None;
+            return Some(DeploymentAction::StopWatchingRollout);
+        }
         action
     }
+
+    /// Shows the rollout panel for a deployment whose rollout the app starts following.
+    pub fn open_rollout(&mut self, namespace: &str, name: &str) {
+        self.rollout = Some(RolloutPanel::new(namespace, name));
+    }
+
+    pub fn rollout_update(&mut self, result: Result<DeploymentRolloutStatus, String>) {
+        if let Some(panel) = &mut self.rollout {
+            panel.status = Some(result);
+        }
+    }
+}
+
+impl TableItem for DeploymentInfo {
+    fn sort_key(&self, column: &str) -> Option<SortKey> {
+        Some(match column {
+            "Name" => SortKey::Text(self.name.clone()),
+            "Namespace" => SortKey::Text(self.namespace.clone()),
+            "Ready" => ready_ratio(self.ready, self.replicas),
+            "Up-to-date" => SortKey::Number(self.updated as f64),
+            "Available" => SortKey::Number(self.available as f64),
+            "Age" => SortKey::Age(self.created),
+            _ => return None,
+        })
+    }
+
+    fn labels(&self) -> &BTreeMap<String, String> {
+        &self.labels
+    }
+
+    fn annotations(&self) -> &BTreeMap<String, String> {
+        &self.annotations
+    }
 }
@@ -0,0 +1,205 @@
+use crate::k8s::{EventInfo, ResourceRef};
+use crate::views::common::*;
+use crate::views::selector::SelectorBar;
+use egui::{Color32, RichText, Ui};
+use egui_extras::{Column, TableBuilder};
+
+#[derive(Default)]
+pub struct EventsView {
+    pub search_filter: String,
+    pub type_filter: Option<String>,
+    pub reason_filter: Option<String>,
+    pub selector: SelectorBar,
+}
+
+fn event_type_color(event_type: &str) -> Color32 {
+    match event_type {
+        "Warning" => Color32::from_rgb(234, 179, 8),
+        _ => Color32::from_rgb(156, 163, 175),
+    }
+}
+
+fn filter_combo(ui: &mut Ui, id: &str, label: &str, selected: &mut Option<String>, options: &[String]) {
+    egui::ComboBox::from_id_salt(id)
+        .selected_text(selected.as_deref().map_or_else(|| format!("All {}", label), str::to_string))
+        .show_ui(ui, |ui| {
+            ui.selectable_value(selected, None, format!("All {}", label));
+            for option in options {
+                ui.selectable_value(selected, Some(option.clone()), option);
+            }
+        });
+}
+
+impl EventsView {
+    pub fn show(&mut self, ui: &mut Ui, events: &[EventInfo], loading: bool, error: Option<&str>) {
+        let mut reasons: Vec<String> = events.iter().map(|e| e.reason.clone()).collect();
+        reasons.sort();
+        reasons.dedup();
+        let types = ["Normal".to_string(), "Warning".to_string()];
+
+        ui.horizontal(|ui| {
+            section_header(ui, "Events");
+            ui.with_layout(egui::Layout::right_to_left(egui::Align::Center), |ui| {
+                search_bar(ui, &mut self.search_filter, "Search events...");
+                filter_combo(ui, "event_reason_filter", "reasons", &mut self.reason_filter, &reasons);
+                filter_combo(ui, "event_type_filter", "types", &mut self.type_filter, &types);
+            });
+        });
+        self.selector.show(ui);
+
+        if loading {
+            loading_spinner(ui);
+            return;
+        }
+
+        if let Some(err) = error {
+            error_label(ui, err);
+            return;
+        }
+
+        let search = self.search_filter.to_lowercase();
+        let filtered: Vec<_> = events
+            .iter()
+            .filter(|e| self.type_filter.as_ref().is_none_or(|t| &e.event_type == t))
+            .filter(|e| self.reason_filter.as_ref().is_none_or(|r| &e.reason == r))
+            .filter(|e| {
+                search.is_empty()
+                    || e.involved_name.to_lowercase().contains(&search)
+                    || e.namespace.to_lowercase().contains(&search)
+                    || e.message.to_lowercase().contains(&search)
+            })
+            .collect();
+
+        if filtered.is_empty() {
+            empty_state(ui, "No events found");
+            return;
+        }
+
+        let available_height = ui.available_height();
+
+        TableBuilder::new(ui)
+            .striped(true)
+            .resizable(true)
+            .cell_layout(egui::Layout::left_to_right(egui::Align::Center))
+            .column(Column::auto().at_least(60.0))  // Last Seen
+            .column(Column::auto().at_least(80.0))  // Type
+            .column(Column::auto().at_least(140.0)) // Reason
+            .column(Column::auto().at_least(220.0)) // Object
+            .column(Column::auto().at_least(100.0)) // Namespace
+            .column(Column::auto().at_least(50.0))  // Count
+            .column(Column::auto().at_least(120.0)) // Source
+            .column(Column::remainder().at_least(300.0)) // Message
+            .min_scrolled_height(0.0)
+            .max_scroll_height(available_height - 50.0)
+            .header(25.0, |mut header| {
+                header.col(|ui| { ui.strong("Last Seen"); });
+                header.col(|ui| { ui.strong("Type"); });
+                header.col(|ui| { ui.strong("Reason"); });
+                header.col(|ui| { ui.strong("Object"); });
+                header.col(|ui| { ui.strong("Namespace"); });
+                header.col(|ui| { ui.strong("Count"); });
+                header.col(|ui| { ui.strong("Source"); });
+                header.col(|ui| { ui.strong("Message"); });
+            })
+            .body(|mut body| {
+                for event in &filtered {
+                    body.row(30.0, |mut row| {
+                        row.col(|ui| { ui.label(&event.last_seen); });
+                        row.col(|ui| { status_badge(ui, &event.event_type, event_type_color(&event.event_type)); });
+                        row.col(|ui| { ui.label(&event.reason); });
+                        row.col(|ui| { ui.label(format!("{}/{}", event.involved_kind, event.involved_name)); });
+                        row.col(|ui| { ui.label(&event.namespace); });
+                        row.col(|ui| { ui.label(event.count.to_string()); });
+                        row.col(|ui| { ui.label(&event.source); });
+                        row.col(|ui| {
+                            ui.label(truncate_string(&event.message, 120)).on_hover_text(&event.message);
+                        });
+                    });
+                }
+            });
+    }
+}
+
+/// Events section for a detail window, listing events whose `involvedObject`
+/// is the object being shown.
+#[derive(Default)]
+pub struct ObjectEvents {
+    target: Option<ResourceRef>,
+    events: Vec<EventInfo>,
+    loading: bool,
+    error: Option<String>,
+}
+
+impl ObjectEvents {
+    /// Returns the object whose events should be (re)loaded.
+    pub fn show(&mut self, ui: &mut Ui, target: ResourceRef) -> Option<ResourceRef> {
+        let mut load = None;
+
+        if self.target.as_ref() != Some(&target) {
+            *self = Self {
+                target: Some(target.clone()),
+                loading: true,
+                ..Default::default()
+            };
+            load = Some(target.clone());
+        }
+
+        let warnings = self.events.iter().filter(|e| e.event_type == "Warning").count();
+        let title = if warnings > 0 {
+            format!("Events ({}, {} warning)", self.events.len(), warnings)
+        } else {
+            format!("Events ({})", self.events.len())
+        };
+
+        egui::CollapsingHeader::new(RichText::new(title).strong())
+            .id_salt("object_events")
+            .default_open(true)
+            .show(ui, |ui| {
+                if ui.small_button("Refresh").clicked() {
+                    self.loading = true;
+                    load = Some(target.clone());
+                }
+
+                if self.loading {
+                    loading_spinner(ui);
+                    return;
+                }
+
+                if let Some(err) = &self.error {
+                    error_label(ui, err);
+                    return;
+                }
+
+                if self.events.is_empty() {
+                    ui.label(RichText::new("No events").weak());
+                    return;
+                }
+
+                for event in &self.events {
+                    ui.horizontal_wrapped(|ui| {
+                        status_badge(ui, &event.event_type, event_type_color(&event.event_type));
+                        ui.label(RichText::new(&event.reason).strong());
+                        ui.label(RichText::new(format!("{} ago", event.last_seen)).weak());
+                        if event.count > 1 {
+                            ui.label(RichText::new(format!("(x{})", event.count)).weak());
+                        }
+                    });
+                    ui.label(&event.message);
+                    ui.add_space(4.0);
+                }
+            });
+
+        load
+    }
+
+    pub fn set_loaded(&mut self, result: Result<Vec<EventInfo>, String>) {
+        self.loading = false;
+        match result {
+            Ok(events) => {
+                self.events = events;
+                self.error = None;
+            }
+            Err(e) => self.error = Some(e),
+        }
+    }
+}
@@ -0,0 +1,171 @@
+use crate::k8s::{ResourceRef, RevisionInfo};
+use crate::views::common::*;
+use crate::views::safety::changes_allowed;
+use crate::views::yaml::{line_diff, show_diff};
+use egui::{Color32, RichText, Ui};
+use similar::ChangeTag;
+
+#[derive(Clone)]
+pub enum HistoryAction {
+    Load(ResourceRef),
+    Rollback(ResourceRef, i64),
+}
+
+/// Collapsible revision history of a Deployment, with template diffs and rollback.
+#[derive(Default)]
+pub struct RolloutHistory {
+    target: Option<ResourceRef>,
+    revisions: Vec<RevisionInfo>,
+    loading: bool,
+    error: Option<String>,
+    /// Revisions being compared (older, newer) and their template diff.
+    compare: Option<(i64, i64)>,
+    diff: Vec<(ChangeTag, String)>,
+    confirm_undo: Option<i64>,
+}
+
+impl RolloutHistory {
+    pub fn show(&mut self, ui: &mut Ui, target: ResourceRef) -> Option<HistoryAction> {
+        let mut action = None;
+
+        if self.target.as_ref() != Some(&target) {
+            *self = Self {
+                target: Some(target.clone()),
+                loading: true,
+                ..Default::default()
+            };
+            action = Some(HistoryAction::Load(target.clone()));
+        }
+
+        egui::CollapsingHeader::new(RichText::new(format!("Revision History ({})", self.revisions.len())).strong())
+            .id_salt("rollout_history")
+            .default_open(true)
+            .show(ui, |ui| {
+                ui.horizontal(|ui| {
+                    if ui.small_button("Refresh").clicked() {
+                        self.loading = true;
+                        action = Some(HistoryAction::Load(target.clone()));
+                    }
+                    if self.revisions.len() >= 2 && ui.small_button("Compare...").clicked() {
+                        let newest = self.revisions[0].revision;
+                        self.set_compare(self.revisions[1].revision, newest);
+                    }
+                });
+
+                if self.loading {
+                    loading_spinner(ui);
+                    return;
+                }
+
+                if let Some(err) = &self.error {
+                    error_label(ui, err);
+                    return;
+                }
+
+                if self.revisions.is_empty() {
+                    ui.label(RichText::new("No revisions").weak());
+                    return;
+                }
+
+                egui::Grid::new("revision_history").striped(true).num_columns(5).show(ui, |ui| {
+                    for rev in &self.revisions {
+                        if rev.current {
+                            ui.label(RichText::new(format!("#{} (current)", rev.revision)).strong().color(Color32::from_rgb(34, 197, 94)));
+                        } else {
+                            ui.label(format!("#{}", rev.revision));
+                        }
+                        ui.label(rev.images.join(", ")).on_hover_text(&rev.replicaset);
+                        ui.label(RichText::new(format!("{} pods", rev.replicas)).weak());
+                        ui.label(RichText::new(format!("{} ago", rev.age)).weak())
+                            .on_hover_text(rev.change_cause.as_deref().unwrap_or("No change cause recorded"));
+                        if ui
+                            .add_enabled(!rev.current && changes_allowed(ui), egui::Button::new(format!("Undo to revision {}", rev.revision)).small())
+                            .clicked()
+                        {
+                            self.confirm_undo = Some(rev.revision);
+                        }
+                        ui.end_row();
+                    }
+                });
+            });
+
+        if let Some(revision) = self.confirm_undo {
+            match confirm_dialog(
+                ui,
+                "Confirm Rollback",
+                &format!("Roll back deployment '{}' to revision {}?", target.name, revision),
+                "Undo",
+                &target.name,
+            ) {
+                Some(true) => {
+                    self.confirm_undo = None;
+                    self.loading = true;
+                    action = Some(HistoryAction::Rollback(target.clone(), revision));
+                }
+                Some(false) => self.confirm_undo = None,
+                None => {}
+            }
+        }
+
+        if let Some((from, to)) = self.compare {
+            let mut open = true;
+            let (mut new_from, mut new_to) = (from, to);
+            egui::Window::new(format!("Revision diff - {}", target.name))
+                .open(&mut open)
+                .resizable(true)
+                .default_size([700.0, 500.0])
+                .show(ui.ctx(), |ui| {
+                    ui.horizontal(|ui| {
+                        self.revision_combo(ui, "diff_from", &mut new_from);
+                        ui.label("→");
+                        self.revision_combo(ui, "diff_to", &mut new_to);
+                    });
+                    ui.separator();
+                    show_diff(ui, &self.diff);
+                });
+            if !open {
+                self.compare = None;
+            } else if (new_from, new_to) != (from, to) {
+                self.set_compare(new_from, new_to);
+            }
+        }
+
+        action
+    }
+
+    fn revision_combo(&self, ui: &mut Ui, id: &str, selected: &mut i64) {
+        egui::ComboBox::from_id_salt(id)
+            .selected_text(format!("Revision {}", selected))
+            .show_ui(ui, |ui| {
+                for rev in &self.revisions {
+                    ui.selectable_value(selected, rev.revision, format!("Revision {}", rev.revision));
+                }
+            });
+    }
+
+    fn set_compare(&mut self, from: i64, to: i64) {
+        let template = |revision: i64| {
+            self.revisions
+                .iter()
+                .find(|r| r.revision == revision)
+                .map(|r| r.template_yaml.as_str())
+                .unwrap_or_default()
+        };
+        self.diff = line_diff(template(from), template(to));
+        self.compare = Some((from, to));
+    }
+
+    pub fn set_loaded(&mut self, result: Result<Vec<RevisionInfo>, String>) {
+        self.loading = false;
+        match result {
+            Ok(revisions) => {
+                self.revisions = revisions;
+                self.error = None;
+                if let Some((from, to)) = self.compare {
+                    self.set_compare(from, to);
+                }
+            }
+            Err(e) => self.error = Some(e),
+        }
+    }
+}
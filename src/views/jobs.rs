@@ -1,18 +1,28 @@
-use crate::k8s::{JobInfo, JobStatus};
+use crate::k8s::{JobInfo, JobStatus, ResourceKind, ResourceRef};
+use crate::views::bulk::RowSelection;
+use crate::views::columns::{SortKey, TableColumns, TableItem};
 use crate::views::common::*;
+use crate::views::safety::{change_button, GuardedAction, UnconfirmedChange};
+use crate::views::selector::SelectorBar;
+use crate::views::events::ObjectEvents;
+use crate::views::yaml::{detail_tabs, DetailTab, YamlAction, YamlEditor};
 use egui::{Color32, Ui};
 use egui_extras::{Column, TableBuilder};
+use std::collections::BTreeMap;
 
 pub struct JobsView {
     pub search_filter: String,
     pub selected_job: Option<JobInfo>,
     pub show_delete_dialog: bool,
-    pub pending_action: Option<JobAction>,
-}
-
-#[derive(Clone)]
-pub enum JobAction {
-    Delete(String, String),
+    pub detail_tab: DetailTab,
+    pub yaml: YamlEditor,
+    pub events: ObjectEvents,
+    pub cursor: RowCursor,
+    pub selector: SelectorBar,
+    pub columns: TableColumns,
+    pub selection: RowSelection,
+    /// Selected jobs waiting for the bulk delete to be confirmed.
+    pub bulk_delete: Option<Vec<(String, String)>>,
 }
 
 impl Default for JobsView {
@@ -21,7 +31,35 @@ impl Default for JobsView {
             search_filter: String::new(),
             selected_job: None,
             show_delete_dialog: false,
-            pending_action: None,
+            detail_tab: DetailTab::default(),
+            yaml: YamlEditor::default(),
+            events: ObjectEvents::default(),
+            cursor: RowCursor::default(),
+            selector: SelectorBar::default(),
+            columns: TableColumns::new(
+                &["Namespace", "Status", "Completions", "Duration", "Age"],
+                &["Name", "Namespace", "Status", "Age"],
+            ),
+            selection: RowSelection::default(),
+            bulk_delete: None,
+        }
+    }
+}
+
+#[derive(Clone)]
+pub enum JobAction {
+    Delete(String, String),
+    /// Deletes every `(namespace, name)` job at once.
+    DeleteMany(Vec<(String, String)>),
+    Yaml(YamlAction),
+    LoadEvents(ResourceRef),
+}
+
+impl GuardedAction for JobAction {
+    fn unconfirmed_change(&self) -> Option<UnconfirmedChange> {
+        match self {
+            JobAction::Yaml(action) => action.unconfirmed_change(),
+            _ => None,
         }
     }
 }
@@ -40,8 +78,10 @@ impl JobsView {
             section_header(ui, "Jobs");
             ui.with_layout(egui::Layout::right_to_left(egui::Align::Center), |ui| {
                 search_bar(ui, &mut self.search_filter, "Search jobs...");
+                self.columns.chooser(ui);
             });
         });
+        self.selector.show(ui);
 
         if loading {
             loading_spinner(ui);
@@ -53,7 +93,7 @@ impl JobsView {
             return None;
         }
 
-        let filtered: Vec<_> = jobs
+        let mut filtered: Vec<_> = jobs
             .iter()
             .filter(|j| {
                 self.search_filter.is_empty()
@@ -66,54 +106,94 @@ impl JobsView {
             empty_state(ui, "No jobs found");
             return None;
         }
+        self.columns.sort(&mut filtered);
+
+        let keys_enabled = self.selected_job.is_none() && self.bulk_delete.is_none();
+        if let Some((i, command)) = self.cursor.handle_keys(ui, filtered.len(), keys_enabled) {
+            let job = filtered[i];
+            match command {
+                RowCommand::Open => self.selected_job = Some(job.clone()),
+                RowCommand::Delete => {
+                    self.selected_job = Some(job.clone());
+                    self.show_delete_dialog = true;
+                }
+                RowCommand::Logs => {}
+            }
+        }
+
+        let keys: Vec<(&str, &str)> = filtered.iter().map(|j| (j.namespace.as_str(), j.name.as_str())).collect();
+        self.selection.bar(ui, &keys, |ui, targets| {
+            if change_button(ui, "Delete selected").clicked() {
+                self.bulk_delete = Some(targets.to_vec());
+            }
+        });
 
         let available_height = ui.available_height();
 
-        TableBuilder::new(ui)
+        let mut table = TableBuilder::new(ui)
             .striped(true)
             .resizable(true)
             .cell_layout(egui::Layout::left_to_right(egui::Align::Center))
+            .column(Column::exact(24.0)) // Select
             .column(Column::auto().at_least(200.0)) // Name
-            .column(Column::auto().at_least(100.0)) // Namespace
-            .column(Column::auto().at_least(100.0)) // Status
-            .column(Column::auto().at_least(80.0))  // Completions
-            .column(Column::auto().at_least(80.0))  // Duration
-            .column(Column::auto().at_least(60.0))  // Age
-            .column(Column::remainder().at_least(100.0)) // Actions
             .min_scrolled_height(0.0)
-            .max_scroll_height(available_height - 50.0)
+            .max_scroll_height(available_height - 50.0);
+        table = self.columns.add(table, "Namespace", Column::auto().at_least(100.0));
+        table = self.columns.add(table, "Status", Column::auto().at_least(100.0));
+        table = self.columns.add(table, "Completions", Column::auto().at_least(80.0));
+        table = self.columns.add(table, "Duration", Column::auto().at_least(80.0));
+        table = self.columns.add(table, "Age", Column::auto().at_least(60.0));
+        table = self.columns.add_extra(table).column(Column::remainder().at_least(100.0)); // Actions
+        if let Some(row) = self.cursor.scroll_target() {
+            table = table.scroll_to_row(row, None);
+        }
+
+        table
             .header(25.0, |mut header| {
-                header.col(|ui| { ui.strong("Name"); });
-                header.col(|ui| { ui.strong("Namespace"); });
-                header.col(|ui| { ui.strong("Status"); });
-                header.col(|ui| { ui.strong("Completions"); });
-                header.col(|ui| { ui.strong("Duration"); });
-                header.col(|ui| { ui.strong("Age"); });
+                header.col(|ui| self.selection.header_checkbox(ui, &keys));
+                for column in ["Name", "Namespace", "Status", "Completions", "Duration", "Age"] {
+                    self.columns.header(&mut header, column);
+                }
+                self.columns.extra_headers(&mut header);
                 header.col(|ui| { ui.strong("Actions"); });
             })
             .body(|mut body| {
-                for job in &filtered {
+                for (i, job) in filtered.iter().enumerate() {
                     body.row(30.0, |mut row| {
+                        row.set_selected(self.cursor.is_current(i));
+                        row.col(|ui| self.selection.checkbox(ui, &keys, i));
                         row.col(|ui| {
                             if ui.link(&job.name).clicked() {
+                                self.cursor.set(i);
                                 self.selected_job = Some((*job).clone());
                             }
                         });
-                        row.col(|ui| { ui.label(&job.namespace); });
-                        row.col(|ui| {
-                            let (status_text, color) = match &job.status {
-                                JobStatus::Running => ("Running", Color32::from_rgb(59, 130, 246)),
-                                JobStatus::Succeeded => ("Succeeded", Color32::from_rgb(34, 197, 94)),
-                                JobStatus::Failed => ("Failed", Color32::from_rgb(239, 68, 68)),
-                                JobStatus::Pending => ("Pending", Color32::from_rgb(234, 179, 8)),
-                            };
-                            status_badge(ui, status_text, color);
-                        });
-                        row.col(|ui| { ui.label(&job.completions); });
-                        row.col(|ui| { ui.label(&job.duration); });
-                        row.col(|ui| { ui.label(&job.age); });
+                        if self.columns.shows("Namespace") {
+                            row.col(|ui| { ui.label(&job.namespace); });
+                        }
+                        if self.columns.shows("Status") {
+                            row.col(|ui| {
+                                let (status_text, color) = match &job.status {
+                                    JobStatus::Running => ("Running", Color32::from_rgb(59, 130, 246)),
+                                    JobStatus::Succeeded => ("Succeeded", Color32::from_rgb(34, 197, 94)),
+                                    JobStatus::Failed => ("Failed", Color32::from_rgb(239, 68, 68)),
+                                    JobStatus::Pending => ("Pending", Color32::from_rgb(234, 179, 8)),
+                                };
+                                status_badge(ui, status_text, color);
+                            });
+                        }
+                        if self.columns.shows("Completions") {
+                            row.col(|ui| { ui.label(&job.completions); });
+                        }
+                        if self.columns.shows("Duration") {
+                            row.col(|ui| { ui.label(&job.duration); });
+                        }
+                        if self.columns.shows("Age") {
+                            row.col(|ui| { ui.label(&job.age); });
+                        }
+                        self.columns.extra_cells(&mut row, *job);
                         row.col(|ui| {
-                            if ui.small_button("Delete").clicked() {
+                            if change_button(ui, "Delete").clicked() {
                                 self.selected_job = Some((*job).clone());
                                 self.show_delete_dialog = true;
                             }
@@ -122,29 +202,41 @@ impl JobsView {
                 }
             });
 
+        if let Some(targets) = &self.bulk_delete {
+            match confirm_dialog(
+                ui,
+                "Confirm Delete",
+                &format!("Are you sure you want to delete {} jobs?", targets.len()),
+                "Delete",
+                &format!("{} jobs", targets.len()),
+            ) {
+                Some(true) => {
+                    action = Some(JobAction::DeleteMany(targets.clone()));
+                    self.bulk_delete = None;
+                    self.selection.clear();
+                }
+                Some(false) => self.bulk_delete = None,
+                None => {}
+            }
+        }
+
         // Delete dialog
         if self.show_delete_dialog {
             if let Some(job) = &self.selected_job {
-                egui::Window::new("Confirm Delete")
-                    .collapsible(false)
-                    .resizable(false)
-                    .anchor(egui::Align2::CENTER_CENTER, [0.0, 0.0])
-                    .show(ui.ctx(), |ui| {
-                        ui.label(format!("Are you sure you want to delete job '{}'?", job.name));
-                        ui.add_space(16.0);
-                        ui.horizontal(|ui| {
-                            if ui.button("Cancel").clicked() {
-                                self.show_delete_dialog = false;
-                            }
-                            if danger_button(ui, "Delete") {
-                                action = Some(JobAction::Delete(
-                                    job.namespace.clone(),
-                                    job.name.clone(),
-                                ));
-                                self.show_delete_dialog = false;
-                            }
-                        });
-                    });
+                match confirm_dialog(
+                    ui,
+                    "Confirm Delete",
+                    &format!("Are you sure you want to delete job '{}'?", job.name),
+                    "Delete",
+                    &job.name,
+                ) {
+                    Some(true) => {
+                        action = Some(JobAction::Delete(job.namespace.clone(), job.name.clone()));
+                        self.show_delete_dialog = false;
+                    }
+                    Some(false) => self.show_delete_dialog = false,
+                    None => {}
+                }
             }
         }
 
@@ -160,6 +252,14 @@ impl JobsView {
                             close_details = true;
                         }
                         ui.separator();
+                        detail_tabs(ui, &mut self.detail_tab);
+                        if self.detail_tab == DetailTab::Yaml {
+                            let target = ResourceRef::new(ResourceKind::Job, &job.namespace, &job.name);
+                            if let Some(a) = self.yaml.show(ui, target) {
+                                action = Some(JobAction::Yaml(a));
+                            }
+                            return;
+                        }
 
                         info_row(ui, "Name", &job.name);
                         info_row(ui, "Namespace", &job.namespace);
@@ -178,9 +278,17 @@ impl JobsView {
                         if let Some(owner) = &job.owner {
                             info_row(ui, "Owner", owner);
                         }
+
+                        ui.add_space(12.0);
+                        let target = ResourceRef::new(ResourceKind::Job, &job.namespace, &job.name);
+                        if let Some(target) = self.events.show(ui, target) {
+                            action = Some(JobAction::LoadEvents(target));
+                        }
                     });
                 if close_details {
                     self.selected_job = None;
+                    self.detail_tab = DetailTab::Overview;
+                    self.events = ObjectEvents::default();
                 }
             }
         }
@@ -188,3 +296,23 @@ impl JobsView {
         action
     }
 }
+
+impl TableItem for JobInfo {
+    fn sort_key(&self, column: &str) -> Option<SortKey> {
+        Some(match column {
+            "Name" => SortKey::Text(self.name.clone()),
+            "Namespace" => SortKey::Text(self.namespace.clone()),
+            "Status" => SortKey::Text(format!("{:?}", self.status)),
+            "Age" => SortKey::Age(self.created),
+            _ => return None,
+        })
+    }
+
+    fn labels(&self) -> &BTreeMap<String, String> {
+        &self.labels
+    }
+
+    fn annotations(&self) -> &BTreeMap<String, String> {
+        &self.annotations
+    }
+}
@@ -1,15 +1,43 @@
 pub mod deployments;
+pub mod statefulsets;
+pub mod daemonsets;
+pub mod replicasets;
 pub mod pods;
 pub mod services;
 pub mod config;
+pub mod create;
 pub mod jobs;
 pub mod cronjobs;
+pub mod terminal;
+pub mod portforwards;
+pub mod yaml;
+pub mod rollout;
+pub mod history;
+pub mod events;
+pub mod nodes;
+pub mod custom;
+pub mod topology;
+pub mod palette;
+pub mod selector;
+pub mod columns;
+pub mod bulk;
+pub mod audit;
+pub mod safety;
 mod common;
 
 pub use deployments::DeploymentsView;
+pub use statefulsets::StatefulSetsView;
+pub use daemonsets::DaemonSetsView;
+pub use replicasets::ReplicaSetsView;
 pub use pods::PodsView;
 pub use services::ServicesView;
 pub use config::ConfigView;
 pub use jobs::JobsView;
 pub use cronjobs::CronJobsView;
+pub use portforwards::PortForwardsView;
+pub use events::EventsView;
+pub use nodes::NodesView;
+pub use custom::CustomResourcesView;
+pub use topology::TopologyView;
+pub use audit::AuditView;
 pub use common::*;
@@ -0,0 +1,491 @@
+use crate::k8s::{
+    format_cpu_millis, parse_cpu_quantity, DrainPodStatus, EvictionStatus, NodeInfo, PodInfo,
+    UsageHistory,
+};
+use crate::views::columns::{SortKey, TableColumns, TableItem};
+use crate::views::common::*;
+use crate::views::safety::{change_button, changes_allowed, GuardedAction, UnconfirmedChange};
+use crate::views::selector::SelectorBar;
+use egui::{Color32, RichText, ScrollArea, Ui};
+use egui_extras::{Column, TableBuilder};
+use std::collections::{BTreeMap, HashMap};
+
+pub struct NodesView {
+    pub search_filter: String,
+    pub selected_node: Option<NodeInfo>,
+    pub show_drain_dialog: bool,
+    pub drain: Option<DrainProgress>,
+    pub cursor: RowCursor,
+    pub selector: SelectorBar,
+    pub columns: TableColumns,
+}
+
+impl Default for NodesView {
+    fn default() -> Self {
+        Self {
+            search_filter: String::new(),
+            selected_node: None,
+            show_drain_dialog: false,
+            drain: None,
+            cursor: RowCursor::default(),
+            selector: SelectorBar::default(),
+            columns: TableColumns::new(
+                &["Status", "Roles", "Version", "CPU Usage", "Memory Usage", "CPU", "Memory", "Pods", "Age"],
+                &["Name", "Status", "Roles", "Version", "Age"],
+            ),
+        }
+    }
+}
+
+/// Per-pod progress of the drain started from this view.
+pub struct DrainProgress {
+    pub node: String,
+    pub pods: Vec<DrainPodStatus>,
+    pub result: Option<Result<(), String>>,
+}
+
+#[derive(Clone)]
+pub enum NodeAction {
+    Cordon(String),
+    Uncordon(String),
+    Drain(String),
+    CancelDrain,
+}
+
+impl GuardedAction for NodeAction {
+    fn unconfirmed_change(&self) -> Option<UnconfirmedChange> {
+        match self {
+            NodeAction::Cordon(name) => Some(UnconfirmedChange::new(
+                format!("Cordon node '{}'? No new pods are scheduled on it.", name),
+                name,
+            )),
+            NodeAction::Uncordon(name) => Some(UnconfirmedChange::new(
+                format!("Uncordon node '{}'? Pods can be scheduled on it again.", name),
+                name,
+            )),
+            NodeAction::Drain(_) | NodeAction::CancelDrain => None,
+        }
+    }
+}
+
+fn eviction_badge(ui: &mut Ui, status: &EvictionStatus) {
+    match status {
+        EvictionStatus::Pending => status_badge(ui, "Pending", status_color("pending")),
+        EvictionStatus::Terminating => status_badge(ui, "Terminating", status_color("pending")),
+        EvictionStatus::Evicted => status_badge(ui, "Evicted", status_color("succeeded")),
+        EvictionStatus::Blocked(msg) => {
+            status_badge(ui, "Blocked by PDB", Color32::from_rgb(234, 179, 8));
+            ui.label(RichText::new("ⓘ").color(Color32::GRAY)).on_hover_text(msg);
+        }
+        EvictionStatus::Skipped(reason) => {
+            status_badge(ui, "Skipped", status_color("terminated"));
+            ui.label(RichText::new(reason).small().weak());
+        }
+        EvictionStatus::Failed(msg) => {
+            status_badge(ui, "Failed", status_color("failed"));
+            ui.label(RichText::new("ⓘ").color(Color32::GRAY)).on_hover_text(msg);
+        }
+    }
+}
+
+fn usage_label(ui: &mut Ui, text: String, used: u64, allocatable: u64) {
+    if allocatable == 0 {
+        ui.label(text);
+        return;
+    }
+    let percent = used * 100 / allocatable;
+    let color = if percent >= 90 {
+        Color32::from_rgb(239, 68, 68)
+    } else if percent >= 70 {
+        Color32::from_rgb(234, 179, 8)
+    } else {
+        Color32::GRAY
+    };
+    ui.label(RichText::new(format!("{} ({}%)", text, percent)).color(color));
+}
+
+impl NodesView {
+    pub fn show(
+        &mut self,
+        ui: &mut Ui,
+        nodes: &[NodeInfo],
+        pods: &[PodInfo],
+        usage: Option<&HashMap<String, UsageHistory>>,
+        loading: bool,
+        error: Option<&str>,
+    ) -> Option<NodeAction> {
+        let mut action = None;
+
+        ui.horizontal(|ui| {
+            section_header(ui, "Nodes");
+            ui.with_layout(egui::Layout::right_to_left(egui::Align::Center), |ui| {
+                search_bar(ui, &mut self.search_filter, "Search nodes...");
+                self.columns.chooser(ui);
+            });
+        });
+        self.selector.show(ui);
+
+        if loading {
+            loading_spinner(ui);
+            return None;
+        }
+
+        if let Some(err) = error {
+            error_label(ui, err);
+            return None;
+        }
+
+        let mut pod_counts: HashMap<&str, usize> = HashMap::new();
+        for pod in pods {
+            *pod_counts.entry(pod.node.as_str()).or_default() += 1;
+        }
+
+        let mut filtered: Vec<_> = nodes
+            .iter()
+            .filter(|n| {
+                self.search_filter.is_empty()
+                    || n.name.to_lowercase().contains(&self.search_filter.to_lowercase())
+                    || n.roles.iter().any(|r| r.contains(&self.search_filter.to_lowercase()))
+            })
+            .collect();
+
+        if filtered.is_empty() {
+            empty_state(ui, "No nodes found");
+            return None;
+        }
+        self.columns.sort(&mut filtered);
+
+        let keys_enabled = self.selected_node.is_none() && !self.show_drain_dialog && self.drain.is_none();
+        if let Some((i, RowCommand::Open)) = self.cursor.handle_keys(ui, filtered.len(), keys_enabled) {
+            self.selected_node = Some(filtered[i].clone());
+        }
+
+        let available_height = ui.available_height();
+
+        let mut table = TableBuilder::new(ui)
+            .striped(true)
+            .resizable(true)
+            .cell_layout(egui::Layout::left_to_right(egui::Align::Center))
+            .column(Column::auto().at_least(200.0)) // Name
+            .min_scrolled_height(0.0)
+            .max_scroll_height(available_height - 50.0);
+        table = self.columns.add(table, "Status", Column::auto().at_least(160.0));
+        table = self.columns.add(table, "Roles", Column::auto().at_least(100.0));
+        table = self.columns.add(table, "Version", Column::auto().at_least(90.0));
+        // Usage columns are hidden when the cluster has no metrics API
+        if usage.is_some() {
+            table = self.columns.add(table, "CPU Usage", Column::auto().at_least(100.0));
+            table = self.columns.add(table, "Memory Usage", Column::auto().at_least(120.0));
+        }
+        table = self.columns.add(table, "CPU", Column::auto().at_least(90.0));
+        table = self.columns.add(table, "Memory", Column::auto().at_least(150.0));
+        table = self.columns.add(table, "Pods", Column::auto().at_least(70.0));
+        table = self.columns.add(table, "Age", Column::auto().at_least(60.0));
+        table = self.columns.add_extra(table).column(Column::remainder().at_least(150.0)); // Actions
+        if let Some(row) = self.cursor.scroll_target() {
+            table = table.scroll_to_row(row, None);
+        }
+
+        table
+            .header(25.0, |mut header| {
+                for column in ["Name", "Status", "Roles", "Version"] {
+                    self.columns.header(&mut header, column);
+                }
+                if usage.is_some() {
+                    self.columns.header_with_hint(&mut header, "CPU Usage", "Share of allocatable");
+                    self.columns.header_with_hint(&mut header, "Memory Usage", "Share of allocatable");
+                }
+                self.columns.header_with_hint(&mut header, "CPU", "Allocatable / capacity");
+                self.columns.header_with_hint(&mut header, "Memory", "Allocatable / capacity");
+                self.columns.header_with_hint(&mut header, "Pods", "Running here / allocatable");
+                self.columns.header(&mut header, "Age");
+                self.columns.extra_headers(&mut header);
+                header.col(|ui| { ui.strong("Actions"); });
+            })
+            .body(|mut body| {
+                for (i, node) in filtered.iter().enumerate() {
+                    body.row(30.0, |mut row| {
+                        row.set_selected(self.cursor.is_current(i));
+                        row.col(|ui| {
+                            if ui.link(&node.name).clicked() {
+                                self.cursor.set(i);
+                                self.selected_node = Some((*node).clone());
+                            }
+                        });
+                        if self.columns.shows("Status") {
+                            row.col(|ui| {
+                                let color = if node.status == "Ready" { status_color("ready") } else { status_color("failed") };
+                                status_badge(ui, &node.status, color);
+                                if node.unschedulable {
+                                    status_badge(ui, "Cordoned", status_color("pending"));
+                                }
+                            });
+                        }
+                        if self.columns.shows("Roles") {
+                            row.col(|ui| {
+                                ui.label(if node.roles.is_empty() { "<none>".to_string() } else { node.roles.join(",") });
+                            });
+                        }
+                        if self.columns.shows("Version") {
+                            row.col(|ui| { ui.label(&node.kubelet_version); });
+                        }
+                        if let Some(usage) = usage {
+                            let current = usage.get(&node.name).and_then(|h| h.latest());
+                            if self.columns.shows("CPU Usage") {
+                                row.col(|ui| match current {
+                                    Some(u) => {
+                                        let allocatable = parse_cpu_quantity(&node.cpu_allocatable).unwrap_or(0);
+                                        usage_label(ui, format_cpu_millis(u.cpu_millis), u.cpu_millis, allocatable);
+                                    }
+                                    None => { ui.label("-"); }
+                                });
+                            }
+                            if self.columns.shows("Memory Usage") {
+                                row.col(|ui| match current {
+                                    Some(u) => {
+                                        usage_label(ui, format_bytes(u.memory_bytes), u.memory_bytes, node.memory_allocatable);
+                                    }
+                                    None => { ui.label("-"); }
+                                });
+                            }
+                        }
+                        if self.columns.shows("CPU") {
+                            row.col(|ui| { ui.label(format!("{} / {}", node.cpu_allocatable, node.cpu_capacity)); });
+                        }
+                        if self.columns.shows("Memory") {
+                            row.col(|ui| {
+                                ui.label(format!(
+                                    "{} / {}",
+                                    format_bytes(node.memory_allocatable),
+                                    format_bytes(node.memory_capacity)
+                                ));
+                            });
+                        }
+                        if self.columns.shows("Pods") {
+                            row.col(|ui| {
+                                let count = pod_counts.get(node.name.as_str()).copied().unwrap_or(0);
+                                ui.label(format!("{} / {}", count, node.pods_capacity));
+                            });
+                        }
+                        if self.columns.shows("Age") {
+                            row.col(|ui| { ui.label(&node.age); });
+                        }
+                        self.columns.extra_cells(&mut row, *node);
+                        row.col(|ui| {
+                            ui.horizontal(|ui| {
+                                if node.unschedulable {
+                                    if change_button(ui, "Uncordon").on_hover_text("Allow new pods to be scheduled").clicked() {
+                                        action = Some(NodeAction::Uncordon(node.name.clone()));
+                                    }
+                                } else if change_button(ui, "Cordon").on_hover_text("Stop scheduling new pods").clicked() {
+                                    action = Some(NodeAction::Cordon(node.name.clone()));
+                                }
+                                let draining = self.drain.as_ref().is_some_and(|d| d.result.is_none());
+                                if ui
+                                    .add_enabled(!draining && changes_allowed(ui), egui::Button::new("Drain").small())
+                                    .on_hover_text("Cordon and evict all pods")
+                                    .clicked()
+                                {
+                                    self.selected_node = Some((*node).clone());
+                                    self.show_drain_dialog = true;
+                                }
+                            });
+                        });
+                    });
+                }
+            });
+
+        // Drain dialog
+        if self.show_drain_dialog {
+            if let Some(node) = &self.selected_node {
+                match confirm_dialog(
+                    ui,
+                    "Confirm Drain",
+                    &format!(
+                        "Drain node '{}'? It will be cordoned and its pods evicted. \
+                         DaemonSet and static pods are left in place.",
+                        node.name
+                    ),
+                    "Drain",
+                    &node.name,
+                ) {
+                    Some(true) => {
+                        action = Some(NodeAction::Drain(node.name.clone()));
+                        self.drain = Some(DrainProgress {
+                            node: node.name.clone(),
+                            pods: vec![],
+                            result: None,
+                        });
+                        self.selected_node = None;
+                        self.show_drain_dialog = false;
+                    }
+                    Some(false) => self.show_drain_dialog = false,
+                    None => {}
+                }
+            }
+        }
+
+        // Drain progress
+        if let Some(drain) = &self.drain {
+            let mut close = false;
+            egui::Window::new(format!("Drain - {}", drain.node))
+                .resizable(true)
+                .default_width(520.0)
+                .show(ui.ctx(), |ui| {
+                    let evicted = drain.pods.iter().filter(|p| p.status == EvictionStatus::Evicted).count();
+                    let to_evict = drain.pods.iter().filter(|p| !matches!(p.status, EvictionStatus::Skipped(_))).count();
+
+                    ui.horizontal(|ui| {
+                        match &drain.result {
+                            None => {
+                                ui.spinner();
+                                ui.label(format!("Evicted {} of {} pods", evicted, to_evict));
+                            }
+                            Some(Ok(())) => {
+                                status_badge(ui, "Drained", status_color("succeeded"));
+                                ui.label(format!("Evicted {} pods", evicted));
+                            }
+                            Some(Err(e)) => error_label(ui, e),
+                        }
+                        ui.with_layout(egui::Layout::right_to_left(egui::Align::Center), |ui| {
+                            if drain.result.is_some() {
+                                if ui.button("Close").clicked() {
+                                    close = true;
+                                }
+                            } else if danger_button(ui, "Cancel") {
+                                action = Some(NodeAction::CancelDrain);
+                            }
+                        });
+                    });
+                    ui.separator();
+
+                    ScrollArea::vertical().max_height(400.0).show(ui, |ui| {
+                        egui::Grid::new("drain_pods").striped(true).num_columns(2).show(ui, |ui| {
+                            for pod in &drain.pods {
+                                ui.label(format!("{}/{}", pod.namespace, pod.name));
+                                ui.horizontal(|ui| eviction_badge(ui, &pod.status));
+                                ui.end_row();
+                            }
+                        });
+                    });
+                });
+            if close {
+                self.drain = None;
+            }
+        }
+
+        // Node detail panel
+        if let Some(node) = self.selected_node.clone() {
+            if !self.show_drain_dialog {
+                let mut close_details = false;
+                egui::Window::new("Node Details")
+                    .resizable(true)
+                    .default_width(450.0)
+                    .show(ui.ctx(), |ui| {
+                        if ui.button("Close").clicked() {
+                            close_details = true;
+                        }
+                        ui.separator();
+
+                        info_row(ui, "Name", &node.name);
+                        info_row(ui, "Status", &node.status);
+                        info_row(ui, "Schedulable", if node.unschedulable { "No (cordoned)" } else { "Yes" });
+                        info_row(ui, "Roles", &node.roles.join(", "));
+                        info_row(ui, "Internal IP", &node.internal_ip);
+                        info_row(ui, "Kubelet", &node.kubelet_version);
+                        info_row(ui, "OS Image", &node.os_image);
+                        info_row(ui, "Age", &node.age);
+
+                        if let Some(history) = usage.and_then(|u| u.get(&node.name)) {
+                            if let Some(latest) = history.latest() {
+                                ui.add_space(12.0);
+                                ui.label(RichText::new("Usage:").strong());
+                                ui.separator();
+                                ui.horizontal(|ui| {
+                                    ui.label(RichText::new(format!("CPU {}", format_cpu_millis(latest.cpu_millis))).strong());
+                                    sparkline(ui, &history.cpu_series(), Color32::from_rgb(59, 130, 246));
+                                });
+                                ui.horizontal(|ui| {
+                                    ui.label(RichText::new(format!("Memory {}", format_bytes(latest.memory_bytes))).strong());
+                                    sparkline(ui, &history.memory_series(), Color32::from_rgb(168, 85, 247));
+                                });
+                            }
+                        }
+
+                        ui.add_space(12.0);
+                        ui.label(RichText::new("Conditions:").strong());
+                        ui.separator();
+                        for cond in &node.conditions {
+                            // Ready is the only condition that is healthy when True
+                            let healthy = (cond.condition_type == "Ready") == (cond.status == "True");
+                            ui.horizontal(|ui| {
+                                let color = if healthy { status_color("ready") } else { status_color("failed") };
+                                ui.colored_label(color, "●");
+                                ui.strong(&cond.condition_type);
+                                ui.label(&cond.status);
+                                if !cond.reason.is_empty() {
+                                    ui.label(RichText::new(&cond.reason).weak());
+                                }
+                            })
+                            .response
+                            .on_hover_text(&cond.message);
+                        }
+
+                        if !node.taints.is_empty() {
+                            ui.add_space(8.0);
+                            ui.label(RichText::new("Taints:").strong());
+                            for taint in &node.taints {
+                                ui.label(format!("  • {}", taint));
+                            }
+                        }
+                    });
+                if close_details {
+                    self.selected_node = None;
+                }
+            }
+        }
+
+        action
+    }
+
+    pub fn drain_progress(&mut self, status: DrainPodStatus) {
+        let Some(drain) = &mut self.drain else {
+            return;
+        };
+        match drain
+            .pods
+            .iter_mut()
+            .find(|p| p.namespace == status.namespace && p.name == status.name)
+        {
+            Some(existing) => *existing = status,
+            None => drain.pods.push(status),
+        }
+    }
+
+    pub fn drain_finished(&mut self, result: Result<(), String>) {
+        if let Some(drain) = &mut self.drain {
+            drain.result = Some(result);
+        }
+    }
+}
+
+impl TableItem for NodeInfo {
+    fn sort_key(&self, column: &str) -> Option<SortKey> {
+        Some(match column {
+            "Name" => SortKey::Text(self.name.clone()),
+            "Status" => SortKey::Text(self.status.clone()),
+            "Roles" => SortKey::Text(self.roles.join(",")),
+            "Version" => SortKey::Text(self.kubelet_version.clone()),
+            "Age" => SortKey::Age(self.created),
+            _ => return None,
+        })
+    }
+
+    fn labels(&self) -> &BTreeMap<String, String> {
+        &self.labels
+    }
+
+    fn annotations(&self) -> &BTreeMap<String, String> {
+        &self.annotations
+    }
+}
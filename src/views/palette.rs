@@ -0,0 +1,174 @@
+use crate::app::View;
+use egui::{Color32, Key, Modifiers, RichText};
+
+/// Most entries listed under the query; the rest are reachable by typing more.
+const MAX_RESULTS: usize = 12;
+
+/// What picking a palette entry asks the app to do.
+#[derive(Clone, Debug, PartialEq)]
+pub enum PaletteCommand {
+    ShowView(View),
+    SwitchContext(String),
+    /// `None` selects all namespaces.
+    SelectNamespace(Option<String>),
+    /// Opens the details of a loaded resource: its view, namespace and name.
+    OpenResource(View, String, String),
+}
+
+pub struct PaletteEntry {
+    pub label: String,
+    /// Short category shown next to the label, e.g. "View" or "Pod".
+    pub kind: &'static str,
+    pub command: PaletteCommand,
+}
+
+impl PaletteEntry {
+    pub fn new(label: impl Into<String>, kind: &'static str, command: PaletteCommand) -> Self {
+        Self { label: label.into(), kind, command }
+    }
+}
+
+/// Ctrl+K (Cmd+K on macOS) launcher that fuzzy-matches views, contexts,
+/// namespaces and the resources currently loaded.
+#[derive(Default)]
+pub struct CommandPalette {
+    open: bool,
+    query: String,
+    selected: usize,
+}
+
+impl CommandPalette {
+    /// Toggles the palette on Ctrl+K; returns whether it is open afterwards.
+    pub fn handle_shortcut(&mut self, ctx: &egui::Context) -> bool {
+        if ctx.input_mut(|i| i.consume_key(Modifiers::COMMAND, Key::K)) {
+            self.open = !self.open;
+            self.query.clear();
+            self.selected = 0;
+        }
+        self.open
+    }
+
+    pub fn show(&mut self, ctx: &egui::Context, entries: &[PaletteEntry]) -> Option<PaletteCommand> {
+        if !self.open {
+            return None;
+        }
+
+        let mut matches: Vec<(i32, &PaletteEntry)> = entries
+            .iter()
+            .filter_map(|e| fuzzy_score(&self.query, &e.label).map(|score| (score, e)))
+            .collect();
+        // Stable, so equal scores keep the views-contexts-namespaces-resources order
+        matches.sort_by_key(|(score, _)| std::cmp::Reverse(*score));
+        matches.truncate(MAX_RESULTS);
+
+        // Read before the text field sees them, so arrows move the selection
+        let (up, down, enter, escape) = ctx.input_mut(|i| {
+            (
+                i.consume_key(Modifiers::NONE, Key::ArrowUp),
+                i.consume_key(Modifiers::NONE, Key::ArrowDown),
+                i.consume_key(Modifiers::NONE, Key::Enter),
+                i.consume_key(Modifiers::NONE, Key::Escape),
+            )
+        });
+        if down {
+            self.selected += 1;
+        }
+        if up {
+            self.selected = self.selected.saturating_sub(1);
+        }
+        self.selected = self.selected.min(matches.len().saturating_sub(1));
+
+        let mut picked = enter.then(|| matches.get(self.selected).map(|(_, e)| e.command.clone())).flatten();
+
+        egui::Window::new("Command Palette")
+            .title_bar(false)
+            .collapsible(false)
+            .resizable(false)
+            .default_width(480.0)
+            .anchor(egui::Align2::CENTER_TOP, [0.0, 80.0])
+            .show(ctx, |ui| {
+                let response = ui.add(
+                    egui::TextEdit::singleline(&mut self.query)
+                        .hint_text("Go to view, context, namespace or resource...")
+                        .desired_width(f32::INFINITY),
+                );
+                response.request_focus();
+                if response.changed() {
+                    self.selected = 0;
+                }
+                ui.separator();
+
+                if matches.is_empty() {
+                    ui.label(RichText::new("No matches").weak());
+                }
+                for (i, (_, entry)) in matches.iter().enumerate() {
+                    ui.horizontal(|ui| {
+                        let label = ui.selectable_label(i == self.selected, &entry.label);
+                        ui.label(RichText::new(entry.kind).small().color(Color32::GRAY));
+                        if label.clicked() {
+                            picked = Some(entry.command.clone());
+                        }
+                    });
+                }
+            });
+
+        if picked.is_some() || escape {
+            self.open = false;
+        }
+        picked
+    }
+}
+
+/// Scores `candidate` against `query` when every query character appears in it
+/// in order, ignoring case. Runs of consecutive characters and matches at the
+/// start of a word score higher; an empty query matches everything equally.
+pub fn fuzzy_score(query: &str, candidate: &str) -> Option<i32> {
+    let candidate: Vec<char> = candidate.chars().flat_map(char::to_lowercase).collect();
+    let mut score = 0;
+    let mut next = 0;
+    let mut previous: Option<usize> = None;
+
+    for q in query.chars().flat_map(char::to_lowercase).filter(|c| !c.is_whitespace()) {
+        let offset = candidate[next..].iter().position(|&c| c == q)?;
+        let index = next + offset;
+
+        score += 1;
+        if previous.is_some_and(|p| p + 1 == index) {
+            score += 5;
+        }
+        if index == 0 || !candidate[index - 1].is_alphanumeric() {
+            score += 3;
+        }
+        // Gaps make the match less likely to be the one meant
+        score -= offset.min(5) as i32;
+
+        previous = Some(index);
+        next = index + 1;
+    }
+    Some(score)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn matches_subsequences_ignoring_case() {
+        assert!(fuzzy_score("dpl", "Deployments").is_some());
+        assert!(fuzzy_score("WEB", "web-7d4b9c-abcde").is_some());
+        assert!(fuzzy_score("", "anything").is_some());
+        assert_eq!(fuzzy_score("pd", "deployments"), None);
+        assert_eq!(fuzzy_score("webx", "web"), None);
+    }
+
+    #[test]
+    fn prefers_contiguous_and_word_start_matches() {
+        let contiguous = fuzzy_score("web", "web-7d4b9c").unwrap();
+        let scattered = fuzzy_score("web", "worker-5f6d7-xyz12b").unwrap_or(i32::MIN);
+        assert!(contiguous > scattered);
+
+        let word_start = fuzzy_score("core", "kube-system/coredns").unwrap();
+        let mid_word = fuzzy_score("core", "hardcore").unwrap();
+        assert!(word_start > mid_word);
+    }
+}
@@ -1,24 +1,78 @@
-use crate::k8s::PodInfo;
+use crate::k8s::{format_cpu_millis, PodInfo, ResourceKind, ResourceRef, UsageHistory};
+use crate::views::bulk::RowSelection;
+use crate::views::columns::{ready_ratio, SortKey, TableColumns, TableItem};
 use crate::views::common::*;
+use crate::views::safety::{change_button, GuardedAction, UnconfirmedChange};
+use crate::views::selector::SelectorBar;
+use crate::views::events::ObjectEvents;
+use crate::views::yaml::{detail_tabs, DetailTab, YamlAction, YamlEditor};
+use crate::views::terminal::{TerminalEvent, TerminalView};
 use egui::{Color32, RichText, Ui, ScrollArea};
 use egui_extras::{Column, TableBuilder};
+use std::collections::{BTreeMap, HashMap, VecDeque};
+
+/// Upper bound on lines kept in the logs window; older lines are dropped first.
+const MAX_LOG_LINES: usize = 10_000;
+
+/// Run when the exec command box is left blank.
+const DEFAULT_EXEC_COMMAND: &str = "/bin/sh";
 
 pub struct PodsView {
     pub search_filter: String,
     pub selected_pod: Option<PodInfo>,
     pub show_delete_dialog: bool,
     pub show_logs: bool,
-    pub logs_content: String,
+    pub log_lines: VecDeque<String>,
     pub logs_loading: bool,
+    pub follow_logs: bool,
     pub selected_container: Option<String>,
     pub tail_lines: i64,
-    pub pending_action: Option<PodAction>,
+    pub show_exec: bool,
+    pub exec_container: Option<String>,
+    pub exec_command: String,
+    pub terminal: TerminalView,
+    pub show_forward_dialog: bool,
+    pub forward_remote_port: u16,
+    pub forward_local_port: u16,
+    pub detail_tab: DetailTab,
+    pub yaml: YamlEditor,
+    pub events: ObjectEvents,
+    pub cursor: RowCursor,
+    pub selector: SelectorBar,
+    pub columns: TableColumns,
+    pub selection: RowSelection,
+    /// Selected pods waiting for the bulk delete to be confirmed.
+    pub bulk_delete: Option<Vec<(String, String)>>,
 }
 
 #[derive(Clone)]
 pub enum PodAction {
     Delete(String, String),
+    /// Deletes every `(namespace, name)` pod at once.
+    DeleteMany(Vec<(String, String)>),
     GetLogs(String, String, Option<String>, i64),
+    FollowLogs(String, String, Option<String>, i64),
+    StopFollowingLogs,
+    Exec(String, String, Option<String>, Vec<String>),
+    ExecInput(Vec<u8>),
+    ExecResize(u16, u16),
+    CloseExec,
+    PortForward(String, String, u16, u16),
+    Yaml(YamlAction),
+    LoadEvents(ResourceRef),
+}
+
+impl GuardedAction for PodAction {
+    fn unconfirmed_change(&self) -> Option<UnconfirmedChange> {
+        match self {
+            PodAction::Exec(_, name, _, command) => Some(UnconfirmedChange::new(
+                format!("Run '{}' in pod '{}'?", command.join(" "), name),
+                name,
+            )),
+            PodAction::Yaml(action) => action.unconfirmed_change(),
+            _ => None,
+        }
+    }
 }
 
 impl Default for PodsView {
@@ -28,11 +82,29 @@ impl Default for PodsView {
             selected_pod: None,
             show_delete_dialog: false,
             show_logs: false,
-            logs_content: String::new(),
+            log_lines: VecDeque::new(),
             logs_loading: false,
+            follow_logs: false,
             selected_container: None,
             tail_lines: 100,
-            pending_action: None,
+            show_exec: false,
+            exec_container: None,
+            exec_command: DEFAULT_EXEC_COMMAND.to_string(),
+            terminal: TerminalView::default(),
+            show_forward_dialog: false,
+            forward_remote_port: 8080,
+            forward_local_port: 0,
+            detail_tab: DetailTab::default(),
+            yaml: YamlEditor::default(),
+            events: ObjectEvents::default(),
+            cursor: RowCursor::default(),
+            selector: SelectorBar::default(),
+            columns: TableColumns::new(
+                &["Namespace", "Ready", "Status", "Restarts", "CPU", "CPU Req/Lim", "Memory", "Mem Req/Lim", "Age", "Node"],
+                &["Name", "Namespace", "Ready", "Status", "Restarts", "Age", "Node"],
+            ),
+            selection: RowSelection::default(),
+            bulk_delete: None,
         }
     }
 }
@@ -42,6 +114,7 @@ impl PodsView {
         &mut self,
         ui: &mut Ui,
         pods: &[PodInfo],
+        usage: Option<&HashMap<(String, String), UsageHistory>>,
         loading: bool,
         error: Option<&str>,
     ) -> Option<PodAction> {
@@ -51,8 +124,10 @@ impl PodsView {
             section_header(ui, "Pods");
             ui.with_layout(egui::Layout::right_to_left(egui::Align::Center), |ui| {
                 search_bar(ui, &mut self.search_filter, "Search pods...");
+                self.columns.chooser(ui);
             });
         });
+        self.selector.show(ui);
 
         if loading {
             loading_spinner(ui);
@@ -64,7 +139,7 @@ impl PodsView {
             return None;
         }
 
-        let filtered: Vec<_> = pods
+        let mut filtered: Vec<_> = pods
             .iter()
             .filter(|p| {
                 self.search_filter.is_empty()
@@ -78,74 +153,177 @@ impl PodsView {
             empty_state(ui, "No pods found");
             return None;
         }
+        self.columns.sort(&mut filtered);
+
+        let keys_enabled = self.selected_pod.is_none() && self.bulk_delete.is_none();
+        if let Some((i, command)) = self.cursor.handle_keys(ui, filtered.len(), keys_enabled) {
+            let pod = filtered[i];
+            self.selected_pod = Some(pod.clone());
+            match command {
+                RowCommand::Open => {}
+                RowCommand::Logs => {
+                    self.show_logs = true;
+                    self.log_lines.clear();
+                    self.selected_container = pod.containers.first().map(|c| c.name.clone());
+                    if self.selected_container.is_some() {
+                        action = Some(self.logs_action(pod));
+                    }
+                }
+                RowCommand::Delete => self.show_delete_dialog = true,
+            }
+        }
+
+        let keys: Vec<(&str, &str)> = filtered.iter().map(|p| (p.namespace.as_str(), p.name.as_str())).collect();
+        self.selection.bar(ui, &keys, |ui, targets| {
+            if change_button(ui, "Delete selected").clicked() {
+                self.bulk_delete = Some(targets.to_vec());
+            }
+        });
 
         let available_height = ui.available_height();
 
-        TableBuilder::new(ui)
+        // Usage columns are hidden when the cluster has no metrics API
+        let usage_of = |pod: &PodInfo| {
+            usage.map(|u| u.get(&(pod.namespace.clone(), pod.name.clone())).and_then(|h| h.latest()))
+        };
+
+        let mut table = TableBuilder::new(ui)
             .striped(true)
             .resizable(true)
             .cell_layout(egui::Layout::left_to_right(egui::Align::Center))
+            .column(Column::exact(24.0)) // Select
             .column(Column::auto().at_least(180.0)) // Name
-            .column(Column::auto().at_least(100.0)) // Namespace
-            .column(Column::auto().at_least(60.0))  // Ready
-            .column(Column::auto().at_least(100.0)) // Status
-            .column(Column::auto().at_least(70.0))  // Restarts
-            .column(Column::auto().at_least(60.0))  // Age
-            .column(Column::auto().at_least(120.0)) // Node
-            .column(Column::remainder().at_least(150.0)) // Actions
             .min_scrolled_height(0.0)
-            .max_scroll_height(available_height - 50.0)
+            .max_scroll_height(available_height - 50.0);
+        table = self.columns.add(table, "Namespace", Column::auto().at_least(100.0));
+        table = self.columns.add(table, "Ready", Column::auto().at_least(60.0));
+        table = self.columns.add(table, "Status", Column::auto().at_least(100.0));
+        table = self.columns.add(table, "Restarts", Column::auto().at_least(70.0));
+        if usage.is_some() {
+            table = self.columns.add(table, "CPU", Column::auto().at_least(60.0));
+        }
+        table = self.columns.add(table, "CPU Req/Lim", Column::auto().at_least(90.0));
+        if usage.is_some() {
+            table = self.columns.add(table, "Memory", Column::auto().at_least(80.0));
+        }
+        table = self.columns.add(table, "Mem Req/Lim", Column::auto().at_least(130.0));
+        table = self.columns.add(table, "Age", Column::auto().at_least(60.0));
+        table = self.columns.add(table, "Node", Column::auto().at_least(120.0));
+        table = self.columns.add_extra(table).column(Column::remainder().at_least(150.0)); // Actions
+        if let Some(row) = self.cursor.scroll_target() {
+            table = table.scroll_to_row(row, None);
+        }
+
+        table
             .header(25.0, |mut header| {
-                header.col(|ui| { ui.strong("Name"); });
-                header.col(|ui| { ui.strong("Namespace"); });
-                header.col(|ui| { ui.strong("Ready"); });
-                header.col(|ui| { ui.strong("Status"); });
-                header.col(|ui| { ui.strong("Restarts"); });
-                header.col(|ui| { ui.strong("Age"); });
-                header.col(|ui| { ui.strong("Node"); });
+                header.col(|ui| self.selection.header_checkbox(ui, &keys));
+                for column in ["Name", "Namespace", "Ready", "Status", "Restarts"] {
+                    self.columns.header(&mut header, column);
+                }
+                if usage.is_some() {
+                    self.columns.header(&mut header, "CPU");
+                }
+                self.columns.header(&mut header, "CPU Req/Lim");
+                if usage.is_some() {
+                    self.columns.header(&mut header, "Memory");
+                }
+                for column in ["Mem Req/Lim", "Age", "Node"] {
+                    self.columns.header(&mut header, column);
+                }
+                self.columns.extra_headers(&mut header);
                 header.col(|ui| { ui.strong("Actions"); });
             })
             .body(|mut body| {
-                for pod in &filtered {
+                for (i, pod) in filtered.iter().enumerate() {
                     body.row(30.0, |mut row| {
+                        row.set_selected(self.cursor.is_current(i));
+                        row.col(|ui| self.selection.checkbox(ui, &keys, i));
                         row.col(|ui| {
                             if ui.link(&pod.name).clicked() {
+                                self.cursor.set(i);
                                 self.selected_pod = Some((*pod).clone());
                             }
                         });
-                        row.col(|ui| { ui.label(&pod.namespace); });
-                        row.col(|ui| { ui.label(&pod.ready); });
-                        row.col(|ui| {
-                            let color = status_color(&pod.status);
-                            status_badge(ui, &pod.status, color);
-                        });
-                        row.col(|ui| {
-                            let color = if pod.restarts > 0 {
-                                Color32::from_rgb(234, 179, 8)
-                            } else {
-                                Color32::GRAY
-                            };
-                            ui.label(RichText::new(pod.restarts.to_string()).color(color));
-                        });
-                        row.col(|ui| { ui.label(&pod.age); });
-                        row.col(|ui| { ui.label(&pod.node); });
+                        if self.columns.shows("Namespace") {
+                            row.col(|ui| { ui.label(&pod.namespace); });
+                        }
+                        if self.columns.shows("Ready") {
+                            row.col(|ui| { ui.label(&pod.ready); });
+                        }
+                        if self.columns.shows("Status") {
+                            row.col(|ui| {
+                                let color = status_color(&pod.status);
+                                status_badge(ui, &pod.status, color);
+                            });
+                        }
+                        if self.columns.shows("Restarts") {
+                            row.col(|ui| {
+                                let color = if pod.restarts > 0 {
+                                    Color32::from_rgb(234, 179, 8)
+                                } else {
+                                    Color32::GRAY
+                                };
+                                ui.label(RichText::new(pod.restarts.to_string()).color(color));
+                            });
+                        }
+                        let current = usage_of(pod);
+                        if let Some(current) = current.filter(|_| self.columns.shows("CPU")) {
+                            row.col(|ui| match current {
+                                Some(u) => {
+                                    let color = usage_color(u.cpu_millis, pod.cpu_request, pod.cpu_limit);
+                                    ui.label(RichText::new(format_cpu_millis(u.cpu_millis)).color(color));
+                                }
+                                None => { ui.label("-"); }
+                            });
+                        }
+                        if self.columns.shows("CPU Req/Lim") {
+                            row.col(|ui| {
+                                ui.label(requests_and_limits(pod.cpu_request, pod.cpu_limit, format_cpu_millis));
+                            });
+                        }
+                        if let Some(current) = current.filter(|_| self.columns.shows("Memory")) {
+                            row.col(|ui| match current {
+                                Some(u) => {
+                                    let color = usage_color(u.memory_bytes, pod.memory_request, pod.memory_limit);
+                                    ui.label(RichText::new(format_bytes(u.memory_bytes)).color(color));
+                                }
+                                None => { ui.label("-"); }
+                            });
+                        }
+                        if self.columns.shows("Mem Req/Lim") {
+                            row.col(|ui| {
+                                ui.label(requests_and_limits(pod.memory_request, pod.memory_limit, format_bytes));
+                            });
+                        }
+                        if self.columns.shows("Age") {
+                            row.col(|ui| { ui.label(&pod.age); });
+                        }
+                        if self.columns.shows("Node") {
+                            row.col(|ui| { ui.label(&pod.node); });
+                        }
+                        self.columns.extra_cells(&mut row, *pod);
                         row.col(|ui| {
                             ui.horizontal(|ui| {
                                 if ui.small_button("Logs").clicked() {
                                     self.selected_pod = Some((*pod).clone());
                                     self.show_logs = true;
-                                    self.logs_content.clear();
+                                    self.log_lines.clear();
                                     self.selected_container = pod.containers.first().map(|c| c.name.clone());
-                                    if let Some(container) = &self.selected_container {
-                                        action = Some(PodAction::GetLogs(
-                                            pod.namespace.clone(),
-                                            pod.name.clone(),
-                                            Some(container.clone()),
-                                            self.tail_lines,
-                                        ));
+                                    if self.selected_container.is_some() {
+                                        action = Some(self.logs_action(pod));
                                     }
                                 }
-                                if ui.small_button("Delete").clicked() {
+                                if change_button(ui, "Exec").clicked() {
+                                    self.selected_pod = Some((*pod).clone());
+                                    self.show_exec = true;
+                                    self.exec_container = pod.containers.first().map(|c| c.name.clone());
+                                    action = Some(self.exec_action(pod));
+                                }
+                                if ui.small_button("Forward").clicked() {
+                                    self.selected_pod = Some((*pod).clone());
+                                    self.show_forward_dialog = true;
+                                }
+                                if change_button(ui, "Delete").clicked() {
                                     self.selected_pod = Some((*pod).clone());
                                     self.show_delete_dialog = true;
                                 }
@@ -157,7 +335,7 @@ impl PodsView {
 
         // Logs window
         if self.show_logs {
-            if let Some(pod) = &self.selected_pod {
+            if let Some(pod) = self.selected_pod.clone() {
                 let mut open = true;
                 egui::Window::new(format!("Logs - {}", pod.name))
                     .open(&mut open)
@@ -175,35 +353,28 @@ impl PodsView {
                                             &container.name,
                                         ).clicked() {
                                             self.selected_container = Some(container.name.clone());
-                                            action = Some(PodAction::GetLogs(
-                                                pod.namespace.clone(),
-                                                pod.name.clone(),
-                                                Some(container.name.clone()),
-                                                self.tail_lines,
-                                            ));
+                                            action = Some(self.logs_action(&pod));
                                         }
                                     }
                                 });
 
                             ui.label("Tail lines:");
-                            if ui.add(egui::DragValue::new(&mut self.tail_lines).range(10..=10000)).changed() {
-                                if let Some(container) = &self.selected_container {
-                                    action = Some(PodAction::GetLogs(
-                                        pod.namespace.clone(),
-                                        pod.name.clone(),
-                                        Some(container.clone()),
-                                        self.tail_lines,
-                                    ));
-                                }
+                            if ui.add(egui::DragValue::new(&mut self.tail_lines).range(10..=10000)).changed()
+                                && self.selected_container.is_some()
+                            {
+                                action = Some(self.logs_action(&pod));
                             }
 
-                            if ui.button("Refresh").clicked() {
-                                action = Some(PodAction::GetLogs(
-                                    pod.namespace.clone(),
-                                    pod.name.clone(),
-                                    self.selected_container.clone(),
-                                    self.tail_lines,
-                                ));
+                            if ui.checkbox(&mut self.follow_logs, "Follow").changed() {
+                                action = Some(if self.follow_logs {
+                                    self.logs_action(&pod)
+                                } else {
+                                    PodAction::StopFollowingLogs
+                                });
+                            }
+
+                            if !self.follow_logs && ui.button("Refresh").clicked() {
+                                action = Some(self.logs_action(&pod));
                             }
                         });
 
@@ -212,54 +383,174 @@ impl PodsView {
                         if self.logs_loading {
                             loading_spinner(ui);
                         } else {
-                            ScrollArea::vertical()
+                            let row_height = ui.text_style_height(&egui::TextStyle::Monospace);
+                            let output = ScrollArea::both()
                                 .auto_shrink([false, false])
-                                .show(ui, |ui| {
-                                    ui.add(
-                                        egui::TextEdit::multiline(&mut self.logs_content.as_str())
-                                            .font(egui::TextStyle::Monospace)
-                                            .desired_width(f32::INFINITY)
-                                    );
+                                .stick_to_bottom(true)
+                                .show_rows(ui, row_height, self.log_lines.len(), |ui, range| {
+                                    for line in self.log_lines.range(range) {
+                                        ui.label(RichText::new(line).monospace());
+                                    }
                                 });
+
+                            let at_bottom = output.state.offset.y + output.inner_rect.height()
+                                >= output.content_size.y - row_height;
+                            if self.follow_logs && !at_bottom {
+                                ui.label(
+                                    RichText::new("Auto-scroll paused - scroll to the bottom to resume")
+                                        .small()
+                                        .weak(),
+                                );
+                            }
                         }
                     });
 
                 if !open {
+                    if self.follow_logs {
+                        action = Some(PodAction::StopFollowingLogs);
+                        self.follow_logs = false;
+                    }
                     self.show_logs = false;
                     self.selected_pod = None;
                 }
             }
         }
 
-        // Delete dialog
-        if self.show_delete_dialog {
-            if let Some(pod) = &self.selected_pod {
-                egui::Window::new("Confirm Delete")
+        // Exec window
+        if self.show_exec {
+            if let Some(pod) = self.selected_pod.clone() {
+                let mut open = true;
+                egui::Window::new(format!("Exec - {}", pod.name))
+                    .open(&mut open)
+                    .resizable(true)
+                    .default_size([800.0, 500.0])
+                    .show(ui.ctx(), |ui| {
+                        ui.horizontal(|ui| {
+                            ui.label("Container:");
+                            egui::ComboBox::from_id_salt("exec_container_select")
+                                .selected_text(self.exec_container.as_deref().unwrap_or("Select..."))
+                                .show_ui(ui, |ui| {
+                                    for container in &pod.containers {
+                                        if ui.selectable_label(
+                                            self.exec_container.as_ref() == Some(&container.name),
+                                            &container.name,
+                                        ).clicked() {
+                                            self.exec_container = Some(container.name.clone());
+                                            action = Some(self.exec_action(&pod));
+                                        }
+                                    }
+                                });
+
+                            ui.label("Command:");
+                            ui.add(
+                                egui::TextEdit::singleline(&mut self.exec_command)
+                                    .hint_text(DEFAULT_EXEC_COMMAND)
+                                    .desired_width(120.0),
+                            );
+                            if ui.button("Reconnect").clicked() {
+                                action = Some(self.exec_action(&pod));
+                            }
+                        });
+
+                        ui.separator();
+
+                        match self.terminal.show(ui) {
+                            Some(TerminalEvent::Input(bytes)) => action = Some(PodAction::ExecInput(bytes)),
+                            Some(TerminalEvent::Resize(cols, rows)) if action.is_none() => {
+                                action = Some(PodAction::ExecResize(cols, rows));
+                            }
+                            _ => {}
+                        }
+                    });
+
+                if !open {
+                    action = Some(PodAction::CloseExec);
+                    self.show_exec = false;
+                    self.selected_pod = None;
+                }
+            }
+        }
+
+        // Port-forward dialog
+        if self.show_forward_dialog {
+            if let Some(pod) = self.selected_pod.clone() {
+                egui::Window::new("Port Forward")
                     .collapsible(false)
                     .resizable(false)
                     .anchor(egui::Align2::CENTER_CENTER, [0.0, 0.0])
                     .show(ui.ctx(), |ui| {
-                        ui.label(format!("Are you sure you want to delete pod '{}'?", pod.name));
+                        ui.label(format!("Forward a local port to pod: {}", pod.name));
+                        ui.add_space(8.0);
+                        ui.horizontal(|ui| {
+                            ui.label("Pod port:");
+                            ui.add(egui::DragValue::new(&mut self.forward_remote_port).range(1..=65535));
+                        });
+                        ui.horizontal(|ui| {
+                            ui.label("Local port:");
+                            ui.add(egui::DragValue::new(&mut self.forward_local_port).range(0..=65535));
+                            ui.label(RichText::new("(0 = any free port)").small().weak());
+                        });
                         ui.add_space(16.0);
                         ui.horizontal(|ui| {
                             if ui.button("Cancel").clicked() {
-                                self.show_delete_dialog = false;
+                                self.show_forward_dialog = false;
                             }
-                            if danger_button(ui, "Delete") {
-                                action = Some(PodAction::Delete(
+                            if primary_button(ui, "Forward") {
+                                action = Some(PodAction::PortForward(
                                     pod.namespace.clone(),
                                     pod.name.clone(),
+                                    self.forward_remote_port,
+                                    self.forward_local_port,
                                 ));
-                                self.show_delete_dialog = false;
+                                self.show_forward_dialog = false;
+                                self.selected_pod = None;
                             }
                         });
                     });
             }
         }
 
+        if let Some(targets) = &self.bulk_delete {
+            match confirm_dialog(
+                ui,
+                "Confirm Delete",
+                &format!("Are you sure you want to delete {} pods?", targets.len()),
+                "Delete",
+                &format!("{} pods", targets.len()),
+            ) {
+                Some(true) => {
+                    action = Some(PodAction::DeleteMany(targets.clone()));
+                    self.bulk_delete = None;
+                    self.selection.clear();
+                }
+                Some(false) => self.bulk_delete = None,
+                None => {}
+            }
+        }
+
+        // Delete dialog
+        if self.show_delete_dialog {
+            if let Some(pod) = &self.selected_pod {
+                match confirm_dialog(
+                    ui,
+                    "Confirm Delete",
+                    &format!("Are you sure you want to delete pod '{}'?", pod.name),
+                    "Delete",
+                    &pod.name,
+                ) {
+                    Some(true) => {
+                        action = Some(PodAction::Delete(pod.namespace.clone(), pod.name.clone()));
+                        self.show_delete_dialog = false;
+                    }
+                    Some(false) => self.show_delete_dialog = false,
+                    None => {}
+                }
+            }
+        }
+
         // Pod detail panel
         if let Some(pod) = self.selected_pod.clone() {
-            if !self.show_logs && !self.show_delete_dialog {
+            if !self.show_logs && !self.show_exec && !self.show_forward_dialog && !self.show_delete_dialog {
                 let mut close_details = false;
                 egui::Window::new("Pod Details")
                     .resizable(true)
@@ -269,6 +560,14 @@ impl PodsView {
                             close_details = true;
                         }
                         ui.separator();
+                        detail_tabs(ui, &mut self.detail_tab);
+                        if self.detail_tab == DetailTab::Yaml {
+                            let target = ResourceRef::new(ResourceKind::Pod, &pod.namespace, &pod.name);
+                            if let Some(a) = self.yaml.show(ui, target) {
+                                action = Some(PodAction::Yaml(a));
+                            }
+                            return;
+                        }
 
                         info_row(ui, "Name", &pod.name);
                         info_row(ui, "Namespace", &pod.namespace);
@@ -297,9 +596,36 @@ impl PodsView {
                             });
                             ui.add_space(4.0);
                         }
+
+                        ui.add_space(12.0);
+                        ui.label(RichText::new("Resources:").strong());
+                        ui.separator();
+                        info_row(ui, "CPU requests / limits", &requests_and_limits(pod.cpu_request, pod.cpu_limit, format_cpu_millis));
+                        info_row(ui, "Memory requests / limits", &requests_and_limits(pod.memory_request, pod.memory_limit, format_bytes));
+                        if let Some(history) = usage.and_then(|u| u.get(&(pod.namespace.clone(), pod.name.clone()))) {
+                            if let Some(latest) = history.latest() {
+                                ui.add_space(4.0);
+                                ui.horizontal(|ui| {
+                                    ui.label(RichText::new(format!("CPU {}", format_cpu_millis(latest.cpu_millis))).strong());
+                                    sparkline(ui, &history.cpu_series(), Color32::from_rgb(59, 130, 246));
+                                });
+                                ui.horizontal(|ui| {
+                                    ui.label(RichText::new(format!("Memory {}", format_bytes(latest.memory_bytes))).strong());
+                                    sparkline(ui, &history.memory_series(), Color32::from_rgb(168, 85, 247));
+                                });
+                            }
+                        }
+
+                        ui.add_space(12.0);
+                        let target = ResourceRef::new(ResourceKind::Pod, &pod.namespace, &pod.name);
+                        if let Some(target) = self.events.show(ui, target) {
+                            action = Some(PodAction::LoadEvents(target));
+                        }
                     });
                 if close_details {
                     self.selected_pod = None;
+                    self.detail_tab = DetailTab::Overview;
+                    self.events = ObjectEvents::default();
                 }
             }
         }
@@ -307,12 +633,123 @@ impl PodsView {
         action
     }
 
+    fn logs_action(&self, pod: &PodInfo) -> PodAction {
+        let (ns, name, container) = (
+            pod.namespace.clone(),
+            pod.name.clone(),
+            self.selected_container.clone(),
+        );
+        if self.follow_logs {
+            PodAction::FollowLogs(ns, name, container, self.tail_lines)
+        } else {
+            PodAction::GetLogs(ns, name, container, self.tail_lines)
+        }
+    }
+
+    fn exec_action(&mut self, pod: &PodInfo) -> PodAction {
+        self.terminal.reset();
+        let mut command: Vec<String> = self.exec_command.split_whitespace().map(|s| s.to_string()).collect();
+        if command.is_empty() {
+            command.push(DEFAULT_EXEC_COMMAND.to_string());
+        }
+        PodAction::Exec(
+            pod.namespace.clone(),
+            pod.name.clone(),
+            self.exec_container.clone(),
+            command,
+        )
+    }
+
+    pub fn exec_output(&mut self, bytes: &[u8]) {
+        self.terminal.write(bytes);
+    }
+
+    pub fn exec_ended(&mut self, result: Result<(), String>) {
+        self.terminal.status = Some(match result {
+            Ok(()) => "Session ended".to_string(),
+            Err(e) => format!("Error: {}", e),
+        });
+    }
+
     pub fn set_logs(&mut self, logs: String) {
-        self.logs_content = logs;
+        self.log_lines.clear();
+        for line in logs.lines() {
+            self.push_log_line(line.to_string());
+        }
         self.logs_loading = false;
     }
 
     pub fn set_logs_loading(&mut self) {
+        self.log_lines.clear();
         self.logs_loading = true;
     }
+
+    pub fn clear_logs(&mut self) {
+        self.log_lines.clear();
+        self.logs_loading = false;
+    }
+
+    pub fn append_log_line(&mut self, line: String) {
+        self.push_log_line(line);
+    }
+
+    pub fn follow_ended(&mut self, result: Result<(), String>) {
+        self.logs_loading = false;
+        self.follow_logs = false;
+        match result {
+            Ok(()) => self.push_log_line("--- log stream ended ---".to_string()),
+            Err(e) => self.push_log_line(format!("Error: {}", e)),
+        }
+    }
+
+    fn push_log_line(&mut self, line: String) {
+        if self.log_lines.len() == MAX_LOG_LINES {
+            self.log_lines.pop_front();
+        }
+        self.log_lines.push_back(line);
+    }
+}
+
+fn requests_and_limits(request: u64, limit: Option<u64>, format: fn(u64) -> String) -> String {
+    let request = if request == 0 { "-".to_string() } else { format(request) };
+    let limit = match limit {
+        Some(l) if l > 0 => format(l),
+        _ => "-".to_string(),
+    };
+    format!("{} / {}", request, limit)
+}
+
+/// Red when usage is close to the limit, amber when it exceeds the request.
+fn usage_color(used: u64, request: u64, limit: Option<u64>) -> Color32 {
+    match limit {
+        Some(l) if l > 0 && used * 10 >= l * 9 => Color32::from_rgb(239, 68, 68),
+        _ if request > 0 && used > request => Color32::from_rgb(234, 179, 8),
+        _ => Color32::GRAY,
+    }
+}
+
+impl TableItem for PodInfo {
+    fn sort_key(&self, column: &str) -> Option<SortKey> {
+        Some(match column {
+            "Name" => SortKey::Text(self.name.clone()),
+            "Namespace" => SortKey::Text(self.namespace.clone()),
+            "Ready" => {
+                let (ready, total) = self.ready.split_once('/')?;
+                ready_ratio(ready.parse().ok()?, total.parse().ok()?)
+            }
+            "Status" => SortKey::Text(self.status.clone()),
+            "Restarts" => SortKey::Number(self.restarts as f64),
+            "Age" => SortKey::Age(self.created),
+            "Node" => SortKey::Text(self.node.clone()),
+            _ => return None,
+        })
+    }
+
+    fn labels(&self) -> &BTreeMap<String, String> {
+        &self.labels
+    }
+
+    fn annotations(&self) -> &BTreeMap<String, String> {
+        &self.annotations
+    }
 }
@@ -0,0 +1,106 @@
+use crate::k8s::{ForwardStatus, PortForwardInfo};
+use crate::views::common::*;
+use egui::{Color32, RichText, Ui};
+use egui_extras::{Column, TableBuilder};
+use std::sync::atomic::Ordering;
+
+#[derive(Default)]
+pub struct PortForwardsView;
+
+#[derive(Clone)]
+pub enum PortForwardAction {
+    Stop(u64),
+    StopAll,
+}
+
+impl PortForwardsView {
+    pub fn show(&mut self, ui: &mut Ui, forwards: &[PortForwardInfo]) -> Option<PortForwardAction> {
+        let mut action = None;
+
+        ui.horizontal(|ui| {
+            section_header(ui, "Port Forwards");
+            ui.with_layout(egui::Layout::right_to_left(egui::Align::Center), |ui| {
+                if !forwards.is_empty() && danger_button(ui, "Stop All") {
+                    action = Some(PortForwardAction::StopAll);
+                }
+            });
+        });
+
+        if forwards.is_empty() {
+            empty_state(ui, "No active port forwards. Start one from the Pods or Services view.");
+            return action;
+        }
+
+        let available_height = ui.available_height();
+
+        TableBuilder::new(ui)
+            .striped(true)
+            .resizable(true)
+            .cell_layout(egui::Layout::left_to_right(egui::Align::Center))
+            .column(Column::auto().at_least(180.0)) // Target
+            .column(Column::auto().at_least(100.0)) // Namespace
+            .column(Column::auto().at_least(160.0)) // Pod
+            .column(Column::auto().at_least(130.0)) // Local
+            .column(Column::auto().at_least(60.0))  // Remote
+            .column(Column::auto().at_least(60.0))  // Conns
+            .column(Column::auto().at_least(80.0))  // In
+            .column(Column::auto().at_least(80.0))  // Out
+            .column(Column::auto().at_least(100.0)) // Status
+            .column(Column::remainder().at_least(80.0)) // Actions
+            .min_scrolled_height(0.0)
+            .max_scroll_height(available_height - 50.0)
+            .header(25.0, |mut header| {
+                header.col(|ui| { ui.strong("Target"); });
+                header.col(|ui| { ui.strong("Namespace"); });
+                header.col(|ui| { ui.strong("Pod"); });
+                header.col(|ui| { ui.strong("Local"); });
+                header.col(|ui| { ui.strong("Remote"); });
+                header.col(|ui| { ui.strong("Conns"); });
+                header.col(|ui| { ui.strong("In"); });
+                header.col(|ui| { ui.strong("Out"); });
+                header.col(|ui| { ui.strong("Status"); });
+                header.col(|ui| { ui.strong("Actions"); });
+            })
+            .body(|mut body| {
+                for fwd in forwards {
+                    body.row(30.0, |mut row| {
+                        row.col(|ui| { ui.label(&fwd.target); });
+                        row.col(|ui| { ui.label(&fwd.namespace); });
+                        row.col(|ui| { ui.label(fwd.pod.as_deref().unwrap_or("-")); });
+                        row.col(|ui| {
+                            match fwd.local_port {
+                                Some(port) => {
+                                    let addr = format!("127.0.0.1:{}", port);
+                                    if ui.link(&addr).on_hover_text("Copy address").clicked() {
+                                        ui.ctx().copy_text(addr);
+                                    }
+                                }
+                                None => { ui.label("-"); }
+                            }
+                        });
+                        row.col(|ui| { ui.label(fwd.remote_port.to_string()); });
+                        row.col(|ui| { ui.label(fwd.stats.connections.load(Ordering::Relaxed).to_string()); });
+                        row.col(|ui| { ui.label(format_bytes(fwd.stats.bytes_in.load(Ordering::Relaxed))); });
+                        row.col(|ui| { ui.label(format_bytes(fwd.stats.bytes_out.load(Ordering::Relaxed))); });
+                        row.col(|ui| {
+                            match &fwd.status {
+                                ForwardStatus::Starting => status_badge(ui, "Starting", status_color("pending")),
+                                ForwardStatus::Active => status_badge(ui, "Active", status_color("active")),
+                                ForwardStatus::Failed(e) => {
+                                    status_badge(ui, "Failed", status_color("failed"));
+                                    ui.label(RichText::new("ⓘ").color(Color32::GRAY)).on_hover_text(e);
+                                }
+                            }
+                        });
+                        row.col(|ui| {
+                            if ui.small_button("Stop").clicked() {
+                                action = Some(PortForwardAction::Stop(fwd.id));
+                            }
+                        });
+                    });
+                }
+            });
+
+        action
+    }
+}
@@ -0,0 +1,346 @@
+use crate::k8s::{ReplicaSetInfo, ResourceKind, ResourceRef};
+use crate::views::columns::{ready_ratio, SortKey, TableColumns, TableItem};
+use crate::views::common::*;
+use crate::views::safety::{change_button, changes_allowed, GuardedAction, UnconfirmedChange};
+use crate::views::selector::SelectorBar;
+use crate::views::events::ObjectEvents;
+use crate::views::yaml::{detail_tabs, DetailTab, YamlAction, YamlEditor};
+use egui::{Color32, RichText, Ui};
+use egui_extras::{Column, TableBuilder};
+use std::collections::BTreeMap;
+
+pub struct ReplicaSetsView {
+    pub search_filter: String,
+    pub selected_replicaset: Option<ReplicaSetInfo>,
+    pub scale_replicas: i32,
+    pub show_scale_dialog: bool,
+    pub show_delete_dialog: bool,
+    pub show_empty: bool,
+    pub detail_tab: DetailTab,
+    pub yaml: YamlEditor,
+    pub events: ObjectEvents,
+    pub cursor: RowCursor,
+    pub selector: SelectorBar,
+    pub columns: TableColumns,
+}
+
+#[derive(Clone)]
+pub enum ReplicaSetAction {
+    Scale(String, String, i32),
+    Delete(String, String),
+    Yaml(YamlAction),
+    LoadEvents(ResourceRef),
+}
+
+impl GuardedAction for ReplicaSetAction {
+    fn unconfirmed_change(&self) -> Option<UnconfirmedChange> {
+        match self {
+            ReplicaSetAction::Scale(_, name, replicas) => Some(UnconfirmedChange::new(
+                format!("Scale replicaset '{}' to {} replicas?", name, replicas),
+                name,
+            )),
+            ReplicaSetAction::Yaml(action) => action.unconfirmed_change(),
+            _ => None,
+        }
+    }
+}
+
+impl Default for ReplicaSetsView {
+    fn default() -> Self {
+        Self {
+            search_filter: String::new(),
+            selected_replicaset: None,
+            scale_replicas: 1,
+            show_scale_dialog: false,
+            show_delete_dialog: false,
+            show_empty: false,
+            detail_tab: DetailTab::default(),
+            yaml: YamlEditor::default(),
+            events: ObjectEvents::default(),
+            cursor: RowCursor::default(),
+            selector: SelectorBar::default(),
+            columns: TableColumns::new(
+                &["Namespace", "Ready", "Available", "Owner", "Age"],
+                &["Name", "Namespace", "Ready", "Available", "Owner", "Age"],
+            ),
+        }
+    }
+}
+
+impl ReplicaSetsView {
+    pub fn show(
+        &mut self,
+        ui: &mut Ui,
+        replicasets: &[ReplicaSetInfo],
+        loading: bool,
+        error: Option<&str>,
+    ) -> Option<ReplicaSetAction> {
+        let mut action = None;
+
+        ui.horizontal(|ui| {
+            section_header(ui, "ReplicaSets");
+            ui.with_layout(egui::Layout::right_to_left(egui::Align::Center), |ui| {
+                search_bar(ui, &mut self.search_filter, "Search replicasets...");
+                self.columns.chooser(ui);
+                ui.checkbox(&mut self.show_empty, "Show scaled to zero")
+                    .on_hover_text("Old Deployment revisions are kept as ReplicaSets with 0 replicas");
+            });
+        });
+        self.selector.show(ui);
+
+        if loading {
+            loading_spinner(ui);
+            return None;
+        }
+
+        if let Some(err) = error {
+            error_label(ui, err);
+            return None;
+        }
+
+        let mut filtered: Vec<_> = replicasets
+            .iter()
+            .filter(|r| self.show_empty || r.replicas > 0)
+            .filter(|r| {
+                self.search_filter.is_empty()
+                    || r.name.to_lowercase().contains(&self.search_filter.to_lowercase())
+                    || r.namespace.to_lowercase().contains(&self.search_filter.to_lowercase())
+            })
+            .collect();
+
+        if filtered.is_empty() {
+            empty_state(ui, "No replicasets found");
+            return None;
+        }
+        self.columns.sort(&mut filtered);
+
+        if let Some((i, command)) = self.cursor.handle_keys(ui, filtered.len(), self.selected_replicaset.is_none()) {
+            let rs = filtered[i];
+            match command {
+                RowCommand::Open => self.selected_replicaset = Some(rs.clone()),
+                RowCommand::Delete => {
+                    self.selected_replicaset = Some(rs.clone());
+                    self.show_delete_dialog = true;
+                }
+                RowCommand::Logs => {}
+            }
+        }
+
+        let available_height = ui.available_height();
+
+        let mut table = TableBuilder::new(ui)
+            .striped(true)
+            .resizable(true)
+            .cell_layout(egui::Layout::left_to_right(egui::Align::Center))
+            .column(Column::auto().at_least(200.0)) // Name
+            .min_scrolled_height(0.0)
+            .max_scroll_height(available_height - 50.0);
+        table = self.columns.add(table, "Namespace", Column::auto().at_least(100.0));
+        table = self.columns.add(table, "Ready", Column::auto().at_least(80.0));
+        table = self.columns.add(table, "Available", Column::auto().at_least(80.0));
+        table = self.columns.add(table, "Owner", Column::auto().at_least(160.0));
+        table = self.columns.add(table, "Age", Column::auto().at_least(60.0));
+        table = self.columns.add_extra(table).column(Column::remainder().at_least(150.0)); // Actions
+        if let Some(row) = self.cursor.scroll_target() {
+            table = table.scroll_to_row(row, None);
+        }
+
+        table
+            .header(25.0, |mut header| {
+                for column in ["Name", "Namespace", "Ready", "Available", "Owner", "Age"] {
+                    self.columns.header(&mut header, column);
+                }
+                self.columns.extra_headers(&mut header);
+                header.col(|ui| { ui.strong("Actions"); });
+            })
+            .body(|mut body| {
+                for (i, rs) in filtered.iter().enumerate() {
+                    body.row(30.0, |mut row| {
+                        row.set_selected(self.cursor.is_current(i));
+                        row.col(|ui| {
+                            if ui.link(&rs.name).clicked() {
+                                self.cursor.set(i);
+                                self.selected_replicaset = Some((*rs).clone());
+                            }
+                        });
+                        if self.columns.shows("Namespace") {
+                            row.col(|ui| { ui.label(&rs.namespace); });
+                        }
+                        if self.columns.shows("Ready") {
+                            row.col(|ui| {
+                                let ready_text = format!("{}/{}", rs.ready, rs.replicas);
+                                let color = if rs.ready == rs.replicas {
+                                    Color32::from_rgb(34, 197, 94)
+                                } else if rs.ready > 0 {
+                                    Color32::from_rgb(234, 179, 8)
+                                } else {
+                                    Color32::from_rgb(239, 68, 68)
+                                };
+                                ui.label(RichText::new(ready_text).color(color));
+                            });
+                        }
+                        if self.columns.shows("Available") {
+                            row.col(|ui| { ui.label(rs.available.to_string()); });
+                        }
+                        if self.columns.shows("Owner") {
+                            row.col(|ui| { ui.label(rs.owner.as_deref().unwrap_or("-")); });
+                        }
+                        if self.columns.shows("Age") {
+                            row.col(|ui| { ui.label(&rs.age); });
+                        }
+                        self.columns.extra_cells(&mut row, *rs);
+                        row.col(|ui| {
+                            ui.horizontal(|ui| {
+                                // Scaling a Deployment-owned ReplicaSet is undone by its controller
+                                let owned = rs.owner.is_some();
+                                let hint = if owned { "Scale the owning controller instead" } else { "This context is read-only" };
+                                if ui
+                                    .add_enabled(!owned && changes_allowed(ui), egui::Button::new("Scale").small())
+                                    .on_disabled_hover_text(hint)
+                                    .clicked()
+                                {
+                                    self.selected_replicaset = Some((*rs).clone());
+                                    self.scale_replicas = rs.replicas;
+                                    self.show_scale_dialog = true;
+                                }
+                                if change_button(ui, "Delete").on_hover_text("Delete replicaset").clicked() {
+                                    self.selected_replicaset = Some((*rs).clone());
+                                    self.show_delete_dialog = true;
+                                }
+                            });
+                        });
+                    });
+                }
+            });
+
+        // Scale dialog
+        if self.show_scale_dialog {
+            if let Some(rs) = &self.selected_replicaset {
+                egui::Window::new("Scale ReplicaSet")
+                    .collapsible(false)
+                    .resizable(false)
+                    .anchor(egui::Align2::CENTER_CENTER, [0.0, 0.0])
+                    .show(ui.ctx(), |ui| {
+                        ui.label(format!("Scale replicaset: {}", rs.name));
+                        ui.add_space(8.0);
+                        ui.horizontal(|ui| {
+                            ui.label("Replicas:");
+                            ui.add(egui::DragValue::new(&mut self.scale_replicas).range(0..=100));
+                        });
+                        ui.add_space(16.0);
+                        ui.horizontal(|ui| {
+                            if ui.button("Cancel").clicked() {
+                                self.show_scale_dialog = false;
+                            }
+                            if primary_button(ui, "Scale") {
+                                action = Some(ReplicaSetAction::Scale(
+                                    rs.namespace.clone(),
+                                    rs.name.clone(),
+                                    self.scale_replicas,
+                                ));
+                                self.show_scale_dialog = false;
+                            }
+                        });
+                    });
+            }
+        }
+
+        // Delete dialog
+        if self.show_delete_dialog {
+            if let Some(rs) = &self.selected_replicaset {
+                let mut message = format!("Are you sure you want to delete replicaset '{}'?", rs.name);
+                if let Some(owner) = &rs.owner {
+                    message.push_str(&format!(" {} may recreate it.", owner));
+                }
+                match confirm_dialog(ui, "Confirm Delete", &message, "Delete", &rs.name) {
+                    Some(true) => {
+                        action = Some(ReplicaSetAction::Delete(rs.namespace.clone(), rs.name.clone()));
+                        self.show_delete_dialog = false;
+                    }
+                    Some(false) => self.show_delete_dialog = false,
+                    None => {}
+                }
+            }
+        }
+
+        // Detail panel
+        if let Some(rs) = self.selected_replicaset.clone() {
+            if !self.show_scale_dialog && !self.show_delete_dialog {
+                let mut close_details = false;
+                egui::Window::new("ReplicaSet Details")
+                    .resizable(true)
+                    .default_width(400.0)
+                    .show(ui.ctx(), |ui| {
+                        if ui.button("Close").clicked() {
+                            close_details = true;
+                        }
+                        ui.separator();
+                        detail_tabs(ui, &mut self.detail_tab);
+                        let target = ResourceRef::new(ResourceKind::ReplicaSet, &rs.namespace, &rs.name);
+                        if self.detail_tab == DetailTab::Yaml {
+                            if let Some(a) = self.yaml.show(ui, target) {
+                                action = Some(ReplicaSetAction::Yaml(a));
+                            }
+                            return;
+                        }
+
+                        info_row(ui, "Name", &rs.name);
+                        info_row(ui, "Namespace", &rs.namespace);
+                        info_row(ui, "Replicas", &format!("{}/{}", rs.ready, rs.replicas));
+                        info_row(ui, "Owner", rs.owner.as_deref().unwrap_or("-"));
+                        info_row(ui, "Age", &rs.age);
+
+                        if !rs.images.is_empty() {
+                            ui.add_space(8.0);
+                            ui.label(RichText::new("Images:").strong());
+                            for image in &rs.images {
+                                ui.label(format!("  • {}", image));
+                            }
+                        }
+
+                        if !rs.labels.is_empty() {
+                            ui.add_space(8.0);
+                            ui.label(RichText::new("Labels:").strong());
+                            for (k, v) in &rs.labels {
+                                ui.label(format!("  {}={}", k, v));
+                            }
+                        }
+
+                        ui.add_space(12.0);
+                        if let Some(target) = self.events.show(ui, target) {
+                            action = Some(ReplicaSetAction::LoadEvents(target));
+                        }
+                    });
+                if close_details {
+                    self.selected_replicaset = None;
+                    self.detail_tab = DetailTab::Overview;
+                    self.events = ObjectEvents::default();
+                }
+            }
+        }
+
+        action
+    }
+}
+
+impl TableItem for ReplicaSetInfo {
+    fn sort_key(&self, column: &str) -> Option<SortKey> {
+        Some(match column {
+            "Name" => SortKey::Text(self.name.clone()),
+            "Namespace" => SortKey::Text(self.namespace.clone()),
+            "Ready" => ready_ratio(self.ready, self.replicas),
+            "Available" => SortKey::Number(self.available as f64),
+            "Owner" => SortKey::Text(self.owner.clone().unwrap_or_default()),
+            "Age" => SortKey::Age(self.created),
+            _ => return None,
+        })
+    }
+
+    fn labels(&self) -> &BTreeMap<String, String> {
+        &self.labels
+    }
+
+    fn annotations(&self) -> &BTreeMap<String, String> {
+        &self.annotations
+    }
+}
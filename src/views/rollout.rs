@@ -0,0 +1,101 @@
+use crate::k8s::{ResourceRef, RolloutPodStatus};
+use crate::views::common::*;
+use egui::{RichText, Ui};
+
+/// Per-pod rollout section for StatefulSet (by ordinal) and DaemonSet (by node)
+/// detail windows.
+#[derive(Default)]
+pub struct PodRollout {
+    target: Option<ResourceRef>,
+    slots: Vec<RolloutPodStatus>,
+    loading: bool,
+    error: Option<String>,
+}
+
+impl PodRollout {
+    /// Returns the object whose rollout should be (re)loaded.
+    pub fn show(&mut self, ui: &mut Ui, target: ResourceRef, slot_label: &str) -> Option<ResourceRef> {
+        let mut load = None;
+
+        if self.target.as_ref() != Some(&target) {
+            *self = Self {
+                target: Some(target.clone()),
+                loading: true,
+                ..Default::default()
+            };
+            load = Some(target.clone());
+        }
+
+        ui.horizontal(|ui| {
+            ui.label(RichText::new("Rollout:").strong());
+            if ui.small_button("Refresh").clicked() {
+                self.loading = true;
+                load = Some(target.clone());
+            }
+        });
+        ui.separator();
+
+        if self.loading {
+            loading_spinner(ui);
+            return load;
+        }
+
+        if let Some(err) = &self.error {
+            error_label(ui, err);
+            return load;
+        }
+
+        if self.slots.is_empty() {
+            ui.label(RichText::new("No pods").weak());
+            return load;
+        }
+
+        let updated = self.slots.iter().filter(|s| s.updated).count();
+        let ready = self.slots.iter().filter(|s| s.ready).count();
+        ui.add(
+            egui::ProgressBar::new(updated as f32 / self.slots.len() as f32)
+                .text(format!("{} of {} updated, {} ready", updated, self.slots.len(), ready)),
+        );
+        ui.add_space(4.0);
+
+        egui::Grid::new("pod_rollout").striped(true).num_columns(4).show(ui, |ui| {
+            ui.strong(slot_label);
+            ui.strong("Pod");
+            ui.strong("Status");
+            ui.strong("Revision");
+            ui.end_row();
+
+            for slot in &self.slots {
+                ui.label(&slot.slot);
+                ui.label(slot.pod.as_deref().unwrap_or("-"));
+                let status = if slot.status == "Running" && !slot.ready { "Not Ready" } else { slot.status.as_str() };
+                let color = match status {
+                    "Not Ready" | "Missing" => status_color("pending"),
+                    s => status_color(s),
+                };
+                status_badge(ui, status, color);
+                if slot.updated {
+                    ui.label(RichText::new("Updated").color(status_color("succeeded")));
+                } else if slot.pod.is_some() {
+                    ui.label(RichText::new("Old").color(status_color("pending")));
+                } else {
+                    ui.label("-");
+                }
+                ui.end_row();
+            }
+        });
+
+        load
+    }
+
+    pub fn set_loaded(&mut self, result: Result<Vec<RolloutPodStatus>, String>) {
+        self.loading = false;
+        match result {
+            Ok(slots) => {
+                self.slots = slots;
+                self.error = None;
+            }
+            Err(e) => self.error = Some(e),
+        }
+    }
+}
@@ -0,0 +1,104 @@
+use egui::{Color32, Response, RichText, Ui, WidgetText};
+use serde::{Deserialize, Serialize};
+
+/// How carefully changes to a context are guarded.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub enum ContextProfile {
+    #[default]
+    Normal,
+    /// Confirmations ask for the name of the object to be typed.
+    Protected,
+    /// Nothing can be changed.
+    ReadOnly,
+}
+
+impl ContextProfile {
+    pub const ALL: [ContextProfile; 3] = [ContextProfile::Normal, ContextProfile::Protected, ContextProfile::ReadOnly];
+
+    pub fn label(self) -> &'static str {
+        match self {
+            ContextProfile::Normal => "Normal",
+            ContextProfile::Protected => "Protected",
+            ContextProfile::ReadOnly => "Read-only",
+        }
+    }
+
+    pub fn allows_changes(self) -> bool {
+        self != ContextProfile::ReadOnly
+    }
+
+    pub fn color(self) -> Color32 {
+        match self {
+            ContextProfile::Normal => Color32::from_rgb(156, 163, 175),
+            ContextProfile::Protected => Color32::from_rgb(234, 179, 8),
+            ContextProfile::ReadOnly => Color32::from_rgb(239, 68, 68),
+        }
+    }
+
+    fn id() -> egui::Id {
+        egui::Id::new("context_profile")
+    }
+
+    /// Makes this the profile the views guard their controls with. The app
+    /// publishes the current context's profile every frame.
+    pub fn publish(self, ctx: &egui::Context) {
+        ctx.data_mut(|d| d.insert_temp(Self::id(), self));
+    }
+
+    pub fn current(ctx: &egui::Context) -> Self {
+        ctx.data(|d| d.get_temp(Self::id())).unwrap_or_default()
+    }
+}
+
+/// A change a view sends without asking first, such as a scale or a restart.
+/// Protected contexts hold it back until `name` is typed. Changes the views
+/// already confirm with [`confirm_dialog`](crate::views::common::confirm_dialog),
+/// like deletes, are not reported.
+pub struct UnconfirmedChange {
+    pub message: String,
+    pub name: String,
+}
+
+impl UnconfirmedChange {
+    pub fn new(message: String, name: &str) -> Self {
+        Self { message, name: name.to_string() }
+    }
+}
+
+/// View actions that can change the cluster.
+pub trait GuardedAction {
+    fn unconfirmed_change(&self) -> Option<UnconfirmedChange>;
+}
+
+/// Whether controls that change the cluster are enabled for the current context.
+pub fn changes_allowed(ui: &Ui) -> bool {
+    ContextProfile::current(ui.ctx()).allows_changes()
+}
+
+/// `ui.small_button` for an action that changes the cluster; disabled while the
+/// current context is read-only.
+pub fn change_button(ui: &mut Ui, text: impl Into<WidgetText>) -> Response {
+    ui.add_enabled(changes_allowed(ui), egui::Button::new(text).small())
+        .on_disabled_hover_text("This context is read-only")
+}
+
+/// Full-width strip naming the current context's profile; nothing is shown for
+/// normal contexts.
+pub fn banner(ui: &mut Ui, context: &str, profile: ContextProfile) {
+    let text = match profile {
+        ContextProfile::Normal => return,
+        ContextProfile::Protected => format!("{} is protected: type the object's name to confirm changes", context),
+        ContextProfile::ReadOnly => format!("{} is read-only: changes are disabled", context),
+    };
+    let text_color = match profile {
+        ContextProfile::ReadOnly => Color32::WHITE,
+        _ => Color32::BLACK,
+    };
+    egui::Frame::none()
+        .fill(profile.color())
+        .inner_margin(egui::Margin::symmetric(12.0, 6.0))
+        .show(ui, |ui| {
+            ui.set_width(ui.available_width());
+            ui.label(RichText::new(text).color(text_color).strong());
+        });
+}
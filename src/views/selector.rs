@@ -0,0 +1,139 @@
+use crate::k8s::Selectors;
+use crate::views::common::error_label;
+use egui::{RichText, Ui};
+use std::collections::BTreeMap;
+
+/// Chips shown at most, so a namespace with many label values keeps one row.
+const MAX_CHIPS: usize = 8;
+
+/// Label and field selector inputs sent to the API server with the list or
+/// watch, unlike the search box which only filters what was already loaded.
+#[derive(Default)]
+pub struct SelectorBar {
+    labels: String,
+    fields: String,
+    applied: Selectors,
+    error: Option<String>,
+    changed: bool,
+    /// `key=value` suggestions, e.g. from deployment labels or service selectors.
+    pub chips: Vec<String>,
+}
+
+impl SelectorBar {
+    pub fn show(&mut self, ui: &mut Ui) {
+        let mut apply = false;
+        ui.horizontal(|ui| {
+            ui.label("Labels:");
+            let labels = ui.add(
+                egui::TextEdit::singleline(&mut self.labels)
+                    .hint_text("app=web,tier!=cache")
+                    .desired_width(200.0),
+            );
+            ui.label("Fields:");
+            let fields = ui.add(
+                egui::TextEdit::singleline(&mut self.fields)
+                    .hint_text("status.phase=Running")
+                    .desired_width(180.0),
+            );
+            // Taken here so the table does not also act on it once focus is gone
+            apply |= (labels.lost_focus() || fields.lost_focus())
+                && ui.input_mut(|i| i.consume_key(egui::Modifiers::NONE, egui::Key::Enter));
+            apply |= ui.small_button("Apply").clicked();
+            if !self.applied.is_empty() && ui.small_button("Clear").clicked() {
+                self.labels.clear();
+                self.fields.clear();
+                apply = true;
+            }
+            if !self.applied.is_empty() {
+                ui.label(RichText::new("filtered on the server").small().weak());
+            }
+        });
+
+        if !self.chips.is_empty() {
+            ui.horizontal_wrapped(|ui| {
+                for chip in self.chips.iter().take(MAX_CHIPS) {
+                    let active = self.labels.split(',').any(|term| term.trim() == chip.as_str());
+                    if ui.selectable_label(active, RichText::new(chip).small()).clicked() {
+                        self.labels = toggle_term(&self.labels, chip);
+                        apply = true;
+                    }
+                }
+            });
+        }
+
+        if apply {
+            self.apply();
+        }
+        if let Some(error) = &self.error {
+            error_label(ui, error);
+        }
+    }
+
+    /// Validates the inputs and, when they changed, marks the list for reloading.
+    fn apply(&mut self) {
+        match Selectors::new(&self.labels, &self.fields) {
+            Ok(selectors) => {
+                self.error = None;
+                if selectors != self.applied {
+                    self.applied = selectors;
+                    self.changed = true;
+                }
+            }
+            Err(e) => self.error = Some(e.to_string()),
+        }
+    }
+
+    pub fn applied(&self) -> &Selectors {
+        &self.applied
+    }
+
+    /// Whether the applied selectors changed since the last call.
+    pub fn take_changed(&mut self) -> bool {
+        std::mem::take(&mut self.changed)
+    }
+}
+
+/// Unique `key=value` chips from label maps, most common first.
+pub fn label_chips<'a>(maps: impl IntoIterator<Item = &'a BTreeMap<String, String>>) -> Vec<String> {
+    let mut counts: BTreeMap<String, usize> = BTreeMap::new();
+    for map in maps {
+        for (key, value) in map {
+            *counts.entry(format!("{}={}", key, value)).or_default() += 1;
+        }
+    }
+    let mut chips: Vec<(String, usize)> = counts.into_iter().collect();
+    chips.sort_by(|a, b| b.1.cmp(&a.1).then_with(|| a.0.cmp(&b.0)));
+    chips.into_iter().map(|(chip, _)| chip).collect()
+}
+
+/// Adds `term` to a comma-separated selector, or removes it if already there.
+fn toggle_term(selector: &str, term: &str) -> String {
+    let mut terms: Vec<&str> = selector.split(',').map(str::trim).filter(|t| !t.is_empty()).collect();
+    match terms.iter().position(|t| *t == term) {
+        Some(i) => {
+            terms.remove(i);
+        }
+        None => terms.push(term),
+    }
+    terms.join(",")
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn chips_toggle_terms_in_the_selector() {
+        assert_eq!(toggle_term("", "app=web"), "app=web");
+        assert_eq!(toggle_term("tier=db", "app=web"), "tier=db,app=web");
+        assert_eq!(toggle_term("tier=db, app=web", "app=web"), "tier=db");
+    }
+
+    #[test]
+    fn label_chips_put_shared_labels_first() {
+        let web = BTreeMap::from([("app".to_string(), "web".to_string()), ("team".to_string(), "core".to_string())]);
+        let api = BTreeMap::from([("app".to_string(), "api".to_string()), ("team".to_string(), "core".to_string())]);
+
+        assert_eq!(label_chips([&web, &api]), ["team=core", "app=api", "app=web"]);
+    }
+}
@@ -1,13 +1,46 @@
-use crate::k8s::{ServiceInfo, IngressInfo};
+use crate::k8s::{IngressInfo, ResourceKind, ResourceRef, ServiceInfo};
+use crate::views::columns::{SortKey, TableColumns, TableItem};
 use crate::views::common::*;
+use crate::views::selector::SelectorBar;
+use crate::views::safety::{GuardedAction, UnconfirmedChange};
+use crate::views::yaml::{detail_tabs, DetailTab, YamlAction, YamlEditor};
 use egui::{RichText, Ui};
 use egui_extras::{Column, TableBuilder};
+use std::collections::BTreeMap;
 
 pub struct ServicesView {
     pub search_filter: String,
     pub selected_service: Option<ServiceInfo>,
     pub selected_ingress: Option<IngressInfo>,
     pub active_tab: ServiceTab,
+    pub show_forward_dialog: bool,
+    pub forward_service: Option<ServiceInfo>,
+    pub forward_port: u16,
+    pub forward_local_port: u16,
+    pub service_tab: DetailTab,
+    pub service_yaml: YamlEditor,
+    pub ingress_tab: DetailTab,
+    pub ingress_yaml: YamlEditor,
+    pub service_cursor: RowCursor,
+    pub ingress_cursor: RowCursor,
+    pub selector: SelectorBar,
+    pub service_columns: TableColumns,
+    pub ingress_columns: TableColumns,
+}
+
+#[derive(Clone)]
+pub enum ServiceAction {
+    PortForward(String, String, u16, u16),
+    Yaml(YamlAction),
+}
+
+impl GuardedAction for ServiceAction {
+    fn unconfirmed_change(&self) -> Option<UnconfirmedChange> {
+        match self {
+            ServiceAction::Yaml(action) => action.unconfirmed_change(),
+            ServiceAction::PortForward(..) => None,
+        }
+    }
 }
 
 #[derive(Clone, Copy, PartialEq, Default)]
@@ -24,6 +57,22 @@ impl Default for ServicesView {
             selected_service: None,
             selected_ingress: None,
             active_tab: ServiceTab::Services,
+            show_forward_dialog: false,
+            forward_service: None,
+            forward_port: 0,
+            forward_local_port: 0,
+            service_tab: DetailTab::default(),
+            service_yaml: YamlEditor::default(),
+            ingress_tab: DetailTab::default(),
+            ingress_yaml: YamlEditor::default(),
+            service_cursor: RowCursor::default(),
+            ingress_cursor: RowCursor::default(),
+            selector: SelectorBar::default(),
+            service_columns: TableColumns::new(
+                &["Namespace", "Type", "Cluster IP", "External IP", "Ports", "Age"],
+                &["Name", "Namespace", "Type", "Age"],
+            ),
+            ingress_columns: TableColumns::new(&["Namespace", "Hosts", "Paths", "Age"], &["Name", "Namespace", "Age"]),
         }
     }
 }
@@ -36,7 +85,7 @@ impl ServicesView {
         ingresses: &[IngressInfo],
         loading: bool,
         error: Option<&str>,
-    ) {
+    ) -> Option<ServiceAction> {
         ui.horizontal(|ui| {
             if ui.selectable_label(self.active_tab == ServiceTab::Services, "Services").clicked() {
                 self.active_tab = ServiceTab::Services;
@@ -46,18 +95,23 @@ impl ServicesView {
             }
             ui.with_layout(egui::Layout::right_to_left(egui::Align::Center), |ui| {
                 search_bar(ui, &mut self.search_filter, "Search...");
+                match self.active_tab {
+                    ServiceTab::Services => self.service_columns.chooser(ui),
+                    ServiceTab::Ingresses => self.ingress_columns.chooser(ui),
+                }
             });
         });
+        self.selector.show(ui);
         ui.separator();
 
         if loading {
             loading_spinner(ui);
-            return;
+            return None;
         }
 
         if let Some(err) = error {
             error_label(ui, err);
-            return;
+            return None;
         }
 
         match self.active_tab {
@@ -66,8 +120,10 @@ impl ServicesView {
         }
     }
 
-    fn show_services(&mut self, ui: &mut Ui, services: &[ServiceInfo]) {
-        let filtered: Vec<_> = services
+    fn show_services(&mut self, ui: &mut Ui, services: &[ServiceInfo]) -> Option<ServiceAction> {
+        let mut action = None;
+
+        let mut filtered: Vec<_> = services
             .iter()
             .filter(|s| {
                 self.search_filter.is_empty()
@@ -78,54 +134,140 @@ impl ServicesView {
 
         if filtered.is_empty() {
             empty_state(ui, "No services found");
-            return;
+            return None;
+        }
+        self.service_columns.sort(&mut filtered);
+
+        let keys_enabled = self.selected_service.is_none() && !self.show_forward_dialog;
+        if let Some((i, command)) = self.service_cursor.handle_keys(ui, filtered.len(), keys_enabled) {
+            let service = filtered[i];
+            match command {
+                RowCommand::Open => self.selected_service = Some(service.clone()),
+                RowCommand::Logs | RowCommand::Delete => {}
+            }
         }
 
         let available_height = ui.available_height();
 
-        TableBuilder::new(ui)
+        let mut table = TableBuilder::new(ui)
             .striped(true)
             .resizable(true)
             .cell_layout(egui::Layout::left_to_right(egui::Align::Center))
             .column(Column::auto().at_least(150.0)) // Name
-            .column(Column::auto().at_least(100.0)) // Namespace
-            .column(Column::auto().at_least(100.0)) // Type
-            .column(Column::auto().at_least(120.0)) // Cluster IP
-            .column(Column::auto().at_least(120.0)) // External IP
-            .column(Column::auto().at_least(150.0)) // Ports
-            .column(Column::remainder().at_least(60.0)) // Age
             .min_scrolled_height(0.0)
-            .max_scroll_height(available_height - 50.0)
+            .max_scroll_height(available_height - 50.0);
+        table = self.service_columns.add(table, "Namespace", Column::auto().at_least(100.0));
+        table = self.service_columns.add(table, "Type", Column::auto().at_least(100.0));
+        table = self.service_columns.add(table, "Cluster IP", Column::auto().at_least(120.0));
+        table = self.service_columns.add(table, "External IP", Column::auto().at_least(120.0));
+        table = self.service_columns.add(table, "Ports", Column::auto().at_least(150.0));
+        table = self.service_columns.add(table, "Age", Column::auto().at_least(60.0));
+        table = self.service_columns.add_extra(table).column(Column::remainder().at_least(80.0)); // Actions
+        if let Some(row) = self.service_cursor.scroll_target() {
+            table = table.scroll_to_row(row, None);
+        }
+
+        table
             .header(25.0, |mut header| {
-                header.col(|ui| { ui.strong("Name"); });
-                header.col(|ui| { ui.strong("Namespace"); });
-                header.col(|ui| { ui.strong("Type"); });
-                header.col(|ui| { ui.strong("Cluster IP"); });
-                header.col(|ui| { ui.strong("External IP"); });
-                header.col(|ui| { ui.strong("Ports"); });
-                header.col(|ui| { ui.strong("Age"); });
+                for column in ["Name", "Namespace", "Type", "Cluster IP", "External IP", "Ports", "Age"] {
+                    self.service_columns.header(&mut header, column);
+                }
+                self.service_columns.extra_headers(&mut header);
+                header.col(|ui| { ui.strong("Actions"); });
             })
             .body(|mut body| {
-                for service in &filtered {
+                for (i, service) in filtered.iter().enumerate() {
                     body.row(30.0, |mut row| {
+                        row.set_selected(self.service_cursor.is_current(i));
                         row.col(|ui| {
                             if ui.link(&service.name).clicked() {
+                                self.service_cursor.set(i);
                                 self.selected_service = Some((*service).clone());
                             }
                         });
-                        row.col(|ui| { ui.label(&service.namespace); });
-                        row.col(|ui| { ui.label(&service.service_type); });
-                        row.col(|ui| { ui.label(&service.cluster_ip); });
-                        row.col(|ui| { ui.label(&service.external_ip); });
+                        if self.service_columns.shows("Namespace") {
+                            row.col(|ui| { ui.label(&service.namespace); });
+                        }
+                        if self.service_columns.shows("Type") {
+                            row.col(|ui| { ui.label(&service.service_type); });
+                        }
+                        if self.service_columns.shows("Cluster IP") {
+                            row.col(|ui| { ui.label(&service.cluster_ip); });
+                        }
+                        if self.service_columns.shows("External IP") {
+                            row.col(|ui| { ui.label(&service.external_ip); });
+                        }
+                        if self.service_columns.shows("Ports") {
+                            row.col(|ui| {
+                                let ports_str = service.ports.join(", ");
+                                ui.label(&ports_str);
+                            });
+                        }
+                        if self.service_columns.shows("Age") {
+                            row.col(|ui| { ui.label(&service.age); });
+                        }
+                        self.service_columns.extra_cells(&mut row, *service);
                         row.col(|ui| {
-                            let ports_str = service.ports.join(", ");
-                            ui.label(&ports_str);
+                            let ports = service_port_numbers(service);
+                            if ui
+                                .add_enabled(!ports.is_empty() && !service.selector.is_empty(), egui::Button::new("Forward").small())
+                                .on_disabled_hover_text("Service has no ports or no selector")
+                                .clicked()
+                            {
+                                self.forward_port = ports[0];
+                                self.forward_local_port = 0;
+                                self.forward_service = Some((*service).clone());
+                                self.show_forward_dialog = true;
+                            }
                         });
-                        row.col(|ui| { ui.label(&service.age); });
                     });
                 }
             });
 
+        // Port-forward dialog
+        if self.show_forward_dialog {
+            if let Some(svc) = &self.forward_service {
+                egui::Window::new("Port Forward")
+                    .collapsible(false)
+                    .resizable(false)
+                    .anchor(egui::Align2::CENTER_CENTER, [0.0, 0.0])
+                    .show(ui.ctx(), |ui| {
+                        ui.label(format!("Forward a local port to service: {}", svc.name));
+                        ui.add_space(8.0);
+                        ui.horizontal(|ui| {
+                            ui.label("Service port:");
+                            egui::ComboBox::from_id_salt("forward_service_port")
+                                .selected_text(self.forward_port.to_string())
+                                .show_ui(ui, |ui| {
+                                    for port in service_port_numbers(svc) {
+                                        ui.selectable_value(&mut self.forward_port, port, port.to_string());
+                                    }
+                                });
+                        });
+                        ui.horizontal(|ui| {
+                            ui.label("Local port:");
+                            ui.add(egui::DragValue::new(&mut self.forward_local_port).range(0..=65535));
+                            ui.label(RichText::new("(0 = any free port)").small().weak());
+                        });
+                        ui.add_space(16.0);
+                        ui.horizontal(|ui| {
+                            if ui.button("Cancel").clicked() {
+                                self.show_forward_dialog = false;
+                            }
+                            if primary_button(ui, "Forward") {
+                                action = Some(ServiceAction::PortForward(
+                                    svc.namespace.clone(),
+                                    svc.name.clone(),
+                                    self.forward_port,
+                                    self.forward_local_port,
+                                ));
+                                self.show_forward_dialog = false;
+                            }
+                        });
+                    });
+            }
+        }
+
         // Service detail panel
         if let Some(svc) = self.selected_service.clone() {
             let mut close_details = false;
@@ -137,6 +279,14 @@ impl ServicesView {
                         close_details = true;
                     }
                     ui.separator();
+                    detail_tabs(ui, &mut self.service_tab);
+                    if self.service_tab == DetailTab::Yaml {
+                        let target = ResourceRef::new(ResourceKind::Service, &svc.namespace, &svc.name);
+                        if let Some(a) = self.service_yaml.show(ui, target) {
+                            action = Some(ServiceAction::Yaml(a));
+                        }
+                        return;
+                    }
 
                     info_row(ui, "Name", &svc.name);
                     info_row(ui, "Namespace", &svc.namespace);
@@ -161,12 +311,17 @@ impl ServicesView {
                 });
             if close_details {
                 self.selected_service = None;
+                self.service_tab = DetailTab::Overview;
             }
         }
+
+        action
     }
 
-    fn show_ingresses(&mut self, ui: &mut Ui, ingresses: &[IngressInfo]) {
-        let filtered: Vec<_> = ingresses
+    fn show_ingresses(&mut self, ui: &mut Ui, ingresses: &[IngressInfo]) -> Option<ServiceAction> {
+        let mut action = None;
+
+        let mut filtered: Vec<_> = ingresses
             .iter()
             .filter(|i| {
                 self.search_filter.is_empty()
@@ -177,47 +332,72 @@ impl ServicesView {
 
         if filtered.is_empty() {
             empty_state(ui, "No ingresses found");
-            return;
+            return None;
+        }
+        self.ingress_columns.sort(&mut filtered);
+
+        if let Some((i, command)) = self.ingress_cursor.handle_keys(ui, filtered.len(), self.selected_ingress.is_none()) {
+            let ingress = filtered[i];
+            match command {
+                RowCommand::Open => self.selected_ingress = Some(ingress.clone()),
+                RowCommand::Logs | RowCommand::Delete => {}
+            }
         }
 
         let available_height = ui.available_height();
 
-        TableBuilder::new(ui)
+        let mut table = TableBuilder::new(ui)
             .striped(true)
             .resizable(true)
             .cell_layout(egui::Layout::left_to_right(egui::Align::Center))
             .column(Column::auto().at_least(150.0)) // Name
-            .column(Column::auto().at_least(100.0)) // Namespace
-            .column(Column::auto().at_least(200.0)) // Hosts
-            .column(Column::auto().at_least(150.0)) // Paths
-            .column(Column::remainder().at_least(60.0)) // Age
             .min_scrolled_height(0.0)
-            .max_scroll_height(available_height - 50.0)
+            .max_scroll_height(available_height - 50.0);
+        table = self.ingress_columns.add(table, "Namespace", Column::auto().at_least(100.0));
+        table = self.ingress_columns.add(table, "Hosts", Column::auto().at_least(200.0));
+        table = self.ingress_columns.add(table, "Paths", Column::auto().at_least(150.0));
+        table = self.ingress_columns.add(table, "Age", Column::auto().at_least(60.0));
+        table = self.ingress_columns.add_extra(table);
+        if let Some(row) = self.ingress_cursor.scroll_target() {
+            table = table.scroll_to_row(row, None);
+        }
+
+        table
             .header(25.0, |mut header| {
-                header.col(|ui| { ui.strong("Name"); });
-                header.col(|ui| { ui.strong("Namespace"); });
-                header.col(|ui| { ui.strong("Hosts"); });
-                header.col(|ui| { ui.strong("Paths"); });
-                header.col(|ui| { ui.strong("Age"); });
+                for column in ["Name", "Namespace", "Hosts", "Paths", "Age"] {
+                    self.ingress_columns.header(&mut header, column);
+                }
+                self.ingress_columns.extra_headers(&mut header);
             })
             .body(|mut body| {
-                for ingress in &filtered {
+                for (i, ingress) in filtered.iter().enumerate() {
                     body.row(30.0, |mut row| {
+                        row.set_selected(self.ingress_cursor.is_current(i));
                         row.col(|ui| {
                             if ui.link(&ingress.name).clicked() {
+                                self.ingress_cursor.set(i);
                                 self.selected_ingress = Some((*ingress).clone());
                             }
                         });
-                        row.col(|ui| { ui.label(&ingress.namespace); });
-                        row.col(|ui| {
-                            let hosts = ingress.hosts.join(", ");
-                            ui.label(&hosts);
-                        });
-                        row.col(|ui| {
-                            let paths = ingress.paths.join(", ");
-                            ui.label(&paths);
-                        });
-                        row.col(|ui| { ui.label(&ingress.age); });
+                        if self.ingress_columns.shows("Namespace") {
+                            row.col(|ui| { ui.label(&ingress.namespace); });
+                        }
+                        if self.ingress_columns.shows("Hosts") {
+                            row.col(|ui| {
+                                let hosts = ingress.hosts.join(", ");
+                                ui.label(&hosts);
+                            });
+                        }
+                        if self.ingress_columns.shows("Paths") {
+                            row.col(|ui| {
+                                let paths = ingress.paths.join(", ");
+                                ui.label(&paths);
+                            });
+                        }
+                        if self.ingress_columns.shows("Age") {
+                            row.col(|ui| { ui.label(&ingress.age); });
+                        }
+                        self.ingress_columns.extra_cells(&mut row, *ingress);
                     });
                 }
             });
@@ -233,6 +413,14 @@ impl ServicesView {
                         close_details = true;
                     }
                     ui.separator();
+                    detail_tabs(ui, &mut self.ingress_tab);
+                    if self.ingress_tab == DetailTab::Yaml {
+                        let target = ResourceRef::new(ResourceKind::Ingress, &ing.namespace, &ing.name);
+                        if let Some(a) = self.ingress_yaml.show(ui, target) {
+                            action = Some(ServiceAction::Yaml(a));
+                        }
+                        return;
+                    }
 
                     info_row(ui, "Name", &ing.name);
                     info_row(ui, "Namespace", &ing.namespace);
@@ -252,7 +440,58 @@ impl ServicesView {
                 });
             if close_details {
                 self.selected_ingress = None;
+                self.ingress_tab = DetailTab::Overview;
             }
         }
+
+        action
+    }
+}
+
+/// Port numbers from the formatted `ServiceInfo::ports` entries (`80/TCP`, `80:30080/TCP`).
+fn service_port_numbers(service: &ServiceInfo) -> Vec<u16> {
+    service
+        .ports
+        .iter()
+        .filter_map(|p| p.split([':', '/']).next()?.parse().ok())
+        .collect()
+}
+
+impl TableItem for ServiceInfo {
+    fn sort_key(&self, column: &str) -> Option<SortKey> {
+        Some(match column {
+            "Name" => SortKey::Text(self.name.clone()),
+            "Namespace" => SortKey::Text(self.namespace.clone()),
+            "Type" => SortKey::Text(self.service_type.clone()),
+            "Age" => SortKey::Age(self.created),
+            _ => return None,
+        })
+    }
+
+    fn labels(&self) -> &BTreeMap<String, String> {
+        &self.labels
+    }
+
+    fn annotations(&self) -> &BTreeMap<String, String> {
+        &self.annotations
+    }
+}
+
+impl TableItem for IngressInfo {
+    fn sort_key(&self, column: &str) -> Option<SortKey> {
+        Some(match column {
+            "Name" => SortKey::Text(self.name.clone()),
+            "Namespace" => SortKey::Text(self.namespace.clone()),
+            "Age" => SortKey::Age(self.created),
+            _ => return None,
+        })
+    }
+
+    fn labels(&self) -> &BTreeMap<String, String> {
+        &self.labels
+    }
+
+    fn annotations(&self) -> &BTreeMap<String, String> {
+        &self.annotations
     }
 }
@@ -0,0 +1,366 @@
+use crate::k8s::{ResourceKind, ResourceRef, StatefulSetInfo};
+use crate::views::columns::{ready_ratio, SortKey, TableColumns, TableItem};
+use crate::views::common::*;
+use crate::views::safety::{change_button, GuardedAction, UnconfirmedChange};
+use crate::views::selector::SelectorBar;
+use crate::views::events::ObjectEvents;
+use crate::views::rollout::PodRollout;
+use crate::views::yaml::{detail_tabs, DetailTab, YamlAction, YamlEditor};
+use egui::{Color32, RichText, Ui};
+use egui_extras::{Column, TableBuilder};
+use std::collections::BTreeMap;
+
+pub struct StatefulSetsView {
+    pub search_filter: String,
+    pub selected_statefulset: Option<StatefulSetInfo>,
+    pub scale_replicas: i32,
+    pub show_scale_dialog: bool,
+    pub show_delete_dialog: bool,
+    pub detail_tab: DetailTab,
+    pub yaml: YamlEditor,
+    pub events: ObjectEvents,
+    pub rollout: PodRollout,
+    pub cursor: RowCursor,
+    pub selector: SelectorBar,
+    pub columns: TableColumns,
+}
+
+#[derive(Clone)]
+pub enum StatefulSetAction {
+    Scale(String, String, i32),
+    Restart(String, String),
+    Delete(String, String),
+    Yaml(YamlAction),
+    LoadEvents(ResourceRef),
+    LoadRollout(ResourceRef),
+}
+
+impl GuardedAction for StatefulSetAction {
+    fn unconfirmed_change(&self) -> Option<UnconfirmedChange> {
+        match self {
+            StatefulSetAction::Scale(_, name, replicas) => Some(UnconfirmedChange::new(
+                format!("Scale statefulset '{}' to {} replicas?", name, replicas),
+                name,
+            )),
+            StatefulSetAction::Restart(_, name) => Some(UnconfirmedChange::new(
+                format!("Restart statefulset '{}'? Its pods are replaced one ordinal at a time.", name),
+                name,
+            )),
+            StatefulSetAction::Yaml(action) => action.unconfirmed_change(),
+            _ => None,
+        }
+    }
+}
+
+impl Default for StatefulSetsView {
+    fn default() -> Self {
+        Self {
+            search_filter: String::new(),
+            selected_statefulset: None,
+            scale_replicas: 1,
+            show_scale_dialog: false,
+            show_delete_dialog: false,
+            detail_tab: DetailTab::default(),
+            yaml: YamlEditor::default(),
+            events: ObjectEvents::default(),
+            rollout: PodRollout::default(),
+            cursor: RowCursor::default(),
+            selector: SelectorBar::default(),
+            columns: TableColumns::new(
+                &["Namespace", "Ready", "Up-to-date", "Service", "Age"],
+                &["Name", "Namespace", "Ready", "Up-to-date", "Age"],
+            ),
+        }
+    }
+}
+
+impl StatefulSetsView {
+    pub fn show(
+        &mut self,
+        ui: &mut Ui,
+        statefulsets: &[StatefulSetInfo],
+        loading: bool,
+        error: Option<&str>,
+    ) -> Vec<StatefulSetAction> {
+        let mut actions = Vec::new();
+
+        ui.horizontal(|ui| {
+            section_header(ui, "StatefulSets");
+            ui.with_layout(egui::Layout::right_to_left(egui::Align::Center), |ui| {
+                search_bar(ui, &mut self.search_filter, "Search statefulsets...");
+                self.columns.chooser(ui);
+            });
+        });
+        self.selector.show(ui);
+
+        if loading {
+            loading_spinner(ui);
+            return actions;
+        }
+
+        if let Some(err) = error {
+            error_label(ui, err);
+            return actions;
+        }
+
+        let mut filtered: Vec<_> = statefulsets
+            .iter()
+            .filter(|s| {
+                self.search_filter.is_empty()
+                    || s.name.to_lowercase().contains(&self.search_filter.to_lowercase())
+                    || s.namespace.to_lowercase().contains(&self.search_filter.to_lowercase())
+            })
+            .collect();
+
+        if filtered.is_empty() {
+            empty_state(ui, "No statefulsets found");
+            return actions;
+        }
+        self.columns.sort(&mut filtered);
+
+        if let Some((i, command)) = self.cursor.handle_keys(ui, filtered.len(), self.selected_statefulset.is_none()) {
+            let sts = filtered[i];
+            match command {
+                RowCommand::Open => self.selected_statefulset = Some(sts.clone()),
+                RowCommand::Delete => {
+                    self.selected_statefulset = Some(sts.clone());
+                    self.show_delete_dialog = true;
+                }
+                RowCommand::Logs => {}
+            }
+        }
+
+        let available_height = ui.available_height();
+
+        let mut table = TableBuilder::new(ui)
+            .striped(true)
+            .resizable(true)
+            .cell_layout(egui::Layout::left_to_right(egui::Align::Center))
+            .column(Column::auto().at_least(150.0)) // Name
+            .min_scrolled_height(0.0)
+            .max_scroll_height(available_height - 50.0);
+        table = self.columns.add(table, "Namespace", Column::auto().at_least(100.0));
+        table = self.columns.add(table, "Ready", Column::auto().at_least(80.0));
+        table = self.columns.add(table, "Up-to-date", Column::auto().at_least(80.0));
+        table = self.columns.add(table, "Service", Column::auto().at_least(120.0));
+        table = self.columns.add(table, "Age", Column::auto().at_least(60.0));
+        table = self.columns.add_extra(table).column(Column::remainder().at_least(200.0)); // Actions
+        if let Some(row) = self.cursor.scroll_target() {
+            table = table.scroll_to_row(row, None);
+        }
+
+        table
+            .header(25.0, |mut header| {
+                for column in ["Name", "Namespace", "Ready", "Up-to-date", "Service", "Age"] {
+                    self.columns.header(&mut header, column);
+                }
+                self.columns.extra_headers(&mut header);
+                header.col(|ui| { ui.strong("Actions"); });
+            })
+            .body(|mut body| {
+                for (i, sts) in filtered.iter().enumerate() {
+                    body.row(30.0, |mut row| {
+                        row.set_selected(self.cursor.is_current(i));
+                        row.col(|ui| {
+                            if ui.link(&sts.name).clicked() {
+                                self.cursor.set(i);
+                                self.selected_statefulset = Some((*sts).clone());
+                            }
+                        });
+                        if self.columns.shows("Namespace") {
+                            row.col(|ui| { ui.label(&sts.namespace); });
+                        }
+                        if self.columns.shows("Ready") {
+                            row.col(|ui| {
+                                let ready_text = format!("{}/{}", sts.ready, sts.replicas);
+                                let color = if sts.ready == sts.replicas {
+                                    Color32::from_rgb(34, 197, 94)
+                                } else if sts.ready > 0 {
+                                    Color32::from_rgb(234, 179, 8)
+                                } else {
+                                    Color32::from_rgb(239, 68, 68)
+                                };
+                                ui.label(RichText::new(ready_text).color(color));
+                            });
+                        }
+                        if self.columns.shows("Up-to-date") {
+                            row.col(|ui| { ui.label(sts.updated.to_string()); });
+                        }
+                        if self.columns.shows("Service") {
+                            row.col(|ui| { ui.label(&sts.service_name); });
+                        }
+                        if self.columns.shows("Age") {
+                            row.col(|ui| { ui.label(&sts.age); });
+                        }
+                        self.columns.extra_cells(&mut row, *sts);
+                        row.col(|ui| {
+                            ui.horizontal(|ui| {
+                                if change_button(ui, "Scale").clicked() {
+                                    self.selected_statefulset = Some((*sts).clone());
+                                    self.scale_replicas = sts.replicas;
+                                    self.show_scale_dialog = true;
+                                }
+                                if change_button(ui, "Restart").on_hover_text("Rolling restart").clicked() {
+                                    actions.push(StatefulSetAction::Restart(
+                                        sts.namespace.clone(),
+                                        sts.name.clone(),
+                                    ));
+                                }
+                                if change_button(ui, "Delete").on_hover_text("Delete statefulset").clicked() {
+                                    self.selected_statefulset = Some((*sts).clone());
+                                    self.show_delete_dialog = true;
+                                }
+                            });
+                        });
+                    });
+                }
+            });
+
+        // Scale dialog
+        if self.show_scale_dialog {
+            if let Some(sts) = &self.selected_statefulset {
+                egui::Window::new("Scale StatefulSet")
+                    .collapsible(false)
+                    .resizable(false)
+                    .anchor(egui::Align2::CENTER_CENTER, [0.0, 0.0])
+                    .show(ui.ctx(), |ui| {
+                        ui.label(format!("Scale statefulset: {}", sts.name));
+                        ui.label(
+                            RichText::new("Pods are added or removed one ordinal at a time; volumes are kept.")
+                                .small()
+                                .weak(),
+                        );
+                        ui.add_space(8.0);
+                        ui.horizontal(|ui| {
+                            ui.label("Replicas:");
+                            ui.add(egui::DragValue::new(&mut self.scale_replicas).range(0..=100));
+                        });
+                        ui.add_space(16.0);
+                        ui.horizontal(|ui| {
+                            if ui.button("Cancel").clicked() {
+                                self.show_scale_dialog = false;
+                            }
+                            if primary_button(ui, "Scale") {
+                                actions.push(StatefulSetAction::Scale(
+                                    sts.namespace.clone(),
+                                    sts.name.clone(),
+                                    self.scale_replicas,
+                                ));
+                                self.show_scale_dialog = false;
+                            }
+                        });
+                    });
+            }
+        }
+
+        // Delete dialog
+        if self.show_delete_dialog {
+            if let Some(sts) = &self.selected_statefulset {
+                match confirm_dialog(
+                    ui,
+                    "Confirm Delete",
+                    &format!(
+                        "Are you sure you want to delete statefulset '{}'? Its PersistentVolumeClaims are kept.",
+                        sts.name
+                    ),
+                    "Delete",
+                    &sts.name,
+                ) {
+                    Some(true) => {
+                        actions.push(StatefulSetAction::Delete(sts.namespace.clone(), sts.name.clone()));
+                        self.show_delete_dialog = false;
+                    }
+                    Some(false) => self.show_delete_dialog = false,
+                    None => {}
+                }
+            }
+        }
+
+        // Detail panel
+        if let Some(sts) = self.selected_statefulset.clone() {
+            if !self.show_scale_dialog && !self.show_delete_dialog {
+                let mut close_details = false;
+                egui::Window::new("StatefulSet Details")
+                    .resizable(true)
+                    .default_width(450.0)
+                    .show(ui.ctx(), |ui| {
+                        if ui.button("Close").clicked() {
+                            close_details = true;
+                        }
+                        ui.separator();
+                        detail_tabs(ui, &mut self.detail_tab);
+                        let target = ResourceRef::new(ResourceKind::StatefulSet, &sts.namespace, &sts.name);
+                        if self.detail_tab == DetailTab::Yaml {
+                            if let Some(a) = self.yaml.show(ui, target) {
+                                actions.push(StatefulSetAction::Yaml(a));
+                            }
+                            return;
+                        }
+
+                        info_row(ui, "Name", &sts.name);
+                        info_row(ui, "Namespace", &sts.namespace);
+                        info_row(ui, "Replicas", &format!("{}/{}", sts.ready, sts.replicas));
+                        info_row(ui, "Service", &sts.service_name);
+                        info_row(ui, "Current Revision", &sts.current_revision);
+                        info_row(ui, "Update Revision", &sts.update_revision);
+                        info_row(ui, "Age", &sts.age);
+
+                        if !sts.images.is_empty() {
+                            ui.add_space(8.0);
+                            ui.label(RichText::new("Images:").strong());
+                            for image in &sts.images {
+                                ui.label(format!("  • {}", image));
+                            }
+                        }
+
+                        if !sts.labels.is_empty() {
+                            ui.add_space(8.0);
+                            ui.label(RichText::new("Labels:").strong());
+                            for (k, v) in &sts.labels {
+                                ui.label(format!("  {}={}", k, v));
+                            }
+                        }
+
+                        ui.add_space(12.0);
+                        if let Some(target) = self.rollout.show(ui, target.clone(), "Ordinal") {
+                            actions.push(StatefulSetAction::LoadRollout(target));
+                        }
+
+                        ui.add_space(12.0);
+                        if let Some(target) = self.events.show(ui, target) {
+                            actions.push(StatefulSetAction::LoadEvents(target));
+                        }
+                    });
+                if close_details {
+                    self.selected_statefulset = None;
+                    self.detail_tab = DetailTab::Overview;
+                    self.events = ObjectEvents::default();
+                    self.rollout = PodRollout::default();
+                }
+            }
+        }
+
+        actions
+    }
+}
+
+impl TableItem for StatefulSetInfo {
+    fn sort_key(&self, column: &str) -> Option<SortKey> {
+        Some(match column {
+            "Name" => SortKey::Text(self.name.clone()),
+            "Namespace" => SortKey::Text(self.namespace.clone()),
+            "Ready" => ready_ratio(self.ready, self.replicas),
+            "Up-to-date" => SortKey::Number(self.updated as f64),
+            "Age" => SortKey::Age(self.created),
+            _ => return None,
+        })
+    }
+
+    fn labels(&self) -> &BTreeMap<String, String> {
+        &self.labels
+    }
+
+    fn annotations(&self) -> &BTreeMap<String, String> {
+        &self.annotations
+    }
+}
@@ -0,0 +1,272 @@
+use egui::{text::LayoutJob, Color32, EventFilter, FontId, Key, Modifiers, RichText, Sense, TextFormat, Ui};
+
+const SCROLLBACK_LINES: usize = 5_000;
+
+/// The 16 ANSI colors, normal then bright.
+const ANSI_COLORS: [Color32; 16] = [
+    Color32::from_rgb(0, 0, 0),
+    Color32::from_rgb(205, 49, 49),
+    Color32::from_rgb(13, 188, 121),
+    Color32::from_rgb(229, 229, 16),
+    Color32::from_rgb(36, 114, 200),
+    Color32::from_rgb(188, 63, 188),
+    Color32::from_rgb(17, 168, 205),
+    Color32::from_rgb(229, 229, 229),
+    Color32::from_rgb(102, 102, 102),
+    Color32::from_rgb(241, 76, 76),
+    Color32::from_rgb(35, 209, 139),
+    Color32::from_rgb(245, 245, 67),
+    Color32::from_rgb(59, 142, 234),
+    Color32::from_rgb(214, 112, 214),
+    Color32::from_rgb(41, 184, 219),
+    Color32::from_rgb(255, 255, 255),
+];
+
+/// A terminal for exec sessions. Output goes through a VT100 emulator and is drawn
+/// cell by cell, so colors, cursor movement and full-screen programs work. While
+/// the terminal has focus, keystrokes are sent to the session as they are typed.
+pub struct TerminalView {
+    parser: vt100::Parser,
+    /// Wheel movement not yet turned into whole rows of scrollback.
+    scroll_delta: f32,
+    sent_size: Option<(u16, u16)>,
+    pub status: Option<String>,
+}
+
+pub enum TerminalEvent {
+    Input(Vec<u8>),
+    Resize(u16, u16),
+}
+
+impl Default for TerminalView {
+    fn default() -> Self {
+        Self {
+            parser: vt100::Parser::new(24, 80, SCROLLBACK_LINES),
+            scroll_delta: 0.0,
+            sent_size: None,
+            status: None,
+        }
+    }
+}
+
+impl TerminalView {
+    pub fn reset(&mut self) {
+        *self = Self::default();
+    }
+
+    pub fn write(&mut self, bytes: &[u8]) {
+        self.parser.process(bytes);
+    }
+
+    pub fn show(&mut self, ui: &mut Ui) -> Option<TerminalEvent> {
+        let font = FontId::monospace(13.0);
+        let row_height = ui.fonts(|f| f.row_height(&font));
+        let char_width = ui.fonts(|f| f.glyph_width(&font, 'M'));
+
+        let size = egui::vec2(ui.available_width(), (ui.available_height() - 24.0).max(row_height * 4.0));
+        let (rect, response) = ui.allocate_exact_size(size, Sense::click());
+        if response.clicked() {
+            response.request_focus();
+        }
+        let focused = response.has_focus();
+
+        let mut input = Vec::new();
+        if focused {
+            // Keep Tab, the arrows and Escape for the session instead of egui's focus navigation
+            ui.memory_mut(|m| {
+                m.set_focus_lock_filter(
+                    response.id,
+                    EventFilter { tab: true, horizontal_arrows: true, vertical_arrows: true, escape: true },
+                )
+            });
+            let app_cursor = self.parser.screen().application_cursor();
+            ui.input(|i| {
+                for event in &i.events {
+                    encode_event(event, app_cursor, &mut input);
+                }
+            });
+        }
+
+        if !input.is_empty() {
+            self.scroll_delta = 0.0;
+            self.parser.set_scrollback(0);
+        } else if response.hovered() {
+            self.scroll_delta += ui.input(|i| i.raw_scroll_delta.y);
+            let rows = (self.scroll_delta / row_height).trunc();
+            if rows != 0.0 {
+                self.scroll_delta -= rows * row_height;
+                let offset = self.parser.screen().scrollback() as i64 + rows as i64;
+                self.parser.set_scrollback(offset.max(0) as usize);
+            }
+        }
+
+        let cols = (rect.width() / char_width).floor().max(1.0) as u16;
+        let rows = (rect.height() / row_height).floor().max(1.0) as u16;
+        if self.parser.screen().size() != (rows, cols) {
+            self.parser.set_size(rows, cols);
+        }
+
+        self.paint(ui, rect, &font, row_height, focused);
+
+        let screen = self.parser.screen();
+        ui.horizontal(|ui| {
+            let hint = if let Some(status) = &self.status {
+                status.clone()
+            } else if screen.scrollback() > 0 {
+                format!("Scrolled back {} lines; type to return", screen.scrollback())
+            } else if !focused {
+                "Click the terminal to type".to_string()
+            } else {
+                String::new()
+            };
+            ui.label(RichText::new(hint).small().weak());
+        });
+
+        if !input.is_empty() {
+            return Some(TerminalEvent::Input(input));
+        }
+        if self.sent_size != Some((cols, rows)) {
+            self.sent_size = Some((cols, rows));
+            return Some(TerminalEvent::Resize(cols, rows));
+        }
+        None
+    }
+
+    /// Draws the visible rows, merging runs of cells with the same colors into one
+    /// text section.
+    fn paint(&self, ui: &Ui, rect: egui::Rect, font: &FontId, row_height: f32, focused: bool) {
+        let painter = ui.painter_at(rect);
+        let background = ui.visuals().extreme_bg_color;
+        let foreground = ui.visuals().text_color();
+        painter.rect_filled(rect, 2.0, background);
+
+        let screen = self.parser.screen();
+        let (rows, cols) = screen.size();
+        let cursor = (!screen.hide_cursor() && focused && screen.scrollback() == 0).then(|| screen.cursor_position());
+
+        for row in 0..rows {
+            let mut job = LayoutJob::default();
+            let mut run = String::new();
+            let mut run_format: Option<TextFormat> = None;
+            for col in 0..cols {
+                let Some(cell) = screen.cell(row, col) else { continue };
+                if cell.is_wide_continuation() {
+                    continue;
+                }
+
+                let mut fg = match cell.fgcolor() {
+                    vt100::Color::Idx(i) if cell.bold() && i < 8 => ANSI_COLORS[usize::from(i) + 8],
+                    color => cell_color(color).unwrap_or(foreground),
+                };
+                let mut bg = cell_color(cell.bgcolor()).unwrap_or(Color32::TRANSPARENT);
+                if cell.inverse() != (cursor == Some((row, col))) {
+                    let swapped_fg = if bg == Color32::TRANSPARENT { background } else { bg };
+                    (fg, bg) = (swapped_fg, fg);
+                }
+                let format = TextFormat { font_id: font.clone(), color: fg, background: bg, ..Default::default() };
+
+                if run_format.as_ref() != Some(&format) {
+                    if let Some(previous) = run_format.replace(format) {
+                        job.append(&std::mem::take(&mut run), 0.0, previous);
+                    }
+                }
+                let contents = cell.contents();
+                run.push_str(if contents.is_empty() { " " } else { contents.as_str() });
+            }
+            if let Some(format) = run_format {
+                job.append(&run, 0.0, format);
+            }
+
+            let galley = ui.fonts(|f| f.layout_job(job));
+            painter.galley(rect.min + egui::vec2(0.0, f32::from(row) * row_height), galley, foreground);
+        }
+    }
+}
+
+fn cell_color(color: vt100::Color) -> Option<Color32> {
+    // Levels of the 6x6x6 color cube in the 256-color palette
+    let level = |v: u8| if v == 0 { 0 } else { 55 + v * 40 };
+    match color {
+        vt100::Color::Default => None,
+        vt100::Color::Idx(i) if i < 16 => Some(ANSI_COLORS[usize::from(i)]),
+        vt100::Color::Idx(i) if i < 232 => {
+            let i = i - 16;
+            Some(Color32::from_rgb(level(i / 36), level(i / 6 % 6), level(i % 6)))
+        }
+        vt100::Color::Idx(i) => {
+            let gray = 8 + (i - 232) * 10;
+            Some(Color32::from_gray(gray))
+        }
+        vt100::Color::Rgb(r, g, b) => Some(Color32::from_rgb(r, g, b)),
+    }
+}
+
+/// Appends the bytes a terminal would send for `event`.
+fn encode_event(event: &egui::Event, app_cursor: bool, out: &mut Vec<u8>) {
+    match event {
+        egui::Event::Text(text) => out.extend_from_slice(text.as_bytes()),
+        egui::Event::Paste(text) => out.extend_from_slice(text.replace("\r\n", "\r").replace('\n', "\r").as_bytes()),
+        // Ctrl+C and Ctrl+X arrive as clipboard commands on Linux and Windows
+        egui::Event::Copy => out.push(0x03),
+        egui::Event::Cut => out.push(0x18),
+        egui::Event::Key { key, pressed: true, modifiers, .. } => {
+            if let Some(bytes) = key_bytes(*key, *modifiers, app_cursor) {
+                out.extend_from_slice(&bytes);
+            }
+        }
+        _ => {}
+    }
+}
+
+/// Escape sequence for a key that does not produce text. Cursor keys use the SS3
+/// form when the program has switched the terminal to application cursor mode.
+fn key_bytes(key: Key, modifiers: Modifiers, app_cursor: bool) -> Option<Vec<u8>> {
+    let cursor = |c: u8| Some(vec![0x1b, if app_cursor { b'O' } else { b'[' }, c]);
+    match key {
+        Key::Enter => Some(vec![b'\r']),
+        Key::Backspace => Some(vec![0x7f]),
+        Key::Tab if modifiers.shift => Some(b"\x1b[Z".to_vec()),
+        Key::Tab => Some(vec![b'\t']),
+        Key::Escape => Some(vec![0x1b]),
+        Key::ArrowUp => cursor(b'A'),
+        Key::ArrowDown => cursor(b'B'),
+        Key::ArrowRight => cursor(b'C'),
+        Key::ArrowLeft => cursor(b'D'),
+        Key::Home => cursor(b'H'),
+        Key::End => cursor(b'F'),
+        Key::Insert => Some(b"\x1b[2~".to_vec()),
+        Key::Delete => Some(b"\x1b[3~".to_vec()),
+        Key::PageUp => Some(b"\x1b[5~".to_vec()),
+        Key::PageDown => Some(b"\x1b[6~".to_vec()),
+        // Text events are not sent while Ctrl is held, so control characters come from the key
+        _ if modifiers.ctrl && !modifiers.alt => match key.name().as_bytes() {
+            [c @ b'A'..=b'Z'] => Some(vec![c - b'A' + 1]),
+            _ => None,
+        },
+        _ => None,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn cursor_keys_follow_application_mode() {
+        assert_eq!(key_bytes(Key::ArrowUp, Modifiers::NONE, false), Some(b"\x1b[A".to_vec()));
+        assert_eq!(key_bytes(Key::ArrowUp, Modifiers::NONE, true), Some(b"\x1bOA".to_vec()));
+        assert_eq!(key_bytes(Key::PageDown, Modifiers::NONE, true), Some(b"\x1b[6~".to_vec()));
+    }
+
+    #[test]
+    fn ctrl_letters_become_control_characters() {
+        assert_eq!(key_bytes(Key::D, Modifiers::CTRL, false), Some(vec![0x04]));
+        assert_eq!(key_bytes(Key::L, Modifiers::CTRL, false), Some(vec![0x0c]));
+        assert_eq!(key_bytes(Key::D, Modifiers::NONE, false), None);
+
+        let mut out = Vec::new();
+        encode_event(&egui::Event::Copy, false, &mut out);
+        encode_event(&egui::Event::Paste("ls\nexit\n".to_string()), false, &mut out);
+        assert_eq!(out, b"\x03ls\rexit\r");
+    }
+}
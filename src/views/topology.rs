@@ -0,0 +1,267 @@
+use crate::k8s::{ResourceKind, Topology, TopologyNode};
+use crate::views::common::*;
+use egui::{Color32, Pos2, Rect, RichText, Stroke, Ui, Vec2};
+use std::collections::HashMap;
+
+const NODE_SIZE: Vec2 = Vec2::new(190.0, 38.0);
+const COLUMN_SPACING: f32 = 250.0;
+const ROW_SPACING: f32 = 48.0;
+const GROUP_SPACING: f32 = 28.0;
+
+#[derive(Default)]
+pub struct TopologyView {
+    pub search_filter: String,
+    /// Hide groups in which every object is healthy.
+    pub unhealthy_only: bool,
+    selected: Option<usize>,
+}
+
+/// Left-to-right column of a kind: routing, then controllers, then what they own.
+fn column(kind: ResourceKind) -> usize {
+    match kind {
+        ResourceKind::Ingress => 0,
+        ResourceKind::Service
+        | ResourceKind::Deployment
+        | ResourceKind::StatefulSet
+        | ResourceKind::DaemonSet
+        | ResourceKind::CronJob => 1,
+        ResourceKind::ReplicaSet | ResourceKind::Job => 2,
+        _ => 3,
+    }
+}
+
+/// Connected groups of nodes (one per app, roughly), each sorted by column.
+fn groups(topology: &Topology) -> Vec<Vec<usize>> {
+    let mut parent: Vec<usize> = (0..topology.nodes.len()).collect();
+    fn find(parent: &mut [usize], i: usize) -> usize {
+        let mut root = i;
+        while parent[root] != root {
+            root = parent[root];
+        }
+        parent[i] = root;
+        root
+    }
+    for &(a, b) in &topology.edges {
+        let (ra, rb) = (find(&mut parent, a), find(&mut parent, b));
+        parent[ra] = rb;
+    }
+
+    let mut by_root: HashMap<usize, Vec<usize>> = HashMap::new();
+    for i in 0..topology.nodes.len() {
+        let root = find(&mut parent, i);
+        by_root.entry(root).or_default().push(i);
+    }
+
+    let mut groups: Vec<Vec<usize>> = by_root.into_values().collect();
+    let key = |i: &usize| {
+        let node = &topology.nodes[*i];
+        (column(node.kind), node.namespace.clone(), node.name.clone())
+    };
+    for group in &mut groups {
+        group.sort_by_key(key);
+    }
+    groups.sort_by_key(|g| key(&g[0]));
+    groups
+}
+
+/// Positions of a group's nodes relative to its top-left corner, and the group's height.
+/// Each column after the first is ordered by where its parents sit, to keep edges short.
+fn layout(topology: &Topology, group: &[usize]) -> (HashMap<usize, Pos2>, f32) {
+    let mut positions: HashMap<usize, Pos2> = HashMap::new();
+    let mut rows = 0;
+
+    for col in 0..4 {
+        let mut members: Vec<(f32, usize)> = group
+            .iter()
+            .filter(|i| column(topology.nodes[**i].kind) == col)
+            .map(|i| {
+                let parents: Vec<f32> = topology
+                    .edges
+                    .iter()
+                    .filter(|(_, to)| to == i)
+                    .filter_map(|(from, _)| positions.get(from).map(|p| p.y))
+                    .collect();
+                let order = if parents.is_empty() {
+                    f32::MAX
+                } else {
+                    parents.iter().sum::<f32>() / parents.len() as f32
+                };
+                (order, *i)
+            })
+            .collect();
+        // `group` is sorted by name, and the sort is stable, so ties keep that order
+        members.sort_by(|a, b| a.0.total_cmp(&b.0));
+
+        for (row, (_, i)) in members.iter().enumerate() {
+            positions.insert(*i, Pos2::new(col as f32 * COLUMN_SPACING, row as f32 * ROW_SPACING));
+        }
+        rows = rows.max(members.len());
+    }
+
+    (positions, rows as f32 * ROW_SPACING)
+}
+
+fn matches(node: &TopologyNode, filter: &str) -> bool {
+    node.name.to_lowercase().contains(filter) || node.kind.as_str().to_lowercase().contains(filter)
+}
+
+impl TopologyView {
+    pub fn show(&mut self, ui: &mut Ui, topology: &Topology, loading: bool, error: Option<&str>) {
+        ui.horizontal(|ui| {
+            section_header(ui, "Topology");
+            ui.with_layout(egui::Layout::right_to_left(egui::Align::Center), |ui| {
+                search_bar(ui, &mut self.search_filter, "Search objects...");
+                ui.checkbox(&mut self.unhealthy_only, "Unhealthy only");
+            });
+        });
+
+        if loading {
+            loading_spinner(ui);
+            return;
+        }
+
+        if let Some(err) = error {
+            error_label(ui, err);
+            return;
+        }
+
+        let filter = self.search_filter.to_lowercase();
+        let groups: Vec<Vec<usize>> = groups(topology)
+            .into_iter()
+            .filter(|g| !self.unhealthy_only || g.iter().any(|i| !topology.nodes[*i].healthy))
+            .filter(|g| filter.is_empty() || g.iter().any(|i| matches(&topology.nodes[*i], &filter)))
+            .collect();
+
+        if groups.is_empty() {
+            empty_state(ui, "No objects found");
+            return;
+        }
+
+        self.show_selected(ui, topology);
+        ui.label(
+            RichText::new("Ingress → Service → Pod, and controller → ReplicaSet/Job → Pod. Red outlines mark unhealthy objects.")
+                .small()
+                .weak(),
+        );
+        ui.add_space(4.0);
+
+        egui::ScrollArea::both().auto_shrink([false, false]).show(ui, |ui| {
+            let layouts: Vec<_> = groups.iter().map(|g| layout(topology, g)).collect();
+            let height: f32 = layouts.iter().map(|(_, h)| h + GROUP_SPACING).sum();
+            let width = 3.0 * COLUMN_SPACING + NODE_SIZE.x;
+            let (canvas, _) = ui.allocate_exact_size(Vec2::new(width + 16.0, height), egui::Sense::hover());
+
+            // Absolute node rectangles, group by group
+            let mut rects: HashMap<usize, Rect> = HashMap::new();
+            let mut top = canvas.top();
+            for (positions, group_height) in &layouts {
+                for (i, pos) in positions {
+                    let min = Pos2::new(canvas.left() + 8.0 + pos.x, top + pos.y);
+                    rects.insert(*i, Rect::from_min_size(min, NODE_SIZE));
+                }
+                top += group_height + GROUP_SPACING;
+            }
+
+            let pointer = ui.input(|i| i.pointer.hover_pos());
+            let hovered = pointer.and_then(|p| rects.iter().find(|(_, r)| r.contains(p)).map(|(i, _)| *i));
+            let focus = hovered.or(self.selected);
+
+            let painter = ui.painter();
+            for &(from, to) in &topology.edges {
+                let (Some(a), Some(b)) = (rects.get(&from), rects.get(&to)) else {
+                    continue;
+                };
+                let highlighted = focus.is_some_and(|f| f == from || f == to);
+                let stroke = if highlighted {
+                    Stroke::new(2.0, ui.visuals().selection.bg_fill)
+                } else {
+                    Stroke::new(1.0, ui.visuals().weak_text_color())
+                };
+                let start = a.right_center();
+                let end = b.left_center();
+                let bend = (end.x - start.x) / 2.0;
+                painter.add(egui::epaint::CubicBezierShape::from_points_stroke(
+                    [start, start + Vec2::new(bend, 0.0), end - Vec2::new(bend, 0.0), end],
+                    false,
+                    Color32::TRANSPARENT,
+                    stroke,
+                ));
+            }
+
+            for (i, rect) in &rects {
+                let node = &topology.nodes[*i];
+                let dimmed = !filter.is_empty() && !matches(node, &filter);
+                let outline = if !node.healthy {
+                    Stroke::new(2.0, Color32::from_rgb(239, 68, 68))
+                } else if Some(*i) == self.selected {
+                    Stroke::new(2.0, ui.visuals().selection.bg_fill)
+                } else {
+                    ui.visuals().widgets.noninteractive.bg_stroke
+                };
+                let fill = if Some(*i) == hovered {
+                    ui.visuals().widgets.hovered.bg_fill
+                } else {
+                    ui.visuals().faint_bg_color
+                };
+                painter.rect(*rect, 4.0, fill, outline);
+
+                let color = status_color(&node.status);
+                painter.circle_filled(Pos2::new(rect.left() + 10.0, rect.center().y), 5.0, color);
+
+                let text_color = if dimmed { ui.visuals().weak_text_color() } else { ui.visuals().text_color() };
+                painter.text(
+                    Pos2::new(rect.left() + 22.0, rect.top() + 4.0),
+                    egui::Align2::LEFT_TOP,
+                    format!("{} · {}", node.kind.as_str(), node.status),
+                    egui::FontId::proportional(10.0),
+                    ui.visuals().weak_text_color(),
+                );
+                painter.text(
+                    Pos2::new(rect.left() + 22.0, rect.bottom() - 4.0),
+                    egui::Align2::LEFT_BOTTOM,
+                    truncate_string(&node.name, 26),
+                    egui::FontId::proportional(13.0),
+                    text_color,
+                );
+            }
+
+            if let Some(i) = hovered {
+                let node = &topology.nodes[i];
+                egui::show_tooltip_at_pointer(ui.ctx(), ui.layer_id(), egui::Id::new("topology_tooltip"), |ui| {
+                    ui.strong(format!("{} {}", node.kind.as_str(), node.name));
+                    ui.label(format!("Namespace: {}", node.namespace));
+                    ui.label(format!("Status: {}", node.status));
+                    ui.label(&node.detail);
+                });
+                if ui.input(|i| i.pointer.primary_clicked()) {
+                    self.selected = Some(i);
+                }
+            }
+        });
+    }
+
+    fn show_selected(&mut self, ui: &mut Ui, topology: &Topology) {
+        let Some(node) = self.selected.and_then(|i| topology.nodes.get(i)) else {
+            self.selected = None;
+            return;
+        };
+
+        let mut clear = false;
+        ui.horizontal(|ui| {
+            status_badge(ui, &node.status, status_color(&node.status));
+            ui.strong(format!("{} {}/{}", node.kind.as_str(), node.namespace, node.name));
+            ui.label(RichText::new(&node.detail).weak());
+            if ui.small_button("Clear").clicked() {
+                clear = true;
+            }
+        });
+        if clear {
+            self.selected = None;
+        }
+    }
+
+    /// Drops the selection; node indices are not stable across reloads.
+    pub fn set_loaded(&mut self) {
+        self.selected = None;
+    }
+}
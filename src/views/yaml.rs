@@ -0,0 +1,273 @@
+use crate::k8s::{ApplyError, ResourceKind, ResourceRef};
+use crate::views::common::*;
+use crate::views::safety::{changes_allowed, GuardedAction, UnconfirmedChange};
+use egui::text::LayoutJob;
+use egui::{Color32, FontId, RichText, ScrollArea, TextFormat, Ui};
+use similar::{ChangeTag, TextDiff};
+
+#[derive(Clone, Copy, PartialEq, Default)]
+pub enum DetailTab {
+    #[default]
+    Overview,
+    Yaml,
+}
+
+pub fn detail_tabs(ui: &mut Ui, tab: &mut DetailTab) {
+    ui.horizontal(|ui| {
+        ui.selectable_value(tab, DetailTab::Overview, "Overview");
+        ui.selectable_value(tab, DetailTab::Yaml, "YAML");
+    });
+    ui.separator();
+}
+
+#[derive(Clone)]
+pub enum YamlAction {
+    Load(ResourceRef),
+    Diff(ResourceRef, String),
+    Apply(ResourceRef, String, bool),
+}
+
+impl GuardedAction for YamlAction {
+    fn unconfirmed_change(&self) -> Option<UnconfirmedChange> {
+        match self {
+            YamlAction::Apply(target, _, force) => {
+                let kind = target.kind.as_str().to_lowercase();
+                let message = if *force {
+                    format!("Force apply the edited YAML to {} '{}', taking over fields owned by other managers?", kind, target.name)
+                } else {
+                    format!("Apply the edited YAML to {} '{}'?", kind, target.name)
+                };
+                Some(UnconfirmedChange::new(message, &target.name))
+            }
+            YamlAction::Load(_) | YamlAction::Diff(..) => None,
+        }
+    }
+}
+
+/// Full-object YAML tab shared by the detail windows: view, edit, diff against
+/// the live object, and server-side apply.
+#[derive(Default)]
+pub struct YamlEditor {
+    target: Option<ResourceRef>,
+    text: String,
+    loading: bool,
+    error: Option<String>,
+    conflict: Option<String>,
+    diff: Option<Vec<(ChangeTag, String)>>,
+}
+
+impl YamlEditor {
+    pub fn show(&mut self, ui: &mut Ui, target: ResourceRef) -> Option<YamlAction> {
+        let mut action = None;
+
+        if self.target.as_ref() != Some(&target) {
+            *self = Self {
+                target: Some(target.clone()),
+                loading: true,
+                ..Default::default()
+            };
+            return Some(YamlAction::Load(target));
+        }
+
+        let read_only = target.kind == ResourceKind::Secret;
+
+        ui.horizontal(|ui| {
+            if ui.button("Reload").clicked() {
+                self.loading = true;
+                action = Some(YamlAction::Load(target.clone()));
+            }
+            if !read_only {
+                if self.diff.is_some() {
+                    if ui.button("Back to editor").clicked() {
+                        self.diff = None;
+                    }
+                } else if ui.button("Diff").on_hover_text("Server-side dry run against the live object").clicked() {
+                    self.loading = true;
+                    action = Some(YamlAction::Diff(target.clone(), self.text.clone()));
+                }
+                if ui.add_enabled_ui(changes_allowed(ui), |ui| primary_button(ui, "Apply")).inner {
+                    self.loading = true;
+                    action = Some(YamlAction::Apply(target.clone(), self.text.clone(), false));
+                }
+            }
+        });
+
+        if read_only {
+            ui.label(RichText::new("Secret values are redacted; this view is read-only.").small().weak());
+        }
+
+        if let Some(conflict) = self.conflict.clone() {
+            egui::Frame::group(ui.style()).show(ui, |ui| {
+                ui.label(RichText::new("Apply conflicts with fields owned by another manager:").strong());
+                ui.label(RichText::new(&conflict).color(Color32::from_rgb(234, 179, 8)));
+                ui.horizontal(|ui| {
+                    if ui.button("Dismiss").clicked() {
+                        self.conflict = None;
+                    }
+                    if ui.add_enabled_ui(changes_allowed(ui), |ui| danger_button(ui, "Force Apply")).inner {
+                        self.loading = true;
+                        self.conflict = None;
+                        action = Some(YamlAction::Apply(target.clone(), self.text.clone(), true));
+                    }
+                });
+            });
+        }
+
+        if let Some(err) = &self.error {
+            error_label(ui, err);
+        }
+
+        ui.separator();
+
+        if self.loading {
+            loading_spinner(ui);
+            return action;
+        }
+
+        if let Some(diff) = &self.diff {
+            show_diff(ui, diff);
+            return action;
+        }
+
+        let mut layouter = |ui: &Ui, text: &str, wrap_width: f32| {
+            let mut job = highlight_yaml(ui, text);
+            job.wrap.max_width = wrap_width;
+            ui.fonts(|f| f.layout_job(job))
+        };
+
+        ScrollArea::both()
+            .auto_shrink([false, false])
+            .show(ui, |ui| {
+                if read_only {
+                    ui.add(
+                        egui::TextEdit::multiline(&mut self.text.as_str())
+                            .code_editor()
+                            .desired_width(f32::INFINITY)
+                            .layouter(&mut layouter),
+                    );
+                } else {
+                    ui.add(
+                        egui::TextEdit::multiline(&mut self.text)
+                            .code_editor()
+                            .desired_width(f32::INFINITY)
+                            .layouter(&mut layouter),
+                    );
+                }
+            });
+
+        action
+    }
+
+    pub fn set_loaded(&mut self, result: Result<String, String>) {
+        self.loading = false;
+        self.diff = None;
+        match result {
+            Ok(text) => {
+                self.text = text;
+                self.error = None;
+            }
+            Err(e) => self.error = Some(e),
+        }
+    }
+
+    pub fn set_diff(&mut self, result: Result<(String, String), ApplyError>) {
+        self.loading = false;
+        match result {
+            Ok((live, applied)) => {
+                self.error = None;
+                self.diff = Some(line_diff(&live, &applied));
+            }
+            Err(e) => self.set_apply_error(e),
+        }
+    }
+
+    /// Records a failed apply; on success the caller reloads the object.
+    pub fn set_apply_error(&mut self, e: ApplyError) {
+        self.loading = false;
+        match e {
+            ApplyError::Conflict(msg) => self.conflict = Some(msg),
+            ApplyError::Failed(msg) => self.error = Some(msg),
+        }
+    }
+}
+
+pub fn line_diff(old: &str, new: &str) -> Vec<(ChangeTag, String)> {
+    TextDiff::from_lines(old, new)
+        .iter_all_changes()
+        .map(|c| (c.tag(), c.value().trim_end_matches('\n').to_string()))
+        .collect()
+}
+
+pub fn show_diff(ui: &mut Ui, diff: &[(ChangeTag, String)]) {
+    if diff.iter().all(|(tag, _)| *tag == ChangeTag::Equal) {
+        empty_state(ui, "No changes");
+        return;
+    }
+
+    ScrollArea::both()
+        .auto_shrink([false, false])
+        .show(ui, |ui| {
+            for (tag, line) in diff {
+                let (prefix, color) = match tag {
+                    ChangeTag::Delete => ("-", Color32::from_rgb(239, 68, 68)),
+                    ChangeTag::Insert => ("+", Color32::from_rgb(34, 197, 94)),
+                    ChangeTag::Equal => (" ", ui.visuals().weak_text_color()),
+                };
+                ui.label(RichText::new(format!("{} {}", prefix, line)).monospace().color(color));
+            }
+        });
+}
+
+/// Minimal YAML highlighter: keys, comments, list markers and scalar values.
+pub fn highlight_yaml(ui: &Ui, text: &str) -> LayoutJob {
+    let font = FontId::monospace(13.0);
+    let dark = ui.visuals().dark_mode;
+    let plain = ui.visuals().text_color();
+    let key = if dark { Color32::from_rgb(96, 165, 250) } else { Color32::from_rgb(29, 78, 216) };
+    let string = if dark { Color32::from_rgb(134, 239, 172) } else { Color32::from_rgb(21, 128, 61) };
+    let literal = if dark { Color32::from_rgb(251, 191, 36) } else { Color32::from_rgb(180, 83, 9) };
+    let comment = Color32::GRAY;
+
+    let mut job = LayoutJob::default();
+    let append = |job: &mut LayoutJob, s: &str, color: Color32| {
+        job.append(s, 0.0, TextFormat::simple(font.clone(), color));
+    };
+
+    for line in text.split_inclusive('\n') {
+        let trimmed = line.trim_start();
+        let indent = &line[..line.len() - trimmed.len()];
+        append(&mut job, indent, plain);
+
+        if trimmed.starts_with('#') {
+            append(&mut job, trimmed, comment);
+            continue;
+        }
+
+        let mut rest = trimmed;
+        if let Some(after) = rest.strip_prefix("- ") {
+            append(&mut job, "- ", comment);
+            rest = after;
+        }
+
+        let value = match rest.find(": ").or_else(|| rest.trim_end().ends_with(':').then(|| rest.trim_end().len() - 1)) {
+            Some(idx) if !rest.starts_with('"') && !rest.starts_with('\'') => {
+                append(&mut job, &rest[..idx], key);
+                append(&mut job, ":", plain);
+                &rest[idx + 1..]
+            }
+            _ => rest,
+        };
+
+        let scalar = value.trim();
+        let color = if scalar.starts_with('"') || scalar.starts_with('\'') {
+            string
+        } else if scalar.parse::<f64>().is_ok() || matches!(scalar, "true" | "false" | "null" | "~") {
+            literal
+        } else {
+            plain
+        };
+        append(&mut job, value, color);
+    }
+
+    job
+}